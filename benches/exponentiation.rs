@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use encryption_demo::rsa::exponentiation::{mod_pow_with, ExpMethod, DEFAULT_WINDOW_BITS};
+use encryption_demo::rsa::math_utils::mod_pow;
+
+/// p = 4294967291, q = 4294967279: the largest two-prime modulus the demo supports
+const MODULUS: u64 = 18446743979220271189;
+/// Private exponent for e = 3 with that modulus
+const PRIVATE_EXPONENT: u64 = 12297829313753557747;
+
+fn bench_exponent(c: &mut Criterion, group_name: &str, exp: u64) {
+    let mut group = c.benchmark_group(group_name);
+    group.bench_function("mod_pow", |b| b.iter(|| mod_pow(black_box(5), black_box(exp), black_box(MODULUS))));
+    for method in ExpMethod::all(DEFAULT_WINDOW_BITS) {
+        group.bench_with_input(BenchmarkId::from_parameter(method.name()), &method, |b, &method| {
+            b.iter(|| mod_pow_with(black_box(5), black_box(exp), black_box(MODULUS), method))
+        });
+    }
+    group.finish();
+}
+
+fn bench_window_sizes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sliding_window_bits");
+    for k in 1..=6 {
+        group.bench_with_input(BenchmarkId::from_parameter(k), &k, |b, &k| {
+            b.iter(|| mod_pow_with(black_box(5), black_box(PRIVATE_EXPONENT), black_box(MODULUS), ExpMethod::SlidingWindow(k)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_methods(c: &mut Criterion) {
+    bench_exponent(c, "private_exponent_64_bit", PRIVATE_EXPONENT);
+    bench_exponent(c, "public_exponent_65537", 65537);
+    bench_window_sizes(c);
+}
+
+criterion_group!(benches, bench_methods);
+criterion_main!(benches);
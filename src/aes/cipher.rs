@@ -0,0 +1,181 @@
+// ============================================================================
+// AES Block Cipher (FIPS 197)
+// ============================================================================
+// A 16-byte state, arranged as a 4×4 column-major matrix, goes through
+// 10, 12 or 14 rounds for 128, 192 or 256-bit keys:
+//
+//   SubBytes    each byte through the S-box (inverse in GF(2^8), then affine)
+//   ShiftRows   row r rotates left by r positions
+//   MixColumns  each column multiplied by a fixed polynomial over GF(2^8)
+//   AddRoundKey XOR with the round key from the key schedule
+//
+// The last round skips MixColumns. Decryption runs the inverse steps in
+// reverse order. This table-free byte version is for test vectors and
+// teaching, not constant-time production use.
+
+pub const BLOCK_BYTES: usize = 16;
+
+pub type Block = [u8; BLOCK_BYTES];
+
+/// SubBytes table: multiplicative inverse in GF(2^8), then the affine map with 0x63
+pub const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// InvSubBytes table, derived from SBOX at compile time
+pub const INV_SBOX: [u8; 256] = invert(&SBOX);
+
+/// Round constants x^(i-1) in GF(2^8) for the key schedule
+const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+const fn invert(table: &[u8; 256]) -> [u8; 256] {
+    let mut inverse = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        inverse[table[i] as usize] = i as u8;
+        i += 1;
+    }
+    inverse
+}
+
+/// Multiply by x in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1
+fn xtime(b: u8) -> u8 {
+    (b << 1) ^ if b & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+/// Multiply two elements of GF(2^8)
+pub fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    product
+}
+
+fn sub_bytes(state: &mut Block, table: &[u8; 256]) {
+    state.iter_mut().for_each(|b| *b = table[*b as usize]);
+}
+
+/// Byte (row r, column c) lives at index r + 4c
+fn shift_rows(state: &mut Block) {
+    let old = *state;
+    for r in 1..4 {
+        for c in 0..4 {
+            state[r + 4 * c] = old[r + 4 * ((c + r) % 4)];
+        }
+    }
+}
+
+fn inv_shift_rows(state: &mut Block) {
+    let old = *state;
+    for r in 1..4 {
+        for c in 0..4 {
+            state[r + 4 * ((c + r) % 4)] = old[r + 4 * c];
+        }
+    }
+}
+
+/// Multiply every column by the circulant matrix whose first row is `coefficients`
+fn mix_columns_with(state: &mut Block, coefficients: [u8; 4]) {
+    for column in state.chunks_exact_mut(4) {
+        let a: [u8; 4] = column.try_into().expect("4-byte column");
+        for (r, out) in column.iter_mut().enumerate() {
+            *out = (0..4).fold(0, |acc, i| acc ^ gf_mul(coefficients[(4 + i - r) % 4], a[i]));
+        }
+    }
+}
+
+fn add_round_key(state: &mut Block, round_key: &Block) {
+    state.iter_mut().zip(round_key).for_each(|(b, k)| *b ^= k);
+}
+
+/// An expanded AES key: one 16-byte round key per round plus the initial one
+#[derive(Debug, Clone)]
+pub struct Aes {
+    round_keys: Vec<Block>,
+}
+
+impl Aes {
+    /// Expand a 16, 24 or 32-byte key (FIPS 197 §5.2)
+    pub fn new(key: &[u8]) -> Result<Aes, String> {
+        let nk = match key.len() {
+            16 | 24 | 32 => key.len() / 4,
+            n => return Err(format!("AES key must be 16, 24 or 32 bytes, got {}", n)),
+        };
+        let rounds = nk + 6;
+        let mut words: Vec<[u8; 4]> = key.chunks_exact(4).map(|w| w.try_into().expect("4-byte word")).collect();
+        for i in nk..4 * (rounds + 1) {
+            let mut temp = words[i - 1];
+            if i % nk == 0 {
+                temp.rotate_left(1);
+                temp = temp.map(|b| SBOX[b as usize]);
+                temp[0] ^= RCON[i / nk - 1];
+            } else if nk > 6 && i % nk == 4 {
+                temp = temp.map(|b| SBOX[b as usize]);
+            }
+            let previous = words[i - nk];
+            words.push(std::array::from_fn(|j| previous[j] ^ temp[j]));
+        }
+        let round_keys = words
+            .chunks_exact(4)
+            .map(|four| std::array::from_fn(|j| four[j / 4][j % 4]))
+            .collect();
+        Ok(Aes { round_keys })
+    }
+
+    /// 10, 12 or 14
+    pub fn rounds(&self) -> usize {
+        self.round_keys.len() - 1
+    }
+
+    pub fn round_key(&self, round: usize) -> &Block {
+        &self.round_keys[round]
+    }
+
+    pub fn encrypt_block(&self, block: &Block) -> Block {
+        let mut state = *block;
+        add_round_key(&mut state, &self.round_keys[0]);
+        for round in 1..=self.rounds() {
+            sub_bytes(&mut state, &SBOX);
+            shift_rows(&mut state);
+            if round != self.rounds() {
+                mix_columns_with(&mut state, [0x02, 0x03, 0x01, 0x01]);
+            }
+            add_round_key(&mut state, &self.round_keys[round]);
+        }
+        state
+    }
+
+    pub fn decrypt_block(&self, block: &Block) -> Block {
+        let mut state = *block;
+        add_round_key(&mut state, &self.round_keys[self.rounds()]);
+        for round in (0..self.rounds()).rev() {
+            inv_shift_rows(&mut state);
+            sub_bytes(&mut state, &INV_SBOX);
+            add_round_key(&mut state, &self.round_keys[round]);
+            if round != 0 {
+                mix_columns_with(&mut state, [0x0e, 0x0b, 0x0d, 0x09]);
+            }
+        }
+        state
+    }
+}
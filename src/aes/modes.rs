@@ -0,0 +1,50 @@
+// ============================================================================
+// ECB and CBC Modes
+// ============================================================================
+// ECB encrypts each block on its own, so equal plaintext blocks give equal
+// ciphertext blocks. CBC XORs each plaintext block with the previous
+// ciphertext block (the IV for the first) before encrypting. Neither pads:
+// the input must be a whole number of blocks, as in the NIST test vectors.
+
+use super::cipher::{Aes, Block, BLOCK_BYTES};
+
+fn blocks(data: &[u8]) -> Result<impl Iterator<Item = Block> + '_, String> {
+    if !data.len().is_multiple_of(BLOCK_BYTES) {
+        return Err(format!("Data must be a multiple of {} bytes, got {}", BLOCK_BYTES, data.len()));
+    }
+    Ok(data.chunks_exact(BLOCK_BYTES).map(|chunk| chunk.try_into().expect("16-byte block")))
+}
+
+fn to_block(iv: &[u8]) -> Result<Block, String> {
+    iv.try_into().map_err(|_| format!("IV must be {} bytes, got {}", BLOCK_BYTES, iv.len()))
+}
+
+pub fn ecb_encrypt(aes: &Aes, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    Ok(blocks(plaintext)?.flat_map(|block| aes.encrypt_block(&block)).collect())
+}
+
+pub fn ecb_decrypt(aes: &Aes, ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+    Ok(blocks(ciphertext)?.flat_map(|block| aes.decrypt_block(&block)).collect())
+}
+
+pub fn cbc_encrypt(aes: &Aes, iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let mut previous = to_block(iv)?;
+    let mut output = Vec::with_capacity(plaintext.len());
+    for block in blocks(plaintext)? {
+        let mixed: Block = std::array::from_fn(|i| block[i] ^ previous[i]);
+        previous = aes.encrypt_block(&mixed);
+        output.extend_from_slice(&previous);
+    }
+    Ok(output)
+}
+
+pub fn cbc_decrypt(aes: &Aes, iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+    let mut previous = to_block(iv)?;
+    let mut output = Vec::with_capacity(ciphertext.len());
+    for block in blocks(ciphertext)? {
+        let decrypted = aes.decrypt_block(&block);
+        output.extend((0..BLOCK_BYTES).map(|i| decrypted[i] ^ previous[i]));
+        previous = block;
+    }
+    Ok(output)
+}
@@ -0,0 +1,200 @@
+// ============================================================================
+// Avalanche Analysis (strict avalanche criterion)
+// ============================================================================
+// Flip one input bit, hash both messages and compare. For a good hash every
+// output bit flips with probability 1/2, independently of which input bit
+// changed, so the Hamming distance between digests follows Binomial(256, ½):
+// mean 128, standard deviation 8.
+//
+// Bits are numbered from the most significant bit of byte 0. Each sample is
+// a message of the same length: the one given, then random ones.
+
+use crate::common::random::DemoRng;
+use crate::sha256::compression::round_states;
+use crate::sha256::constants::INITIAL_HASH;
+use crate::sha256::hash::sha256_bytes;
+use crate::sha256::preprocessing::preprocess_message;
+use crate::sha256::types::Hash;
+
+pub const OUTPUT_BITS: usize = 256;
+
+/// Longest message analysed, so the work stays interactive
+pub const MAX_INPUT_BYTES: usize = 64;
+
+pub const MAX_SAMPLES: usize = 256;
+
+/// Rounds of the SHA-256 compression function
+pub const ROUNDS: usize = 64;
+
+/// Flip counts for every (input bit, output bit) pair plus the round-by-round spread
+#[derive(Debug, Clone)]
+pub struct AvalancheReport {
+    pub input_bits: usize,
+    pub samples: usize,
+    /// flip_counts[i][j]: how often output bit j flipped when input bit i was flipped
+    pub flip_counts: Vec<Vec<u32>>,
+    /// distance_histogram[d]: trials whose digests differed in d bits
+    pub distance_histogram: Vec<u64>,
+    /// Mean bits differing in each working variable a..h after each round, for flips in the first block
+    pub round_word_distance: Vec<[f64; 8]>,
+    /// Fewest state bits differing after each round, over the same flips
+    pub round_min_distance: Vec<u32>,
+}
+
+impl AvalancheReport {
+    /// Number of single-bit flips hashed
+    pub fn trials(&self) -> usize {
+        self.input_bits * self.samples
+    }
+
+    pub fn flip_probability(&self, input_bit: usize, output_bit: usize) -> f64 {
+        self.flip_counts[input_bit][output_bit] as f64 / self.samples as f64
+    }
+
+    /// How often each output bit flipped, over all input bits and samples
+    pub fn output_bit_probability(&self) -> Vec<f64> {
+        (0..OUTPUT_BITS)
+            .map(|j| self.flip_counts.iter().map(|row| row[j] as f64).sum::<f64>() / self.trials() as f64)
+            .collect()
+    }
+
+    pub fn mean_distance(&self) -> f64 {
+        let total: f64 = self.distance_histogram.iter().enumerate().map(|(d, &count)| d as f64 * count as f64).sum();
+        total / self.trials() as f64
+    }
+
+    pub fn distance_std_dev(&self) -> f64 {
+        let mean = self.mean_distance();
+        let total: f64 = self
+            .distance_histogram
+            .iter()
+            .enumerate()
+            .map(|(d, &count)| (d as f64 - mean).powi(2) * count as f64)
+            .sum();
+        (total / self.trials() as f64).sqrt()
+    }
+
+    /// Mean state bits (of 256) differing after each round
+    pub fn round_mean_distance(&self) -> Vec<f64> {
+        self.round_word_distance.iter().map(|words| words.iter().sum()).collect()
+    }
+
+    /// Flip probabilities averaged over blocks of `row_bits` input bits by `column_bits` output bits
+    ///
+    /// Shrinks the full matrix to something a terminal or a web page can draw.
+    pub fn heatmap(&self, row_bits: usize, column_bits: usize) -> Vec<Vec<f64>> {
+        let row_bits = row_bits.max(1);
+        let column_bits = column_bits.max(1);
+        self.flip_counts
+            .chunks(row_bits)
+            .map(|rows| {
+                (0..OUTPUT_BITS)
+                    .step_by(column_bits)
+                    .map(|start| {
+                        let end = (start + column_bits).min(OUTPUT_BITS);
+                        let flips: u64 = rows.iter().flat_map(|row| &row[start..end]).map(|&count| count as u64).sum();
+                        flips as f64 / (rows.len() * (end - start) * self.samples) as f64
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The full matrix: one row per input bit, one column per output bit
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("input_bit");
+        for j in 0..OUTPUT_BITS {
+            csv.push_str(&format!(",out_{}", j));
+        }
+        csv.push('\n');
+        for i in 0..self.input_bits {
+            csv.push_str(&i.to_string());
+            for j in 0..OUTPUT_BITS {
+                csv.push_str(&format!(",{:.4}", self.flip_probability(i, j)));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+pub fn flip_bit(message: &[u8], bit: usize) -> Vec<u8> {
+    let mut flipped = message.to_vec();
+    flipped[bit / 8] ^= 0x80 >> (bit % 8);
+    flipped
+}
+
+fn bit_is_set(bytes: &[u8], bit: usize) -> bool {
+    bytes[bit / 8] & (0x80 >> (bit % 8)) != 0
+}
+
+pub fn hamming_distance(a: &[u8], b: &[u8]) -> u32 {
+    a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+fn state_distance(a: &Hash, b: &Hash) -> [u32; 8] {
+    std::array::from_fn(|i| (a[i] ^ b[i]).count_ones())
+}
+
+pub fn validate_input(message: &[u8], samples: usize) -> Result<(), String> {
+    if message.is_empty() || message.len() > MAX_INPUT_BYTES {
+        return Err(format!("Message must be 1 to {} bytes, got {}", MAX_INPUT_BYTES, message.len()));
+    }
+    if samples == 0 || samples > MAX_SAMPLES {
+        return Err(format!("Samples must be between 1 and {}, got {}", MAX_SAMPLES, samples));
+    }
+    Ok(())
+}
+
+/// Flip every input bit of `samples` messages and record which output bits change
+pub fn analyze(message: &[u8], samples: usize, seed: u64) -> Result<AvalancheReport, String> {
+    validate_input(message, samples)?;
+    let input_bits = message.len() * 8;
+    // Bits past the first 64 bytes cannot reach the first block's rounds
+    let first_block_bits = input_bits.min(512);
+    let mut rng = DemoRng::new(seed);
+    let mut report = AvalancheReport {
+        input_bits,
+        samples,
+        flip_counts: vec![vec![0; OUTPUT_BITS]; input_bits],
+        distance_histogram: vec![0; OUTPUT_BITS + 1],
+        round_word_distance: vec![[0.0; 8]; ROUNDS],
+        round_min_distance: vec![OUTPUT_BITS as u32; ROUNDS],
+    };
+
+    for sample in 0..samples {
+        let mut base = message.to_vec();
+        if sample > 0 {
+            rng.fill_bytes(&mut base);
+        }
+        let digest = sha256_bytes(&base);
+        let base_rounds = round_states(INITIAL_HASH, &preprocess_message(&base).blocks[0]);
+
+        for bit in 0..input_bits {
+            let flipped = flip_bit(&base, bit);
+            let other = sha256_bytes(&flipped);
+            let difference: Vec<u8> = digest.iter().zip(other).map(|(x, y)| x ^ y).collect();
+            for (j, count) in report.flip_counts[bit].iter_mut().enumerate() {
+                *count += u32::from(bit_is_set(&difference, j));
+            }
+            report.distance_histogram[hamming_distance(&digest, &other) as usize] += 1;
+
+            if bit < first_block_bits {
+                let rounds = round_states(INITIAL_HASH, &preprocess_message(&flipped).blocks[0]);
+                for (t, (a, b)) in base_rounds.iter().zip(&rounds).enumerate() {
+                    let words = state_distance(a, b);
+                    for (total, distance) in report.round_word_distance[t].iter_mut().zip(words) {
+                        *total += distance as f64;
+                    }
+                    report.round_min_distance[t] = report.round_min_distance[t].min(words.iter().sum());
+                }
+            }
+        }
+    }
+
+    let round_trials = (first_block_bits * samples) as f64;
+    for words in &mut report.round_word_distance {
+        words.iter_mut().for_each(|total| *total /= round_trials);
+    }
+    Ok(report)
+}
@@ -0,0 +1,241 @@
+// ============================================================================
+// Birthday Collisions on Truncated SHA-256
+// ============================================================================
+// Keeping only the first n bits of SHA-256 leaves N = 2^n possible outputs.
+// After k random inputs a collision is likely once k ≈ √N = 2^(n/2), and on
+// average the first one appears after √(πN/2) ≈ 1.25 · 2^(n/2) hashes.
+//
+//   Table:   store every hash until one repeats            memory ~2^(n/2)
+//   Floyd:   walk x → h(x) until the path loops (rho)      memory O(1)
+//   DP:      many walks, store only "distinguished" points memory ~2^(n/2-d)
+//
+// The walk inputs are the prefix followed by x in hex, so every collision is
+// between two printable messages.
+
+use std::collections::HashMap;
+
+use crate::common::random::DemoRng;
+use crate::sha256::hash::sha256_bytes;
+
+pub const MIN_BITS: u32 = 8;
+pub const MAX_BITS: u32 = 48;
+
+/// Largest table the table search may build (about 256 MiB with hash map overhead)
+pub const MAX_TABLE_ENTRIES: usize = 1 << 23;
+
+/// A walk longer than this many times 2^d is assumed stuck in a cycle without distinguished points
+const MAX_WALK_FACTOR: u64 = 20;
+
+/// SHA-256 cut to its first `bits` bits, over messages `prefix ‖ hex(x)`
+#[derive(Debug, Clone)]
+pub struct TruncatedSha256 {
+    pub bits: u32,
+    pub prefix: Vec<u8>,
+}
+
+impl TruncatedSha256 {
+    pub fn new(bits: u32, prefix: &[u8]) -> Result<TruncatedSha256, String> {
+        if !(MIN_BITS..=MAX_BITS).contains(&bits) {
+            return Err(format!("Truncation must be {} to {} bits, got {}", MIN_BITS, MAX_BITS, bits));
+        }
+        Ok(TruncatedSha256 { bits, prefix: prefix.to_vec() })
+    }
+
+    /// The first `bits` bits of SHA-256(data), as a number
+    pub fn hash(&self, data: &[u8]) -> u64 {
+        let digest = sha256_bytes(data);
+        let top = u64::from_be_bytes(digest[..8].try_into().expect("8 bytes"));
+        top >> (64 - self.bits)
+    }
+
+    /// The message for point x: the prefix, then x as fixed-width hex
+    pub fn encode(&self, x: u64) -> Vec<u8> {
+        let width = self.bits.div_ceil(4) as usize;
+        [self.prefix.as_slice(), format!("{:0width$x}", x, width = width).as_bytes()].concat()
+    }
+
+    /// One step of the walk: x → h(encode(x)), a map from n-bit values to n-bit values
+    pub fn step(&self, x: u64) -> u64 {
+        self.hash(&self.encode(x))
+    }
+
+    /// Outputs in the truncated space
+    pub fn space(&self) -> f64 {
+        2f64.powi(self.bits as i32)
+    }
+}
+
+/// Two different messages with the same truncated hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub first: Vec<u8>,
+    pub second: Vec<u8>,
+    pub hash: u64,
+}
+
+/// What a search found and what it cost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchOutcome {
+    pub collision: Option<Collision>,
+    /// Truncated hashes computed
+    pub hashes: u64,
+    /// Entries held in memory at the end
+    pub stored: usize,
+}
+
+/// Mean hashes before the first collision: √(π · 2^n / 2)
+pub fn expected_hashes(bits: u32) -> f64 {
+    (std::f64::consts::PI * 2f64.powi(bits as i32) / 2.0).sqrt()
+}
+
+/// Chance of at least one collision among k hashes: 1 − e^(−k(k−1) / 2N)
+pub fn collision_probability(k: f64, bits: u32) -> f64 {
+    1.0 - (-(k * (k - 1.0)) / (2.0 * 2f64.powi(bits as i32))).exp()
+}
+
+/// Distinguished bits that keep about 2^(n/4) points in memory
+pub fn default_distinguished_bits(bits: u32) -> u32 {
+    (bits / 4).max(1)
+}
+
+/// Distinguished points need 1 ≤ d < n/2, or a walk outlasts the expected 2^(n/2) hashes
+pub fn validate_distinguished_bits(bits: u32, distinguished_bits: u32) -> Result<(), String> {
+    if distinguished_bits == 0 || 2 * distinguished_bits >= bits {
+        return Err(format!(
+            "Distinguished bits must be at least 1 and below half of {} bits, got {}",
+            bits, distinguished_bits
+        ));
+    }
+    Ok(())
+}
+
+fn collision_between(h: &TruncatedSha256, x: u64, y: u64) -> Collision {
+    Collision { first: h.encode(x), second: h.encode(y), hash: h.step(x) }
+}
+
+/// Hash x = start, start + 1, … and remember each result until one repeats
+pub fn table_search(h: &TruncatedSha256, start: u64, max_hashes: u64) -> SearchOutcome {
+    let mut seen: HashMap<u64, u64> = HashMap::new();
+    let mut hashes = 0;
+    for x in (start..).take(max_hashes as usize) {
+        if seen.len() >= MAX_TABLE_ENTRIES {
+            break;
+        }
+        let value = h.step(x);
+        hashes += 1;
+        if let Some(&other) = seen.get(&value) {
+            return SearchOutcome { collision: Some(collision_between(h, other, x)), hashes, stored: seen.len() };
+        }
+        seen.insert(value, x);
+    }
+    SearchOutcome { collision: None, hashes, stored: seen.len() }
+}
+
+/// Floyd's tortoise and hare on the walk from `start`
+///
+/// The walk must loop because the space is finite. Where the tail joins the
+/// cycle, two different points map to the same value: a collision. If the
+/// start lies on the cycle there is no tail, so the next start is tried.
+pub fn floyd_search(h: &TruncatedSha256, start: u64, max_hashes: u64) -> SearchOutcome {
+    let mask = (1u64 << h.bits) - 1;
+    let mut hashes = 0u64;
+    let mut x0 = start & mask;
+
+    while hashes < max_hashes {
+        // Phase 1: the hare runs twice as fast until they meet on the cycle
+        let mut tortoise = h.step(x0);
+        let mut hare = h.step(tortoise);
+        hashes += 2;
+        while tortoise != hare {
+            if hashes >= max_hashes {
+                return SearchOutcome { collision: None, hashes, stored: 2 };
+            }
+            tortoise = h.step(tortoise);
+            hare = h.step(h.step(hare));
+            hashes += 3;
+        }
+
+        // Phase 2: restart the tortoise; both reach the cycle entry after the tail length
+        let mut tortoise = x0;
+        while tortoise != hare {
+            let (next_tortoise, next_hare) = (h.step(tortoise), h.step(hare));
+            hashes += 2;
+            if next_tortoise == next_hare {
+                return SearchOutcome { collision: Some(collision_between(h, tortoise, hare)), hashes, stored: 2 };
+            }
+            tortoise = next_tortoise;
+            hare = next_hare;
+        }
+        x0 = (x0 + 1) & mask;
+    }
+    SearchOutcome { collision: None, hashes, stored: 2 }
+}
+
+/// Walk from `start` until a point with its low `distinguished_bits` bits zero; None if the walk loops
+fn walk_to_distinguished(h: &TruncatedSha256, start: u64, distinguished_bits: u32, hashes: &mut u64) -> Option<(u64, u64)> {
+    let mask = (1u64 << distinguished_bits) - 1;
+    let max_length = MAX_WALK_FACTOR << distinguished_bits;
+    let mut x = start;
+    for length in 1..=max_length {
+        x = h.step(x);
+        *hashes += 1;
+        if x & mask == 0 {
+            return Some((x, length));
+        }
+    }
+    None
+}
+
+/// Replay two walks that reach the same distinguished point and find where they merge
+fn locate_merge(h: &TruncatedSha256, mut a: (u64, u64), mut b: (u64, u64), hashes: &mut u64) -> Option<Collision> {
+    if a.1 < b.1 {
+        std::mem::swap(&mut a, &mut b);
+    }
+    let (mut x, mut y) = (a.0, b.0);
+    for _ in 0..a.1 - b.1 {
+        x = h.step(x);
+        *hashes += 1;
+    }
+    // One start lies on the other walk: same path, no collision
+    if x == y {
+        return None;
+    }
+    loop {
+        let (next_x, next_y) = (h.step(x), h.step(y));
+        *hashes += 2;
+        if next_x == next_y {
+            return Some(Collision { first: h.encode(x), second: h.encode(y), hash: next_x });
+        }
+        x = next_x;
+        y = next_y;
+    }
+}
+
+/// Parallel-collision search (van Oorschot–Wiener): random walks that stop at distinguished points
+///
+/// Only the end points are stored. When two walks end at the same point they
+/// merged somewhere, and replaying both from their starts finds the collision.
+/// `distinguished_bits` must pass `validate_distinguished_bits`.
+pub fn distinguished_point_search(h: &TruncatedSha256, distinguished_bits: u32, seed: u64, max_hashes: u64) -> SearchOutcome {
+    let space_mask = (1u64 << h.bits) - 1;
+    let mut rng = DemoRng::new(seed);
+    let mut points: HashMap<u64, (u64, u64)> = HashMap::new();
+    let mut hashes = 0u64;
+
+    while hashes < max_hashes {
+        let start = rng.next_u64() & space_mask;
+        let Some((point, length)) = walk_to_distinguished(h, start, distinguished_bits, &mut hashes) else { continue };
+        match points.get(&point) {
+            Some(&(other_start, other_length)) if other_start != start => {
+                if let Some(collision) = locate_merge(h, (start, length), (other_start, other_length), &mut hashes) {
+                    return SearchOutcome { collision: Some(collision), hashes, stored: points.len() };
+                }
+            }
+            Some(_) => {}
+            None => {
+                points.insert(point, (start, length));
+            }
+        }
+    }
+    SearchOutcome { collision: None, hashes, stored: points.len() }
+}
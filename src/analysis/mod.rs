@@ -0,0 +1,4 @@
+pub mod avalanche;
+pub mod birthday;
+pub mod demo;
+pub mod reduced;
@@ -0,0 +1,108 @@
+// ============================================================================
+// Preimages for Reduced-Round SHA-256
+// ============================================================================
+// Experiments on the NON-STANDARD variant in sha256::reduced. Nothing here
+// applies to full SHA-256.
+//
+//   Brute force: try messages until the first n digest bits match; about
+//   2^n attempts whatever the round count, since it ignores the structure.
+//
+//   Inversion:   round t writes new a and e from one T1 that contains W[t].
+//   With r ≤ 4 rounds all new a and e values are still in the output, so
+//   each W[t] can be solved for in turn: a full 256-bit preimage at once.
+
+use super::avalanche::{flip_bit, hamming_distance};
+use crate::sha256::compression::round;
+use crate::sha256::math_utils::{ch, sigma1};
+use crate::sha256::reduced::NonStandardSha256;
+use crate::sha256::types::{Hash, Word};
+
+/// Most rounds whose new a and e values all remain in the digest
+pub const INVERTIBLE_ROUNDS: usize = 4;
+
+pub const MIN_TARGET_BITS: u32 = 8;
+pub const MAX_TARGET_BITS: u32 = 40;
+
+/// Result of a brute-force search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreimageOutcome {
+    pub message: Option<Vec<u8>>,
+    pub attempts: u64,
+}
+
+/// The first `bits` bits of a digest, as a number
+pub fn leading_bits(digest: &[u8; 32], bits: u32) -> u64 {
+    u64::from_be_bytes(digest[..8].try_into().expect("8 bytes")) >> (64 - bits)
+}
+
+/// Hash 8-byte counters until the first `bits` bits equal those of `target`
+///
+/// The counter is little-endian so that its changing bytes land in W[0],
+/// the one word that even a single round mixes in.
+pub fn brute_force_preimage(variant: &NonStandardSha256, target: &[u8; 32], bits: u32, max_attempts: u64) -> Result<PreimageOutcome, String> {
+    if !(MIN_TARGET_BITS..=MAX_TARGET_BITS).contains(&bits) {
+        return Err(format!("Target must be {} to {} bits, got {}", MIN_TARGET_BITS, MAX_TARGET_BITS, bits));
+    }
+    let wanted = leading_bits(target, bits);
+    for attempt in 0..max_attempts {
+        let candidate = attempt.to_le_bytes().to_vec();
+        if leading_bits(&variant.hash_bytes(&candidate), bits) == wanted {
+            return Ok(PreimageOutcome { message: Some(candidate), attempts: attempt + 1 });
+        }
+    }
+    Ok(PreimageOutcome { message: None, attempts: max_attempts })
+}
+
+/// Solve for the message words W[0..r] that compress `variant.initial_hash` to `target`
+///
+/// Only for r ≤ 4 rounds. Fails if no single block reaches the target, which
+/// happens when it was not produced by this variant.
+pub fn invert_rounds(variant: &NonStandardSha256, target: &Hash) -> Result<Vec<Word>, String> {
+    let rounds = variant.rounds;
+    if rounds > INVERTIBLE_ROUNDS {
+        return Err(format!("Direct inversion needs at most {} rounds, got {}", INVERTIBLE_ROUNDS, rounds));
+    }
+    let iv = variant.initial_hash;
+    let output: Hash = std::array::from_fn(|i| target[i].wrapping_sub(iv[i]));
+
+    // After round t + 1 the new a sits at index r - t - 1 and the new e four places later
+    let mut state = iv;
+    let mut words = Vec::with_capacity(rounds);
+    for t in 0..rounds {
+        let [_, _, _, d, e, f, g, h] = state;
+        let t1 = output[4 + rounds - t - 1].wrapping_sub(d);
+        let w = t1
+            .wrapping_sub(h)
+            .wrapping_sub(sigma1(e))
+            .wrapping_sub(ch(e, f, g))
+            .wrapping_sub(variant.round_constants[t]);
+        state = round(state, variant.round_constants[t], w);
+        words.push(w);
+    }
+    if state != output {
+        return Err("Target is not reachable from the IV in this many rounds".to_string());
+    }
+    Ok(words)
+}
+
+/// Message whose padded first block starts with `words`: a full-hash preimage when r ≤ 4
+pub fn words_to_message(words: &[Word]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_be_bytes()).collect()
+}
+
+/// Mean output bits changed per single-bit input flip, and how many output bits any flip reaches
+pub fn diffusion(variant: &NonStandardSha256, message: &[u8]) -> (f64, usize) {
+    let digest = variant.hash_bytes(message);
+    let mut reached = [0u8; 32];
+    let mut total = 0;
+    let input_bits = message.len() * 8;
+    for bit in 0..input_bits {
+        let flipped = variant.hash_bytes(&flip_bit(message, bit));
+        total += hamming_distance(&digest, &flipped);
+        for (seen, (a, b)) in reached.iter_mut().zip(digest.iter().zip(flipped)) {
+            *seen |= a ^ b;
+        }
+    }
+    let mean = total as f64 / input_bits.max(1) as f64;
+    (mean, reached.iter().map(|byte| byte.count_ones() as usize).sum())
+}
@@ -0,0 +1,53 @@
+// ============================================================================
+// Argon2 Compression G (RFC 9106 §3.5–3.6)
+// ============================================================================
+// G(X, Y) xors the two blocks into R, views R as an 8×8 grid of 16-byte
+// registers and runs the permutation P over every row and then every column.
+// P is BLAKE2b's round with the additions replaced by the multiplication-
+// hardened BlaMka: a + b + 2·lo32(a)·lo32(b).
+
+use super::types::Block;
+
+fn blamka(a: u64, b: u64) -> u64 {
+    a.wrapping_add(b).wrapping_add(2u64.wrapping_mul(a & 0xffff_ffff).wrapping_mul(b & 0xffff_ffff))
+}
+
+fn gb(v: &mut Block, a: usize, b: usize, c: usize, d: usize) {
+    v[a] = blamka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = blamka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = blamka(v[a], v[b]);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = blamka(v[c], v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// P over the 16 words at `index`: four column GBs, then four diagonal GBs
+fn permute(v: &mut Block, index: [usize; 16]) {
+    gb(v, index[0], index[4], index[8], index[12]);
+    gb(v, index[1], index[5], index[9], index[13]);
+    gb(v, index[2], index[6], index[10], index[14]);
+    gb(v, index[3], index[7], index[11], index[15]);
+    gb(v, index[0], index[5], index[10], index[15]);
+    gb(v, index[1], index[6], index[11], index[12]);
+    gb(v, index[2], index[7], index[8], index[13]);
+    gb(v, index[3], index[4], index[9], index[14]);
+}
+
+/// G(X, Y) = P_columns(P_rows(X ⊕ Y)) ⊕ X ⊕ Y
+pub fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = *x;
+    r.iter_mut().zip(y).for_each(|(r, y)| *r ^= y);
+    let mut q = r;
+
+    for row in 0..8 {
+        permute(&mut q, std::array::from_fn(|i| 16 * row + i));
+    }
+    for column in 0..8 {
+        permute(&mut q, std::array::from_fn(|i| 2 * column + 16 * (i / 2) + i % 2));
+    }
+
+    q.iter_mut().zip(r).for_each(|(q, r)| *q ^= r);
+    q
+}
@@ -0,0 +1,32 @@
+/// Version 1.3 (0x13 = 19), the only version RFC 9106 specifies
+pub const VERSION: u32 = 0x13;
+
+pub const BLOCK_BYTES: usize = 1024;
+pub const BLOCK_WORDS: usize = BLOCK_BYTES / 8;
+
+/// Each pass is cut into four slices; lanes synchronize at slice boundaries
+pub const SYNC_POINTS: usize = 4;
+
+/// Pseudo-random indices produced by one address block in data-independent mode
+pub const ADDRESSES_PER_BLOCK: usize = BLOCK_WORDS;
+
+pub const MIN_SALT_BYTES: usize = 8;
+pub const MIN_TAG_BYTES: usize = 4;
+pub const MAX_LANES: u32 = (1 << 24) - 1;
+
+/// Cap for the demo so a hostile PHC string cannot exhaust the machine (1 GiB)
+pub const MAX_MEMORY_KIB: u32 = 1 << 20;
+
+/// Pass cap for the same reason; the OWASP rows need at most 5
+pub const MAX_PASSES: u32 = 1 << 10;
+
+/// Cap on blocks filled over all passes (memory KiB × passes): 1 GiB four times over
+pub const MAX_WORK_KIB: u64 = 1 << 22;
+
+/// OWASP Password Storage Cheat Sheet minimums for Argon2id: (memory KiB, passes)
+///
+/// Each row costs about the same; any one of them is acceptable.
+pub const OWASP_MINIMUMS: [(u32, u32); 5] = [(47_104, 1), (19_456, 2), (12_288, 3), (9_216, 4), (7_168, 5)];
+
+/// Salt length `password hash` generates
+pub const DEFAULT_SALT_BYTES: usize = 16;
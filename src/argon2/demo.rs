@@ -0,0 +1,215 @@
+use std::time::Instant;
+
+use super::constants::SYNC_POINTS;
+use super::hash::{argon2_with_trace, h_prime, initial_hash, validate_params, Reference};
+use super::password::{generate_salt, hash_password, verify_password, weaknesses};
+use super::phc::{encode_phc, parse_phc};
+use super::types::Argon2Params;
+use crate::common::random::DemoRng;
+
+/// Widest lane drawn as a grid; larger memories only print the tag
+const MAX_GRID_COLUMNS: usize = 64;
+
+/// Widest lane whose fill order is numbered cell by cell
+const MAX_ORDER_COLUMNS: usize = 32;
+
+/// Heat map shades from never read to most read
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+fn print_parameters(params: &Argon2Params) {
+    println!("\n=== Parameters ===");
+    println!(
+        "  {} v=19: m = {} KiB, t = {} pass(es), p = {} lane(s), T = {} bytes",
+        params.variant.name(),
+        params.memory_kib,
+        params.passes,
+        params.lanes,
+        params.tag_length
+    );
+    println!(
+        "  m' = {} blocks of 1 KiB → {} lane(s) × {} columns, {} slices of {} blocks",
+        params.block_count(),
+        params.lanes,
+        params.lane_length(),
+        SYNC_POINTS,
+        params.segment_length()
+    );
+}
+
+/// Row prefix with slice separators for a lane of cells
+fn print_grid_row(label: &str, cells: &[String], segment: usize) {
+    let slices: Vec<String> = cells.chunks(segment).map(|slice| slice.concat()).collect();
+    println!("  {:<7} |{}|", label, slices.join("|"));
+}
+
+fn print_fill_order(params: &Argon2Params, references: &[Reference]) {
+    let lanes = params.lanes as usize;
+    let lane_length = params.lane_length();
+    println!("\n=== Fill Order (pass 0) ===");
+    println!("  H = seeded from H0; numbers give the order blocks are written.");
+    println!("  Lanes fill the same slice in parallel and wait for each other at every |.");
+
+    let mut order = vec![vec!["  H".to_string(); lane_length]; lanes];
+    for (step, reference) in references.iter().filter(|r| r.pass == 0).enumerate() {
+        order[reference.lane][reference.index] = format!("{:>3}", step + 1);
+    }
+    for (lane, cells) in order.iter().enumerate() {
+        print_grid_row(&format!("lane {}", lane), cells, params.segment_length());
+    }
+}
+
+fn print_lane_references(params: &Argon2Params, references: &[Reference]) {
+    println!("\n=== References (pass 0, lane 0) ===");
+    println!("  i = index from an address block (data-independent), d = from the previous block's first word");
+    for reference in references.iter().filter(|r| r.pass == 0 && r.lane == 0) {
+        let previous = (reference.index + params.lane_length() - 1) % params.lane_length();
+        println!(
+            "  B[0][{:>2}] = G(B[0][{:>2}], B[{}][{:>2}])  slice {}  {}",
+            reference.index,
+            previous,
+            reference.ref_lane,
+            reference.ref_index,
+            reference.slice,
+            if reference.data_independent { "i" } else { "d" }
+        );
+    }
+}
+
+fn print_heat_map(params: &Argon2Params, references: &[Reference]) {
+    let lane_length = params.lane_length();
+    let mut counts = vec![vec![0usize; lane_length]; params.lanes as usize];
+    for reference in references {
+        counts[reference.ref_lane][reference.ref_index] += 1;
+    }
+    let max = counts.iter().flatten().copied().max().unwrap_or(0).max(1);
+
+    println!("\n=== Reference Heat Map (all passes) ===");
+    println!("  How often each block was read as the second input of G: '{}' (max {})", String::from_utf8_lossy(SHADES), max);
+    for (lane, row) in counts.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|&count| (SHADES[count * (SHADES.len() - 1) / max] as char).to_string())
+            .collect();
+        print_grid_row(&format!("lane {}", lane), &cells, params.segment_length());
+    }
+    println!("  Recent blocks are favoured (the index is squared), but every block stays reachable.");
+}
+
+fn print_weaknesses(problems: &[String]) {
+    for problem in problems {
+        println!("  ⚠️  {}", problem);
+    }
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Hash with a trace of H0, the first blocks, the fill order and the reference pattern
+///
+/// Use small memory (e.g. 32 KiB over 2 lanes) to see the whole matrix.
+pub fn run_argon2_demo(password: &str, salt: &[u8], params: &Argon2Params) {
+    println!("🔑 Argon2 Password Hashing Demo");
+    println!("Password: \"{}\"", password);
+    println!("Salt: \"{}\" ({} bytes)", String::from_utf8_lossy(salt), salt.len());
+
+    if let Err(e) = validate_params(params, salt) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+    print_parameters(params);
+
+    let h0 = initial_hash(params, password.as_bytes(), salt);
+    println!("\n=== H0 = BLAKE2b-512(p, T, m, t, v, y, P, S, K, X) ===");
+    println!("  H0: {}", hex::encode(h0));
+
+    println!("\n=== First Blocks: B[i][j] = H'(H0 ‖ j ‖ i) for j = 0, 1 ===");
+    println!("  H' chains BLAKE2b-512 and keeps 32 bytes of each link to reach 1024 bytes");
+    for lane in 0..params.lanes.min(4) {
+        for column in 0..2u32 {
+            let seed = [h0.as_slice(), &column.to_le_bytes(), &lane.to_le_bytes()].concat();
+            println!("  B[{}][{}]: {}…", lane, column, hex::encode(&h_prime(&seed, 1024)[..16]));
+        }
+    }
+
+    let draw = params.lane_length() <= MAX_GRID_COLUMNS;
+    let start = Instant::now();
+    let output = match argon2_with_trace(params, password.as_bytes(), salt, draw) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return;
+        }
+    };
+    let elapsed = start.elapsed();
+
+    if draw {
+        if params.lane_length() <= MAX_ORDER_COLUMNS {
+            print_fill_order(params, &output.references);
+        }
+        print_lane_references(params, &output.references);
+        print_heat_map(params, &output.references);
+    } else {
+        println!("\n💡 Tip: Use at most {} KiB per lane to draw the memory matrix", MAX_GRID_COLUMNS);
+    }
+
+    println!("\n=== Tag = H'(B[0][q-1] ⊕ … ⊕ B[p-1][q-1]) ===");
+    println!("  Tag: {}", hex::encode(&output.tag));
+    println!("  PHC: {}", encode_phc(params, salt, &output.tag));
+    println!("  Computed in {:.1?}", elapsed);
+
+    let problems = weaknesses(params, salt);
+    if !problems.is_empty() {
+        println!("\n=== Not for Real Passwords ===");
+        print_weaknesses(&problems);
+    }
+}
+
+/// Hash a password into a PHC string; false if the parameters are rejected
+pub fn run_password_hash(password: &str, params: &Argon2Params, salt: Option<&[u8]>) -> bool {
+    let salt = salt.map(<[u8]>::to_vec).unwrap_or_else(|| generate_salt(&mut DemoRng::from_entropy()).to_vec());
+    let start = Instant::now();
+    match hash_password(password.as_bytes(), params, &salt) {
+        Ok(phc) => {
+            println!("{}", phc);
+            eprintln!("✅ Hashed in {:.1?} using {} KiB", start.elapsed(), params.block_count());
+            true
+        }
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            eprintln!("💡 Tip: The defaults (argon2id, m=19456, t=2, p=1, 16-byte salt) meet the OWASP minimum");
+            false
+        }
+    }
+}
+
+/// Check a password against a PHC string; false on mismatch or a malformed string
+pub fn run_password_verify(password: &str, phc: &str) -> bool {
+    let stored = match parse_phc(phc) {
+        Ok(stored) => stored,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            return false;
+        }
+    };
+
+    match verify_password(password.as_bytes(), phc, &[]) {
+        Ok(true) => {
+            println!("✅ Password matches");
+            let problems = weaknesses(&stored.params(), &stored.salt);
+            if !problems.is_empty() {
+                println!("⚠️  Stored hash uses weak parameters; rehash on this login:");
+                print_weaknesses(&problems);
+            }
+            true
+        }
+        Ok(false) => {
+            println!("❌ Password does not match");
+            false
+        }
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,231 @@
+// ============================================================================
+// Argon2 (RFC 9106)
+// ============================================================================
+//   H0        = BLAKE2b-512(p, T, m, t, v, y, P, S, K, X)      lengths prefixed
+//   B[i][0]   = H'(H0 ‖ 0 ‖ i)     B[i][1] = H'(H0 ‖ 1 ‖ i)   for each lane i
+//   B[i][j]   = G(B[i][j-1], B[l][z])                          xored in after pass 0
+//   tag       = H'(B[0][q-1] ⊕ B[1][q-1] ⊕ … ⊕ B[p-1][q-1])
+//
+// The reference block B[l][z] is picked from a pseudo-random 64-bit value:
+// the first word of the previous block (data-dependent, Argon2d) or a word
+// of an address block derived from the position alone (data-independent,
+// Argon2i). Argon2id uses the second for the first half of the first pass.
+
+use super::compression::compress;
+use super::constants::{
+    ADDRESSES_PER_BLOCK, BLOCK_WORDS, MAX_LANES, MAX_MEMORY_KIB, MAX_PASSES, MAX_WORK_KIB, MIN_SALT_BYTES, MIN_TAG_BYTES, SYNC_POINTS, VERSION,
+};
+use super::types::{Argon2Params, Block};
+use crate::blake2::hash::{blake2b, blake2b_512};
+use crate::blake2::types::Blake2Params;
+
+/// Where one block was written and which earlier block it mixed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reference {
+    pub pass: u32,
+    pub slice: usize,
+    pub lane: usize,
+    /// Column j of the block being written
+    pub index: usize,
+    pub ref_lane: usize,
+    pub ref_index: usize,
+    pub data_independent: bool,
+}
+
+/// The result of a full run, with every reference when recording was asked for
+#[derive(Debug, Clone)]
+pub struct Argon2Output {
+    pub initial_hash: [u8; 64],
+    pub tag: Vec<u8>,
+    pub references: Vec<Reference>,
+}
+
+fn le32(value: usize) -> [u8; 4] {
+    (value as u32).to_le_bytes()
+}
+
+/// H'^T: BLAKE2b for T ≤ 64, otherwise a chain of BLAKE2b-512 keeping 32 bytes of each link
+pub fn h_prime(input: &[u8], length: usize) -> Vec<u8> {
+    let input = [le32(length).as_slice(), input].concat();
+    if length <= 64 {
+        return blake2b(&input, &Blake2Params::new(length)).expect("1..=64-byte BLAKE2b digest");
+    }
+
+    let links = length.div_ceil(32) - 2;
+    let mut v = blake2b_512(&input);
+    let mut output = v[..32].to_vec();
+    for _ in 1..links {
+        v = blake2b_512(&v);
+        output.extend_from_slice(&v[..32]);
+    }
+    output.extend(blake2b(&v, &Blake2Params::new(length - 32 * links)).expect("33..=64-byte BLAKE2b digest"));
+    output
+}
+
+/// H0: BLAKE2b-512 over the parameters and length-prefixed inputs
+pub fn initial_hash(params: &Argon2Params, password: &[u8], salt: &[u8]) -> [u8; 64] {
+    let mut input = Vec::new();
+    for value in [params.lanes, params.tag_length as u32, params.memory_kib, params.passes, VERSION, params.variant.type_code()] {
+        input.extend_from_slice(&value.to_le_bytes());
+    }
+    for field in [password, salt, &params.secret, &params.associated_data] {
+        input.extend_from_slice(&le32(field.len()));
+        input.extend_from_slice(field);
+    }
+    blake2b_512(&input)
+}
+
+pub fn validate_params(params: &Argon2Params, salt: &[u8]) -> Result<(), String> {
+    if params.lanes == 0 || params.lanes > MAX_LANES {
+        return Err(format!("Lanes must be between 1 and {}, got {}", MAX_LANES, params.lanes));
+    }
+    if (params.memory_kib as usize) < 8 * params.lanes as usize {
+        return Err(format!("Memory must be at least 8 KiB per lane ({} KiB), got {}", 8 * params.lanes, params.memory_kib));
+    }
+    if params.memory_kib > MAX_MEMORY_KIB {
+        return Err(format!("Memory {} KiB is over the {} KiB demo limit", params.memory_kib, MAX_MEMORY_KIB));
+    }
+    if params.passes == 0 {
+        return Err("Argon2 needs at least one pass".to_string());
+    }
+    if params.passes > MAX_PASSES {
+        return Err(format!("{} passes is over the {} pass demo limit", params.passes, MAX_PASSES));
+    }
+    if params.memory_kib as u64 * params.passes as u64 > MAX_WORK_KIB {
+        return Err(format!(
+            "m = {} KiB with t = {} fills {} KiB, over the {} KiB demo limit",
+            params.memory_kib,
+            params.passes,
+            params.memory_kib as u64 * params.passes as u64,
+            MAX_WORK_KIB
+        ));
+    }
+    if params.tag_length < MIN_TAG_BYTES || params.tag_length > u32::MAX as usize {
+        return Err(format!("Tag length must be at least {} bytes, got {}", MIN_TAG_BYTES, params.tag_length));
+    }
+    if salt.len() < MIN_SALT_BYTES {
+        return Err(format!("Salt must be at least {} bytes, got {}", MIN_SALT_BYTES, salt.len()));
+    }
+    Ok(())
+}
+
+pub fn block_from_bytes(bytes: &[u8]) -> Block {
+    std::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().expect("8-byte word")))
+}
+
+pub fn block_to_bytes(block: &Block) -> Vec<u8> {
+    block.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// Pick the reference column z in lane `ref_lane` from the low 32 bits of the pseudo-random value
+///
+/// Only blocks already finished are eligible; the squaring skews the choice
+/// toward recently written blocks.
+fn reference_index(params: &Argon2Params, pass: u32, slice: usize, index: usize, j1: u64, same_lane: bool) -> usize {
+    let segment = params.segment_length();
+    let lane_length = params.lane_length();
+    let area = match (pass, same_lane) {
+        (0, true) => slice * segment + index - 1,
+        (0, false) => slice * segment - usize::from(index == 0),
+        (_, true) => lane_length - segment + index - 1,
+        (_, false) => lane_length - segment - usize::from(index == 0),
+    };
+
+    let x = (j1 * j1) >> 32;
+    let y = (area as u64 * x) >> 32;
+    let relative = area - 1 - y as usize;
+    let start = if pass == 0 || slice == SYNC_POINTS - 1 { 0 } else { (slice + 1) * segment };
+    (start + relative) % lane_length
+}
+
+/// Fill one lane's segment of one slice
+fn fill_segment(memory: &mut [Block], params: &Argon2Params, pass: u32, slice: usize, lane: usize, references: Option<&mut Vec<Reference>>) {
+    let lane_length = params.lane_length();
+    let data_independent = params.variant.data_independent(pass, slice);
+    let zero: Block = [0; BLOCK_WORDS];
+    let mut input: Block = [0; BLOCK_WORDS];
+    let mut addresses: Block = [0; BLOCK_WORDS];
+    let next_addresses = |input: &mut Block| {
+        input[6] += 1;
+        compress(&zero, &compress(&zero, input))
+    };
+
+    if data_independent {
+        let fields = [pass as usize, lane, slice, params.block_count(), params.passes as usize, params.variant.type_code() as usize];
+        for (word, value) in input.iter_mut().zip(fields) {
+            *word = value as u64;
+        }
+    }
+
+    let start = if pass == 0 && slice == 0 { 2 } else { 0 };
+    if data_independent && start == 2 {
+        addresses = next_addresses(&mut input);
+    }
+
+    let mut references = references;
+    for index in start..params.segment_length() {
+        let column = slice * params.segment_length() + index;
+        let offset = lane * lane_length + column;
+        let previous = if column == 0 { offset + lane_length - 1 } else { offset - 1 };
+
+        let pseudo_random = if data_independent {
+            if index % ADDRESSES_PER_BLOCK == 0 {
+                addresses = next_addresses(&mut input);
+            }
+            addresses[index % ADDRESSES_PER_BLOCK]
+        } else {
+            memory[previous][0]
+        };
+
+        let ref_lane = if pass == 0 && slice == 0 { lane } else { (pseudo_random >> 32) as usize % params.lanes as usize };
+        let ref_index = reference_index(params, pass, slice, index, pseudo_random & 0xffff_ffff, ref_lane == lane);
+
+        let mut block = compress(&memory[previous], &memory[ref_lane * lane_length + ref_index]);
+        if pass > 0 {
+            block.iter_mut().zip(memory[offset]).for_each(|(new, old)| *new ^= old);
+        }
+        memory[offset] = block;
+
+        if let Some(references) = references.as_deref_mut() {
+            references.push(Reference { pass, slice, lane, index: column, ref_lane, ref_index, data_independent });
+        }
+    }
+}
+
+/// Run Argon2, optionally recording every reference for visualization
+pub fn argon2_with_trace(params: &Argon2Params, password: &[u8], salt: &[u8], record: bool) -> Result<Argon2Output, String> {
+    validate_params(params, salt)?;
+    let lanes = params.lanes as usize;
+    let lane_length = params.lane_length();
+    let initial_hash = initial_hash(params, password, salt);
+
+    let mut memory: Vec<Block> = vec![[0; BLOCK_WORDS]; params.block_count()];
+    for lane in 0..lanes {
+        for column in 0..2 {
+            let seed = [initial_hash.as_slice(), &le32(column), &le32(lane)].concat();
+            memory[lane * lane_length + column] = block_from_bytes(&h_prime(&seed, 1024));
+        }
+    }
+
+    // Lanes only read other lanes' finished slices, so running them in turn matches a parallel fill
+    let mut references = Vec::new();
+    for pass in 0..params.passes {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..lanes {
+                fill_segment(&mut memory, params, pass, slice, lane, record.then_some(&mut references));
+            }
+        }
+    }
+
+    let mut last = memory[lane_length - 1];
+    for lane in 1..lanes {
+        last.iter_mut().zip(memory[lane * lane_length + lane_length - 1]).for_each(|(c, b)| *c ^= b);
+    }
+    let tag = h_prime(&block_to_bytes(&last), params.tag_length);
+    Ok(Argon2Output { initial_hash, tag, references })
+}
+
+/// The raw tag
+pub fn argon2(params: &Argon2Params, password: &[u8], salt: &[u8]) -> Result<Vec<u8>, String> {
+    argon2_with_trace(params, password, salt, false).map(|output| output.tag)
+}
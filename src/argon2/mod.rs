@@ -0,0 +1,7 @@
+pub mod compression;
+pub mod constants;
+pub mod demo;
+pub mod hash;
+pub mod password;
+pub mod phc;
+pub mod types;
@@ -0,0 +1,60 @@
+use super::constants::{DEFAULT_SALT_BYTES, OWASP_MINIMUMS};
+use super::hash::argon2;
+use super::phc::{encode_phc, parse_phc};
+use super::types::{Argon2Params, Argon2Variant};
+use crate::common::random::DemoRng;
+
+/// Minimum tag length accepted for stored password hashes
+pub const MIN_STORED_TAG_BYTES: usize = 16;
+
+/// Every reason these parameters are too weak for storing passwords (empty if none)
+pub fn weaknesses(params: &Argon2Params, salt: &[u8]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if params.variant != Argon2Variant::Argon2id {
+        problems.push(format!("{} is not recommended for passwords; use argon2id", params.variant.name()));
+    }
+    if !OWASP_MINIMUMS.iter().any(|&(memory, passes)| params.memory_kib >= memory && params.passes >= passes) {
+        let options: Vec<String> = OWASP_MINIMUMS.iter().map(|(m, t)| format!("m={} t={}", m, t)).collect();
+        problems.push(format!(
+            "m={} KiB, t={} is below every OWASP minimum ({})",
+            params.memory_kib,
+            params.passes,
+            options.join(", ")
+        ));
+    }
+    if salt.len() < DEFAULT_SALT_BYTES {
+        problems.push(format!("Salt is {} bytes; use at least {}", salt.len(), DEFAULT_SALT_BYTES));
+    }
+    if params.tag_length < MIN_STORED_TAG_BYTES {
+        problems.push(format!("Tag is {} bytes; use at least {}", params.tag_length, MIN_STORED_TAG_BYTES));
+    }
+    problems
+}
+
+/// A fresh random salt
+///
+/// Salts only need to be unique, not secret, so the demo generator is enough here.
+pub fn generate_salt(rng: &mut DemoRng) -> [u8; DEFAULT_SALT_BYTES] {
+    let mut salt = [0u8; DEFAULT_SALT_BYTES];
+    rng.fill_bytes(&mut salt);
+    salt
+}
+
+/// Hash a password into a PHC string, refusing weak parameters
+pub fn hash_password(password: &[u8], params: &Argon2Params, salt: &[u8]) -> Result<String, String> {
+    let problems = weaknesses(params, salt);
+    if !problems.is_empty() {
+        return Err(format!("Weak parameters: {}", problems.join("; ")));
+    }
+    Ok(encode_phc(params, salt, &argon2(params, password, salt)?))
+}
+
+/// Recompute the hash from the parameters in the PHC string and compare in constant time
+///
+/// Old, weaker hashes still verify, so users can log in and be rehashed.
+pub fn verify_password(password: &[u8], phc: &str, secret: &[u8]) -> Result<bool, String> {
+    let stored = parse_phc(phc)?;
+    let params = Argon2Params { secret: secret.to_vec(), ..stored.params() };
+    let computed = argon2(&params, password, &stored.salt)?;
+    Ok(computed.iter().zip(&stored.hash).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0)
+}
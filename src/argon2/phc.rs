@@ -0,0 +1,93 @@
+// ============================================================================
+// PHC String Format
+// ============================================================================
+//   $argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>
+//
+// Salt and hash are standard base64 without padding. The string carries
+// everything a verifier needs except the optional secret K, which is the
+// point of a pepper.
+
+use super::constants::VERSION;
+use super::hash::validate_params;
+use super::types::{Argon2Params, Argon2Variant};
+use crate::common::base64::{decode, encode_unpadded};
+
+/// A parsed `$argon2…$` string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhcHash {
+    pub variant: Argon2Variant,
+    pub version: u32,
+    pub memory_kib: u32,
+    pub passes: u32,
+    pub lanes: u32,
+    pub salt: Vec<u8>,
+    pub hash: Vec<u8>,
+}
+
+impl PhcHash {
+    /// Parameters to recompute the hash, with the tag length taken from the stored hash
+    pub fn params(&self) -> Argon2Params {
+        Argon2Params {
+            variant: self.variant,
+            memory_kib: self.memory_kib,
+            passes: self.passes,
+            lanes: self.lanes,
+            tag_length: self.hash.len(),
+            ..Default::default()
+        }
+    }
+}
+
+pub fn encode_phc(params: &Argon2Params, salt: &[u8], hash: &[u8]) -> String {
+    format!(
+        "${}$v={}$m={},t={},p={}${}${}",
+        params.variant.name(),
+        VERSION,
+        params.memory_kib,
+        params.passes,
+        params.lanes,
+        encode_unpadded(salt),
+        encode_unpadded(hash)
+    )
+}
+
+fn parse_number(field: &str, name: &str) -> Result<u32, String> {
+    match field.split_once('=') {
+        Some((key, value)) if key == name => value.parse().map_err(|_| format!("Invalid value for {}: '{}'", name, value)),
+        _ => Err(format!("Expected {}=…, found '{}'", name, field)),
+    }
+}
+
+pub fn parse_phc(text: &str) -> Result<PhcHash, String> {
+    let fields: Vec<&str> = text.trim().split('$').collect();
+    let [empty, id, version, costs, salt, hash] = fields.as_slice() else {
+        return Err("Expected $argon2id$v=19$m=…,t=…,p=…$salt$hash".to_string());
+    };
+    if !empty.is_empty() {
+        return Err("PHC string must start with '$'".to_string());
+    }
+
+    let variant: Argon2Variant = id.parse()?;
+    let version = parse_number(version, "v")?;
+    if version != VERSION {
+        return Err(format!("Unsupported Argon2 version {} (expected {})", version, VERSION));
+    }
+
+    let costs: Vec<&str> = costs.split(',').collect();
+    let [m, t, p] = costs.as_slice() else {
+        return Err(format!("Expected m=…,t=…,p=…, found '{}'", costs.join(",")));
+    };
+    let parsed = PhcHash {
+        variant,
+        version,
+        memory_kib: parse_number(m, "m")?,
+        passes: parse_number(t, "t")?,
+        lanes: parse_number(p, "p")?,
+        salt: decode(salt)?,
+        hash: decode(hash)?,
+    };
+
+    // Reject hostile costs here, before a verifier commits to the work
+    validate_params(&parsed.params(), &parsed.salt)?;
+    Ok(parsed)
+}
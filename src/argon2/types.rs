@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use super::constants::{BLOCK_WORDS, SYNC_POINTS};
+
+/// A 1 KiB memory block as 128 little-endian words
+pub type Block = [u64; BLOCK_WORDS];
+
+/// Argon2 type y (RFC 9106 §3.1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Argon2Variant {
+    /// Data-dependent indexing: strongest against GPU trade-offs, leaks through cache timing
+    Argon2d,
+    /// Data-independent indexing: safe against side channels, weaker against trade-offs
+    Argon2i,
+    /// Argon2i for the first half of the first pass, Argon2d after that
+    Argon2id,
+}
+
+impl Argon2Variant {
+    /// Lower-case name used as the PHC identifier
+    pub fn name(&self) -> &'static str {
+        match self {
+            Argon2Variant::Argon2d => "argon2d",
+            Argon2Variant::Argon2i => "argon2i",
+            Argon2Variant::Argon2id => "argon2id",
+        }
+    }
+
+    pub fn type_code(&self) -> u32 {
+        match self {
+            Argon2Variant::Argon2d => 0,
+            Argon2Variant::Argon2i => 1,
+            Argon2Variant::Argon2id => 2,
+        }
+    }
+
+    /// Whether reference indices come from address blocks rather than the previous block
+    pub fn data_independent(&self, pass: u32, slice: usize) -> bool {
+        match self {
+            Argon2Variant::Argon2d => false,
+            Argon2Variant::Argon2i => true,
+            Argon2Variant::Argon2id => pass == 0 && slice < SYNC_POINTS / 2,
+        }
+    }
+}
+
+impl FromStr for Argon2Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().trim_start_matches("argon2") {
+            "d" => Ok(Argon2Variant::Argon2d),
+            "i" => Ok(Argon2Variant::Argon2i),
+            "id" => Ok(Argon2Variant::Argon2id),
+            _ => Err(format!("Unknown Argon2 variant '{}' (expected argon2d, argon2i or argon2id)", s)),
+        }
+    }
+}
+
+/// Cost parameters and optional inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argon2Params {
+    pub variant: Argon2Variant,
+    /// m: memory in KiB (1 KiB blocks)
+    pub memory_kib: u32,
+    /// t: passes over memory
+    pub passes: u32,
+    /// p: lanes filled in parallel
+    pub lanes: u32,
+    /// T: tag length in bytes
+    pub tag_length: usize,
+    /// K: optional secret (pepper), never stored in the PHC string
+    pub secret: Vec<u8>,
+    /// X: optional associated data
+    pub associated_data: Vec<u8>,
+}
+
+impl Default for Argon2Params {
+    /// Argon2id, 19 MiB, 2 passes, 1 lane: the OWASP minimum
+    fn default() -> Self {
+        Argon2Params {
+            variant: Argon2Variant::Argon2id,
+            memory_kib: 19_456,
+            passes: 2,
+            lanes: 1,
+            tag_length: 32,
+            secret: Vec::new(),
+            associated_data: Vec::new(),
+        }
+    }
+}
+
+impl Argon2Params {
+    /// m' = 4p · ⌊m / 4p⌋: memory rounded down to whole segments
+    pub fn block_count(&self) -> usize {
+        let lanes = self.lanes as usize;
+        SYNC_POINTS * lanes * (self.memory_kib as usize / (SYNC_POINTS * lanes))
+    }
+
+    /// q = m' / p columns per lane
+    pub fn lane_length(&self) -> usize {
+        self.block_count() / self.lanes as usize
+    }
+
+    pub fn segment_length(&self) -> usize {
+        self.lane_length() / SYNC_POINTS
+    }
+}
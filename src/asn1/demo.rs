@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::Path;
+
+use super::dump::{explain, format_tree};
+use super::parser::parse_der;
+use crate::common::pem::{decode_pem, is_pem};
+
+/// Strip PEM armor if present, returning the label (if any) and the DER bytes
+fn load_der(bytes: Vec<u8>) -> Result<(Option<String>, Vec<u8>), String> {
+    if !is_pem(&bytes) {
+        return Ok((None, bytes));
+    }
+    let text = String::from_utf8(bytes).map_err(|_| "PEM file is not valid UTF-8".to_string())?;
+    let (label, der) = decode_pem(&text)?;
+    Ok((Some(label), der))
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Parse a DER or PEM file and print its structure with explanations
+pub fn run_asn1_dump(path: &Path) {
+    println!("🔍 ASN.1 DER Dump: {}", path.display());
+
+    let loaded = fs::read(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+        .and_then(load_der);
+    let (label, der) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+
+    match &label {
+        Some(label) => println!("PEM label: {} ({} bytes of DER)", label, der.len()),
+        None => println!("Raw DER: {} bytes", der.len()),
+    }
+
+    let nodes = match parse_der(&der) {
+        Ok(nodes) => nodes,
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            eprintln!("💡 Tip: The input must be DER (or PEM-wrapped DER); BER indefinite lengths are not supported");
+            return;
+        }
+    };
+
+    println!("\n=== Structure ===");
+    for line in format_tree(&nodes) {
+        println!("{}", line);
+    }
+
+    println!("\n=== Explanations ===");
+    for note in explain(&nodes) {
+        println!("  • {}", note);
+    }
+}
@@ -0,0 +1,235 @@
+// ============================================================================
+// ASN.1 DER Encoding and Decoding
+// ============================================================================
+// Encoders cover the handful of types that PKCS#1, PKCS#8 and
+// SubjectPublicKeyInfo use; the decoder reads any definite-length element.
+
+use super::types::{Tag, TagClass};
+
+pub const TAG_BOOLEAN: u8 = 0x01;
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_BIT_STRING: u8 = 0x03;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_NULL: u8 = 0x05;
+pub const TAG_OID: u8 = 0x06;
+pub const TAG_SEQUENCE: u8 = 0x30;
+pub const TAG_SET: u8 = 0x31;
+
+/// A decoded tag-length-value element borrowing its content from the input
+///
+/// `tag` is the first identifier octet; use `read_header` for the full tag number.
+#[derive(Debug, Clone, Copy)]
+pub struct DerElement<'a> {
+    pub tag: u8,
+    pub content: &'a [u8],
+}
+
+/// Identifier and length octets of one element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub tag: Tag,
+    /// Number of identifier plus length octets
+    pub header_len: usize,
+    pub length: usize,
+}
+
+// ----------------------------------------------------------------------------
+// Encoding
+// ----------------------------------------------------------------------------
+
+/// Short form for lengths below 128, otherwise 0x80 | byte-count followed by the length
+pub fn encode_length(length: usize) -> Vec<u8> {
+    if length < 0x80 {
+        return vec![length as u8];
+    }
+    let bytes: Vec<u8> = length.to_be_bytes().into_iter().skip_while(|&b| b == 0).collect();
+    let mut encoded = vec![0x80 | bytes.len() as u8];
+    encoded.extend(bytes);
+    encoded
+}
+
+pub fn encode_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    encoded.extend(encode_length(content.len()));
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+/// Non-negative INTEGER: minimal big-endian bytes, with a 0x00 prefix if the top bit is set
+pub fn encode_integer(value: u64) -> Vec<u8> {
+    let mut bytes: Vec<u8> = value.to_be_bytes().into_iter().skip_while(|&b| b == 0).collect();
+    if bytes.first().is_none_or(|&b| b & 0x80 != 0) {
+        bytes.insert(0, 0);
+    }
+    encode_tlv(TAG_INTEGER, &bytes)
+}
+
+pub fn encode_sequence(elements: &[Vec<u8>]) -> Vec<u8> {
+    encode_tlv(TAG_SEQUENCE, &elements.concat())
+}
+
+pub fn encode_null() -> Vec<u8> {
+    encode_tlv(TAG_NULL, &[])
+}
+
+/// OBJECT IDENTIFIER: first two arcs packed as 40·a + b, then base-128 with continuation bits
+pub fn encode_oid(arcs: &[u64]) -> Vec<u8> {
+    let mut content = Vec::new();
+    let first = arcs.first().copied().unwrap_or(0) * 40 + arcs.get(1).copied().unwrap_or(0);
+    for &arc in std::iter::once(&first).chain(arcs.iter().skip(2)) {
+        let mut groups = vec![(arc & 0x7f) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            groups.push(0x80 | (rest & 0x7f) as u8);
+            rest >>= 7;
+        }
+        content.extend(groups.iter().rev());
+    }
+    encode_tlv(TAG_OID, &content)
+}
+
+pub fn encode_octet_string(content: &[u8]) -> Vec<u8> {
+    encode_tlv(TAG_OCTET_STRING, content)
+}
+
+/// BIT STRING whose content is a whole number of bytes (0 unused bits)
+pub fn encode_bit_string(content: &[u8]) -> Vec<u8> {
+    let mut bits = vec![0u8];
+    bits.extend_from_slice(content);
+    encode_tlv(TAG_BIT_STRING, &bits)
+}
+
+// ----------------------------------------------------------------------------
+// Decoding
+// ----------------------------------------------------------------------------
+
+/// Decode the identifier and length octets at the start of the input
+///
+/// Tag numbers ≥ 31 use the high-tag-number form (X.690 §8.1.2.4). The
+/// indefinite length 0x80 is BER-only and rejected, as DER requires.
+pub fn read_header(input: &[u8]) -> Result<Header, String> {
+    let (&identifier, mut rest) = input.split_first().ok_or("Unexpected end of DER input")?;
+    let class = match identifier >> 6 {
+        0 => TagClass::Universal,
+        1 => TagClass::Application,
+        2 => TagClass::ContextSpecific,
+        _ => TagClass::Private,
+    };
+    let constructed = identifier & 0x20 != 0;
+
+    let mut number = (identifier & 0x1f) as u32;
+    if number == 0x1f {
+        number = 0;
+        loop {
+            let (&byte, remaining) = rest.split_first().ok_or("Truncated high tag number")?;
+            rest = remaining;
+            if number > u32::MAX >> 7 {
+                return Err("Tag number too large".to_string());
+            }
+            number = number << 7 | (byte & 0x7f) as u32;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let (&first, rest) = rest.split_first().ok_or("Missing DER length")?;
+    let (length, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else if first == 0x80 {
+        return Err("Indefinite length (0x80) is not allowed in DER".to_string());
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count > 8 || rest.len() < count {
+            return Err(format!("Unsupported DER length encoding 0x{:02x}", first));
+        }
+        let length = rest[..count].iter().fold(0usize, |acc, &b| acc << 8 | b as usize);
+        (length, &rest[count..])
+    };
+
+    Ok(Header {
+        tag: Tag { class, constructed, number },
+        header_len: input.len() - rest.len(),
+        length,
+    })
+}
+
+/// Read one element, returning it and the remaining input
+pub fn read_element(input: &[u8]) -> Result<(DerElement<'_>, &[u8]), String> {
+    let header = read_header(input)?;
+    let rest = &input[header.header_len..];
+
+    if rest.len() < header.length {
+        return Err(format!("DER element claims {} bytes but only {} remain", header.length, rest.len()));
+    }
+    Ok((DerElement { tag: input[0], content: &rest[..header.length] }, &rest[header.length..]))
+}
+
+/// Read an element and check that it has the expected tag
+pub fn expect_element(input: &[u8], tag: u8) -> Result<(DerElement<'_>, &[u8]), String> {
+    let (element, rest) = read_element(input)?;
+    if element.tag != tag {
+        return Err(format!("Expected DER tag 0x{:02x}, found 0x{:02x}", tag, element.tag));
+    }
+    Ok((element, rest))
+}
+
+/// Split the content of a constructed element into its children
+pub fn read_children(content: &[u8]) -> Result<Vec<DerElement<'_>>, String> {
+    let mut children = Vec::new();
+    let mut rest = content;
+    while !rest.is_empty() {
+        let (child, remaining) = read_element(rest)?;
+        children.push(child);
+        rest = remaining;
+    }
+    Ok(children)
+}
+
+/// Decode a non-negative INTEGER that fits in u64
+pub fn decode_integer(element: &DerElement) -> Result<u64, String> {
+    if element.tag != TAG_INTEGER {
+        return Err(format!("Expected INTEGER, found tag 0x{:02x}", element.tag));
+    }
+    if element.content.first().is_some_and(|&b| b & 0x80 != 0) {
+        return Err("Negative INTEGER is not a valid key component".to_string());
+    }
+    let significant: Vec<u8> = element.content.iter().copied().skip_while(|&b| b == 0).collect();
+    if significant.len() > 8 {
+        return Err(format!(
+            "INTEGER of {} bits is too large for this demo (max 64 bits)",
+            significant.len() * 8
+        ));
+    }
+    Ok(significant.iter().fold(0u64, |acc, &b| acc << 8 | b as u64))
+}
+
+/// Decode an OBJECT IDENTIFIER into its arcs
+pub fn decode_oid(element: &DerElement) -> Result<Vec<u64>, String> {
+    if element.tag != TAG_OID {
+        return Err(format!("Expected OBJECT IDENTIFIER, found tag 0x{:02x}", element.tag));
+    }
+    decode_oid_content(element.content)
+}
+
+/// Decode the content octets of an OBJECT IDENTIFIER
+pub fn decode_oid_content(content: &[u8]) -> Result<Vec<u64>, String> {
+    if content.last().is_some_and(|&b| b & 0x80 != 0) {
+        return Err("Truncated OBJECT IDENTIFIER arc".to_string());
+    }
+    let mut values = Vec::new();
+    let mut current = 0u64;
+    for &byte in content {
+        if current > u64::MAX >> 7 {
+            return Err("OBJECT IDENTIFIER arc too large".to_string());
+        }
+        current = current << 7 | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            values.push(current);
+            current = 0;
+        }
+    }
+    let first = *values.first().ok_or("Empty OBJECT IDENTIFIER")?;
+    let (a, b) = if first < 80 { (first / 40, first % 40) } else { (2, first - 80) };
+    Ok([a, b].into_iter().chain(values.into_iter().skip(1)).collect())
+}
@@ -0,0 +1,255 @@
+use super::der::decode_oid_content;
+use super::oid::{format_oid, lookup_oid};
+use super::types::{Asn1Node, Tag, TagClass};
+
+/// Values longer than this are shown as a prefix … suffix preview
+const MAX_HEX_BYTES: usize = 24;
+
+fn hex_preview(bytes: &[u8]) -> String {
+    if bytes.len() <= MAX_HEX_BYTES {
+        hex::encode(bytes)
+    } else {
+        format!(
+            "{}…{} ({} bytes)",
+            hex::encode(&bytes[..16]),
+            hex::encode(&bytes[bytes.len() - 4..]),
+            bytes.len()
+        )
+    }
+}
+
+fn describe_integer(content: &[u8]) -> String {
+    if content.is_empty() {
+        return "<empty>".to_string();
+    }
+    if content.len() <= 16 {
+        // Sign-extend the two's complement big-endian value
+        let fill = if content[0] & 0x80 != 0 { 0xff } else { 0x00 };
+        let mut bytes = [fill; 16];
+        bytes[16 - content.len()..].copy_from_slice(content);
+        let value = i128::from_be_bytes(bytes);
+        return match value {
+            -9..=9 => value.to_string(),
+            v if v > 0 => format!("{} (0x{:x})", v, v),
+            v => format!("{} (0x{})", v, hex::encode(content)),
+        };
+    }
+    let significant = content.iter().skip_while(|&&b| b == 0).count();
+    let top = content[content.len() - significant];
+    let bits = significant * 8 - top.leading_zeros() as usize;
+    format!("{}-bit: {}", bits, hex_preview(content))
+}
+
+/// `YYMMDDHHMMSSZ` / `YYYYMMDDHHMMSSZ` as an ISO-style date
+fn describe_time(text: &str, two_digit_year: bool) -> String {
+    let digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+    let year_len = if two_digit_year { 2 } else { 4 };
+    if digits.len() < year_len + 10 {
+        return format!("\"{}\"", text);
+    }
+    let year = if two_digit_year {
+        // RFC 5280 §4.1.2.5.1: 50–99 → 19xx, 00–49 → 20xx
+        let yy: u32 = digits[..2].parse().unwrap_or(0);
+        if yy >= 50 { 1900 + yy } else { 2000 + yy }
+    } else {
+        digits[..4].parse().unwrap_or(0)
+    };
+    let rest = &digits[year_len..];
+    format!(
+        "\"{}\" = {}-{}-{} {}:{}:{} UTC",
+        text,
+        year,
+        &rest[0..2],
+        &rest[2..4],
+        &rest[4..6],
+        &rest[6..8],
+        &rest[8..10]
+    )
+}
+
+/// Human-readable value of a single node (empty for NULL)
+pub fn describe_value(node: &Asn1Node) -> String {
+    let content = node.content.as_slice();
+    if node.tag.constructed {
+        return format!("{} element(s)", node.children.len());
+    }
+    if node.tag.class != TagClass::Universal {
+        return hex_preview(content);
+    }
+
+    match node.tag.number {
+        1 => if content.first().is_some_and(|&b| b != 0) { "TRUE" } else { "FALSE" }.to_string(),
+        2 | 10 => describe_integer(content),
+        3 => match content.split_first() {
+            Some((unused, _)) if node.encapsulates => format!("unused bits {}, encapsulates:", unused),
+            Some((unused, bits)) => format!("unused bits {}: {}", unused, hex_preview(bits)),
+            None => "<empty>".to_string(),
+        },
+        4 if node.encapsulates => "encapsulates:".to_string(),
+        5 => String::new(),
+        6 => match decode_oid_content(content) {
+            Ok(arcs) => match lookup_oid(&arcs) {
+                Some(info) => format!("{} ({})", format_oid(&arcs), info.name),
+                None => format_oid(&arcs),
+            },
+            Err(e) => format!("<invalid: {}>", e),
+        },
+        12 | 19 | 20 | 22 => format!("\"{}\"", String::from_utf8_lossy(content)),
+        23 => describe_time(&String::from_utf8_lossy(content), true),
+        24 => describe_time(&String::from_utf8_lossy(content), false),
+        30 => {
+            let units: Vec<u16> = content.chunks(2).map(|c| u16::from_be_bytes([c[0], *c.get(1).unwrap_or(&0)])).collect();
+            format!("\"{}\"", String::from_utf16_lossy(&units))
+        }
+        _ => hex_preview(content),
+    }
+}
+
+fn push_tree_lines(node: &Asn1Node, depth: usize, lines: &mut Vec<String>) {
+    let value = describe_value(node);
+    let separator = if value.is_empty() { "" } else { "  " };
+    lines.push(format!(
+        "{:>5}: d={} hl={} l={:>4}  {}{}{}{}",
+        node.offset,
+        depth,
+        node.header.len(),
+        node.content.len(),
+        "  ".repeat(depth),
+        node.tag.name(),
+        separator,
+        value
+    ));
+    for child in &node.children {
+        push_tree_lines(child, depth + 1, lines);
+    }
+}
+
+/// One line per element: offset, depth, header length, content length, tag and value
+///
+/// The first four columns match `openssl asn1parse -i`.
+pub fn format_tree(nodes: &[Asn1Node]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for node in nodes {
+        push_tree_lines(node, 0, &mut lines);
+    }
+    lines
+}
+
+fn universal_tag_meaning(number: u32) -> &'static str {
+    match number {
+        1 => "true/false flag, e.g. the critical bit on an extension",
+        2 => "two's complement big-endian integer; key components, versions and serial numbers",
+        3 => "bit string whose first content octet counts unused trailing bits; holds keys and signatures",
+        4 => "arbitrary bytes; often wraps another DER structure",
+        5 => "placeholder with no content, e.g. absent algorithm parameters",
+        6 => "object identifier naming an algorithm, attribute or extension",
+        12 | 19 | 20 | 22 | 30 => "character string",
+        16 => "ordered list of fields, like a struct",
+        17 => "unordered collection, used for the attributes of a distinguished name",
+        23 | 24 => "timestamp, e.g. certificate validity",
+        _ => "universal type",
+    }
+}
+
+fn explain_identifier(node: &Asn1Node) -> String {
+    let identifier = node.header[0];
+    let meaning = match node.tag.class {
+        TagClass::Universal => universal_tag_meaning(node.tag.number),
+        TagClass::ContextSpecific if node.tag.constructed => {
+            "explicitly tagged field; the meaning comes from the enclosing structure"
+        }
+        TagClass::ContextSpecific => "implicitly tagged field; the meaning comes from the enclosing structure",
+        _ => "application- or private-defined tag",
+    };
+    format!(
+        "0x{:02x} = {:02b} {} {:05b}: {}, {}, tag {} → {}: {}",
+        identifier,
+        identifier >> 6,
+        (identifier >> 5) & 1,
+        identifier & 0x1f,
+        node.tag.class.name(),
+        if node.tag.constructed { "constructed" } else { "primitive" },
+        node.tag.number,
+        node.tag.name(),
+        meaning
+    )
+}
+
+fn explain_length(node: &Asn1Node) -> String {
+    let identifier_len = node.header.len() - length_octets(node);
+    let length = &node.header[identifier_len..];
+    if length.len() == 1 {
+        format!("Length 0x{:02x}: short form, lengths below 128 fit in one octet ({})", length[0], node.content.len())
+    } else {
+        format!(
+            "Length {}: long form, 0x{:02x} says {} length octet(s) follow, giving {}",
+            length.iter().map(|b| format!("0x{:02x}", b)).collect::<Vec<_>>().join(" "),
+            length[0],
+            length.len() - 1,
+            node.content.len()
+        )
+    }
+}
+
+fn length_octets(node: &Asn1Node) -> usize {
+    let identifier_len = if node.header[0] & 0x1f == 0x1f {
+        1 + node.header[1..].iter().take_while(|&&b| b & 0x80 != 0).count() + 1
+    } else {
+        1
+    };
+    node.header.len() - identifier_len
+}
+
+/// Notes on each distinct tag, length form and OID seen, in order of first appearance
+pub fn explain(nodes: &[Asn1Node]) -> Vec<String> {
+    let all: Vec<&Asn1Node> = nodes.iter().flat_map(|node| node.depth_first()).collect();
+    let mut notes = Vec::new();
+
+    let mut seen_tags: Vec<Tag> = Vec::new();
+    for node in &all {
+        if !seen_tags.contains(&node.tag) {
+            seen_tags.push(node.tag);
+            notes.push(explain_identifier(node));
+        }
+    }
+
+    if let Some(node) = all.iter().find(|node| length_octets(node) == 1) {
+        notes.push(explain_length(node));
+    }
+    if let Some(node) = all.iter().find(|node| length_octets(node) > 1) {
+        notes.push(explain_length(node));
+    }
+
+    if let Some(node) = all
+        .iter()
+        .find(|node| node.tag.is_universal(2) && node.content.len() > 1 && node.content[0] == 0)
+    {
+        notes.push(format!(
+            "Offset {}: INTEGER starts with 0x00 so the value stays positive (the next octet has its top bit set)",
+            node.offset
+        ));
+    }
+
+    for node in all.iter().filter(|node| node.encapsulates) {
+        notes.push(format!(
+            "Offset {}: {} content is itself DER, so it is parsed as a nested structure",
+            node.offset,
+            node.tag.name()
+        ));
+    }
+
+    let mut seen_oids: Vec<Vec<u64>> = Vec::new();
+    for node in all.iter().filter(|node| node.tag.is_universal(6)) {
+        let Ok(arcs) = decode_oid_content(&node.content) else { continue };
+        if seen_oids.contains(&arcs) {
+            continue;
+        }
+        notes.push(match lookup_oid(&arcs) {
+            Some(info) => format!("{} = {}: {}", format_oid(&arcs), info.name, info.description),
+            None => format!("{}: not in the built-in OID table", format_oid(&arcs)),
+        });
+        seen_oids.push(arcs);
+    }
+
+    notes
+}
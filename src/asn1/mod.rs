@@ -0,0 +1,6 @@
+pub mod types;
+pub mod der;
+pub mod oid;
+pub mod parser;
+pub mod dump;
+pub mod demo;
@@ -0,0 +1,83 @@
+// ============================================================================
+// Object Identifier Registry
+// ============================================================================
+// Names follow OpenSSL's short names so the dump can be compared with
+// `openssl asn1parse`.
+
+/// rsaEncryption (PKCS#1): 1.2.840.113549.1.1.1
+pub const OID_RSA_ENCRYPTION: &[u64] = &[1, 2, 840, 113549, 1, 1, 1];
+
+/// A known OID with its conventional name and a one-line explanation
+pub struct OidInfo {
+    pub arcs: &'static [u64],
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+const fn oid(arcs: &'static [u64], name: &'static str, description: &'static str) -> OidInfo {
+    OidInfo { arcs, name, description }
+}
+
+pub const KNOWN_OIDS: &[OidInfo] = &[
+    // PKCS#1 (RFC 8017)
+    oid(OID_RSA_ENCRYPTION, "rsaEncryption", "RSA public key, used as the algorithm in SPKI and PKCS#8"),
+    oid(&[1, 2, 840, 113549, 1, 1, 5], "sha1WithRSAEncryption", "PKCS#1 v1.5 signature with SHA-1 (deprecated)"),
+    oid(&[1, 2, 840, 113549, 1, 1, 7], "rsaesOaep", "RSAES-OAEP encryption; parameters name the hash and MGF"),
+    oid(&[1, 2, 840, 113549, 1, 1, 8], "mgf1", "Mask generation function MGF1 used by OAEP and PSS"),
+    oid(&[1, 2, 840, 113549, 1, 1, 10], "rsassaPss", "RSASSA-PSS probabilistic signature scheme"),
+    oid(&[1, 2, 840, 113549, 1, 1, 11], "sha256WithRSAEncryption", "PKCS#1 v1.5 signature with SHA-256"),
+    oid(&[1, 2, 840, 113549, 1, 1, 12], "sha384WithRSAEncryption", "PKCS#1 v1.5 signature with SHA-384"),
+    oid(&[1, 2, 840, 113549, 1, 1, 13], "sha512WithRSAEncryption", "PKCS#1 v1.5 signature with SHA-512"),
+    // PKCS#5, #7, #9
+    oid(&[1, 2, 840, 113549, 1, 5, 12], "PBKDF2", "Password-based key derivation function 2 (PKCS#5)"),
+    oid(&[1, 2, 840, 113549, 1, 5, 13], "PBES2", "Password-based encryption scheme 2, wraps encrypted PKCS#8 keys"),
+    oid(&[1, 2, 840, 113549, 1, 7, 1], "pkcs7-data", "CMS/PKCS#7 plain data content type"),
+    oid(&[1, 2, 840, 113549, 1, 7, 2], "pkcs7-signedData", "CMS/PKCS#7 signed data content type"),
+    oid(&[1, 2, 840, 113549, 1, 9, 1], "emailAddress", "Legacy e-mail attribute in a distinguished name"),
+    oid(&[1, 2, 840, 113549, 1, 9, 14], "extensionRequest", "Extensions requested in a PKCS#10 CSR"),
+    oid(&[1, 2, 840, 113549, 2, 9], "hmacWithSHA256", "HMAC-SHA-256 pseudo-random function for PBKDF2"),
+    // Elliptic curves (RFC 5480)
+    oid(&[1, 2, 840, 10045, 2, 1], "id-ecPublicKey", "Elliptic-curve public key; the parameter names the curve"),
+    oid(&[1, 2, 840, 10045, 3, 1, 7], "prime256v1", "NIST P-256 curve (secp256r1)"),
+    oid(&[1, 2, 840, 10045, 4, 3, 2], "ecdsa-with-SHA256", "ECDSA signature with SHA-256"),
+    oid(&[1, 2, 840, 10045, 4, 3, 3], "ecdsa-with-SHA384", "ECDSA signature with SHA-384"),
+    oid(&[1, 3, 132, 0, 34], "secp384r1", "NIST P-384 curve"),
+    oid(&[1, 3, 132, 0, 35], "secp521r1", "NIST P-521 curve"),
+    oid(&[1, 3, 101, 110], "X25519", "Curve25519 Diffie-Hellman key (RFC 8410)"),
+    oid(&[1, 3, 101, 112], "ED25519", "Ed25519 signature key (RFC 8410)"),
+    // Hash and cipher algorithms (NIST)
+    oid(&[1, 3, 14, 3, 2, 26], "sha1", "SHA-1 hash"),
+    oid(&[2, 16, 840, 1, 101, 3, 4, 1, 2], "aes-128-cbc", "AES-128 in CBC mode; the parameter is the IV"),
+    oid(&[2, 16, 840, 1, 101, 3, 4, 1, 42], "aes-256-cbc", "AES-256 in CBC mode; the parameter is the IV"),
+    oid(&[2, 16, 840, 1, 101, 3, 4, 2, 1], "sha256", "SHA-256 hash"),
+    oid(&[2, 16, 840, 1, 101, 3, 4, 2, 2], "sha384", "SHA-384 hash"),
+    oid(&[2, 16, 840, 1, 101, 3, 4, 2, 3], "sha512", "SHA-512 hash"),
+    // X.500 attribute types (RFC 5280 Appendix A)
+    oid(&[2, 5, 4, 3], "commonName", "CN: the name of the subject or issuer"),
+    oid(&[2, 5, 4, 6], "countryName", "C: two-letter country code"),
+    oid(&[2, 5, 4, 7], "localityName", "L: city or locality"),
+    oid(&[2, 5, 4, 8], "stateOrProvinceName", "ST: state or province"),
+    oid(&[2, 5, 4, 10], "organizationName", "O: organisation"),
+    oid(&[2, 5, 4, 11], "organizationalUnitName", "OU: department within the organisation"),
+    // X.509 v3 extensions (RFC 5280 §4.2)
+    oid(&[2, 5, 29, 14], "subjectKeyIdentifier", "Hash identifying this certificate's public key"),
+    oid(&[2, 5, 29, 15], "keyUsage", "Bit flags limiting what the key may be used for"),
+    oid(&[2, 5, 29, 17], "subjectAltName", "DNS names, IPs and e-mail addresses the certificate covers"),
+    oid(&[2, 5, 29, 19], "basicConstraints", "Whether the subject is a CA, and its path length"),
+    oid(&[2, 5, 29, 31], "crlDistributionPoints", "Where to fetch revocation lists"),
+    oid(&[2, 5, 29, 32], "certificatePolicies", "Policies the certificate was issued under"),
+    oid(&[2, 5, 29, 35], "authorityKeyIdentifier", "Identifies the issuer's key that signed this certificate"),
+    oid(&[2, 5, 29, 37], "extendedKeyUsage", "Purposes such as TLS server or client authentication"),
+    oid(&[1, 3, 6, 1, 5, 5, 7, 1, 1], "authorityInfoAccess", "OCSP responder and issuer certificate URLs"),
+    oid(&[1, 3, 6, 1, 5, 5, 7, 3, 1], "serverAuth", "Extended key usage: TLS server authentication"),
+    oid(&[1, 3, 6, 1, 5, 5, 7, 3, 2], "clientAuth", "Extended key usage: TLS client authentication"),
+];
+
+pub fn lookup_oid(arcs: &[u64]) -> Option<&'static OidInfo> {
+    KNOWN_OIDS.iter().find(|info| info.arcs == arcs)
+}
+
+/// Dotted-decimal form, e.g. `1.2.840.113549.1.1.1`
+pub fn format_oid(arcs: &[u64]) -> String {
+    arcs.iter().map(u64::to_string).collect::<Vec<_>>().join(".")
+}
@@ -0,0 +1,77 @@
+use super::der::read_header;
+use super::types::{Asn1Node, Tag};
+
+/// Deeper nesting than this is treated as malformed rather than risking the stack
+pub const MAX_DEPTH: usize = 64;
+
+/// Parse every top-level element in the input into a tree
+pub fn parse_der(input: &[u8]) -> Result<Vec<Asn1Node>, String> {
+    parse_elements(input, 0, 0)
+}
+
+fn parse_elements(input: &[u8], base_offset: usize, depth: usize) -> Result<Vec<Asn1Node>, String> {
+    if depth > MAX_DEPTH {
+        return Err(format!("Nesting deeper than {} levels", MAX_DEPTH));
+    }
+
+    let mut nodes = Vec::new();
+    let mut position = 0;
+    while position < input.len() {
+        let offset = base_offset + position;
+        let header = read_header(&input[position..]).map_err(|e| format!("At offset {}: {}", offset, e))?;
+
+        let content_start = position + header.header_len;
+        let remaining = input.len() - content_start;
+        if header.length > remaining {
+            return Err(format!(
+                "At offset {}: element claims {} bytes but only {} remain",
+                offset, header.length, remaining
+            ));
+        }
+        let content = &input[content_start..content_start + header.length];
+        let content_offset = base_offset + content_start;
+
+        let (children, encapsulates) = if header.tag.constructed {
+            (parse_elements(content, content_offset, depth + 1)?, false)
+        } else {
+            match encapsulated(&header.tag, content) {
+                Some((skip, inner)) => match parse_elements(inner, content_offset + skip, depth + 1) {
+                    Ok(children) => (children, true),
+                    Err(_) => (Vec::new(), false),
+                },
+                None => (Vec::new(), false),
+            }
+        };
+
+        nodes.push(Asn1Node {
+            offset,
+            header: input[position..content_start].to_vec(),
+            tag: header.tag,
+            content: content.to_vec(),
+            children,
+            encapsulates,
+        });
+        position = content_start + header.length;
+    }
+    Ok(nodes)
+}
+
+/// BIT STRING and OCTET STRING payloads that look like a nested SEQUENCE
+///
+/// SubjectPublicKeyInfo, PKCS#8 and X.509 extensions all wrap DER this way.
+/// Returns how many leading bytes to skip (the unused-bits octet) and the payload.
+fn encapsulated<'a>(tag: &Tag, content: &'a [u8]) -> Option<(usize, &'a [u8])> {
+    let (skip, inner) = if tag.is_universal(3) {
+        match content.split_first() {
+            Some((0, inner)) => (1, inner),
+            _ => return None,
+        }
+    } else if tag.is_universal(4) {
+        (0, content)
+    } else {
+        return None;
+    };
+
+    // Only a SEQUENCE or SET, so random bytes are not mistaken for DER
+    matches!(inner.first(), Some(0x30) | Some(0x31)).then_some((skip, inner))
+}
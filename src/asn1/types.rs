@@ -0,0 +1,96 @@
+// ============================================================================
+// ASN.1 Types
+// ============================================================================
+
+/// The two class bits at the top of an identifier octet (X.690 §8.1.2.2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagClass {
+    Universal,
+    Application,
+    ContextSpecific,
+    Private,
+}
+
+impl TagClass {
+    pub fn name(self) -> &'static str {
+        match self {
+            TagClass::Universal => "universal",
+            TagClass::Application => "application",
+            TagClass::ContextSpecific => "context-specific",
+            TagClass::Private => "private",
+        }
+    }
+}
+
+/// A decoded identifier: class, primitive/constructed bit and tag number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag {
+    pub class: TagClass,
+    pub constructed: bool,
+    pub number: u32,
+}
+
+impl Tag {
+    pub fn is_universal(&self, number: u32) -> bool {
+        self.class == TagClass::Universal && self.number == number
+    }
+
+    /// Short name as printed in the tree: `SEQUENCE`, `[0]`, `[APPLICATION 3]`, …
+    pub fn name(&self) -> String {
+        match self.class {
+            TagClass::Universal => universal_tag_name(self.number)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("[UNIVERSAL {}]", self.number)),
+            TagClass::Application => format!("[APPLICATION {}]", self.number),
+            TagClass::ContextSpecific => format!("[{}]", self.number),
+            TagClass::Private => format!("[PRIVATE {}]", self.number),
+        }
+    }
+}
+
+/// Names of the universal tags that appear in keys, signatures and certificates
+pub fn universal_tag_name(number: u32) -> Option<&'static str> {
+    let name = match number {
+        1 => "BOOLEAN",
+        2 => "INTEGER",
+        3 => "BIT STRING",
+        4 => "OCTET STRING",
+        5 => "NULL",
+        6 => "OBJECT IDENTIFIER",
+        10 => "ENUMERATED",
+        12 => "UTF8String",
+        16 => "SEQUENCE",
+        17 => "SET",
+        19 => "PrintableString",
+        20 => "T61String",
+        22 => "IA5String",
+        23 => "UTCTime",
+        24 => "GeneralizedTime",
+        30 => "BMPString",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// One parsed element together with where it sits in the input
+#[derive(Debug, Clone)]
+pub struct Asn1Node {
+    /// Byte offset of the identifier octet from the start of the input
+    pub offset: usize,
+    /// Identifier and length octets exactly as encoded
+    pub header: Vec<u8>,
+    pub tag: Tag,
+    pub content: Vec<u8>,
+    /// Parsed children of a constructed element, or of a string that wraps DER
+    pub children: Vec<Asn1Node>,
+    /// True when `children` came from a BIT STRING / OCTET STRING payload
+    pub encapsulates: bool,
+}
+
+impl Asn1Node {
+    pub fn depth_first(&self) -> Vec<&Asn1Node> {
+        std::iter::once(self)
+            .chain(self.children.iter().flat_map(|child| child.depth_first()))
+            .collect()
+    }
+}
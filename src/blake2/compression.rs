@@ -0,0 +1,91 @@
+use super::constants::SIGMA;
+use super::types::{Blake2Variant, Word};
+
+/// The mixing function G: two additions of message words, four rotations
+#[allow(clippy::too_many_arguments)]
+pub fn g<W: Word>(v: &mut [W; 16], a: usize, b: usize, c: usize, d: usize, x: W, y: W, rotations: [u32; 4]) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(rotations[0]);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(rotations[1]);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(rotations[2]);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(rotations[3]);
+}
+
+// G is applied to the four columns, then the four diagonals, of the 4×4 work vector
+const COLUMNS_THEN_DIAGONALS: [[usize; 4]; 8] = [
+    [0, 4, 8, 12], [1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15],
+    [0, 5, 10, 15], [1, 6, 11, 12], [2, 7, 8, 13], [3, 4, 9, 14],
+];
+
+fn print_work_vector<W: Word>(label: &str, v: &[W; 16]) {
+    println!("\n  {}:", label);
+    for row in v.chunks(4) {
+        let words: Vec<String> = row.iter().map(|word| format!("{:0width$x}", word, width = 2 * W::BYTES)).collect();
+        println!("    {}", words.join(" "));
+    }
+}
+
+/// The compression function F (RFC 7693 §3.2)
+///
+/// `counter` is the number of bytes hashed so far including this block, and
+/// `last` marks the final block. With `show_steps` round 1 prints every G call
+/// and the other rounds print v[0] only.
+pub fn compress<W: Word>(
+    variant: &Blake2Variant<W>,
+    h: [W; 8],
+    block: &[W; 16],
+    counter: u128,
+    last: bool,
+    show_steps: bool,
+) -> [W; 8] {
+    // Work vector: chaining value on top, IV below, counter and final flag mixed in
+    let mut v = [W::default(); 16];
+    v[..8].copy_from_slice(&h);
+    v[8..].copy_from_slice(&variant.iv);
+    v[12] = v[12] ^ W::from_u128(counter);
+    v[13] = v[13] ^ W::from_u128(counter >> (8 * W::BYTES));
+    if last {
+        v[14] = v[14].not();
+    }
+
+    if show_steps {
+        println!("\n  Counter t = {} bytes, final block: {}", counter, last);
+        print_work_vector("Initial work vector v (h ‖ IV ⊕ t, f)", &v);
+    }
+
+    for round in 0..variant.rounds {
+        let s = &SIGMA[round % 10];
+        for (i, &[a, b, c, d]) in COLUMNS_THEN_DIAGONALS.iter().enumerate() {
+            g(&mut v, a, b, c, d, block[s[2 * i]], block[s[2 * i + 1]], variant.rotations);
+            if show_steps && round == 0 {
+                println!(
+                    "    G{}(v[{}], v[{}], v[{}], v[{}], m[{}], m[{}]) {} → v[{}] = {:0width$x}",
+                    i, a, b, c, d, s[2 * i], s[2 * i + 1],
+                    if i < 4 { "column  " } else { "diagonal" },
+                    a, v[a], width = 2 * W::BYTES
+                );
+            }
+        }
+        if show_steps && round == 0 {
+            print_work_vector("After round 1", &v);
+            println!();
+        } else if show_steps {
+            println!("  Round {:2}: σ = SIGMA[{}], v[0] = {:0width$x}", round + 1, round % 10, v[0], width = 2 * W::BYTES);
+        }
+    }
+
+    // Feed-forward: h'[i] = h[i] ⊕ v[i] ⊕ v[i + 8]
+    let result: [W; 8] = std::array::from_fn(|i| h[i] ^ v[i] ^ v[i + 8]);
+
+    if show_steps {
+        println!("\n  Feed-forward h[i] ⊕ v[i] ⊕ v[i+8]:");
+        for (i, word) in result.iter().enumerate() {
+            println!("    h[{}] = {:0width$x}", i, word, width = 2 * W::BYTES);
+        }
+    }
+
+    result
+}
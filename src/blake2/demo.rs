@@ -0,0 +1,72 @@
+use super::compression::compress;
+use super::constants::{BLAKE2B, BLAKE2S};
+use super::hash::{digest_bytes, initial_state, parameter_block, prepare_blocks, validate_params};
+use super::types::{Blake2Params, Blake2Variant, Word};
+
+fn run_blake2_demo_internal<W: Word>(
+    variant: &Blake2Variant<W>,
+    message_text: Option<&str>,
+    message_bytes: &[u8],
+    params: &Blake2Params,
+) {
+    // Header
+    println!("🔐 {} Hash Algorithm Demo", variant.name);
+    if let Some(text) = message_text {
+        println!("Message: \"{}\"", text);
+    }
+    println!("Message bytes: {:02x?}", message_bytes);
+    println!("Message length: {} bytes", message_bytes.len());
+
+    if let Err(e) = validate_params(variant, params) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+
+    println!("\n=== Parameters ===");
+    println!("  Word size: {} bits, rounds: {}, block: {} bytes", 8 * W::BYTES, variant.rounds, variant.block_bytes());
+    println!("  G rotations: {:?}", variant.rotations);
+    println!("  Digest length: {} bytes (max {})", params.digest_length, variant.max_output_bytes());
+    println!("  Key: {} bytes{}", params.key.len(), if params.key.is_empty() { " (unkeyed)" } else { " (MAC mode)" });
+    println!("  Salt: {}", if params.salt.is_empty() { "none".to_string() } else { hex::encode(&params.salt) });
+    println!("  Personalization: {}", if params.personal.is_empty() { "none".to_string() } else { hex::encode(&params.personal) });
+
+    let block = parameter_block(variant, params);
+    let h = initial_state(variant, params);
+    println!("\n=== Initial State h₀ = IV ⊕ Parameter Block ===");
+    println!("  Parameter block: {}", hex::encode(&block));
+    println!("    byte 0 digest length = {}, byte 1 key length = {}, fanout = depth = 1", block[0], block[1]);
+    for (i, (iv, word)) in variant.iv.iter().zip(h.iter()).enumerate() {
+        println!("  h[{}] = {:0width$x} ⊕ P[{}] = {:0width$x}", i, iv, i, word, width = 2 * W::BYTES);
+    }
+
+    let blocks = prepare_blocks(variant, &params.key, message_bytes);
+    println!("\n=== Message Blocks ===");
+    println!("  No length padding: the byte counter t and a final-block flag replace Merkle–Damgård strengthening");
+    for (i, block) in blocks.iter().enumerate() {
+        let role = if i == 0 && !params.key.is_empty() { " (key block)" } else { "" };
+        println!("  Block {}: t = {}, final = {}{}", i + 1, block.counter, block.last, role);
+    }
+
+    let mut state = h;
+    for (i, block) in blocks.iter().enumerate() {
+        println!("\n=== Compression Function (Block {}/{}) ===", i + 1, blocks.len());
+        state = compress(variant, state, &block.words, block.counter, block.last, i == 0 || block.last);
+    }
+
+    println!("\n=== Final Hash ===");
+    println!("  {}-{}: {}", variant.name, params.digest_length * 8, hex::encode(digest_bytes(&state, params.digest_length)));
+
+    println!("\n✅ {} hash computation completed!", variant.name);
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+pub fn run_blake2b_demo(message_text: Option<&str>, message_bytes: &[u8], params: &Blake2Params) {
+    run_blake2_demo_internal(&BLAKE2B, message_text, message_bytes, params);
+}
+
+pub fn run_blake2s_demo(message_text: Option<&str>, message_bytes: &[u8], params: &Blake2Params) {
+    run_blake2_demo_internal(&BLAKE2S, message_text, message_bytes, params);
+}
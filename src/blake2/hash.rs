@@ -0,0 +1,123 @@
+use super::compression::compress;
+use super::constants::{BLAKE2B, BLAKE2S};
+use super::types::{Blake2Params, Blake2Variant, Word};
+
+/// One message block with the counter and flag it is compressed with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blake2Block<W: Word> {
+    pub words: [W; 16],
+    /// Bytes hashed so far, including this block (padding excluded)
+    pub counter: u128,
+    pub last: bool,
+}
+
+/// Check the parameters against the variant's limits
+pub fn validate_params<W: Word>(variant: &Blake2Variant<W>, params: &Blake2Params) -> Result<(), String> {
+    let max = variant.max_output_bytes();
+    if params.digest_length == 0 || params.digest_length > max {
+        return Err(format!("{} digest length must be 1..={} bytes, got {}", variant.name, max, params.digest_length));
+    }
+    if params.key.len() > max {
+        return Err(format!("{} key must be at most {} bytes, got {}", variant.name, max, params.key.len()));
+    }
+    for (field, value) in [("salt", &params.salt), ("personalization", &params.personal)] {
+        if value.len() > variant.salt_bytes() {
+            return Err(format!(
+                "{} {} must be at most {} bytes, got {}",
+                variant.name, field, variant.salt_bytes(), value.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The parameter block (digest length, key length, fanout = depth = 1, salt, personalization)
+pub fn parameter_block<W: Word>(variant: &Blake2Variant<W>, params: &Blake2Params) -> Vec<u8> {
+    let mut block = vec![0u8; 8 * W::BYTES];
+    block[0] = params.digest_length as u8;
+    block[1] = params.key.len() as u8;
+    block[2] = 1;
+    block[3] = 1;
+    let salt_offset = 4 * W::BYTES;
+    let personal_offset = salt_offset + variant.salt_bytes();
+    block[salt_offset..salt_offset + params.salt.len()].copy_from_slice(&params.salt);
+    block[personal_offset..personal_offset + params.personal.len()].copy_from_slice(&params.personal);
+    block
+}
+
+/// h₀ = IV ⊕ parameter block
+pub fn initial_state<W: Word>(variant: &Blake2Variant<W>, params: &Blake2Params) -> [W; 8] {
+    let block = parameter_block(variant, params);
+    std::array::from_fn(|i| variant.iv[i] ^ W::from_le_slice(&block[i * W::BYTES..(i + 1) * W::BYTES]))
+}
+
+/// Split the (key block ‖ message) stream into little-endian blocks with their counters
+///
+/// A key is zero-padded to a full block and hashed first. The empty message
+/// still produces one all-zero final block.
+pub fn prepare_blocks<W: Word>(variant: &Blake2Variant<W>, key: &[u8], message: &[u8]) -> Vec<Blake2Block<W>> {
+    let block_bytes = variant.block_bytes();
+    let mut data = Vec::new();
+    if !key.is_empty() {
+        data.extend_from_slice(key);
+        data.resize(block_bytes, 0);
+    }
+    data.extend_from_slice(message);
+
+    let block_count = data.len().div_ceil(block_bytes).max(1);
+    (0..block_count)
+        .map(|i| {
+            let end = ((i + 1) * block_bytes).min(data.len());
+            let mut bytes = data[(i * block_bytes).min(end)..end].to_vec();
+            bytes.resize(block_bytes, 0);
+            Blake2Block {
+                words: std::array::from_fn(|j| W::from_le_slice(&bytes[j * W::BYTES..(j + 1) * W::BYTES])),
+                counter: end as u128,
+                last: i + 1 == block_count,
+            }
+        })
+        .collect()
+}
+
+/// Serialise the state little-endian and keep the first `digest_length` bytes
+pub fn digest_bytes<W: Word>(h: &[W; 8], digest_length: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(8 * W::BYTES);
+    for word in h {
+        word.append_le(&mut out);
+    }
+    out.truncate(digest_length);
+    out
+}
+
+/// BLAKE2 in sequential mode with any parameters, without printing any steps
+pub fn blake2<W: Word>(variant: &Blake2Variant<W>, params: &Blake2Params, message: &[u8]) -> Result<Vec<u8>, String> {
+    validate_params(variant, params)?;
+    let h = prepare_blocks(variant, &params.key, message)
+        .iter()
+        .fold(initial_state(variant, params), |h, block| {
+            compress(variant, h, &block.words, block.counter, block.last, false)
+        });
+    Ok(digest_bytes(&h, params.digest_length))
+}
+
+pub fn blake2b(message: &[u8], params: &Blake2Params) -> Result<Vec<u8>, String> {
+    blake2(&BLAKE2B, params, message)
+}
+
+pub fn blake2s(message: &[u8], params: &Blake2Params) -> Result<Vec<u8>, String> {
+    blake2(&BLAKE2S, params, message)
+}
+
+/// Unkeyed BLAKE2b with the full 64-byte digest
+pub fn blake2b_512(message: &[u8]) -> [u8; 64] {
+    let mut digest = [0u8; 64];
+    digest.copy_from_slice(&blake2b(message, &Blake2Params::new(64)).expect("default parameters are valid"));
+    digest
+}
+
+/// Unkeyed BLAKE2s with the full 32-byte digest
+pub fn blake2s_256(message: &[u8]) -> [u8; 32] {
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(&blake2s(message, &Blake2Params::new(32)).expect("default parameters are valid"));
+    digest
+}
@@ -0,0 +1,110 @@
+use std::fmt::LowerHex;
+use std::ops::BitXor;
+
+/// The word operations BLAKE2 needs, implemented for u64 (BLAKE2b) and u32 (BLAKE2s)
+pub trait Word: Copy + Default + PartialEq + BitXor<Output = Self> + LowerHex {
+    const BYTES: usize;
+
+    fn wrapping_add(self, other: Self) -> Self;
+    fn rotate_right(self, n: u32) -> Self;
+    fn not(self) -> Self;
+    /// Truncating conversion, used for the byte counter and parameter words
+    fn from_u128(value: u128) -> Self;
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    fn append_le(self, out: &mut Vec<u8>);
+}
+
+impl Word for u64 {
+    const BYTES: usize = 8;
+
+    fn wrapping_add(self, other: Self) -> Self {
+        u64::wrapping_add(self, other)
+    }
+    fn rotate_right(self, n: u32) -> Self {
+        u64::rotate_right(self, n)
+    }
+    fn not(self) -> Self {
+        !self
+    }
+    fn from_u128(value: u128) -> Self {
+        value as u64
+    }
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(bytes);
+        u64::from_le_bytes(word)
+    }
+    fn append_le(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl Word for u32 {
+    const BYTES: usize = 4;
+
+    fn wrapping_add(self, other: Self) -> Self {
+        u32::wrapping_add(self, other)
+    }
+    fn rotate_right(self, n: u32) -> Self {
+        u32::rotate_right(self, n)
+    }
+    fn not(self) -> Self {
+        !self
+    }
+    fn from_u128(value: u128) -> Self {
+        value as u32
+    }
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        let mut word = [0u8; 4];
+        word.copy_from_slice(bytes);
+        u32::from_le_bytes(word)
+    }
+    fn append_le(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+/// Constants that distinguish BLAKE2b from BLAKE2s
+#[derive(Debug, Clone, Copy)]
+pub struct Blake2Variant<W: Word> {
+    pub name: &'static str,
+    pub iv: [W; 8],
+    pub rounds: usize,
+    /// Rotation distances R1..R4 of the G function
+    pub rotations: [u32; 4],
+}
+
+impl<W: Word> Blake2Variant<W> {
+    /// 128 bytes for BLAKE2b, 64 for BLAKE2s
+    pub fn block_bytes(&self) -> usize {
+        16 * W::BYTES
+    }
+
+    /// Longest digest and key: 64 bytes for BLAKE2b, 32 for BLAKE2s
+    pub fn max_output_bytes(&self) -> usize {
+        8 * W::BYTES
+    }
+
+    /// Salt and personalization length: 16 bytes for BLAKE2b, 8 for BLAKE2s
+    pub fn salt_bytes(&self) -> usize {
+        2 * W::BYTES
+    }
+}
+
+/// The fields of the parameter block used in sequential mode (RFC 7693 §2.5)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Blake2Params {
+    pub digest_length: usize,
+    pub key: Vec<u8>,
+    /// Zero-padded to the variant's salt length
+    pub salt: Vec<u8>,
+    /// Zero-padded to the variant's personalization length
+    pub personal: Vec<u8>,
+}
+
+impl Blake2Params {
+    /// Unkeyed hashing with the given digest length
+    pub fn new(digest_length: usize) -> Self {
+        Blake2Params { digest_length, ..Default::default() }
+    }
+}
@@ -0,0 +1,88 @@
+use super::constants::{MSG_PERMUTATION, ROUNDS};
+use crate::sha256::constants::INITIAL_HASH;
+
+/// Chaining values and keys are eight words; the IV is SHA-256's
+pub type ChainingValue = [u32; 8];
+
+pub const IV: ChainingValue = INITIAL_HASH;
+
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, mx: u32, my: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(mx);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(my);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+/// One round: G on the four columns, then on the four diagonals
+pub fn round(state: &mut [u32; 16], m: &[u32; 16]) {
+    g(state, 0, 4, 8, 12, m[0], m[1]);
+    g(state, 1, 5, 9, 13, m[2], m[3]);
+    g(state, 2, 6, 10, 14, m[4], m[5]);
+    g(state, 3, 7, 11, 15, m[6], m[7]);
+    g(state, 0, 5, 10, 15, m[8], m[9]);
+    g(state, 1, 6, 11, 12, m[10], m[11]);
+    g(state, 2, 7, 8, 13, m[12], m[13]);
+    g(state, 3, 4, 9, 14, m[14], m[15]);
+}
+
+pub fn permute(m: &[u32; 16]) -> [u32; 16] {
+    std::array::from_fn(|i| m[MSG_PERMUTATION[i]])
+}
+
+/// The compression function: 16 output words, the first 8 being the new chaining value
+///
+/// With `show_steps` the state is printed after every round.
+pub fn compress(
+    chaining_value: &ChainingValue,
+    block_words: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+    show_steps: bool,
+) -> [u32; 16] {
+    let mut state = [0u32; 16];
+    state[..8].copy_from_slice(chaining_value);
+    state[8..12].copy_from_slice(&IV[..4]);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = block_len;
+    state[15] = flags;
+
+    if show_steps {
+        println!("    State: cv ‖ IV[0..4] ‖ t = {} ‖ b = {} ‖ d = 0x{:02x}", counter, block_len, flags);
+    }
+
+    let mut m = *block_words;
+    for r in 0..ROUNDS {
+        round(&mut state, &m);
+        if show_steps {
+            let words: Vec<String> = state[..4].iter().map(|word| format!("{:08x}", word)).collect();
+            println!("    Round {}: v[0..4] = {}", r + 1, words.join(" "));
+        }
+        if r + 1 < ROUNDS {
+            m = permute(&m);
+        }
+    }
+
+    // First half: chaining value. Second half: extra output for the XOF
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= chaining_value[i];
+    }
+    state
+}
+
+/// Read a block of up to 64 bytes as little-endian words, zero-padded
+pub fn block_words(block: &[u8]) -> [u32; 16] {
+    let mut padded = [0u8; 64];
+    padded[..block.len()].copy_from_slice(block);
+    std::array::from_fn(|i| u32::from_le_bytes([padded[4 * i], padded[4 * i + 1], padded[4 * i + 2], padded[4 * i + 3]]))
+}
+
+pub fn first_8_words(words: &[u32; 16]) -> ChainingValue {
+    std::array::from_fn(|i| words[i])
+}
@@ -0,0 +1,39 @@
+/// Bytes per compression block
+pub const BLOCK_LEN: usize = 64;
+
+/// Bytes per chunk: 16 blocks chained together, the leaves of the tree
+pub const CHUNK_LEN: usize = 1024;
+
+/// Default output and key length
+pub const OUT_LEN: usize = 32;
+pub const KEY_LEN: usize = 32;
+
+/// Rounds of the compression function (BLAKE2s uses 10)
+pub const ROUNDS: usize = 7;
+
+// Domain flags (BLAKE3 spec §2.1)
+pub const CHUNK_START: u32 = 1 << 0;
+pub const CHUNK_END: u32 = 1 << 1;
+pub const PARENT: u32 = 1 << 2;
+pub const ROOT: u32 = 1 << 3;
+pub const KEYED_HASH: u32 = 1 << 4;
+pub const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+pub const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+/// Applied to the message words between rounds, instead of BLAKE2's SIGMA table
+pub const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// Names of the set flags, for traces
+pub fn flag_names(flags: u32) -> String {
+    let names = [
+        (CHUNK_START, "CHUNK_START"),
+        (CHUNK_END, "CHUNK_END"),
+        (PARENT, "PARENT"),
+        (ROOT, "ROOT"),
+        (KEYED_HASH, "KEYED_HASH"),
+        (DERIVE_KEY_CONTEXT, "DERIVE_KEY_CONTEXT"),
+        (DERIVE_KEY_MATERIAL, "DERIVE_KEY_MATERIAL"),
+    ];
+    let set: Vec<&str> = names.iter().filter(|(bit, _)| flags & bit != 0).map(|(_, name)| *name).collect();
+    if set.is_empty() { "none".to_string() } else { set.join(" | ") }
+}
@@ -0,0 +1,142 @@
+use super::compression::{block_words, compress, ChainingValue, IV};
+use super::constants::{
+    flag_names, BLOCK_LEN, CHUNK_END, CHUNK_LEN, CHUNK_START, DERIVE_KEY_CONTEXT, DERIVE_KEY_MATERIAL, KEYED_HASH, KEY_LEN,
+    ROOT,
+};
+use super::hash::{blake3_with, key_words};
+use super::tree::{hash_subtree, TreeNode};
+use crate::common::validation::{validate_message_length, validate_non_empty_message};
+
+/// Largest input the demo accepts (1024 chunks)
+const MAX_INPUT: usize = 1 << 20;
+
+/// Trees with more chunks than this are summarised instead of drawn
+const MAX_CHUNKS_DRAWN: usize = 32;
+
+fn short_cv(cv: &ChainingValue) -> String {
+    format!("{:08x}{:08x}…", cv[0], cv[1])
+}
+
+fn print_tree(node: &TreeNode, prefix: &str, connector: &str, child_prefix: &str) {
+    match node {
+        TreeNode::Chunk { index, start, end, cv } => {
+            println!("  {}{}chunk {} [{}..{}) cv = {}", prefix, connector, index, start, end, short_cv(cv));
+        }
+        TreeNode::Parent { cv, left, right } => {
+            println!("  {}{}parent ({} chunks) cv = {}", prefix, connector, node.chunk_count(), short_cv(cv));
+            let next = format!("{}{}", prefix, child_prefix);
+            print_tree(left, &next, "├── ", "│   ");
+            print_tree(right, &next, "└── ", "    ");
+        }
+    }
+}
+
+/// The chaining key and flags for the selected mode
+fn mode_key(key: Option<&[u8]>, context: Option<&str>) -> Result<(ChainingValue, u32, String), String> {
+    match (key, context) {
+        (Some(_), Some(_)) => Err("Choose either a key or a context, not both".to_string()),
+        (Some(key), None) => {
+            let key: [u8; KEY_LEN] = key
+                .try_into()
+                .map_err(|_| format!("Key must be exactly {} bytes, got {}", KEY_LEN, key.len()))?;
+            Ok((key_words(&key), KEYED_HASH, "keyed_hash (MAC): the key replaces the IV".to_string()))
+        }
+        (None, Some(context)) => {
+            let context_key = blake3_with(context.as_bytes(), &IV, DERIVE_KEY_CONTEXT, KEY_LEN);
+            let mut key = [0u8; KEY_LEN];
+            key.copy_from_slice(&context_key);
+            Ok((
+                key_words(&key),
+                DERIVE_KEY_MATERIAL,
+                format!("derive_key: context {:?} hashed to key {}", context, hex::encode(key)),
+            ))
+        }
+        (None, None) => Ok((IV, 0, "hash: the IV is the key".to_string())),
+    }
+}
+
+fn run_blake3_demo_internal(
+    message_label: Option<String>,
+    input: &[u8],
+    length: usize,
+    key: Option<&[u8]>,
+    context: Option<&str>,
+) {
+    // Header
+    println!("🌳 BLAKE3 Hash Algorithm Demo");
+    if let Some(label) = message_label {
+        println!("Message: {}", label);
+    }
+    println!("Input length: {} bytes", input.len());
+
+    if let Err(e) = validate_non_empty_message(input).and_then(|_| validate_message_length(input, MAX_INPUT)) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+    if length == 0 {
+        eprintln!("\n❌ Error: Output length must be at least 1 byte");
+        return;
+    }
+    let (key_cv, flags, description) = match mode_key(key, context) {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return;
+        }
+    };
+
+    println!("\n=== Mode ===");
+    println!("  {}", description);
+    println!("  Mode flags: {}", flag_names(flags));
+
+    // The first compression in detail
+    println!("\n=== Compression Function (chunk 0, block 1) ===");
+    println!("  7 rounds of G on columns then diagonals; the message words are permuted between rounds");
+    let first_block = &input[..input.len().min(BLOCK_LEN)];
+    let mut first_flags = flags | CHUNK_START;
+    if input.len() <= BLOCK_LEN {
+        first_flags |= CHUNK_END;
+    }
+    println!("  Flags: {}", flag_names(first_flags));
+    compress(&key_cv, &block_words(first_block), 0, first_block.len() as u32, first_flags, true);
+
+    // The tree over all chunks
+    let (tree, root) = hash_subtree(input, 0, &key_cv, flags);
+    println!("\n=== Chunk Tree ===");
+    println!("  {} chunk{} of up to {} bytes, depth {}", tree.chunk_count(), if tree.chunk_count() == 1 { "" } else { "s" }, CHUNK_LEN, tree.depth());
+    println!("  Each chunk chains its {} blocks; parents compress left cv ‖ right cv", CHUNK_LEN / BLOCK_LEN);
+    if tree.chunk_count() <= MAX_CHUNKS_DRAWN {
+        print_tree(&tree, "", "", "");
+    } else {
+        println!("  (tree with {} chunks not drawn; use a shorter input to see it)", tree.chunk_count());
+    }
+
+    // Root and extendable output
+    println!("\n=== Root Output ===");
+    println!("  Root node flags: {}", flag_names(root.flags | ROOT));
+    println!("  Output block 0:");
+    let output = root.root_output_bytes(length, true);
+    println!("  {} bytes = {} root compression{} with counter 0..{}", length, length.div_ceil(64),
+             if length.div_ceil(64) == 1 { "" } else { "s" }, length.div_ceil(64));
+
+    println!("\n=== Final Hash ===");
+    println!("  BLAKE3: {}", hex::encode(&output));
+
+    println!("\n✅ BLAKE3 hash computation completed!");
+    if tree.chunk_count() == 1 {
+        println!("💡 Tip: Use --repeat to grow the input past {} bytes and see the chunk tree", CHUNK_LEN);
+    }
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+pub fn run_blake3_demo(input: &[u8], length: usize, key: Option<&[u8]>, context: Option<&str>) {
+    run_blake3_demo_internal(None, input, length, key, context);
+}
+
+pub fn run_blake3_demo_text(text: &str, repeat: usize, length: usize, key: Option<&[u8]>, context: Option<&str>) {
+    let label = if repeat > 1 { format!("\"{}\" × {}", text, repeat) } else { format!("\"{}\"", text) };
+    run_blake3_demo_internal(Some(label), text.repeat(repeat).as_bytes(), length, key, context);
+}
@@ -0,0 +1,38 @@
+use super::compression::{ChainingValue, IV};
+use super::constants::{DERIVE_KEY_CONTEXT, DERIVE_KEY_MATERIAL, KEYED_HASH, KEY_LEN, OUT_LEN};
+use super::tree::hash_subtree;
+
+/// Hash with a given key words and mode flags, reading `length` bytes of output
+pub fn blake3_with(input: &[u8], key: &ChainingValue, flags: u32, length: usize) -> Vec<u8> {
+    let (_, root) = hash_subtree(input, 0, key, flags);
+    root.root_output_bytes(length, false)
+}
+
+pub fn key_words(key: &[u8; KEY_LEN]) -> ChainingValue {
+    std::array::from_fn(|i| u32::from_le_bytes([key[4 * i], key[4 * i + 1], key[4 * i + 2], key[4 * i + 3]]))
+}
+
+/// Default BLAKE3 hash, 32 bytes
+pub fn blake3(input: &[u8]) -> [u8; OUT_LEN] {
+    let mut digest = [0u8; OUT_LEN];
+    digest.copy_from_slice(&blake3_with(input, &IV, 0, OUT_LEN));
+    digest
+}
+
+/// Extendable output: any length, and shorter outputs are prefixes of longer ones
+pub fn blake3_xof(input: &[u8], length: usize) -> Vec<u8> {
+    blake3_with(input, &IV, 0, length)
+}
+
+/// Keyed hash (a MAC): the 32-byte key replaces the IV
+pub fn blake3_keyed(key: &[u8; KEY_LEN], input: &[u8], length: usize) -> Vec<u8> {
+    blake3_with(input, &key_words(key), KEYED_HASH, length)
+}
+
+/// Key derivation: hash the context string to a key, then hash the material under it
+pub fn blake3_derive_key(context: &str, material: &[u8], length: usize) -> Vec<u8> {
+    let context_key = blake3_with(context.as_bytes(), &IV, DERIVE_KEY_CONTEXT, KEY_LEN);
+    let mut key = [0u8; KEY_LEN];
+    key.copy_from_slice(&context_key);
+    blake3_with(material, &key_words(&key), DERIVE_KEY_MATERIAL, length)
+}
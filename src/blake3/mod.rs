@@ -0,0 +1,5 @@
+pub mod constants;
+pub mod demo;
+pub mod compression;
+pub mod hash;
+pub mod tree;
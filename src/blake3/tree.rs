@@ -0,0 +1,139 @@
+// ============================================================================
+// BLAKE3 Chunk Tree
+// ============================================================================
+// The input is split into 1 KiB chunks. Each chunk is hashed on its own (so
+// chunks can be processed in parallel) and the chunk chaining values are
+// combined pairwise by parent nodes. A left subtree always holds the largest
+// power-of-two number of chunks that leaves at least one for the right.
+//
+// Only the root compression carries the ROOT flag; its output can be read at
+// any length by re-running it with counter 0, 1, 2, ... (64 bytes each).
+
+use super::compression::{block_words, compress, first_8_words, ChainingValue};
+use super::constants::{BLOCK_LEN, CHUNK_END, CHUNK_LEN, CHUNK_START, PARENT, ROOT};
+
+/// A compression that has not been run yet, so the root can add the ROOT flag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub input_cv: ChainingValue,
+    pub block_words: [u32; 16],
+    pub counter: u64,
+    pub block_len: u32,
+    pub flags: u32,
+}
+
+impl Output {
+    /// Chaining value of a non-root node
+    pub fn chaining_value(&self) -> ChainingValue {
+        first_8_words(&compress(&self.input_cv, &self.block_words, self.counter, self.block_len, self.flags, false))
+    }
+
+    /// `length` bytes of root output: one compression per 64 bytes, counting up
+    pub fn root_output_bytes(&self, length: usize, show_steps: bool) -> Vec<u8> {
+        let mut out = Vec::with_capacity(length);
+        let mut output_block = 0u64;
+        while out.len() < length {
+            let words = compress(
+                &self.input_cv,
+                &self.block_words,
+                output_block,
+                self.block_len,
+                self.flags | ROOT,
+                show_steps && output_block == 0,
+            );
+            out.extend(words.iter().flat_map(|word| word.to_le_bytes()));
+            output_block += 1;
+        }
+        out.truncate(length);
+        out
+    }
+}
+
+/// Hash one chunk: its blocks are chained, CHUNK_START on the first and CHUNK_END on the last
+///
+/// Returns the final block's compression unrun, since it is the root when the
+/// input fits in one chunk.
+pub fn chunk_output(key: &ChainingValue, chunk: &[u8], chunk_counter: u64, flags: u32) -> Output {
+    let blocks: Vec<&[u8]> = if chunk.is_empty() { vec![&[]] } else { chunk.chunks(BLOCK_LEN).collect() };
+    let mut cv = *key;
+    for (i, block) in blocks.iter().enumerate() {
+        let block_flags = flags | if i == 0 { CHUNK_START } else { 0 } | if i + 1 == blocks.len() { CHUNK_END } else { 0 };
+        let output = Output {
+            input_cv: cv,
+            block_words: block_words(block),
+            counter: chunk_counter,
+            block_len: block.len() as u32,
+            flags: block_flags,
+        };
+        if i + 1 == blocks.len() {
+            return output;
+        }
+        cv = output.chaining_value();
+    }
+    unreachable!("a chunk always has at least one block")
+}
+
+/// A parent node compresses left ‖ right chaining values as one 64-byte block
+pub fn parent_output(left: &ChainingValue, right: &ChainingValue, key: &ChainingValue, flags: u32) -> Output {
+    let mut block_words = [0u32; 16];
+    block_words[..8].copy_from_slice(left);
+    block_words[8..].copy_from_slice(right);
+    Output { input_cv: *key, block_words, counter: 0, block_len: BLOCK_LEN as u32, flags: flags | PARENT }
+}
+
+/// The shape of the tree with each node's chaining value, for rendering
+///
+/// The root's chaining value is the one it would have as a non-root node;
+/// the digest comes from `Output::root_output_bytes` instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeNode {
+    Chunk { index: u64, start: usize, end: usize, cv: ChainingValue },
+    Parent { cv: ChainingValue, left: Box<TreeNode>, right: Box<TreeNode> },
+}
+
+impl TreeNode {
+    pub fn cv(&self) -> ChainingValue {
+        match self {
+            TreeNode::Chunk { cv, .. } | TreeNode::Parent { cv, .. } => *cv,
+        }
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        match self {
+            TreeNode::Chunk { .. } => 1,
+            TreeNode::Parent { left, right, .. } => left.chunk_count() + right.chunk_count(),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        match self {
+            TreeNode::Chunk { .. } => 0,
+            TreeNode::Parent { left, right, .. } => 1 + left.depth().max(right.depth()),
+        }
+    }
+}
+
+/// Bytes in the left subtree: the largest power-of-two number of chunks below the total
+pub fn left_subtree_len(input_len: usize) -> usize {
+    let full_chunks = (input_len - 1) / CHUNK_LEN;
+    (1usize << full_chunks.ilog2()) * CHUNK_LEN
+}
+
+/// Hash the subtree over `input`, which starts `offset` bytes into the whole input
+///
+/// Returns the tree and the subtree root's compression, not yet run.
+pub fn hash_subtree(input: &[u8], offset: usize, key: &ChainingValue, flags: u32) -> (TreeNode, Output) {
+    if input.len() <= CHUNK_LEN {
+        let index = (offset / CHUNK_LEN) as u64;
+        let output = chunk_output(key, input, index, flags);
+        let node = TreeNode::Chunk { index, start: offset, end: offset + input.len(), cv: output.chaining_value() };
+        return (node, output);
+    }
+
+    let split = left_subtree_len(input.len());
+    let (left, _) = hash_subtree(&input[..split], offset, key, flags);
+    let (right, _) = hash_subtree(&input[split..], offset + split, key, flags);
+    let output = parent_output(&left.cv(), &right.cv(), key, flags);
+    let node = TreeNode::Parent { cv: output.chaining_value(), left: Box::new(left), right: Box::new(right) };
+    (node, output)
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use super::runner::run_directory;
+use super::types::FileReport;
+
+/// Maximum number of individual failures printed per file
+const MAX_FAILURES_SHOWN: usize = 5;
+
+fn print_file_report(report: &FileReport) {
+    let icon = match (report.failed, report.passed) {
+        (0, 0) => "⏭️ ",
+        (0, _) => "✅",
+        _ => "❌",
+    };
+    println!("\n{} {}", icon, report.file_name);
+    if !report.description.is_empty() {
+        println!("  Test: {}", report.description);
+    }
+    println!(
+        "  Passed: {}, Failed: {}, Skipped: {}",
+        report.passed, report.failed, report.skipped
+    );
+    if let Some(note) = &report.note {
+        println!("  Note: {}", note);
+    }
+    for failure in report.failures.iter().take(MAX_FAILURES_SHOWN) {
+        println!("    ✗ {}", failure);
+    }
+    if report.failures.len() > MAX_FAILURES_SHOWN {
+        println!("    … and {} more", report.failures.len() - MAX_FAILURES_SHOWN);
+    }
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Run every vendored vector file and print per-file results
+///
+/// Returns `true` when no vector failed.
+pub fn run_selftest(dir: &Path) -> bool {
+    println!("🧪 NIST CAVP Self-Test");
+    println!("Vector directory: {}", dir.display());
+
+    let reports = match run_directory(dir) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return false;
+        }
+    };
+
+    if reports.is_empty() {
+        eprintln!("\n❌ Error: No .rsp files found");
+        return false;
+    }
+
+    reports.iter().for_each(print_file_report);
+
+    let passed: usize = reports.iter().map(|r| r.passed).sum();
+    let failed: usize = reports.iter().map(|r| r.failed).sum();
+    let skipped: usize = reports.iter().map(|r| r.skipped).sum();
+
+    println!("\n=== Summary ===");
+    println!("  Files: {}", reports.len());
+    println!("  Passed: {}, Failed: {}, Skipped: {}", passed, failed, skipped);
+
+    if failed == 0 {
+        println!("\n✅ All executed vectors passed!");
+    } else {
+        println!("\n❌ {} vector(s) failed.", failed);
+    }
+    failed == 0
+}
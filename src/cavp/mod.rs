@@ -0,0 +1,4 @@
+pub mod types;
+pub mod parser;
+pub mod runner;
+pub mod demo;
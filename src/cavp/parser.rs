@@ -0,0 +1,82 @@
+use super::types::{CavpFile, CavpRecord, CavpSection, VectorKind};
+
+/// Parse the text of a NIST CAVP `.rsp` response file
+///
+/// The format is line oriented:
+/// - `# ...` comment lines (the leading block describes the test)
+/// - `[...]` section headers such as `[L = 32]` or `[ENCRYPT]`
+/// - `KEY = value` fields, grouped into records by blank lines
+pub fn parse_rsp(text: &str) -> Result<CavpFile, String> {
+    let mut file = CavpFile::default();
+    let mut record = CavpRecord::default();
+
+    for (line_number, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim();
+
+        if line.is_empty() {
+            flush_record(&mut file, &mut record);
+        } else if let Some(comment) = line.strip_prefix('#') {
+            if file.sections.is_empty() && record.fields.is_empty() {
+                file.header.push(comment.trim().to_string());
+            }
+        } else if line.starts_with('[') && line.ends_with(']') {
+            flush_record(&mut file, &mut record);
+            file.sections.push(CavpSection {
+                name: line[1..line.len() - 1].trim().to_string(),
+                records: Vec::new(),
+            });
+        } else if let Some((key, value)) = line.split_once('=') {
+            record.fields.push((key.trim().to_string(), value.trim().to_string()));
+        } else {
+            return Err(format!("Line {}: expected `KEY = value`, got {:?}", line_number + 1, line));
+        }
+    }
+
+    flush_record(&mut file, &mut record);
+    Ok(file)
+}
+
+fn flush_record(file: &mut CavpFile, record: &mut CavpRecord) {
+    if record.fields.is_empty() {
+        return;
+    }
+    if file.sections.is_empty() {
+        file.sections.push(CavpSection::default());
+    }
+    let section = file.sections.last_mut().expect("section was just ensured");
+    section.records.push(std::mem::take(record));
+}
+
+/// Human-readable description taken from the header, e.g. `SHA-256 ShortMsg`
+pub fn describe(file: &CavpFile) -> String {
+    file.header
+        .iter()
+        .find_map(|line| {
+            let start = line.find('"')?;
+            let end = line[start + 1..].find('"')?;
+            Some(line[start + 1..start + 1 + end].to_string())
+        })
+        .or_else(|| file.header.iter().find(|line| line.contains("AESVS")).cloned())
+        .unwrap_or_default()
+}
+
+/// Work out which test a file contains from its header, falling back to the file name
+pub fn detect_kind(file_name: &str, file: &CavpFile) -> VectorKind {
+    let haystack = format!("{} {}", file.header.join(" "), file_name);
+
+    if haystack.contains("AESVS") || file_name.starts_with("ECB") || file_name.starts_with("CBC") {
+        if haystack.contains("MMT") {
+            VectorKind::AesMultiBlock
+        } else if ["GFSbox", "KeySbox", "VarKey", "VarTxt"].iter().any(|t| haystack.contains(t)) {
+            VectorKind::AesKnownAnswer
+        } else {
+            VectorKind::Unknown
+        }
+    } else if haystack.contains("Monte") {
+        VectorKind::HashMonte
+    } else if haystack.contains("ShortMsg") || haystack.contains("LongMsg") {
+        VectorKind::HashMessage
+    } else {
+        VectorKind::Unknown
+    }
+}
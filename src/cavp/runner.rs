@@ -0,0 +1,291 @@
+use std::fs;
+use std::path::Path;
+
+use super::parser::{describe, detect_kind, parse_rsp};
+use super::types::{CavpFile, CavpRecord, FileReport, VectorKind};
+use crate::aes::cipher::Aes;
+use crate::aes::modes::{cbc_decrypt, cbc_encrypt, ecb_decrypt, ecb_encrypt};
+use crate::sha1::hash::sha1_bytes;
+use crate::sha256::hash::sha256_bytes;
+
+/// Hash functions the runner can check against SHAVS files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// Match a label such as `SHA-256` or a file name prefix such as `SHA256`
+    pub fn from_label(label: &str) -> Option<Self> {
+        let normalised: String = label
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_uppercase();
+        match normalised.as_str() {
+            "SHA1" => Some(HashAlgorithm::Sha1),
+            "SHA256" => Some(HashAlgorithm::Sha256),
+            _ => None,
+        }
+    }
+
+    pub fn digest(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha1 => sha1_bytes(message).to_vec(),
+            HashAlgorithm::Sha256 => sha256_bytes(message).to_vec(),
+        }
+    }
+}
+
+/// Run every `.rsp` file in a directory, sorted by file name
+pub fn run_directory(dir: &Path) -> Result<Vec<FileReport>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Cannot read {}: {}", dir.display(), e))?;
+
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rsp"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let contents = fs::read_to_string(path)
+                .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+            let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            Ok(run_file(&file_name, &contents))
+        })
+        .collect()
+}
+
+/// Parse one response file and check every record against the in-crate implementation
+pub fn run_file(file_name: &str, contents: &str) -> FileReport {
+    let mut report = FileReport {
+        file_name: file_name.to_string(),
+        description: String::new(),
+        kind: VectorKind::Unknown,
+        passed: 0,
+        failed: 0,
+        skipped: 0,
+        failures: Vec::new(),
+        note: None,
+    };
+
+    let file = match parse_rsp(contents) {
+        Ok(file) => file,
+        Err(e) => {
+            report.failed = 1;
+            report.failures.push(format!("Parse error: {}", e));
+            return report;
+        }
+    };
+
+    report.description = describe(&file);
+    report.kind = detect_kind(file_name, &file);
+
+    match report.kind {
+        VectorKind::HashMessage | VectorKind::HashMonte => {
+            match HashAlgorithm::from_label(&hash_label(file_name, &report.description)) {
+                Some(algorithm) if report.kind == VectorKind::HashMessage => {
+                    check_hash_messages(&file, algorithm, &mut report)
+                }
+                Some(algorithm) => check_hash_monte(&file, algorithm, &mut report),
+                None => skip_all(&file, &mut report, "hash algorithm is not implemented in this crate"),
+            }
+        }
+        VectorKind::AesKnownAnswer | VectorKind::AesMultiBlock => match AesMode::detect(file_name, &file) {
+            Some(mode) => check_aes(&file, mode, &mut report),
+            None => skip_all(&file, &mut report, "AES mode is not implemented in this crate (ECB and CBC only)"),
+        },
+        VectorKind::Unknown => skip_all(&file, &mut report, "unrecognised vector file"),
+    }
+
+    report
+}
+
+/// AES modes of operation the runner can check against AESVS files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AesMode {
+    Ecb,
+    Cbc,
+}
+
+impl AesMode {
+    /// Read the mode from a file name prefix such as `CBCMMT128`, or from the `for ECB` header line
+    pub fn detect(file_name: &str, file: &CavpFile) -> Option<Self> {
+        let from_label = |label: &str| match label {
+            l if l.starts_with("ECB") => Some(AesMode::Ecb),
+            l if l.starts_with("CBC") => Some(AesMode::Cbc),
+            _ => None,
+        };
+        from_label(file_name).or_else(|| {
+            file.header
+                .iter()
+                .find_map(|line| line.split_once(" for ").and_then(|(_, mode)| from_label(mode.trim())))
+        })
+    }
+}
+
+fn hash_label(file_name: &str, description: &str) -> String {
+    match description.split_whitespace().next() {
+        Some(label) if label.starts_with("SHA") => label.to_string(),
+        _ => ["ShortMsg", "LongMsg", "Monte"]
+            .iter()
+            .find_map(|suffix| file_name.find(suffix).map(|i| file_name[..i].to_string()))
+            .unwrap_or_default(),
+    }
+}
+
+fn skip_all(file: &CavpFile, report: &mut FileReport, reason: &str) {
+    report.skipped = file.sections.iter().map(|s| s.records.len()).sum();
+    report.note = Some(reason.to_string());
+}
+
+fn decode_field(record: &CavpRecord, key: &str) -> Result<Vec<u8>, String> {
+    let value = record.get(key).ok_or_else(|| format!("missing {}", key))?;
+    hex::decode(value).map_err(|e| format!("bad hex in {}: {}", key, e))
+}
+
+fn check_hash_messages(file: &CavpFile, algorithm: HashAlgorithm, report: &mut FileReport) {
+    for record in file.sections.iter().flat_map(|s| &s.records) {
+        let Some(bit_length) = record.get("Len").and_then(|l| l.parse::<usize>().ok()) else {
+            report.skipped += 1;
+            continue;
+        };
+
+        // Byte-oriented implementation: partial-byte messages cannot be expressed
+        if bit_length % 8 != 0 {
+            report.skipped += 1;
+            continue;
+        }
+
+        let outcome = decode_field(record, "Msg").and_then(|msg| {
+            let expected = decode_field(record, "MD")?;
+            let message = msg
+                .get(..bit_length / 8)
+                .ok_or_else(|| format!("malformed record: Msg has {} bytes, fewer than Len", msg.len()))?;
+            Ok((algorithm.digest(message), expected))
+        });
+
+        match outcome {
+            Ok((actual, expected)) if actual == expected => report.passed += 1,
+            Ok((actual, expected)) => {
+                report.failed += 1;
+                report.failures.push(format!(
+                    "Len = {}: expected {}, got {}",
+                    bit_length,
+                    hex::encode(expected),
+                    hex::encode(actual)
+                ));
+            }
+            Err(e) => {
+                report.failed += 1;
+                report.failures.push(format!("Len = {}: {}", bit_length, e));
+            }
+        }
+    }
+}
+
+/// AESVS known-answer and multi-block tests (AESVS §6.2, §6.3)
+///
+/// `[ENCRYPT]` records are checked by encrypting PLAINTEXT, `[DECRYPT]`
+/// records by decrypting CIPHERTEXT; CBC files also carry an IV.
+fn check_aes(file: &CavpFile, mode: AesMode, report: &mut FileReport) {
+    for section in &file.sections {
+        let encrypt = match section.name.as_str() {
+            "ENCRYPT" => true,
+            "DECRYPT" => false,
+            _ => {
+                report.skipped += section.records.len();
+                continue;
+            }
+        };
+        let (input_key, output_key) = if encrypt { ("PLAINTEXT", "CIPHERTEXT") } else { ("CIPHERTEXT", "PLAINTEXT") };
+
+        for record in &section.records {
+            let count = record.get("COUNT").unwrap_or_default();
+            let outcome = (|| {
+                let aes = Aes::new(&decode_field(record, "KEY")?)?;
+                let input = decode_field(record, input_key)?;
+                let expected = decode_field(record, output_key)?;
+                let actual = match (mode, encrypt) {
+                    (AesMode::Ecb, true) => ecb_encrypt(&aes, &input)?,
+                    (AesMode::Ecb, false) => ecb_decrypt(&aes, &input)?,
+                    (AesMode::Cbc, true) => cbc_encrypt(&aes, &decode_field(record, "IV")?, &input)?,
+                    (AesMode::Cbc, false) => cbc_decrypt(&aes, &decode_field(record, "IV")?, &input)?,
+                };
+                Ok::<_, String>((actual, expected))
+            })();
+
+            match outcome {
+                Ok((actual, expected)) if actual == expected => report.passed += 1,
+                Ok((actual, expected)) => {
+                    report.failed += 1;
+                    report.failures.push(format!(
+                        "{} COUNT = {}: expected {}, got {}",
+                        section.name,
+                        count,
+                        hex::encode(expected),
+                        hex::encode(actual)
+                    ));
+                }
+                Err(e) => {
+                    report.failed += 1;
+                    report.failures.push(format!("{} COUNT = {}: {}", section.name, count, e));
+                }
+            }
+        }
+    }
+}
+
+/// SHAVS Monte Carlo test (SHAVS §6.4)
+///
+/// Each checkpoint chains 1000 hashes where every message is the
+/// concatenation of the previous three digests:
+/// ```text
+/// MD₀ = MD₁ = MD₂ = Seed
+/// Mᵢ = MDᵢ₋₃ ‖ MDᵢ₋₂ ‖ MDᵢ₋₁,  MDᵢ = H(Mᵢ)   for i = 3..1002
+/// checkpoint = MD₁₀₀₂, which becomes the next Seed
+/// ```
+fn check_hash_monte(file: &CavpFile, algorithm: HashAlgorithm, report: &mut FileReport) {
+    let records: Vec<&CavpRecord> = file.sections.iter().flat_map(|s| &s.records).collect();
+
+    let Some(mut seed) = records.iter().find_map(|r| decode_field(r, "Seed").ok()) else {
+        report.failed += 1;
+        report.failures.push("missing Seed".to_string());
+        return;
+    };
+
+    for record in records.iter().filter(|r| r.get("COUNT").is_some()) {
+        let count = record.get("COUNT").unwrap_or_default();
+        let expected = match decode_field(record, "MD") {
+            Ok(expected) => expected,
+            Err(e) => {
+                report.failed += 1;
+                report.failures.push(format!("COUNT = {}: {}", count, e));
+                continue;
+            }
+        };
+
+        let mut window = [seed.clone(), seed.clone(), seed];
+        for _ in 3..1003 {
+            let message = window.concat();
+            window = [window[1].clone(), window[2].clone(), algorithm.digest(&message)];
+        }
+        let [_, _, checkpoint] = window;
+
+        if checkpoint == expected {
+            report.passed += 1;
+        } else {
+            report.failed += 1;
+            report.failures.push(format!(
+                "COUNT = {}: expected {}, got {}",
+                count,
+                hex::encode(&expected),
+                hex::encode(&checkpoint)
+            ));
+        }
+        seed = checkpoint;
+    }
+}
@@ -0,0 +1,66 @@
+// ============================================================================
+// NIST CAVP Response File Types
+// ============================================================================
+
+/// One `KEY = value` group from a response file, separated by blank lines
+#[derive(Debug, Clone, Default)]
+pub struct CavpRecord {
+    pub fields: Vec<(String, String)>,
+}
+
+impl CavpRecord {
+    /// Look up a field by name (case-sensitive, as written by CAVS)
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Records sharing one bracketed header such as `[L = 32]` or `[ENCRYPT]`
+#[derive(Debug, Clone, Default)]
+pub struct CavpSection {
+    pub name: String,
+    pub records: Vec<CavpRecord>,
+}
+
+/// A parsed `.rsp` file: the leading `#` comment lines plus its sections
+#[derive(Debug, Clone, Default)]
+pub struct CavpFile {
+    pub header: Vec<String>,
+    pub sections: Vec<CavpSection>,
+}
+
+/// Which kind of validation test a response file contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorKind {
+    /// SHAVS ShortMsg / LongMsg: `Len`, `Msg`, `MD`
+    HashMessage,
+    /// SHAVS Monte Carlo: a `Seed` followed by 100 chained `MD` checkpoints
+    HashMonte,
+    /// AESVS known-answer tests (GFSbox, KeySbox, VarKey, VarTxt)
+    AesKnownAnswer,
+    /// AESVS multi-block message tests
+    AesMultiBlock,
+    Unknown,
+}
+
+/// Pass/fail counts for a single vector file
+#[derive(Debug, Clone)]
+pub struct FileReport {
+    pub file_name: String,
+    pub description: String,
+    pub kind: VectorKind,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub failures: Vec<String>,
+    pub note: Option<String>,
+}
+
+impl FileReport {
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+}
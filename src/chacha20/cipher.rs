@@ -0,0 +1,79 @@
+// ============================================================================
+// ChaCha20 Stream Cipher (RFC 8439)
+// ============================================================================
+// A 4×4 matrix of 32-bit words - constants, 256-bit key, block counter and
+// 96-bit nonce - is scrambled by 20 rounds of add-rotate-xor and added back to
+// its input. The 64-byte result is XORed into the data, so encryption and
+// decryption are the same operation.
+
+pub const KEY_BYTES: usize = 32;
+pub const NONCE_BYTES: usize = 12;
+pub const BLOCK_BYTES: usize = 64;
+
+/// "expand 32-byte k" as four little-endian words
+pub const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+pub type State = [u32; 16];
+
+/// The quarter round on words a, b, c, d of the state (RFC 8439 §2.2)
+pub fn quarter_round(state: &mut State, a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+fn le_words<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0u32; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    words
+}
+
+/// Initial state: constants ‖ key ‖ counter ‖ nonce
+pub fn initial_state(key: &[u8; KEY_BYTES], counter: u32, nonce: &[u8; NONCE_BYTES]) -> State {
+    let mut state = [0u32; 16];
+    state[..4].copy_from_slice(&CONSTANTS);
+    state[4..12].copy_from_slice(&le_words::<8>(key));
+    state[12] = counter;
+    state[13..].copy_from_slice(&le_words::<3>(nonce));
+    state
+}
+
+/// One 64-byte keystream block: 10 column/diagonal double rounds, then add the input
+pub fn block(key: &[u8; KEY_BYTES], counter: u32, nonce: &[u8; NONCE_BYTES]) -> [u8; BLOCK_BYTES] {
+    let input = initial_state(key, counter, nonce);
+    let mut state = input;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut output = [0u8; BLOCK_BYTES];
+    for ((chunk, word), original) in output.chunks_exact_mut(4).zip(state).zip(input) {
+        chunk.copy_from_slice(&word.wrapping_add(original).to_le_bytes());
+    }
+    output
+}
+
+/// Encrypt or decrypt: XOR the data with the keystream starting at block `counter`
+pub fn apply_keystream(key: &[u8; KEY_BYTES], counter: u32, nonce: &[u8; NONCE_BYTES], data: &[u8]) -> Vec<u8> {
+    data.chunks(BLOCK_BYTES)
+        .zip(counter..)
+        .flat_map(|(chunk, counter)| {
+            let keystream = block(key, counter, nonce);
+            chunk.iter().zip(keystream).map(|(byte, key_byte)| byte ^ key_byte).collect::<Vec<_>>()
+        })
+        .collect()
+}
@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use super::known::{compare_digests, differing_blocks, differing_offsets, load_pair, KnownCollision, KNOWN_COLLISIONS};
+
+/// Number of differing offsets listed before eliding the rest
+const MAX_OFFSETS_SHOWN: usize = 8;
+
+/// Width the history paragraphs are wrapped to
+const TEXT_WIDTH: usize = 88;
+
+fn print_wrapped(text: &str) {
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.len() + 1 + word.len() > TEXT_WIDTH {
+            println!("  {}", line);
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        println!("  {}", line);
+    }
+}
+
+/// Print one pair and return whether it still collides under its broken hash
+fn print_collision(dir: &Path, collision: &KnownCollision, suffix: &[u8]) -> bool {
+    println!("\n=== {} ({}, {}) ===", collision.name, collision.broken.name(), collision.year);
+    print_wrapped(collision.history);
+
+    let (first, second) = match load_pair(dir, collision) {
+        Ok(pair) => pair,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return false;
+        }
+    };
+
+    let offsets = differing_offsets(&first, &second);
+    let shown: Vec<String> = offsets.iter().take(MAX_OFFSETS_SHOWN).map(|offset| offset.to_string()).collect();
+    println!("\n  Files: {} ({} bytes), {} ({} bytes)", collision.files[0], first.len(), collision.files[1], second.len());
+    println!(
+        "  Differing bytes: {} at offsets {}{}",
+        offsets.len(),
+        shown.join(", "),
+        if offsets.len() > MAX_OFFSETS_SHOWN { ", …" } else { "" }
+    );
+    println!("  Differing 64-byte blocks: {:?}", differing_blocks(&first, &second));
+
+    println!("\n  Digests:");
+    let mut holds = true;
+    for comparison in compare_digests(&first, &second) {
+        let verdict = match (comparison.collides(), comparison.digest == collision.broken) {
+            (true, _) => "✅ identical",
+            (false, true) => "❌ differ (expected a collision)",
+            (false, false) => "differ",
+        };
+        println!("    {:<7} {}", comparison.digest.name(), comparison.first);
+        println!("    {:<7} {}  {}", "", comparison.second, verdict);
+        if comparison.digest == collision.broken && !comparison.collides() {
+            holds = false;
+        }
+    }
+
+    // Merkle–Damgård: same state after the last differing block ⇒ same digest for any common suffix
+    let extended = |data: &[u8]| [data, suffix].concat();
+    let after = collision.broken.hex(&extended(&first)) == collision.broken.hex(&extended(&second));
+    println!("\n  Appending {:?} to both files:", String::from_utf8_lossy(suffix));
+    println!(
+        "    {} {}",
+        collision.broken.name(),
+        if after { "✅ still identical — the chaining value already matched" } else { "❌ no longer identical" }
+    );
+
+    holds && after
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Hash every vendored collision pair with MD5, SHA-1 and SHA-256
+///
+/// Returns `true` when every pair collides under the hash it breaks.
+pub fn run_collision_demo(dir: &Path, suffix: &str) -> bool {
+    println!("💥 Known MD5 and SHA-1 Collisions");
+    println!("Vector directory: {}", dir.display());
+
+    let results: Vec<bool> = KNOWN_COLLISIONS
+        .iter()
+        .map(|collision| print_collision(dir, collision, suffix.as_bytes()))
+        .collect();
+
+    println!("\n=== Why These Hashes Are Deprecated ===");
+    println!("  A signature covers the hash, not the document. If two documents share a digest,");
+    println!("  a signature on the harmless one is also valid for the other.");
+    println!("  • MD5 (128-bit): collisions in seconds since 2005; chosen-prefix collisions since 2007");
+    println!("  • SHA-1 (160-bit): identical-prefix collision in 2017, chosen-prefix in 2020");
+    println!("  • SHA-256: no collision known; the pairs above all differ under it");
+    println!("  Both broken hashes are Merkle–Damgård, so one colliding block pair can be reused");
+    println!("  under any common prefix state and extended with any common suffix.");
+
+    let passed = results.iter().filter(|&&ok| ok).count();
+    println!("\n=== Summary ===");
+    println!("  {}/{} published collisions reproduced", passed, results.len());
+    if passed == results.len() {
+        println!("\n✅ Every pair collides under the hash it breaks");
+    }
+    passed == results.len()
+}
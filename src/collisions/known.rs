@@ -0,0 +1,128 @@
+// ============================================================================
+// Published MD5 and SHA-1 Collisions
+// ============================================================================
+// Each pair is two different files with the same digest. Because MD5 and SHA-1
+// are Merkle–Damgård hashes, equal internal state after the colliding blocks
+// stays equal for any common suffix, so the vendored files keep only the part
+// that matters (e.g. the first 320 bytes of the SHAttered PDFs).
+
+use std::fs;
+use std::path::Path;
+
+use crate::md5::hash::md5_hex;
+use crate::sha1::hash::sha1_bytes;
+use crate::sha256::hash::sha256_bytes;
+
+/// Hash functions the collision demo compares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Digest {
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+impl Digest {
+    pub const ALL: [Digest; 3] = [Digest::Md5, Digest::Sha1, Digest::Sha256];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Digest::Md5 => "MD5",
+            Digest::Sha1 => "SHA-1",
+            Digest::Sha256 => "SHA-256",
+        }
+    }
+
+    pub fn hex(&self, data: &[u8]) -> String {
+        match self {
+            Digest::Md5 => md5_hex(data),
+            Digest::Sha1 => hex::encode(sha1_bytes(data)),
+            Digest::Sha256 => hex::encode(sha256_bytes(data)),
+        }
+    }
+}
+
+/// A published collision and where its two files are vendored
+#[derive(Debug, Clone, Copy)]
+pub struct KnownCollision {
+    pub name: &'static str,
+    /// The hash the pair collides under
+    pub broken: Digest,
+    pub year: u16,
+    pub files: [&'static str; 2],
+    pub history: &'static str,
+}
+
+pub const KNOWN_COLLISIONS: [KnownCollision; 3] = [
+    KnownCollision {
+        name: "Wang et al. MD5 collision",
+        broken: Digest::Md5,
+        year: 2004,
+        files: ["md5-wang-1.bin", "md5-wang-2.bin"],
+        history: "The first published MD5 collision: two 128-byte messages found with differential \
+                  cryptanalysis. By 2008 the same technique forged a rogue CA certificate, and in 2012 \
+                  the Flame malware used an MD5 chosen-prefix collision to fake a Windows Update signature.",
+    },
+    KnownCollision {
+        name: "SHAttered",
+        broken: Digest::Sha1,
+        year: 2017,
+        files: ["shattered-1-prefix.bin", "shattered-2-prefix.bin"],
+        history: "The first SHA-1 collision (CWI Amsterdam and Google), about 2⁶³ SHA-1 computations. \
+                  These are the first 320 bytes of two PDFs that render differently but share a SHA-1; \
+                  the rest of both files is identical.",
+    },
+    KnownCollision {
+        name: "SHA-1 is a Shambles",
+        broken: Digest::Sha1,
+        year: 2020,
+        files: ["sha-mbles-1.bin", "sha-mbles-2.bin"],
+        history: "A chosen-prefix collision (Leurent and Peyrin): both prefixes were picked in advance, \
+                  then used to forge PGP key certifications. Chosen-prefix attacks are what turn a \
+                  collision into impersonation.",
+    },
+];
+
+/// Both digests of a pair under one hash function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestComparison {
+    pub digest: Digest,
+    pub first: String,
+    pub second: String,
+}
+
+impl DigestComparison {
+    pub fn collides(&self) -> bool {
+        self.first == self.second
+    }
+}
+
+/// Hash both inputs with every function in `Digest::ALL`
+pub fn compare_digests(first: &[u8], second: &[u8]) -> Vec<DigestComparison> {
+    Digest::ALL
+        .iter()
+        .map(|&digest| DigestComparison { digest, first: digest.hex(first), second: digest.hex(second) })
+        .collect()
+}
+
+/// Offsets where the inputs differ (positions past the shorter input count as different)
+pub fn differing_offsets(first: &[u8], second: &[u8]) -> Vec<usize> {
+    (0..first.len().max(second.len()))
+        .filter(|&i| first.get(i) != second.get(i))
+        .collect()
+}
+
+/// Indices of the 64-byte compression blocks that contain a difference
+pub fn differing_blocks(first: &[u8], second: &[u8]) -> Vec<usize> {
+    let mut blocks: Vec<usize> = differing_offsets(first, second).iter().map(|offset| offset / 64).collect();
+    blocks.dedup();
+    blocks
+}
+
+/// Read the two files of a collision from `dir`
+pub fn load_pair(dir: &Path, collision: &KnownCollision) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let read = |name: &str| {
+        let path = dir.join(name);
+        fs::read(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+    };
+    Ok((read(collision.files[0])?, read(collision.files[1])?))
+}
@@ -0,0 +1,2 @@
+pub mod known;
+pub mod demo;
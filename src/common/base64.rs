@@ -0,0 +1,73 @@
+/// Standard base64 alphabet (RFC 4648 §4)
+const STANDARD_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// URL- and filename-safe alphabet (RFC 4648 §5), used by JWK and JWS
+const URL_SAFE_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn encode_with(bytes: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(alphabet[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else if pad {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+fn decode_with(text: &str, alphabet: &[u8; 64]) -> Result<Vec<u8>, String> {
+    let symbols: Vec<u8> = text
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .take_while(|&b| b != b'=')
+        .map(|b| {
+            alphabet
+                .iter()
+                .position(|&a| a == b)
+                .map(|v| v as u8)
+                .ok_or_else(|| format!("Invalid base64 character {:?}", b as char))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if symbols.len() % 4 == 1 {
+        return Err("Invalid base64 length".to_string());
+    }
+
+    let mut output = Vec::with_capacity(symbols.len() * 3 / 4);
+    for chunk in symbols.chunks(4) {
+        let group = chunk.iter().enumerate().fold(0u32, |acc, (i, &v)| acc | (v as u32) << (18 - 6 * i));
+        for i in 0..chunk.len() - 1 {
+            output.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+    Ok(output)
+}
+
+/// Encode bytes as padded standard base64
+pub fn encode(bytes: &[u8]) -> String {
+    encode_with(bytes, STANDARD_ALPHABET, true)
+}
+
+/// Encode bytes as standard base64 without `=` padding (OpenSSH fingerprints)
+pub fn encode_unpadded(bytes: &[u8]) -> String {
+    encode_with(bytes, STANDARD_ALPHABET, false)
+}
+
+/// Decode standard base64, ignoring whitespace and accepting missing padding
+pub fn decode(text: &str) -> Result<Vec<u8>, String> {
+    decode_with(text, STANDARD_ALPHABET)
+}
+
+/// Encode bytes as unpadded base64url, as JOSE requires (RFC 7515 §2)
+pub fn encode_url(bytes: &[u8]) -> String {
+    encode_with(bytes, URL_SAFE_ALPHABET, false)
+}
+
+/// Decode base64url, with or without padding
+pub fn decode_url(text: &str) -> Result<Vec<u8>, String> {
+    decode_with(text, URL_SAFE_ALPHABET)
+}
@@ -1 +1,4 @@
+pub mod base64;
+pub mod pem;
+pub mod random;
 pub mod validation;
@@ -0,0 +1,32 @@
+use super::base64;
+
+/// Wrap DER bytes in `-----BEGIN <label>-----` armor with 64-character lines (RFC 7468)
+pub fn encode_pem(label: &str, der: &[u8]) -> String {
+    let body = base64::encode(der);
+    let mut pem = format!("-----BEGIN {}-----\n", label);
+    for line in body.as_bytes().chunks(64) {
+        pem.push_str(std::str::from_utf8(line).unwrap_or_default());
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END {}-----\n", label));
+    pem
+}
+
+/// Extract the label and DER bytes from the first PEM block in the text
+pub fn decode_pem(text: &str) -> Result<(String, Vec<u8>), String> {
+    let begin = text.find("-----BEGIN ").ok_or("No PEM BEGIN line found")?;
+    let after_begin = &text[begin + "-----BEGIN ".len()..];
+    let label_end = after_begin.find("-----").ok_or("Malformed PEM BEGIN line")?;
+    let label = &after_begin[..label_end];
+
+    let end_marker = format!("-----END {}-----", label);
+    let body_start = label_end + "-----".len();
+    let body_end = after_begin.find(&end_marker).ok_or_else(|| format!("Missing {}", end_marker))?;
+
+    let der = base64::decode(&after_begin[body_start..body_end])?;
+    Ok((label.to_string(), der))
+}
+
+pub fn is_pem(bytes: &[u8]) -> bool {
+    bytes.windows(11).any(|w| w == b"-----BEGIN ")
+}
@@ -0,0 +1,62 @@
+// ============================================================================
+// Demo Random Number Generator
+// ============================================================================
+// SplitMix64 (Steele, Lea & Flood, 2014). Fast and statistically good, but
+// predictable from its output: fine for blinding factors and test data in a
+// teaching demo, never for real keys.
+
+#[derive(Debug, Clone)]
+pub struct DemoRng {
+    state: u64,
+}
+
+impl DemoRng {
+    pub fn new(seed: u64) -> Self {
+        DemoRng { state: seed }
+    }
+
+    /// Seed from the clock and a stack address so separate runs differ
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_entropy() -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        let marker = 0u8;
+        DemoRng::new(nanos ^ (&marker as *const u8 as u64).rotate_left(32))
+    }
+
+    /// Seed from Math.random: SystemTime::now panics on wasm32-unknown-unknown
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_entropy() -> Self {
+        let half = || (js_sys::Math::random() * 4_294_967_296.0) as u64;
+        DemoRng::new((half() << 32) | half())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, bound) by rejection, so small bounds are not biased
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Bound must be positive");
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(8) {
+            let value = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&value[..chunk.len()]);
+        }
+    }
+}
@@ -6,7 +6,7 @@ pub fn validate_non_empty_message(message: &[u8]) -> Result<(), String> {
 }
 
 pub fn is_printable_ascii(bytes: &[u8]) -> bool {
-    bytes.iter().all(|&b| b >= 32 && b <= 126)
+    bytes.iter().all(|b| (32..=126).contains(b))
 }
 
 pub fn validate_message_length(message: &[u8], max_length: usize) -> Result<(), String> {
@@ -16,3 +16,19 @@ pub fn validate_message_length(message: &[u8], max_length: usize) -> Result<(),
     Ok(())
 }
 
+/// Hex bytes from a hash command's message argument, or None if it is text
+///
+/// Hex is a non-empty, even-length run of hex digits, optionally after 0x.
+/// Anything else is text, except that a 0x prefix promises hex: `0xzz` is an error.
+pub fn parse_hex_message(text: &str) -> Result<Option<Vec<u8>>, String> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(digits) => match hex::decode(digits) {
+            Ok(bytes) if !bytes.is_empty() => Ok(Some(bytes)),
+            _ => Err(format!("'{}' is not an even number of hex digits after 0x", text)),
+        },
+        None if !text.is_empty() && text.len().is_multiple_of(2) && text.chars().all(|c| c.is_ascii_hexdigit()) => {
+            Ok(hex::decode(text).ok())
+        }
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,198 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use super::hkdf::{hkdf_expand, hkdf_expand_blocks, hkdf_extract, HASH_BYTES};
+use super::pbkdf2::{pbkdf2_block, pbkdf2_hmac_sha256};
+use super::scrypt::{finish, initial_blocks, ro_mix, scrypt, to_bytes, validate_params};
+use super::types::ScryptParams;
+use crate::sha256::hash::sha256_bytes;
+
+/// Baseline runs used to time a single SHA-256
+const BASELINE_RUNS: u32 = 2000;
+
+/// Password search spaces an offline attacker might walk through
+const SEARCH_SPACES: [(&str, f64, i32); 3] = [
+    ("6 lowercase letters", 26.0, 6),
+    ("8 lowercase + digits", 36.0, 8),
+    ("10 printable ASCII", 95.0, 10),
+];
+
+fn print_inputs(secret_label: &str, secret: &str, salt: &[u8], length: usize) {
+    println!("{}: \"{}\"", secret_label, secret);
+    println!("Salt: \"{}\" ({} bytes)", String::from_utf8_lossy(salt), salt.len());
+    println!("Output length: {} bytes", length);
+}
+
+fn format_seconds(seconds: f64) -> String {
+    const UNITS: [(f64, &str); 5] = [(31_557_600.0, "years"), (86_400.0, "days"), (3600.0, "hours"), (60.0, "minutes"), (1.0, "s")];
+    match UNITS.iter().find(|(unit, _)| seconds >= *unit) {
+        Some((unit, name)) if seconds / unit >= 1e6 => format!("{:.1e} {}", seconds / unit, name),
+        Some((unit, name)) => format!("{:.1} {}", seconds / unit, name),
+        None if seconds >= 1e-3 => format!("{:.1} ms", seconds * 1e3),
+        None => format!("{:.1} µs", seconds * 1e6),
+    }
+}
+
+/// Seconds for one SHA-256 of a short input, measured on this machine
+fn sha256_seconds(input: &[u8]) -> f64 {
+    let start = Instant::now();
+    for _ in 0..BASELINE_RUNS {
+        black_box(sha256_bytes(black_box(input)));
+    }
+    start.elapsed().as_secs_f64() / BASELINE_RUNS as f64
+}
+
+/// Compare the cost of one guess against a plain hash and walk it through the search spaces
+fn print_cost_comparison(name: &str, per_guess: f64, baseline: f64) {
+    println!("\n=== Why Password Hashing Must Be Slow ===");
+    println!("  One SHA-256 here:  {}", format_seconds(baseline));
+    println!("  One {} guess: {} ({:.0}× SHA-256)", name, format_seconds(per_guess), per_guess / baseline);
+    println!("\n  Time to try every password on one core (attackers run thousands in parallel):");
+    println!("  {:<22} {:>12} {:>16} {:>16}", "Search space", "Guesses", "SHA-256", name);
+    for (label, alphabet, length) in SEARCH_SPACES {
+        let guesses = alphabet.powi(length);
+        println!(
+            "  {:<22} {:>12.1e} {:>16} {:>16}",
+            label,
+            guesses,
+            format_seconds(guesses * baseline),
+            format_seconds(guesses * per_guess)
+        );
+    }
+    println!("\n  The defender pays the cost once per login; the attacker pays it once per guess.");
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// HKDF extract and expand over a high-entropy input key
+pub fn run_hkdf_demo(ikm: &str, salt: &[u8], info: &[u8], length: usize) {
+    println!("🔑 HKDF-SHA256 Key Derivation Demo");
+    print_inputs("Input keying material", ikm, salt, length);
+    println!("Info: \"{}\"", String::from_utf8_lossy(info));
+
+    println!("\n=== Extract: PRK = HMAC(salt, IKM) ===");
+    if salt.is_empty() {
+        println!("  Empty salt → {} zero bytes", HASH_BYTES);
+    }
+    let prk = hkdf_extract(salt, ikm.as_bytes());
+    println!("  PRK: {}", hex::encode(prk));
+
+    println!("\n=== Expand: T(i) = HMAC(PRK, T(i-1) ‖ info ‖ i) ===");
+    let blocks = match hkdf_expand_blocks(&prk, info, length) {
+        Ok(blocks) => blocks,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return;
+        }
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        println!("  T({}) = {}", i + 1, hex::encode(block));
+    }
+
+    let okm = hkdf_expand(&prk, info, length).expect("length already checked");
+    println!("\n=== Derived Key ===");
+    println!("  OKM: {}", hex::encode(&okm));
+
+    let other_purpose = hkdf_expand(&prk, &[info, b" (other purpose)"].concat(), length).expect("length already checked");
+    println!("\n=== Domain Separation ===");
+    println!("  info ‖ \" (other purpose)\" → {}", hex::encode(&other_purpose));
+    println!("  One secret, independent keys: each info string gets its own output");
+
+    let baseline = sha256_seconds(ikm.as_bytes());
+    let start = Instant::now();
+    black_box(hkdf_expand(&hkdf_extract(salt, ikm.as_bytes()), info, length).ok());
+    print_cost_comparison("HKDF", start.elapsed().as_secs_f64(), baseline);
+    println!("\n💡 Tip: HKDF is for secrets that are already random. Use PBKDF2, scrypt or Argon2 for passwords.");
+}
+
+/// PBKDF2 with a trace of the first output block and timings for growing iteration counts
+pub fn run_pbkdf2_demo(password: &str, salt: &[u8], iterations: u32, length: usize) {
+    println!("🔑 PBKDF2-HMAC-SHA256 Key Derivation Demo");
+    print_inputs("Password", password, salt, length);
+    println!("Iterations: {}", iterations);
+
+    let start = Instant::now();
+    let key = match pbkdf2_hmac_sha256(password.as_bytes(), salt, iterations, length) {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return;
+        }
+    };
+    let elapsed = start.elapsed().as_secs_f64();
+
+    println!("\n=== Block 1: T_1 = U_1 ⊕ U_2 ⊕ … ⊕ U_{} ===", iterations);
+    pbkdf2_block(password.as_bytes(), salt, iterations, 1, true);
+    println!("\n=== Derived Key ===");
+    println!("  DK: {}", hex::encode(&key));
+    println!("  {} block(s) × {} HMACs in {}", length.div_ceil(32), iterations, format_seconds(elapsed));
+
+    println!("\n=== Cost Grows Linearly With Iterations ===");
+    let counts: Vec<u32> = std::iter::successors(Some(1u32), |c| c.checked_mul(10)).take_while(|&c| c < iterations).collect();
+    for count in counts.into_iter().chain([iterations]) {
+        let start = Instant::now();
+        black_box(pbkdf2_hmac_sha256(password.as_bytes(), salt, count, 32).ok());
+        println!("  {:>10} iterations: {}", count, format_seconds(start.elapsed().as_secs_f64()));
+    }
+
+    let per_guess = elapsed / length.div_ceil(32) as f64;
+    print_cost_comparison("PBKDF2", per_guess, sha256_seconds(password.as_bytes()));
+    println!("\n💡 Tip: OWASP suggests 600,000 iterations for PBKDF2-HMAC-SHA256. GPUs still run it cheaply, so prefer memory-hard KDFs.");
+}
+
+/// scrypt with the PBKDF2 wrapping, a ROMix trace of the first lane and the memory cost
+pub fn run_scrypt_demo(password: &str, salt: &[u8], params: &ScryptParams, length: usize) {
+    println!("🔑 scrypt Key Derivation Demo");
+    print_inputs("Password", password, salt, length);
+
+    if let Err(e) = validate_params(params) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+    if length == 0 {
+        eprintln!("\n❌ Error: scrypt output length must be at least 1 byte");
+        return;
+    }
+
+    println!("\n=== Parameters ===");
+    println!("  N = 2^{} = {}, r = {}, p = {}", params.log_n, params.n(), params.r, params.p);
+    println!("  Block: 128 · r = {} bytes ({} Salsa20/8 calls per BlockMix)", 128 * params.r, 2 * params.r);
+    println!("  Memory per lane: 128 · r · N = {} KiB", params.memory_bytes() >> 10);
+
+    let start = Instant::now();
+    println!("\n=== B = PBKDF2(P, S, 1, p · 128r) ===");
+    let mut blocks = initial_blocks(password.as_bytes(), salt, params).expect("parameters already checked");
+    for (i, block) in blocks.iter().enumerate() {
+        println!("  B_{} = {}…", i, hex::encode(&to_bytes(block)[..16]));
+    }
+
+    println!("\n=== ROMix (lane 0) ===");
+    for (i, block) in blocks.iter_mut().enumerate() {
+        ro_mix(block, params.n(), params.r, i == 0);
+    }
+    if params.p > 1 {
+        println!("  The other {} lane(s) mix independently the same way", params.p - 1);
+    }
+
+    let key = finish(password.as_bytes(), &blocks, length).expect("length already checked");
+    let elapsed = start.elapsed().as_secs_f64();
+    println!("\n=== DK = PBKDF2(P, B, 1, dkLen) ===");
+    println!("  DK: {}", hex::encode(&key));
+    println!("  Derived in {}", format_seconds(elapsed));
+
+    println!("\n=== Memory Is the Cost ===");
+    println!("  Each guess fills {} KiB before it can finish.", params.memory_bytes() >> 10);
+    println!("  Keeping only every k-th V entry saves memory but recomputes up to k BlockMix per read,");
+    println!("  so a GPU with thousands of cores runs out of memory long before it runs out of cores.");
+    for log_n in [params.log_n.saturating_sub(4), params.log_n.saturating_sub(2)].into_iter().filter(|&n| n > 0) {
+        let smaller = ScryptParams { log_n, ..*params };
+        let start = Instant::now();
+        black_box(scrypt(password.as_bytes(), salt, &smaller, 32).ok());
+        println!("  N = 2^{:<2} {:>8} KiB: {}", log_n, smaller.memory_bytes() >> 10, format_seconds(start.elapsed().as_secs_f64()));
+    }
+    println!("  N = 2^{:<2} {:>8} KiB: {}", params.log_n, params.memory_bytes() >> 10, format_seconds(elapsed));
+
+    print_cost_comparison("scrypt", elapsed, sha256_seconds(password.as_bytes()));
+}
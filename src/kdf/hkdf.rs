@@ -0,0 +1,51 @@
+// ============================================================================
+// HKDF-SHA256 (RFC 5869)
+// ============================================================================
+// Extract concentrates the entropy of the input keying material into one
+// pseudorandom key; expand stretches that key into as many bytes as needed,
+// with `info` binding each output to its purpose:
+//
+//   PRK  = HMAC(salt, IKM)
+//   T(i) = HMAC(PRK, T(i-1) ‖ info ‖ i)      T(0) = empty
+//   OKM  = first L bytes of T(1) ‖ T(2) ‖ …
+//
+// HKDF is fast on purpose. It is for secrets that are already high-entropy
+// (a Diffie-Hellman output, a master key), not for passwords.
+
+use crate::sha256::hmac::hmac_sha256;
+
+pub const HASH_BYTES: usize = 32;
+
+/// Expand counts blocks in one byte, so at most 255 of them
+pub const MAX_OUTPUT_BYTES: usize = 255 * HASH_BYTES;
+
+/// PRK = HMAC-SHA256(salt, IKM); an empty salt means HashLen zero bytes
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; HASH_BYTES] {
+    let salt = if salt.is_empty() { &[0u8; HASH_BYTES][..] } else { salt };
+    hmac_sha256(salt, ikm)
+}
+
+/// The output blocks T(1), T(2), … covering `length` bytes
+pub fn hkdf_expand_blocks(prk: &[u8], info: &[u8], length: usize) -> Result<Vec<[u8; HASH_BYTES]>, String> {
+    if length == 0 || length > MAX_OUTPUT_BYTES {
+        return Err(format!("HKDF output length must be 1..={} bytes, got {}", MAX_OUTPUT_BYTES, length));
+    }
+    let mut blocks: Vec<[u8; HASH_BYTES]> = Vec::new();
+    for counter in 1..=length.div_ceil(HASH_BYTES) as u8 {
+        let previous = blocks.last().map(|block| block.as_slice()).unwrap_or_default();
+        blocks.push(hmac_sha256(prk, &[previous, info, &[counter]].concat()));
+    }
+    Ok(blocks)
+}
+
+/// OKM = the first `length` bytes of T(1) ‖ T(2) ‖ …
+pub fn hkdf_expand(prk: &[u8], info: &[u8], length: usize) -> Result<Vec<u8>, String> {
+    let mut okm = hkdf_expand_blocks(prk, info, length)?.concat();
+    okm.truncate(length);
+    Ok(okm)
+}
+
+/// Extract then expand
+pub fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], length: usize) -> Result<Vec<u8>, String> {
+    hkdf_expand(&hkdf_extract(salt, ikm), info, length)
+}
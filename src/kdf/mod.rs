@@ -0,0 +1,5 @@
+pub mod demo;
+pub mod hkdf;
+pub mod pbkdf2;
+pub mod scrypt;
+pub mod types;
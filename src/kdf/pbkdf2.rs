@@ -0,0 +1,55 @@
+// ============================================================================
+// PBKDF2-HMAC-SHA256 (RFC 8018 §5.2)
+// ============================================================================
+// Each 32-byte output block chains the PRF c times and xors every link:
+//
+//   U_1 = HMAC(P, S ‖ INT(i))
+//   U_j = HMAC(P, U_{j-1})
+//   T_i = U_1 ⊕ U_2 ⊕ … ⊕ U_c
+//
+// The iteration count c is the only cost knob: an attacker pays the same c
+// HMACs per guess, but nothing stops them running guesses in parallel.
+
+use crate::sha256::hmac::hmac_sha256;
+
+pub const HASH_BYTES: usize = 32;
+
+/// Iterations printed at each end of a traced block
+const TRACE_ITERATIONS: u32 = 3;
+
+/// T_i for block `index` (counting from 1)
+pub fn pbkdf2_block(password: &[u8], salt: &[u8], iterations: u32, index: u32, show_steps: bool) -> [u8; HASH_BYTES] {
+    let mut u = hmac_sha256(password, &[salt, &index.to_be_bytes()].concat());
+    let mut t = u;
+    if show_steps {
+        println!("  U_1 = HMAC(P, S ‖ {:08x}) = {}", index, hex::encode(u));
+    }
+
+    for j in 2..=iterations {
+        u = hmac_sha256(password, &u);
+        t.iter_mut().zip(u).for_each(|(t, u)| *t ^= u);
+        if show_steps && (j <= TRACE_ITERATIONS || j == iterations) {
+            if j == iterations && j > TRACE_ITERATIONS + 1 {
+                println!("  … {} more iterations …", j - TRACE_ITERATIONS - 1);
+            }
+            println!("  U_{} = HMAC(P, U_{}) = {}", j, j - 1, hex::encode(u));
+            println!("  T ⊕= U_{} → {}", j, hex::encode(t));
+        }
+    }
+    t
+}
+
+/// Derive `length` bytes from a password with c = `iterations`
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> Result<Vec<u8>, String> {
+    if iterations == 0 {
+        return Err("PBKDF2 needs at least one iteration".to_string());
+    }
+    if length == 0 {
+        return Err("PBKDF2 output length must be at least 1 byte".to_string());
+    }
+    let mut output: Vec<u8> = (1..=length.div_ceil(HASH_BYTES) as u32)
+        .flat_map(|index| pbkdf2_block(password, salt, iterations, index, false))
+        .collect();
+    output.truncate(length);
+    Ok(output)
+}
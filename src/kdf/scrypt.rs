@@ -0,0 +1,160 @@
+// ============================================================================
+// scrypt (RFC 7914)
+// ============================================================================
+// scrypt wraps a memory-hard core in two single-iteration PBKDF2 calls:
+//
+//   B        = PBKDF2(P, S, 1, p · 128r)        p blocks of 128r bytes
+//   B_i      = ROMix(B_i, N)                    for each of the p lanes
+//   DK       = PBKDF2(P, B, 1, dkLen)
+//
+// ROMix first fills V with N successive BlockMix outputs, then makes N
+// reads at data-dependent indices. Skipping the table means recomputing
+// from the start, so trading memory for time costs an attacker dearly.
+
+use super::pbkdf2::pbkdf2_hmac_sha256;
+use super::types::ScryptParams;
+
+/// Cap for the demo so a typo in log_n cannot exhaust the machine
+pub const MAX_MEMORY_BYTES: usize = 1 << 30;
+
+/// Salsa20/8 core: 8 rounds (4 double rounds) plus the feed-forward
+pub fn salsa20_8(input: &[u32; 16]) -> [u32; 16] {
+    let mut x = *input;
+    let mut quarter = |a: usize, b: usize, c: usize, d: usize| {
+        x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+        x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+        x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+        x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+    };
+    for _ in 0..4 {
+        // Columns
+        quarter(0, 4, 8, 12);
+        quarter(5, 9, 13, 1);
+        quarter(10, 14, 2, 6);
+        quarter(15, 3, 7, 11);
+        // Rows
+        quarter(0, 1, 2, 3);
+        quarter(5, 6, 7, 4);
+        quarter(10, 11, 8, 9);
+        quarter(15, 12, 13, 14);
+    }
+
+    let mut output = [0u32; 16];
+    for (out, (x, input)) in output.iter_mut().zip(x.iter().zip(input)) {
+        *out = x.wrapping_add(*input);
+    }
+    output
+}
+
+/// BlockMix: chain Salsa20/8 over the 2r 64-byte sub-blocks, then put even outputs before odd
+pub fn block_mix(block: &[u32], r: usize) -> Vec<u32> {
+    let mut x: [u32; 16] = block[block.len() - 16..].try_into().expect("block holds 2r sub-blocks");
+    let mut output = vec![0u32; block.len()];
+    for (i, sub_block) in block.chunks(16).enumerate() {
+        x.iter_mut().zip(sub_block).for_each(|(x, b)| *x ^= b);
+        x = salsa20_8(&x);
+        let slot = (i / 2) + (i % 2) * r;
+        output[16 * slot..16 * slot + 16].copy_from_slice(&x);
+    }
+    output
+}
+
+/// Integerify: the first word of the last 64-byte sub-block, reduced mod N
+pub fn integerify(block: &[u32], n: usize) -> usize {
+    block[block.len() - 16] as usize & (n - 1)
+}
+
+fn print_block(label: &str, block: &[u32]) {
+    println!("  {:<8} {}…", label, hex::encode(to_bytes(&block[..4])));
+}
+
+/// ROMix: fill V with N BlockMix outputs, then mix in N pseudo-randomly chosen entries
+pub fn ro_mix(block: &mut [u32], n: usize, r: usize, show_steps: bool) {
+    let traced = |i: usize| show_steps && (i < 2 || i == n - 1);
+    let mut x = block.to_vec();
+    let mut v = Vec::with_capacity(n * x.len());
+    if show_steps {
+        println!("  Fill: V[i] = X, X = BlockMix(X)  ({} × {} bytes)", n, 4 * x.len());
+    }
+    for i in 0..n {
+        if traced(i) {
+            print_block(&format!("V[{}]", i), &x);
+        } else if show_steps && i == 2 && n > 3 {
+            println!("  …");
+        }
+        v.extend_from_slice(&x);
+        x = block_mix(&x, r);
+    }
+
+    if show_steps {
+        println!("  Mix: j = Integerify(X) mod N, X = BlockMix(X ⊕ V[j])");
+    }
+    for i in 0..n {
+        let j = integerify(&x, n);
+        if traced(i) {
+            println!("  step {:<4} reads V[{}]", i, j);
+        } else if show_steps && i == 2 && n > 3 {
+            println!("  …");
+        }
+        x.iter_mut().zip(&v[j * block.len()..(j + 1) * block.len()]).for_each(|(x, v)| *x ^= v);
+        x = block_mix(&x, r);
+    }
+    block.copy_from_slice(&x);
+}
+
+/// RFC 7914 limits (N a power of two below 2^(16r), r · p < 2^30) plus the demo memory cap on V and B
+pub fn validate_params(params: &ScryptParams) -> Result<(), String> {
+    if params.r == 0 || params.p == 0 {
+        return Err(format!("r and p must be at least 1, got r = {}, p = {}", params.r, params.p));
+    }
+    if params.r.saturating_mul(params.p) >= 1 << 30 {
+        return Err(format!("r · p must be below 2^30, got {}", params.r.saturating_mul(params.p)));
+    }
+    let max_log_n = (16 * params.r).min(63) - 1;
+    if params.log_n == 0 || params.log_n as usize > max_log_n {
+        return Err(format!("log_n must be between 1 and {} for r = {}, got {}", max_log_n, params.r, params.log_n));
+    }
+    // V holds N blocks of 128r bytes; B holds p more
+    let memory = 128u128 * params.r as u128 * ((1u128 << params.log_n) + params.p as u128);
+    if memory > MAX_MEMORY_BYTES as u128 {
+        return Err(format!(
+            "N = 2^{} with r = {}, p = {} needs {} MiB, over the {} MiB demo limit",
+            params.log_n,
+            params.r,
+            params.p,
+            memory >> 20,
+            MAX_MEMORY_BYTES >> 20
+        ));
+    }
+    Ok(())
+}
+
+fn to_words(bytes: &[u8]) -> Vec<u32> {
+    bytes.chunks(4).map(|word| u32::from_le_bytes(word.try_into().expect("4-byte word"))).collect()
+}
+
+pub fn to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// The p lanes of B before ROMix, as little-endian words
+pub fn initial_blocks(password: &[u8], salt: &[u8], params: &ScryptParams) -> Result<Vec<Vec<u32>>, String> {
+    validate_params(params)?;
+    let bytes = pbkdf2_hmac_sha256(password, salt, 1, params.p * 128 * params.r)?;
+    Ok(bytes.chunks(128 * params.r).map(to_words).collect())
+}
+
+/// Derive `length` bytes from a password
+pub fn scrypt(password: &[u8], salt: &[u8], params: &ScryptParams, length: usize) -> Result<Vec<u8>, String> {
+    let mut blocks = initial_blocks(password, salt, params)?;
+    for block in blocks.iter_mut() {
+        ro_mix(block, params.n(), params.r, false);
+    }
+    finish(password, &blocks, length)
+}
+
+/// DK = PBKDF2(P, B, 1, dkLen) over the mixed lanes
+pub fn finish(password: &[u8], blocks: &[Vec<u32>], length: usize) -> Result<Vec<u8>, String> {
+    let mixed: Vec<u8> = blocks.iter().flat_map(|block| to_bytes(block)).collect();
+    pbkdf2_hmac_sha256(password, &mixed, 1, length)
+}
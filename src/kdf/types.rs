@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+/// The key derivation functions the `derive` command can run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfAlgorithm {
+    Hkdf,
+    Pbkdf2,
+    Scrypt,
+}
+
+impl KdfAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            KdfAlgorithm::Hkdf => "HKDF-SHA256",
+            KdfAlgorithm::Pbkdf2 => "PBKDF2-HMAC-SHA256",
+            KdfAlgorithm::Scrypt => "scrypt",
+        }
+    }
+}
+
+impl FromStr for KdfAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "hkdf" | "hkdfsha256" => Ok(KdfAlgorithm::Hkdf),
+            "pbkdf2" | "pbkdf2hmacsha256" => Ok(KdfAlgorithm::Pbkdf2),
+            "scrypt" => Ok(KdfAlgorithm::Scrypt),
+            _ => Err(format!("Unknown KDF '{}' (expected hkdf, pbkdf2 or scrypt)", s)),
+        }
+    }
+}
+
+/// scrypt cost parameters (RFC 7914 §2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryptParams {
+    /// N = 2^log_n, the number of 128·r-byte blocks ROMix keeps in memory
+    pub log_n: u8,
+    /// Block size factor
+    pub r: usize,
+    /// Parallelization: independent ROMix lanes
+    pub p: usize,
+}
+
+impl Default for ScryptParams {
+    /// N = 2^14, r = 8, p = 1: 16 MiB, the interactive-login setting from the scrypt paper
+    fn default() -> Self {
+        ScryptParams { log_n: 14, r: 8, p: 1 }
+    }
+}
+
+impl ScryptParams {
+    pub fn n(&self) -> usize {
+        1 << self.log_n
+    }
+
+    /// Bytes of the V array each ROMix lane fills: 128 · r · N
+    pub fn memory_bytes(&self) -> usize {
+        128 * self.r * self.n()
+    }
+}
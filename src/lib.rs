@@ -1,4 +1,19 @@
+pub mod aes;
+pub mod analysis;
+pub mod argon2;
+pub mod asn1;
+pub mod blake2;
+pub mod blake3;
+pub mod cavp;
+pub mod chacha20;
+pub mod collisions;
 pub mod common;
+pub mod kdf;
+pub mod md5;
+pub mod merkle;
+pub mod pow;
 pub mod rsa;
+pub mod sha1;
 pub mod sha256;
+pub mod sha3;
 pub mod wasm;
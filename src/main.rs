@@ -1,5 +1,7 @@
-use encryption_demo::{rsa, sha256};
-use clap::{Parser, Subcommand};
+use encryption_demo::{analysis, argon2, asn1, blake2, blake3, cavp, collisions, kdf, md5, merkle, pow, rsa, sha1, sha256, sha3};
+use encryption_demo::common::validation::parse_hex_message;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "encryption-demo")]
@@ -12,7 +14,83 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Algorithm {
+    /// Encrypt and decrypt a message with a key built from p and q, or work with key files
+    #[command(args_conflicts_with_subcommands = true)]
     Rsa {
+        #[command(subcommand)]
+        command: Option<RsaCommand>,
+
+        #[arg(short, long, required = true)]
+        message: Option<String>,
+
+        #[arg(short = 'p', required = true)]
+        p: Option<u64>,
+        #[arg(short = 'q', required = true)]
+        q: Option<u64>,
+        /// Public exponent: a number, or common, smallest, f4 (65537 only) or random
+        #[arg(long = "e", default_value = "common")]
+        e: rsa::types::ExponentPolicy,
+    },
+
+    /// Multi-prime RSA (RFC 8017) with CRT decryption, comparing d mod φ(n) and d mod λ(n)
+    RsaMultiPrime {
+        #[arg(short, long)]
+        message: String,
+
+        /// Two or more distinct primes, comma-separated
+        #[arg(long, value_delimiter = ',', num_args = 1.., required = true)]
+        primes: Vec<u64>,
+        /// Compute d modulo Carmichael's λ(n) instead of φ(n)
+        #[arg(long)]
+        lambda: bool,
+    },
+
+    /// Wiener's attack: generate a small-d key from p, q or attack a given (n, e)
+    RsaWiener {
+        #[arg(short = 'p', requires = "q", conflicts_with_all = ["n", "e"])]
+        p: Option<u64>,
+        #[arg(short = 'q', requires = "p")]
+        q: Option<u64>,
+
+        #[arg(long, requires = "e")]
+        n: Option<u64>,
+        #[arg(long, requires = "n")]
+        e: Option<u64>,
+    },
+
+    /// Factor n with several algorithms, derive d and decrypt a ciphertext
+    RsaCrack {
+        #[arg(long)]
+        n: u64,
+        #[arg(long)]
+        e: u64,
+        #[arg(short, long)]
+        ciphertext: Option<u64>,
+    },
+
+    /// Recover m directly as ∛c when e = 3 and m³ < n
+    RsaCubeRoot {
+        #[arg(short, long)]
+        message: String,
+
+        #[arg(short = 'p')]
+        p: u64,
+        #[arg(short = 'q')]
+        q: u64,
+    },
+
+    /// Håstad's broadcast attack: same message, three e = 3 keys
+    RsaHastad {
+        #[arg(short, long)]
+        message: String,
+
+        /// Search for the six primes starting here (keep below ~2^20 so n₁n₂n₃ fits in u128)
+        #[arg(long, default_value_t = 1_000_000)]
+        prime_start: u64,
+    },
+
+    /// Common-modulus attack: one n, two coprime public exponents
+    RsaCommonModulus {
         #[arg(short, long)]
         message: String,
 
@@ -22,32 +100,739 @@ enum Algorithm {
         q: u64,
     },
 
+    /// Multiply ciphertexts and scale an encrypted amount without the key, then stop it with OAEP
+    RsaMalleability {
+        #[arg(short, long, default_value_t = 100)]
+        amount: u64,
+        /// Second message for E(m₁)·E(m₂) = E(m₁·m₂)
+        #[arg(long, default_value_t = 3)]
+        other: u64,
+        /// Factor the attacker scales the amount by
+        #[arg(short, long, default_value_t = 10)]
+        factor: u64,
+
+        #[arg(short = 'p', default_value_t = 4294967291)]
+        p: u64,
+        #[arg(short = 'q', default_value_t = 4294967279)]
+        q: u64,
+    },
+
+    /// Recover d by timing decryptions, then show the Montgomery ladder and blinding stop it
+    RsaTiming {
+        #[arg(short = 'p', default_value_t = 65521)]
+        p: u64,
+        #[arg(short = 'q', default_value_t = 65519)]
+        q: u64,
+
+        #[arg(long, default_value_t = rsa::attacks::timing::DEFAULT_SAMPLES)]
+        samples: usize,
+        #[arg(long, default_value_t = rsa::attacks::timing::DEFAULT_REPETITIONS)]
+        repetitions: usize,
+    },
+
+    /// Compare square-and-multiply, Montgomery and windowed exponentiation: counts and timing
+    RsaExp {
+        #[arg(short, long)]
+        base: u64,
+        #[arg(short, long)]
+        exp: u64,
+        #[arg(short = 'n', long)]
+        modulus: u64,
+
+        #[arg(short, long, default_value_t = rsa::exponentiation::DEFAULT_WINDOW_BITS)]
+        window: u32,
+        #[arg(long, default_value_t = 100_000)]
+        iterations: u32,
+    },
+
+    /// Franklin-Reiter attack on m and α·m + β encrypted with e = 3
+    RsaFranklinReiter {
+        #[arg(short, long)]
+        message: String,
+
+        #[arg(short = 'p')]
+        p: u64,
+        #[arg(short = 'q')]
+        q: u64,
+
+        #[arg(long, default_value_t = 1)]
+        alpha: u64,
+        #[arg(long, default_value_t = 1)]
+        beta: u64,
+    },
+
     Sha256 {
         #[arg(short, long)]
         message: String,
     },
+
+    /// SHA-3 / SHAKE: Keccak-f[1600] step trace, absorb/squeeze phases and length extension
+    Sha3 {
+        #[arg(short, long)]
+        message: String,
+
+        /// sha3-224, sha3-256, sha3-384, sha3-512, shake128 or shake256
+        #[arg(long, default_value = "sha3-256")]
+        variant: sha3::types::Sha3Variant,
+
+        /// Output bytes for SHAKE (ignored by the fixed-length SHA3 functions)
+        #[arg(short, long, default_value_t = 32)]
+        length: usize,
+    },
+
+    /// BLAKE2b / BLAKE2s with keyed mode, salt, personalization and variable digest length
+    Blake2 {
+        #[arg(short, long)]
+        message: String,
+
+        #[arg(long, value_enum, default_value_t = Blake2Flavor::Blake2b)]
+        variant: Blake2Flavor,
+
+        /// Digest bytes (default: 64 for BLAKE2b, 32 for BLAKE2s)
+        #[arg(short, long)]
+        length: Option<usize>,
+
+        /// Key for MAC mode (text)
+        #[arg(long)]
+        key: Option<String>,
+        #[arg(long)]
+        salt: Option<String>,
+        /// Personalization string
+        #[arg(long)]
+        personal: Option<String>,
+    },
+
+    /// Derive a key with HKDF, PBKDF2 or scrypt and compare the cost of a guess
+    Derive {
+        /// hkdf, pbkdf2 or scrypt
+        #[arg(short, long, default_value = "pbkdf2")]
+        algorithm: kdf::types::KdfAlgorithm,
+
+        /// Password (input keying material for HKDF)
+        #[arg(short, long)]
+        password: String,
+
+        #[arg(short, long, default_value = "NaCl")]
+        salt: String,
+
+        /// Output bytes
+        #[arg(short, long, default_value_t = 32)]
+        length: usize,
+
+        /// HKDF context string binding the key to its purpose
+        #[arg(long, default_value = "")]
+        info: String,
+
+        /// PBKDF2 iteration count
+        #[arg(short, long, default_value_t = 10_000)]
+        iterations: u32,
+
+        /// scrypt: N = 2^log_n
+        #[arg(long, default_value_t = 14)]
+        log_n: u8,
+        /// scrypt block size factor
+        #[arg(short, default_value_t = 8)]
+        r: usize,
+        /// scrypt parallelization factor
+        #[arg(long = "parallel", default_value_t = 1)]
+        p: usize,
+    },
+
+    /// Argon2 with a trace of the memory matrix fill order (use small memory to see it all)
+    Argon2 {
+        #[arg(short, long)]
+        password: String,
+
+        #[arg(short, long, default_value = "somesalt")]
+        salt: String,
+
+        /// argon2d, argon2i or argon2id
+        #[arg(long, default_value = "argon2id")]
+        variant: argon2::types::Argon2Variant,
+
+        /// Memory in KiB
+        #[arg(short, long, default_value_t = 32)]
+        memory: u32,
+        #[arg(short = 't', long, default_value_t = 2)]
+        passes: u32,
+        #[arg(long, default_value_t = 2)]
+        lanes: u32,
+
+        /// Tag bytes
+        #[arg(short, long, default_value_t = 32)]
+        length: usize,
+    },
+
+    /// Hash a password into an Argon2 PHC string, or verify one
+    Password {
+        #[command(subcommand)]
+        command: PasswordCommand,
+    },
+
+    /// BLAKE3: round trace, 1 KiB chunk tree and extendable output
+    Blake3 {
+        #[arg(short, long)]
+        message: String,
+
+        /// Repeat the message to build inputs longer than one chunk
+        #[arg(long, default_value_t = 1)]
+        repeat: usize,
+
+        /// Output bytes
+        #[arg(short, long, default_value_t = 32)]
+        length: usize,
+
+        /// 32-byte key for keyed_hash mode (text)
+        #[arg(long)]
+        key: Option<String>,
+        /// Context string for derive_key mode
+        #[arg(long, conflicts_with = "key")]
+        context: Option<String>,
+    },
+
+    /// SHA-1 with the same round tracing as SHA-256 (broken; for teaching only)
+    Sha1 {
+        #[arg(short, long)]
+        message: String,
+    },
+
+    /// MD5 with per-round tracing (broken; for teaching only)
+    Md5 {
+        #[arg(short, long)]
+        message: String,
+    },
+
+    /// Hash the published MD5 and SHA-1 collision pairs to show identical digests
+    HashCollisions {
+        #[arg(short, long, default_value = "tests/vectors/collisions")]
+        dir: PathBuf,
+
+        /// Common suffix appended to both files to show the collision survives
+        #[arg(long, default_value = "Any common suffix keeps the collision.")]
+        suffix: String,
+    },
+
+    /// Merkle tree over leaves or file chunks, with an inclusion proof and its path highlighted
+    Merkle {
+        /// Comma-separated leaves (text)
+        #[arg(long, value_delimiter = ',', required_unless_present = "file")]
+        leaves: Vec<String>,
+
+        /// Build the tree over fixed-size chunks of a file instead
+        #[arg(short, long, conflicts_with = "leaves")]
+        file: Option<PathBuf>,
+        #[arg(long, default_value_t = 1024)]
+        chunk_size: usize,
+
+        /// Leaf index to prove
+        #[arg(long)]
+        prove: Option<usize>,
+
+        /// rfc6962 (0x00/0x01 prefixes) or plain
+        #[arg(long, default_value = "rfc6962")]
+        scheme: merkle::hash::HashScheme,
+    },
+
+    /// Bitcoin-style proof of work: SHA256d over an 80-byte header, nonce search and midstate speedup
+    Pow {
+        /// Block contents; the merkle root is SHA256d of this text
+        #[arg(short, long, default_value = "Alice pays Bob 1 BTC")]
+        data: String,
+
+        /// Leading zero bits the block hash needs (sets the target to 2^(256 - n))
+        #[arg(short, long, default_value_t = 16, conflicts_with = "bits")]
+        zero_bits: u32,
+
+        /// Compact target as in the header, e.g. 1f00ffff
+        #[arg(long)]
+        bits: Option<pow::target::CompactBits>,
+
+        /// Header timestamp (default: now)
+        #[arg(long)]
+        time: Option<u32>,
+
+        #[arg(long, default_value_t = 10_000_000)]
+        max_attempts: u64,
+    },
+
+    /// Avalanche effect: flip each input bit and measure how SHA-256's output and rounds respond
+    Avalanche {
+        /// Text, or hex bytes prefixed with 0x (at most 64 bytes)
+        #[arg(short, long)]
+        message: String,
+
+        /// Messages analysed: the one given plus random ones of the same length
+        #[arg(long, default_value_t = 16)]
+        samples: usize,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+
+        /// Write the input × output flip-probability matrix as CSV
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+
+    /// Birthday attack: find collisions in SHA-256 truncated to n bits with a table, Floyd's rho and distinguished points
+    Birthday {
+        /// Output bits kept (8 to 48)
+        #[arg(short, long, default_value_t = 32)]
+        bits: u32,
+
+        /// Text before the hex counter in every message
+        #[arg(short, long, default_value = "birthday-")]
+        prefix: String,
+
+        /// Searches run with each method
+        #[arg(short, long, default_value_t = 3)]
+        trials: usize,
+
+        /// Low zero bits that make a walk point distinguished (default: bits / 4)
+        #[arg(short, long)]
+        distinguished_bits: Option<u32>,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+
+    /// NON-STANDARD reduced-round SHA-256 for cryptanalysis experiments, with a preimage search
+    ReducedSha256 {
+        /// Text, or hex bytes prefixed with 0x (at most 55 bytes, one block)
+        #[arg(short, long)]
+        message: String,
+
+        /// Rounds run, 1 to 64 (64 with no substitutions is real SHA-256)
+        #[arg(short, long, default_value_t = 4)]
+        rounds: usize,
+
+        /// Replacement IV as 64 hex digits
+        #[arg(long)]
+        iv: Option<String>,
+
+        /// Replacement round constants K[0], K[1], … as comma-separated hex words
+        #[arg(long, value_delimiter = ',')]
+        constants: Vec<String>,
+
+        /// Leading digest bits the brute-force search must match (8 to 40)
+        #[arg(short, long, default_value_t = 20)]
+        bits: u32,
+        #[arg(long, default_value_t = 1 << 26)]
+        max_attempts: u64,
+    },
+
+    /// Print the ASN.1 structure of a DER or PEM file, like `openssl asn1parse` with explanations
+    Asn1Dump {
+        file: PathBuf,
+    },
+
+    /// Run NIST CAVP response files through the in-crate implementations
+    Selftest {
+        #[arg(short, long, default_value = "tests/vectors/cavp")]
+        dir: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum RsaCommand {
+    /// Generate a key from p and q and write it as PEM (or DER)
+    Keygen {
+        #[arg(short = 'p')]
+        p: u64,
+        #[arg(short = 'q')]
+        q: u64,
+        /// Compute d modulo Carmichael's λ(n) instead of φ(n), as OpenSSL does
+        #[arg(long)]
+        lambda: bool,
+        /// Public exponent: a number, or common, smallest, f4 (65537 only) or random
+        #[arg(long = "e", default_value = "common")]
+        e: rsa::types::ExponentPolicy,
+
+        #[arg(short, long)]
+        out: PathBuf,
+        /// Also write the public key to this file
+        #[arg(long)]
+        public_out: Option<PathBuf>,
+
+        #[arg(short, long, value_enum, default_value_t = KeyFileFormat::Pkcs8)]
+        format: KeyFileFormat,
+        /// Write raw DER instead of PEM
+        #[arg(long)]
+        der: bool,
+    },
+
+    /// Print the components and fingerprints of a PEM, DER, JWK or OpenSSH key file
+    Show {
+        #[arg(short, long)]
+        key: PathBuf,
+    },
+
+    /// Convert the public half of a key file to another format
+    Export {
+        #[arg(short, long)]
+        key: PathBuf,
+        #[arg(short, long, value_enum)]
+        format: PublicKeyFileFormat,
+        /// Comment appended to an OpenSSH line
+        #[arg(long)]
+        comment: Option<String>,
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Check a key file and/or n, e, d, p, q for consistency and known weaknesses
+    CheckKey {
+        #[arg(short, long)]
+        key: Option<PathBuf>,
+
+        #[arg(long)]
+        n: Option<u64>,
+        #[arg(long)]
+        e: Option<u64>,
+        #[arg(long)]
+        d: Option<u64>,
+        #[arg(short = 'p')]
+        p: Option<u64>,
+        #[arg(short = 'q')]
+        q: Option<u64>,
+
+        /// Other moduli to batch-GCD against n, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        moduli: Vec<u64>,
+    },
+
+    /// Encrypt a file of any size to a key: RSA-KEM wraps a session secret, ChaCha20 + HMAC-SHA256 seal the data
+    Seal {
+        #[arg(short, long)]
+        key: PathBuf,
+        #[arg(short, long = "in")]
+        input: PathBuf,
+        #[arg(short, long)]
+        out: PathBuf,
+        /// Write the envelope as PEM ("RSA KEM ENVELOPE") instead of binary
+        #[arg(long)]
+        armor: bool,
+    },
+
+    /// Verify and decrypt an envelope written by `seal` (binary or PEM)
+    Open {
+        #[arg(short, long)]
+        key: PathBuf,
+        #[arg(short, long = "in")]
+        input: PathBuf,
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+
+    /// Encrypt a number or short text with a public or private key file
+    Encrypt {
+        #[arg(short, long)]
+        key: PathBuf,
+        #[arg(short, long)]
+        message: String,
+    },
+
+    /// Decrypt a ciphertext with a private key file
+    Decrypt {
+        #[arg(short, long)]
+        key: PathBuf,
+        #[arg(short, long)]
+        ciphertext: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum PasswordCommand {
+    /// Print a $argon2id$… string; weak parameters are refused
+    Hash {
+        #[arg(short, long)]
+        password: String,
+
+        /// Salt as text (default: 16 random bytes)
+        #[arg(short, long)]
+        salt: Option<String>,
+
+        #[arg(long, default_value = "argon2id")]
+        variant: argon2::types::Argon2Variant,
+
+        /// Memory in KiB
+        #[arg(short, long, default_value_t = 19_456)]
+        memory: u32,
+        #[arg(short = 't', long, default_value_t = 2)]
+        passes: u32,
+        #[arg(long, default_value_t = 1)]
+        lanes: u32,
+
+        /// Tag bytes
+        #[arg(short, long, default_value_t = 32)]
+        length: usize,
+    },
+
+    /// Check a password against a PHC string (exit status 1 on mismatch)
+    Verify {
+        #[arg(short, long)]
+        password: String,
+
+        #[arg(long)]
+        hash: String,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Blake2Flavor {
+    /// 64-bit words, up to 64-byte digests
+    Blake2b,
+    /// 32-bit words, up to 32-byte digests
+    Blake2s,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum KeyFileFormat {
+    /// RSAPrivateKey / RSAPublicKey ("RSA PRIVATE KEY")
+    Pkcs1,
+    /// PrivateKeyInfo / SubjectPublicKeyInfo ("PRIVATE KEY")
+    Pkcs8,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PublicKeyFileFormat {
+    /// PEM "RSA PUBLIC KEY"
+    Pkcs1,
+    /// PEM "PUBLIC KEY" (SubjectPublicKeyInfo)
+    Spki,
+    /// JSON Web Key with base64url n and e
+    Jwk,
+    /// ssh-rsa line for authorized_keys
+    Openssh,
+}
+
+impl From<PublicKeyFileFormat> for rsa::serialization::PublicKeyFormat {
+    fn from(format: PublicKeyFileFormat) -> Self {
+        match format {
+            PublicKeyFileFormat::Pkcs1 => rsa::serialization::PublicKeyFormat::Pkcs1,
+            PublicKeyFileFormat::Spki => rsa::serialization::PublicKeyFormat::Spki,
+            PublicKeyFileFormat::Jwk => rsa::serialization::PublicKeyFormat::Jwk,
+            PublicKeyFileFormat::Openssh => rsa::serialization::PublicKeyFormat::OpenSsh,
+        }
+    }
+}
+
+impl From<KeyFileFormat> for rsa::serialization::KeyFormat {
+    fn from(format: KeyFileFormat) -> Self {
+        match format {
+            KeyFileFormat::Pkcs1 => rsa::serialization::KeyFormat::Pkcs1,
+            KeyFileFormat::Pkcs8 => rsa::serialization::KeyFormat::Pkcs8,
+        }
+    }
+}
+
+fn totient(lambda: bool) -> rsa::types::Totient {
+    if lambda { rsa::types::Totient::Carmichael } else { rsa::types::Totient::Euler }
+}
+
+/// Hash demos treat an even-length hex string (optionally 0x-prefixed) as raw bytes, anything else as text
+fn hex_message(message: &str) -> Option<Vec<u8>> {
+    parse_hex_message(message).unwrap_or_else(|e| {
+        eprintln!("❌ Error: {}", e);
+        std::process::exit(1);
+    })
+}
+
+fn run_rsa_command(command: RsaCommand) {
+    match command {
+        RsaCommand::Keygen { p, q, lambda, e, out, public_out, format, der } => {
+            rsa::demo::run_keygen(p, q, totient(lambda), e, &out, public_out.as_deref(), format.into(), der);
+        }
+        RsaCommand::Show { key } => rsa::demo::run_show_key(&key),
+        RsaCommand::Export { key, format, comment, out } => {
+            rsa::demo::run_export_key(&key, format.into(), comment.as_deref(), out.as_deref());
+        }
+        RsaCommand::CheckKey { key, n, e, d, p, q, moduli } => {
+            let given = rsa::key_check::KeyCheckInput { n, e, d, p, q, ..Default::default() };
+            if !rsa::demo::run_check_key(key.as_deref(), given, &moduli) {
+                std::process::exit(1);
+            }
+        }
+        RsaCommand::Seal { key, input, out, armor } => rsa::demo::run_seal(&key, &input, &out, armor),
+        RsaCommand::Open { key, input, out } => rsa::demo::run_open(&key, &input, &out),
+        RsaCommand::Encrypt { key, message } => rsa::demo::run_encrypt_with_key(&key, &message),
+        RsaCommand::Decrypt { key, ciphertext } => rsa::demo::run_decrypt_with_key(&key, ciphertext),
+    }
+}
+
+fn run_password_command(command: PasswordCommand) {
+    let accepted = match command {
+        PasswordCommand::Hash { password, salt, variant, memory, passes, lanes, length } => {
+            let params = argon2::types::Argon2Params {
+                variant,
+                memory_kib: memory,
+                passes,
+                lanes,
+                tag_length: length,
+                ..Default::default()
+            };
+            argon2::demo::run_password_hash(&password, &params, salt.as_deref().map(str::as_bytes))
+        }
+        PasswordCommand::Verify { password, hash } => argon2::demo::run_password_verify(&password, &hash),
+    };
+    if !accepted {
+        std::process::exit(1);
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
 
     match cli.algorithm {
-        Algorithm::Rsa { message, p, q } => {
+        Algorithm::Rsa { command: Some(command), .. } => run_rsa_command(command),
+        Algorithm::Rsa { message: Some(message), p: Some(p), q: Some(q), e, .. } => {
             // Auto-detect: try parsing as number, fall back to text
             if let Ok(num) = message.parse() {
-                rsa::demo::run_rsa_demo(num, p, q);
+                rsa::demo::run_rsa_demo_with_exponent(num, p, q, e);
             } else {
-                rsa::demo::run_rsa_demo_text(&message, p, q);
+                rsa::demo::run_rsa_demo_text_with_exponent(&message, p, q, e);
+            }
+        }
+        Algorithm::Rsa { .. } => eprintln!("❌ Error: Provide -m, -p and -q, or a subcommand such as keygen"),
+        Algorithm::RsaMultiPrime { message, primes, lambda } => {
+            rsa::demo::run_multi_prime_demo(&message, &primes, totient(lambda));
+        }
+        Algorithm::RsaWiener { p, q, n, e } => match (p, q, n, e) {
+            (Some(p), Some(q), _, _) => rsa::attacks::demo::run_wiener_demo(p, q),
+            (_, _, Some(n), Some(e)) => rsa::attacks::demo::run_wiener_attack(n, e),
+            _ => eprintln!("❌ Error: Provide either -p and -q, or --n and --e"),
+        },
+        Algorithm::RsaCrack { n, e, ciphertext } => {
+            if !rsa::attacks::demo::run_crack_demo(n, e, ciphertext) {
+                std::process::exit(1);
+            }
+        }
+        Algorithm::RsaCubeRoot { message, p, q } => rsa::attacks::demo::run_cube_root_demo(&message, p, q),
+        Algorithm::RsaHastad { message, prime_start } => {
+            rsa::attacks::demo::run_hastad_demo(&message, prime_start);
+        }
+        Algorithm::RsaCommonModulus { message, p, q } => {
+            rsa::attacks::demo::run_common_modulus_demo(&message, p, q);
+        }
+        Algorithm::RsaMalleability { amount, other, factor, p, q } => {
+            rsa::attacks::demo::run_malleability_demo(amount, other, factor, p, q);
+        }
+        Algorithm::RsaTiming { p, q, samples, repetitions } => {
+            rsa::attacks::demo::run_timing_attack_demo(p, q, samples, repetitions);
+        }
+        Algorithm::RsaExp { base, exp, modulus, window, iterations } => {
+            rsa::demo::run_exponentiation_demo(base, exp, modulus, window, iterations);
+        }
+        Algorithm::RsaFranklinReiter { message, p, q, alpha, beta } => {
+            rsa::attacks::demo::run_franklin_reiter_demo(&message, p, q, alpha, beta);
+        }
+        Algorithm::Sha256 { message } => match hex_message(&message) {
+            Some(bytes) => sha256::demo::run_sha256_demo(bytes),
+            None => sha256::demo::run_sha256_demo_text(&message),
+        },
+        Algorithm::Sha3 { message, variant, length } => match hex_message(&message) {
+            Some(bytes) => sha3::demo::run_sha3_demo(bytes, variant, length),
+            None => sha3::demo::run_sha3_demo_text(&message, variant, length),
+        },
+        Algorithm::Blake2 { message, variant, length, key, salt, personal } => {
+            let bytes = hex_message(&message);
+            let text = bytes.is_none().then_some(message.as_str());
+            let message_bytes = bytes.unwrap_or_else(|| message.as_bytes().to_vec());
+            let params = blake2::types::Blake2Params {
+                digest_length: length.unwrap_or(match variant {
+                    Blake2Flavor::Blake2b => 64,
+                    Blake2Flavor::Blake2s => 32,
+                }),
+                key: key.map(String::into_bytes).unwrap_or_default(),
+                salt: salt.map(String::into_bytes).unwrap_or_default(),
+                personal: personal.map(String::into_bytes).unwrap_or_default(),
+            };
+            match variant {
+                Blake2Flavor::Blake2b => blake2::demo::run_blake2b_demo(text, &message_bytes, &params),
+                Blake2Flavor::Blake2s => blake2::demo::run_blake2s_demo(text, &message_bytes, &params),
             }
         }
-        Algorithm::Sha256 { message } => {
-            if message.chars().all(|c| c.is_ascii_hexdigit()) && message.len() % 2 == 0 && message.len() > 0 {
-                match hex::decode(&message) {
-                    Ok(bytes) => sha256::demo::run_sha256_demo(bytes),
-                    Err(_) => sha256::demo::run_sha256_demo_text(&message),
+        Algorithm::Derive { algorithm, password, salt, length, info, iterations, log_n, r, p } => match algorithm {
+            kdf::types::KdfAlgorithm::Hkdf => kdf::demo::run_hkdf_demo(&password, salt.as_bytes(), info.as_bytes(), length),
+            kdf::types::KdfAlgorithm::Pbkdf2 => kdf::demo::run_pbkdf2_demo(&password, salt.as_bytes(), iterations, length),
+            kdf::types::KdfAlgorithm::Scrypt => {
+                let params = kdf::types::ScryptParams { log_n, r, p };
+                kdf::demo::run_scrypt_demo(&password, salt.as_bytes(), &params, length)
+            }
+        },
+        Algorithm::Argon2 { password, salt, variant, memory, passes, lanes, length } => {
+            let params = argon2::types::Argon2Params {
+                variant,
+                memory_kib: memory,
+                passes,
+                lanes,
+                tag_length: length,
+                ..Default::default()
+            };
+            argon2::demo::run_argon2_demo(&password, salt.as_bytes(), &params)
+        }
+        Algorithm::Password { command } => run_password_command(command),
+        Algorithm::Blake3 { message, repeat, length, key, context } => {
+            let key = key.as_deref().map(str::as_bytes);
+            match hex_message(&message) {
+                Some(bytes) => blake3::demo::run_blake3_demo(&bytes.repeat(repeat), length, key, context.as_deref()),
+                None => blake3::demo::run_blake3_demo_text(&message, repeat, length, key, context.as_deref()),
+            }
+        }
+        Algorithm::Sha1 { message } => match hex_message(&message) {
+            Some(bytes) => sha1::demo::run_sha1_demo(bytes),
+            None => sha1::demo::run_sha1_demo_text(&message),
+        },
+        Algorithm::Md5 { message } => match hex_message(&message) {
+            Some(bytes) => md5::demo::run_md5_demo(bytes),
+            None => md5::demo::run_md5_demo_text(&message),
+        },
+        Algorithm::HashCollisions { dir, suffix } => {
+            if !collisions::demo::run_collision_demo(&dir, &suffix) {
+                std::process::exit(1);
+            }
+        }
+        Algorithm::Merkle { leaves, file, chunk_size, prove, scheme } => match file {
+            Some(path) => merkle::demo::run_merkle_file_demo(&path, chunk_size, scheme, prove),
+            None => {
+                let leaves: Vec<Vec<u8>> = leaves.into_iter().map(String::into_bytes).collect();
+                merkle::demo::run_merkle_demo(&leaves, scheme, prove)
+            }
+        },
+        Algorithm::Pow { data, zero_bits, bits, time, max_attempts } => {
+            let bits = match bits.map_or_else(|| pow::target::CompactBits::from_zero_bits(zero_bits), Ok) {
+                Ok(bits) => bits,
+                Err(e) => {
+                    eprintln!("❌ Error: {}", e);
+                    std::process::exit(1);
                 }
-            } else {
-                sha256::demo::run_sha256_demo_text(&message);
+            };
+            let time = time.unwrap_or_else(|| {
+                let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+                now.as_secs() as u32
+            });
+            if !pow::demo::run_pow_demo(&data, bits, time, max_attempts) {
+                std::process::exit(1);
+            }
+        }
+        Algorithm::Avalanche { message, samples, seed, csv } => {
+            let bytes = hex_message(&message).unwrap_or_else(|| message.into_bytes());
+            if !analysis::demo::run_avalanche_demo(&bytes, samples, seed, csv.as_deref()) {
+                std::process::exit(1);
+            }
+        }
+        Algorithm::Birthday { bits, prefix, trials, distinguished_bits, seed } => {
+            if !analysis::demo::run_birthday_demo(bits, &prefix, trials, seed, distinguished_bits) {
+                std::process::exit(1);
+            }
+        }
+        Algorithm::ReducedSha256 { message, rounds, iv, constants, bits, max_attempts } => {
+            let bytes = hex_message(&message).unwrap_or_else(|| message.into_bytes());
+            if !analysis::demo::run_reduced_sha256_demo(&bytes, rounds, iv.as_deref(), &constants, bits, max_attempts) {
+                std::process::exit(1);
+            }
+        }
+        Algorithm::Asn1Dump { file } => asn1::demo::run_asn1_dump(&file),
+        Algorithm::Selftest { dir } => {
+            if !cavp::demo::run_selftest(&dir) {
+                std::process::exit(1);
             }
         }
         // Future algorithms handled here:
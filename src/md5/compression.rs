@@ -0,0 +1,75 @@
+use super::constants::{SHIFTS, SINE_TABLE};
+use super::types::{Block, Hash, Word};
+
+/// Name of the boolean function used in each 16-round stage
+pub const STAGE_FUNCTIONS: [&str; 4] = ["F", "G", "H", "I"];
+
+/// The stage's boolean function and the message word it reads (RFC 1321 §3.4)
+pub fn round_function(i: usize, b: Word, c: Word, d: Word) -> (Word, usize) {
+    match i / 16 {
+        0 => ((b & c) | (!b & d), i),
+        1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+        2 => (b ^ c ^ d, (3 * i + 5) % 16),
+        _ => (c ^ (b | !d), (7 * i) % 16),
+    }
+}
+
+pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
+    if show_steps {
+        println!("\n  Message words (little-endian):");
+        for (i, word) in block.iter().enumerate() {
+            println!("    M[{:2}] = 0x{:08x}", i, word);
+        }
+    }
+
+    // Initialize working variables
+    let [mut a, mut b, mut c, mut d] = hash;
+
+    if show_steps {
+        println!("\n  Initial working variables:");
+        println!("    a = 0x{:08x}, b = 0x{:08x}, c = 0x{:08x}, d = 0x{:08x}", a, b, c, d);
+    }
+
+    // Main loop (64 rounds in four stages of 16)
+    for i in 0..64 {
+        let (f, g) = round_function(i, b, c, d);
+        let shift = SHIFTS[i / 16][i % 4];
+        let sum = a.wrapping_add(f).wrapping_add(SINE_TABLE[i]).wrapping_add(block[g]);
+        let traced = show_steps && (!(4..60).contains(&i) || i % 16 == 0 || i % 16 == 15);
+
+        if traced {
+            println!("\n  Round {} ({}):", i + 1, STAGE_FUNCTIONS[i / 16]);
+            println!("    b' = b + ROTL^{}(a + {}(b,c,d) + K[{}] + M[{}])", shift, STAGE_FUNCTIONS[i / 16], i, g);
+            println!("       = 0x{:08x} + ROTL^{}(0x{:08x} + 0x{:08x} + 0x{:08x} + 0x{:08x})",
+                     b, shift, a, f, SINE_TABLE[i], block[g]);
+        }
+
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(sum.rotate_left(shift));
+
+        if traced {
+            println!("       = 0x{:08x}", b);
+            println!("    New values: a=0x{:08x}, b=0x{:08x}, c=0x{:08x}, d=0x{:08x}", a, b, c, d);
+        }
+    }
+
+    // Add compressed chunk to current hash value
+    let result = [
+        hash[0].wrapping_add(a),
+        hash[1].wrapping_add(b),
+        hash[2].wrapping_add(c),
+        hash[3].wrapping_add(d),
+    ];
+
+    if show_steps {
+        println!("\n  Final addition:");
+        for i in 0..4 {
+            println!("    H[{}] = 0x{:08x} + 0x{:08x} = 0x{:08x}",
+                     i, hash[i], [a, b, c, d][i], result[i]);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,67 @@
+use super::constants::INITIAL_HASH;
+use super::compression::compress_block;
+use super::preprocessing::preprocess_message;
+use crate::sha256::preprocessing::print_padding_steps;
+use crate::sha256::validation::validate_message;
+
+fn run_md5_demo_internal(message_text: Option<&str>, message_bytes: &[u8]) {
+    // Header
+    println!("🔐 MD5 Hash Algorithm Demo");
+    println!("⚠️  MD5 is broken: collisions take seconds on a laptop. Do not use it for security.");
+    if let Some(text) = message_text {
+        println!("Message: \"{}\"", text);
+    }
+    println!("Message bytes: {:02x?}", message_bytes);
+    println!("Message length: {} bytes", message_bytes.len());
+
+    if let Err(e) = validate_message(message_bytes) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+
+    // Initial values
+    println!("\n=== Initial Hash Values ===");
+    for (i, word) in INITIAL_HASH.iter().enumerate() {
+        println!("  {} = 0x{:08x}", ['A', 'B', 'C', 'D'][i], word);
+    }
+
+    // Preprocess message: same padding as SHA-256, little-endian length and words
+    let blocks = preprocess_message(message_bytes);
+    print_padding_steps(message_bytes, blocks.len());
+    println!("  Length field byte order: little-endian (SHA uses big-endian)");
+
+    // Process each block
+    let mut hash = INITIAL_HASH;
+
+    for (i, block) in blocks.iter().enumerate() {
+        println!("\n=== Compression Function (Block {}/{}) ===", i + 1, blocks.len());
+        hash = compress_block(hash, block, true);
+
+        println!("\n  Hash after block {}:", i + 1);
+        for (j, word) in hash.iter().enumerate() {
+            println!("    H[{}] = 0x{:08x}", j, word);
+        }
+    }
+
+    // Final hash: words are serialised little-endian
+    let digest: String = hash.iter().map(|word| hex::encode(word.to_le_bytes())).collect();
+    println!("\n=== Final Hash ===");
+    println!("  MD5: {}", digest);
+    println!("  (each word is written little-endian, so H[0] = 0x{:08x} becomes {})",
+             hash[0], hex::encode(hash[0].to_le_bytes()));
+
+    println!("\n✅ MD5 hash computation completed!");
+    println!("💡 Tip: Run `hash-collisions` to see two different messages with the same MD5");
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+pub fn run_md5_demo(message_bytes: Vec<u8>) {
+    run_md5_demo_internal(None, &message_bytes);
+}
+
+pub fn run_md5_demo_text(text: &str) {
+    run_md5_demo_internal(Some(text), text.as_bytes());
+}
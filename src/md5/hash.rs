@@ -0,0 +1,25 @@
+use super::compression::compress_block;
+use super::constants::INITIAL_HASH;
+use super::preprocessing::preprocess_message;
+use super::types::Hash;
+
+/// Compute the MD5 hash of a message without printing any steps
+pub fn md5(message: &[u8]) -> Hash {
+    preprocess_message(message)
+        .iter()
+        .fold(INITIAL_HASH, |hash, block| compress_block(hash, block, false))
+}
+
+/// Compute the MD5 digest as 16 bytes; unlike SHA, each word is written little-endian
+pub fn md5_bytes(message: &[u8]) -> [u8; 16] {
+    let mut digest = [0u8; 16];
+    for (chunk, word) in digest.chunks_mut(4).zip(md5(message)) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+/// Lowercase hex of `md5_bytes`, the form `md5sum` prints
+pub fn md5_hex(message: &[u8]) -> String {
+    hex::encode(md5_bytes(message))
+}
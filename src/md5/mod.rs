@@ -0,0 +1,6 @@
+pub mod constants;
+pub mod demo;
+pub mod compression;
+pub mod hash;
+pub mod preprocessing;
+pub mod types;
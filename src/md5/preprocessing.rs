@@ -0,0 +1,16 @@
+use super::types::Block;
+use crate::sha256::preprocessing::{pad_message, LengthEncoding};
+
+/// Pad like SHA-256, but with a little-endian length, and read little-endian words
+pub fn preprocess_message(message: &[u8]) -> Vec<Block> {
+    pad_message(message, LengthEncoding::LittleEndian)
+        .chunks(64)
+        .map(|chunk| {
+            let mut block = [0u32; 16];
+            for (word, bytes) in block.iter_mut().zip(chunk.chunks(4)) {
+                *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            }
+            block
+        })
+        .collect()
+}
@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use super::hash::{Digest, HashScheme};
+use super::proof::{inclusion_proof, root_from_proof, sibling_ranges, verify_inclusion, InclusionProof};
+use super::tree::{chunk_leaves, merkle_root, MerkleNode, MerkleTree};
+
+/// Largest tree drawn node by node
+const MAX_DRAWN_LEAVES: usize = 64;
+
+/// Text leaves longer than this are shown by length
+const MAX_LABEL_CHARS: usize = 24;
+
+fn short(hash: &Digest) -> String {
+    format!("{}…", hex::encode(&hash[..8]))
+}
+
+fn leaf_label(data: &[u8]) -> String {
+    match std::str::from_utf8(data) {
+        Ok(text) if text.chars().count() <= MAX_LABEL_CHARS && !text.chars().any(char::is_control) => format!("{:?}", text),
+        _ => format!("{} bytes", data.len()),
+    }
+}
+
+/// Role of a node relative to the proof being shown
+fn marker(node: &MerkleNode, proof: Option<&(usize, Vec<(usize, usize)>)>) -> String {
+    let Some((leaf_index, siblings)) = proof else { return String::new() };
+    let (start, end) = node.range();
+    if (start..end).contains(leaf_index) {
+        return if end - start == 1 { "  ◀ proven leaf".to_string() } else { "  ● path".to_string() };
+    }
+    match siblings.iter().position(|&range| range == (start, end)) {
+        Some(i) => format!("  ◆ proof[{}]", i),
+        None => String::new(),
+    }
+}
+
+fn print_node(node: &MerkleNode, leaves: &[Vec<u8>], proof: Option<&(usize, Vec<(usize, usize)>)>, prefix: &str, branch: &str, child_prefix: &str) {
+    let description = match node {
+        MerkleNode::Leaf { index, hash } => format!("leaf {} {} = {}", index, leaf_label(&leaves[*index]), short(hash)),
+        MerkleNode::Node { hash, start, end, .. } => format!("node [{}..{}) = {}", start, end, short(hash)),
+    };
+    println!("{}{}{}{}", prefix, branch, description, marker(node, proof));
+
+    if let MerkleNode::Node { left, right, .. } = node {
+        let next = format!("{}{}", prefix, child_prefix);
+        print_node(left, leaves, proof, &next, "├── ", "│   ");
+        print_node(right, leaves, proof, &next, "└── ", "    ");
+    }
+}
+
+fn print_hash_rules(scheme: HashScheme) {
+    println!("\n=== Hashing: {} ===", scheme.name());
+    match scheme {
+        HashScheme::Rfc6962 => {
+            println!("  leaf = SHA-256(0x00 ‖ data)");
+            println!("  node = SHA-256(0x01 ‖ left ‖ right)");
+        }
+        HashScheme::Plain => {
+            println!("  leaf = SHA-256(data)");
+            println!("  node = SHA-256(left ‖ right)");
+        }
+    }
+    println!("  Split at the largest power of two below n, so odd leaves are promoted, never duplicated");
+}
+
+fn print_proof(tree: &MerkleTree, leaves: &[Vec<u8>], proof: &InclusionProof) {
+    println!("\n=== Inclusion Proof for Leaf {} ===", proof.leaf_index);
+    println!("  Leaf data: {}", leaf_label(&leaves[proof.leaf_index]));
+    println!("  Leaf hash: {}", hex::encode(tree.leaf_hashes[proof.leaf_index]));
+    println!("  Path ({} hashes for {} leaves, bottom first):", proof.path.len(), proof.tree_size);
+    for (i, sibling) in proof.path.iter().enumerate() {
+        println!("  proof[{}] = {}", i, hex::encode(sibling));
+    }
+
+    println!("\n=== Verification ===");
+    println!("  r = leaf hash = {}", short(&tree.leaf_hashes[proof.leaf_index]));
+    match root_from_proof(tree.scheme, &tree.leaf_hashes[proof.leaf_index], proof, true) {
+        Ok(root) if root == tree.root() => println!("\n✅ Recomputed root matches: leaf {} is in the tree", proof.leaf_index),
+        Ok(root) => println!("\n❌ Recomputed root {} does not match", short(&root)),
+        Err(e) => println!("\n❌ Error: {}", e),
+    }
+
+    let tampered = [leaves[proof.leaf_index].as_slice(), b"!"].concat();
+    if !verify_inclusion(tree.scheme, &tampered, proof, &tree.root()) {
+        println!("  Changing the leaf to {} breaks the proof", leaf_label(&tampered));
+    }
+    println!(
+        "  The verifier needed {} hashes instead of all {} leaves",
+        proof.path.len(),
+        proof.tree_size
+    );
+}
+
+fn run_merkle_demo_internal(source: &str, leaves: &[Vec<u8>], scheme: HashScheme, prove: Option<usize>) {
+    println!("🌳 Merkle Tree Demo");
+    println!("Leaves: {} ({})", leaves.len(), source);
+
+    let tree = MerkleTree::new(scheme, leaves);
+    print_hash_rules(scheme);
+
+    let proof = match prove.map(|index| inclusion_proof(&tree, index)) {
+        Some(Ok(proof)) => Some(proof),
+        Some(Err(e)) => {
+            eprintln!("\n❌ Error: {}", e);
+            return;
+        }
+        None => None,
+    };
+
+    println!("\n=== Tree ({} leaves, depth {}) ===", tree.size(), tree.root_node.as_ref().map_or(0, MerkleNode::depth));
+    match &tree.root_node {
+        None => println!("  Empty tree: root = SHA-256(\"\")"),
+        Some(root) if tree.size() <= MAX_DRAWN_LEAVES => {
+            let highlight = proof.as_ref().map(|proof| (proof.leaf_index, sibling_ranges(root, proof.leaf_index)));
+            print_node(root, leaves, highlight.as_ref(), "  ", "", "");
+        }
+        Some(_) => println!("  {} leaves: too many to draw (limit {})", tree.size(), MAX_DRAWN_LEAVES),
+    }
+    println!("\n  Root: {}", hex::encode(tree.root()));
+
+    if let Some(proof) = &proof {
+        print_proof(&tree, leaves, proof);
+    } else if !leaves.is_empty() {
+        println!("\n💡 Tip: Add --prove <index> to build and check an inclusion proof");
+    }
+
+    print_second_preimage_for(leaves);
+}
+
+/// Compare honest and forged roots under both schemes
+fn print_second_preimage_for(leaves: &[Vec<u8>]) {
+    let plain = MerkleTree::new(HashScheme::Plain, leaves);
+    let Some(MerkleNode::Node { left, right, .. }) = &plain.root_node else { return };
+    let forged_leaf = [left.hash(), right.hash()].concat();
+
+    println!("\n=== Second Preimage: a Node Posing as a Leaf ===");
+    println!("  Forged list: a single 64-byte leaf = (left child ‖ right child) of the plain root");
+    for scheme in [HashScheme::Plain, HashScheme::Rfc6962] {
+        let honest = merkle_root(scheme, leaves);
+        let forged = merkle_root(scheme, &[&forged_leaf]);
+        let verdict = if honest == forged { "⚠️  same root for different data" } else { "✅ roots differ" };
+        println!("  {:<30} honest {}  forged {}  {}", scheme.name(), short(&honest), short(&forged), verdict);
+    }
+    println!("  The 0x00/0x01 prefixes keep leaf and node preimages apart");
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Build a tree over the given leaves, optionally proving one of them
+pub fn run_merkle_demo(leaves: &[Vec<u8>], scheme: HashScheme, prove: Option<usize>) {
+    run_merkle_demo_internal("from the command line", leaves, scheme, prove);
+}
+
+/// Build a tree over fixed-size chunks of a file
+pub fn run_merkle_file_demo(path: &Path, chunk_size: usize, scheme: HashScheme, prove: Option<usize>) {
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("❌ Error: Cannot read {}: {}", path.display(), e);
+            return;
+        }
+    };
+    match chunk_leaves(&data, chunk_size) {
+        Ok(leaves) => {
+            let source = format!("{}, {} bytes in {}-byte chunks", path.display(), data.len(), chunk_size);
+            run_merkle_demo_internal(&source, &leaves, scheme, prove);
+        }
+        Err(e) => eprintln!("❌ Error: {}", e),
+    }
+}
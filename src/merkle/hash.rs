@@ -0,0 +1,67 @@
+// ============================================================================
+// Merkle Tree Hashing (RFC 6962 §2.1)
+// ============================================================================
+//   leaf  = SHA-256(0x00 ‖ data)
+//   node  = SHA-256(0x01 ‖ left ‖ right)
+//   empty = SHA-256("")
+//
+// Without the prefix bytes an interior node's 64-byte preimage left ‖ right
+// is also a valid leaf, so a tree over different data can share the root
+// (a second-preimage attack). The plain scheme is kept to demonstrate that.
+
+use std::str::FromStr;
+
+use crate::sha256::hash::sha256_bytes;
+
+pub type Digest = [u8; 32];
+
+pub const LEAF_PREFIX: u8 = 0x00;
+pub const NODE_PREFIX: u8 = 0x01;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashScheme {
+    /// Domain-separated leaf and node hashes, as Certificate Transparency uses
+    Rfc6962,
+    /// SHA-256(data) and SHA-256(left ‖ right), with no separation
+    Plain,
+}
+
+impl HashScheme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashScheme::Rfc6962 => "RFC 6962",
+            HashScheme::Plain => "plain (no domain separation)",
+        }
+    }
+
+    pub fn leaf_hash(&self, data: &[u8]) -> Digest {
+        match self {
+            HashScheme::Rfc6962 => sha256_bytes(&[&[LEAF_PREFIX], data].concat()),
+            HashScheme::Plain => sha256_bytes(data),
+        }
+    }
+
+    pub fn node_hash(&self, left: &Digest, right: &Digest) -> Digest {
+        match self {
+            HashScheme::Rfc6962 => sha256_bytes(&[&[NODE_PREFIX], left.as_slice(), right].concat()),
+            HashScheme::Plain => sha256_bytes(&[left.as_slice(), right].concat()),
+        }
+    }
+
+    /// Root of the tree with no leaves
+    pub fn empty_root(&self) -> Digest {
+        sha256_bytes(b"")
+    }
+}
+
+impl FromStr for HashScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "rfc6962" | "ct" => Ok(HashScheme::Rfc6962),
+            "plain" => Ok(HashScheme::Plain),
+            _ => Err(format!("Unknown Merkle hash scheme '{}' (expected rfc6962 or plain)", s)),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod demo;
+pub mod hash;
+pub mod proof;
+pub mod tree;
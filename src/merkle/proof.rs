@@ -0,0 +1,94 @@
+// ============================================================================
+// Inclusion Proofs (RFC 6962 §2.1.1, verification per RFC 9162 §2.1.3.2)
+// ============================================================================
+// The audit path for leaf m lists the sibling of every node on the way from
+// the leaf to the root, bottom first. The verifier rebuilds the root from the
+// leaf hash and the path, using the index and tree size to know on which side
+// each sibling sits.
+
+use super::hash::{Digest, HashScheme};
+use super::tree::{MerkleNode, MerkleTree};
+
+/// Proof that leaf `leaf_index` is in a tree of `tree_size` leaves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InclusionProof {
+    pub leaf_index: usize,
+    pub tree_size: usize,
+    pub path: Vec<Digest>,
+}
+
+fn collect_path(node: &MerkleNode, leaf_index: usize, path: &mut Vec<Digest>) {
+    if let MerkleNode::Node { left, right, .. } = node {
+        let (_, split) = left.range();
+        if leaf_index < split {
+            collect_path(left, leaf_index, path);
+            path.push(right.hash());
+        } else {
+            collect_path(right, leaf_index, path);
+            path.push(left.hash());
+        }
+    }
+}
+
+/// PATH(m, D[n]): the siblings from the leaf up
+pub fn inclusion_proof(tree: &MerkleTree, leaf_index: usize) -> Result<InclusionProof, String> {
+    let root = match &tree.root_node {
+        Some(root) if leaf_index < tree.size() => root,
+        _ => return Err(format!("Leaf {} is not in a tree of {} leaves", leaf_index, tree.size())),
+    };
+    let mut path = Vec::new();
+    collect_path(root, leaf_index, &mut path);
+    Ok(InclusionProof { leaf_index, tree_size: tree.size(), path })
+}
+
+/// Leaf ranges covered by each proof sibling, in path order, for highlighting a drawn tree
+pub fn sibling_ranges(node: &MerkleNode, leaf_index: usize) -> Vec<(usize, usize)> {
+    match node {
+        MerkleNode::Leaf { .. } => Vec::new(),
+        MerkleNode::Node { left, right, .. } => {
+            let (on_path, sibling) = if leaf_index < left.range().1 { (left, right) } else { (right, left) };
+            let mut ranges = sibling_ranges(on_path, leaf_index);
+            ranges.push(sibling.range());
+            ranges
+        }
+    }
+}
+
+/// Recompute the root from a leaf hash and its proof, printing each step when asked
+pub fn root_from_proof(scheme: HashScheme, leaf_hash: &Digest, proof: &InclusionProof, show_steps: bool) -> Result<Digest, String> {
+    if proof.leaf_index >= proof.tree_size {
+        return Err(format!("Leaf index {} is outside a tree of {} leaves", proof.leaf_index, proof.tree_size));
+    }
+
+    let (mut f, mut s) = (proof.leaf_index, proof.tree_size - 1);
+    let mut r = *leaf_hash;
+    for (level, sibling) in proof.path.iter().enumerate() {
+        if s == 0 {
+            return Err(format!("Proof has {} hashes, more than a tree of {} leaves needs", proof.path.len(), proof.tree_size));
+        }
+        let sibling_on_left = f & 1 == 1 || f == s;
+        r = if sibling_on_left { scheme.node_hash(sibling, &r) } else { scheme.node_hash(&r, sibling) };
+        if show_steps {
+            let formula = if sibling_on_left { "node(path, r)" } else { "node(r, path)" };
+            println!("  {}. r = {:<13} = {}…", level + 1, formula, hex::encode(&r[..8]));
+        }
+        if f & 1 == 0 && f == s {
+            // The right edge: skip levels where this node has no sibling
+            while f & 1 == 0 && f != 0 {
+                f >>= 1;
+                s >>= 1;
+            }
+        }
+        f >>= 1;
+        s >>= 1;
+    }
+    if s != 0 {
+        return Err(format!("Proof has {} hashes, too few for a tree of {} leaves", proof.path.len(), proof.tree_size));
+    }
+    Ok(r)
+}
+
+/// Check a proof against a trusted root
+pub fn verify_inclusion(scheme: HashScheme, leaf_data: &[u8], proof: &InclusionProof, root: &Digest) -> bool {
+    root_from_proof(scheme, &scheme.leaf_hash(leaf_data), proof, false).is_ok_and(|computed| computed == *root)
+}
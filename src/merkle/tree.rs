@@ -0,0 +1,101 @@
+// ============================================================================
+// Merkle Tree Construction (RFC 6962 §2.1)
+// ============================================================================
+// MTH(D[n]) splits at k, the largest power of two below n:
+//
+//   MTH(D[n]) = node(MTH(D[0:k]), MTH(D[k:n]))
+//
+// The left subtree is always perfect, so an odd leaf is promoted rather than
+// duplicated (duplication would let two different lists share a root).
+
+use super::hash::{Digest, HashScheme};
+
+/// A node of the tree with the leaf range it covers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MerkleNode {
+    Leaf { index: usize, hash: Digest },
+    Node { hash: Digest, start: usize, end: usize, left: Box<MerkleNode>, right: Box<MerkleNode> },
+}
+
+impl MerkleNode {
+    pub fn hash(&self) -> Digest {
+        match self {
+            MerkleNode::Leaf { hash, .. } | MerkleNode::Node { hash, .. } => *hash,
+        }
+    }
+
+    /// Leaf indices covered, as start..end
+    pub fn range(&self) -> (usize, usize) {
+        match self {
+            MerkleNode::Leaf { index, .. } => (*index, index + 1),
+            MerkleNode::Node { start, end, .. } => (*start, *end),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        match self {
+            MerkleNode::Leaf { .. } => 0,
+            MerkleNode::Node { left, right, .. } => 1 + left.depth().max(right.depth()),
+        }
+    }
+}
+
+/// k: the largest power of two strictly below n (n ≥ 2)
+pub fn split_point(n: usize) -> usize {
+    1 << (n - 1).ilog2()
+}
+
+/// Build the subtree over already-hashed leaves, numbering them from `offset`
+pub fn build_subtree(scheme: HashScheme, leaf_hashes: &[Digest], offset: usize) -> MerkleNode {
+    if let [hash] = leaf_hashes {
+        return MerkleNode::Leaf { index: offset, hash: *hash };
+    }
+    let k = split_point(leaf_hashes.len());
+    let left = build_subtree(scheme, &leaf_hashes[..k], offset);
+    let right = build_subtree(scheme, &leaf_hashes[k..], offset + k);
+    MerkleNode::Node {
+        hash: scheme.node_hash(&left.hash(), &right.hash()),
+        start: offset,
+        end: offset + leaf_hashes.len(),
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+/// A tree over a list of leaves
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    pub scheme: HashScheme,
+    pub leaf_hashes: Vec<Digest>,
+    /// None for the empty tree
+    pub root_node: Option<MerkleNode>,
+}
+
+impl MerkleTree {
+    pub fn new<T: AsRef<[u8]>>(scheme: HashScheme, leaves: &[T]) -> MerkleTree {
+        let leaf_hashes: Vec<Digest> = leaves.iter().map(|leaf| scheme.leaf_hash(leaf.as_ref())).collect();
+        let root_node = (!leaf_hashes.is_empty()).then(|| build_subtree(scheme, &leaf_hashes, 0));
+        MerkleTree { scheme, leaf_hashes, root_node }
+    }
+
+    pub fn size(&self) -> usize {
+        self.leaf_hashes.len()
+    }
+
+    pub fn root(&self) -> Digest {
+        self.root_node.as_ref().map(MerkleNode::hash).unwrap_or_else(|| self.scheme.empty_root())
+    }
+}
+
+/// MTH over the leaves without keeping the tree
+pub fn merkle_root<T: AsRef<[u8]>>(scheme: HashScheme, leaves: &[T]) -> Digest {
+    MerkleTree::new(scheme, leaves).root()
+}
+
+/// Split data into fixed-size chunks to use as leaves (the last may be shorter)
+pub fn chunk_leaves(data: &[u8], chunk_size: usize) -> Result<Vec<Vec<u8>>, String> {
+    if chunk_size == 0 {
+        return Err("Chunk size must be at least 1 byte".to_string());
+    }
+    Ok(data.chunks(chunk_size).map(<[u8]>::to_vec).collect())
+}
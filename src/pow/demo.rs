@@ -0,0 +1,159 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use super::header::{display_hash, genesis_header, sha256d, BlockHeader};
+use super::mining::{hash_with_nonce, mine, Midstate};
+use super::target::{
+    difficulty, expected_attempts, hash_as_number, hash_meets_target, leading_zero_bits, CompactBits, Target,
+    DIFFICULTY_1_BITS,
+};
+
+/// Nonces hashed by each method when comparing the midstate speedup
+const BENCH_NONCES: u32 = 20_000;
+
+fn format_rate(hashes: f64, seconds: f64) -> String {
+    let rate = hashes / seconds.max(1e-9);
+    match rate {
+        r if r >= 1e6 => format!("{:.2} MH/s", r / 1e6),
+        r if r >= 1e3 => format!("{:.1} kH/s", r / 1e3),
+        r => format!("{:.0} H/s", r),
+    }
+}
+
+fn print_header(header: &BlockHeader, data: &str) {
+    let bytes = header.to_bytes();
+    println!("\n=== Block Header (80 bytes) ===");
+    println!("  version      {:>10}  {}", header.version, hex::encode(&bytes[..4]));
+    println!("  prev block   {}", display_hash(&header.prev_block));
+    println!("  merkle root  {}  = SHA256d(\"{}\")", display_hash(&header.merkle_root), data);
+    println!("  time         {:>10}  {}", header.time, hex::encode(&bytes[68..72]));
+    println!("  bits         0x{:08x}  {}", header.bits, hex::encode(&bytes[72..76]));
+    println!("  nonce        {:>10}  {}  ← searched", header.nonce, hex::encode(&bytes[76..]));
+    println!("\n  Integers are little-endian; hashes are printed byte-reversed, as block explorers do.");
+    println!("  Bytes  0..64: {}", hex::encode(&bytes[..64]));
+    println!("  Bytes 64..80: {}  (second SHA-256 block, with the nonce)", hex::encode(&bytes[64..]));
+}
+
+fn print_target(bits: CompactBits, target: &Target) {
+    let expected = expected_attempts(target);
+    println!("\n=== Target ===");
+    println!("  bits {} → mantissa 0x{:06x} · 256^({} - 3)", bits, bits.0 & 0x007f_ffff, bits.0 >> 24);
+    println!("  target     {}", hex::encode(target));
+    println!("  Difficulty {:.6e} (relative to Bitcoin's easiest target, 0x{:08x})", difficulty(target), DIFFICULTY_1_BITS);
+    println!("  Expected attempts = 2^256 / (target + 1) ≈ {:.0} (2^{:.1})", expected, expected.log2());
+}
+
+fn print_midstate(midstate: &Midstate) {
+    println!("\n=== Midstate: SHA-256 State After Bytes 0..64 ===");
+    let words: Vec<String> = midstate.state.iter().map(|word| format!("{:08x}", word)).collect();
+    println!("  {}", words.join(" "));
+    println!("  Only bytes 64..80 change with the nonce, so this compression is done once.");
+    println!("  Each nonce then costs 2 compressions (second block, outer SHA-256) instead of 3.");
+}
+
+fn print_search(header: &BlockHeader, target: &Target, max_attempts: u64) {
+    println!("\n=== Mining ===");
+    let start = Instant::now();
+    let outcome = mine(header, target, max_attempts, true);
+    let seconds = start.elapsed().as_secs_f64();
+    let expected = expected_attempts(target);
+
+    match outcome.nonce {
+        Some(nonce) => {
+            println!("  ✅ Found nonce {} after {} attempts", nonce, outcome.attempts);
+            println!("  Block hash: {}", display_hash(&outcome.best_hash));
+            println!("  {} leading zero bits, at or below the target", leading_zero_bits(&hash_as_number(&outcome.best_hash)));
+            println!("  {:.2}× the expected number of attempts", outcome.attempts as f64 / expected);
+        }
+        None => {
+            println!("  ⚠️  No nonce found in {} attempts", outcome.attempts);
+            println!(
+                "  Best hash: {} (nonce {}, {} leading zero bits)",
+                display_hash(&outcome.best_hash),
+                outcome.best_nonce,
+                leading_zero_bits(&hash_as_number(&outcome.best_hash))
+            );
+            println!("  Real miners change the timestamp or the coinbase extra nonce and search again.");
+        }
+    }
+    println!("  Time: {:.2} s, hash rate: {}", seconds, format_rate(outcome.attempts as f64, seconds));
+    if outcome.nonce.is_none() && (outcome.attempts as f64) < expected {
+        println!("\n💡 Tip: Raise --max-attempts or lower --zero-bits");
+    }
+}
+
+fn print_speedup(header: &BlockHeader) {
+    let bytes = header.to_bytes();
+    let midstate = Midstate::new(&bytes);
+
+    let start = Instant::now();
+    for nonce in 0..BENCH_NONCES {
+        black_box(hash_with_nonce(black_box(&bytes), nonce));
+    }
+    let full = start.elapsed().as_secs_f64();
+
+    let start = Instant::now();
+    for nonce in 0..BENCH_NONCES {
+        black_box(midstate.hash(black_box(nonce)));
+    }
+    let cached = start.elapsed().as_secs_f64();
+
+    println!("\n=== Midstate Speedup ({} nonces each) ===", BENCH_NONCES);
+    println!("  Full SHA256d of 80 bytes:  {:.3} s  {}", full, format_rate(BENCH_NONCES as f64, full));
+    println!("  From the cached midstate:  {:.3} s  {}", cached, format_rate(BENCH_NONCES as f64, cached));
+    println!("  Speedup: {:.2}× (3 → 2 compressions, and no padding per nonce)", full / cached.max(1e-9));
+}
+
+fn print_genesis_check() {
+    let genesis = genesis_header();
+    let hash = genesis.hash();
+    let target = CompactBits(genesis.bits).target().expect("valid genesis bits");
+    println!("\n=== Real Block: Bitcoin Genesis ===");
+    println!("  Hash:   {}", display_hash(&hash));
+    println!("  Target: {}", hex::encode(target));
+    if hash_meets_target(&hash, &target) {
+        println!("  ✅ Nonce {} meets bits 0x{:08x}: about 2^32 hashes of work", genesis.nonce, genesis.bits);
+    } else {
+        println!("  ❌ Genesis header does not meet its target");
+    }
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Build a one-transaction header over `data` and search nonces for the target
+///
+/// Returns false when the bits do not encode a usable target.
+pub fn run_pow_demo(data: &str, bits: CompactBits, time: u32, max_attempts: u64) -> bool {
+    println!("⛏️  Proof-of-Work Mining Demo");
+    println!("Block data: \"{}\"", data);
+
+    let target = match bits.target() {
+        Ok(target) => target,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return false;
+        }
+    };
+    if target == [0; 32] {
+        eprintln!("\n❌ Error: Bits {} encode a zero target, which no hash can meet", bits);
+        return false;
+    }
+
+    let header = BlockHeader {
+        version: 1,
+        prev_block: genesis_header().hash(),
+        merkle_root: sha256d(data.as_bytes()),
+        time,
+        bits: bits.0,
+        nonce: 0,
+    };
+    print_header(&header, data);
+    print_target(bits, &target);
+    print_midstate(&Midstate::new(&header.to_bytes()));
+    print_search(&header, &target, max_attempts);
+    print_speedup(&header);
+    print_genesis_check();
+    true
+}
@@ -0,0 +1,77 @@
+// ============================================================================
+// Bitcoin Block Header
+// ============================================================================
+//   version (4) ‖ previous block (32) ‖ merkle root (32) ‖ time (4) ‖ bits (4) ‖ nonce (4)
+//
+// Integers are little-endian and hashes are stored in internal byte order,
+// the reverse of how block explorers print them. The block hash is
+// SHA256d(header) = SHA-256(SHA-256(header)).
+
+use crate::sha256::hash::sha256_bytes;
+
+pub const HEADER_BYTES: usize = 80;
+
+/// The nonce is the last field, so it always lands in the second SHA-256 block
+pub const NONCE_OFFSET: usize = 76;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub version: u32,
+    pub prev_block: [u8; 32],
+    pub merkle_root: [u8; 32],
+    /// Unix time in seconds
+    pub time: u32,
+    /// Target in compact form
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+impl BlockHeader {
+    pub fn to_bytes(&self) -> [u8; HEADER_BYTES] {
+        let mut bytes = [0u8; HEADER_BYTES];
+        bytes[..4].copy_from_slice(&self.version.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.prev_block);
+        bytes[36..68].copy_from_slice(&self.merkle_root);
+        bytes[68..72].copy_from_slice(&self.time.to_le_bytes());
+        bytes[72..76].copy_from_slice(&self.bits.to_le_bytes());
+        bytes[NONCE_OFFSET..].copy_from_slice(&self.nonce.to_le_bytes());
+        bytes
+    }
+
+    pub fn hash(&self) -> [u8; 32] {
+        sha256d(&self.to_bytes())
+    }
+}
+
+/// SHA-256 applied twice, as Bitcoin does for headers and transactions
+pub fn sha256d(data: &[u8]) -> [u8; 32] {
+    sha256_bytes(&sha256_bytes(data))
+}
+
+/// A hash as block explorers print it: the bytes reversed, so leading zeros come first
+pub fn display_hash(hash: &[u8; 32]) -> String {
+    hash.iter().rev().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Parse a hash printed by a block explorer back into internal byte order
+pub fn hash_from_display(text: &str) -> Result<[u8; 32], String> {
+    let mut bytes: [u8; 32] = hex::decode(text)
+        .map_err(|e| format!("Invalid hash hex: {}", e))?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("A block hash is 32 bytes, got {}", bytes.len()))?;
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Block 0 of the Bitcoin main chain, mined on 3 January 2009
+pub fn genesis_header() -> BlockHeader {
+    BlockHeader {
+        version: 1,
+        prev_block: [0; 32],
+        merkle_root: hash_from_display("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b")
+            .expect("valid genesis merkle root"),
+        time: 1231006505,
+        bits: 0x1d00ffff,
+        nonce: 2083236893,
+    }
+}
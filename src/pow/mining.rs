@@ -0,0 +1,97 @@
+// ============================================================================
+// Nonce Search with a Cached Midstate
+// ============================================================================
+// The 80-byte header spans two SHA-256 blocks. Bytes 0..64 (version, previous
+// block and most of the merkle root) never change while the nonce is searched,
+// so their compression is done once. Each nonce then costs two compressions:
+//
+//   first  = compress(midstate, header[64..80] ‖ padding)
+//   hash   = compress(H0, first ‖ padding)
+//
+// instead of the three a full SHA256d would run.
+
+use super::header::{sha256d, BlockHeader, HEADER_BYTES, NONCE_OFFSET};
+use super::target::{hash_as_number, hash_meets_target, Target};
+use crate::sha256::compression::compress_block;
+use crate::sha256::constants::INITIAL_HASH;
+use crate::sha256::math_utils::bytes_to_words;
+use crate::sha256::types::{Block, Hash};
+
+/// Word of the second block holding the nonce
+const NONCE_WORD: usize = (NONCE_OFFSET - 64) / 4;
+
+/// The SHA-256 state after the fixed first 64 bytes, plus the pre-padded second block
+#[derive(Debug, Clone)]
+pub struct Midstate {
+    pub state: Hash,
+    tail: Block,
+}
+
+impl Midstate {
+    pub fn new(header: &[u8; HEADER_BYTES]) -> Midstate {
+        let first: Block = bytes_to_words(&header[..64]).try_into().expect("64 bytes are 16 words");
+        let mut tail = [0; 16];
+        tail[..4].copy_from_slice(&bytes_to_words(&header[64..]));
+        tail[4] = 0x8000_0000;
+        tail[15] = (HEADER_BYTES * 8) as u32;
+        Midstate { state: compress_block(INITIAL_HASH, &first, false), tail }
+    }
+
+    /// SHA256d of the header with this nonce
+    pub fn hash(&self, nonce: u32) -> [u8; 32] {
+        let mut tail = self.tail;
+        tail[NONCE_WORD] = nonce.swap_bytes();
+        let first = compress_block(self.state, &tail, false);
+
+        let mut outer = [0; 16];
+        outer[..8].copy_from_slice(&first);
+        outer[8] = 0x8000_0000;
+        outer[15] = 256;
+        let digest = compress_block(INITIAL_HASH, &outer, false);
+
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_mut(4).zip(digest) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        bytes
+    }
+}
+
+/// SHA256d of the header with this nonce, hashing all 80 bytes every time
+pub fn hash_with_nonce(header: &[u8; HEADER_BYTES], nonce: u32) -> [u8; 32] {
+    let mut header = *header;
+    header[NONCE_OFFSET..].copy_from_slice(&nonce.to_le_bytes());
+    sha256d(&header)
+}
+
+/// What a search found within its attempt budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiningOutcome {
+    /// The first nonce meeting the target, if any
+    pub nonce: Option<u32>,
+    /// Lowest hash seen and its nonce; the winning hash when one was found
+    pub best_hash: [u8; 32],
+    pub best_nonce: u32,
+    pub attempts: u64,
+}
+
+/// Try nonces upward from the header's nonce until one meets the target or the budget runs out
+pub fn mine(header: &BlockHeader, target: &Target, max_attempts: u64, use_midstate: bool) -> MiningOutcome {
+    let bytes = header.to_bytes();
+    let midstate = Midstate::new(&bytes);
+    let mut outcome = MiningOutcome { nonce: None, best_hash: [0xff; 32], best_nonce: header.nonce, attempts: 0 };
+
+    for nonce in (header.nonce..=u32::MAX).take(max_attempts.try_into().unwrap_or(usize::MAX)) {
+        let hash = if use_midstate { midstate.hash(nonce) } else { hash_with_nonce(&bytes, nonce) };
+        outcome.attempts += 1;
+        if hash_as_number(&hash) < hash_as_number(&outcome.best_hash) {
+            outcome.best_hash = hash;
+            outcome.best_nonce = nonce;
+        }
+        if hash_meets_target(&hash, target) {
+            outcome.nonce = Some(nonce);
+            break;
+        }
+    }
+    outcome
+}
@@ -0,0 +1,4 @@
+pub mod demo;
+pub mod header;
+pub mod mining;
+pub mod target;
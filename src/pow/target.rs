@@ -0,0 +1,111 @@
+// ============================================================================
+// Targets and Difficulty
+// ============================================================================
+// A block is valid when its hash, read as a little-endian 256-bit number, is
+// at most the target. Headers store the target in 4-byte compact form:
+//
+//   bits = exponent (1 byte) ‖ mantissa (3 bytes)
+//   target = mantissa · 256^(exponent - 3)
+//
+// Difficulty is how much harder the target is than Bitcoin's easiest one,
+// 0x1d00ffff, and every hash succeeds with probability (target + 1) / 2^256.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A 256-bit target, big-endian
+pub type Target = [u8; 32];
+
+/// The easiest Bitcoin target, defined as difficulty 1
+pub const DIFFICULTY_1_BITS: u32 = 0x1d00ffff;
+
+/// Compact target encoding as found in the header's bits field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactBits(pub u32);
+
+impl CompactBits {
+    /// Bits whose target is exactly 2^(256 - zero_bits), so a hash below it starts with that many zero bits
+    pub fn from_zero_bits(zero_bits: u32) -> Result<CompactBits, String> {
+        if !(1..=248).contains(&zero_bits) {
+            return Err(format!("Leading zero bits must be between 1 and 248, got {}", zero_bits));
+        }
+        let power = 256 - zero_bits;
+        let exponent = power / 8 + 2;
+        let mantissa = 1u32 << (power % 8 + 8);
+        Ok(CompactBits(exponent << 24 | mantissa))
+    }
+
+    pub fn target(&self) -> Result<Target, String> {
+        let exponent = (self.0 >> 24) as usize;
+        let mantissa = self.0 & 0x007f_ffff;
+        if self.0 & 0x0080_0000 != 0 {
+            return Err(format!("Bits {} encode a negative target", self));
+        }
+
+        let mut target = [0u8; 32];
+        if exponent <= 3 {
+            target[28..].copy_from_slice(&(mantissa >> (8 * (3 - exponent))).to_be_bytes());
+            return Ok(target);
+        }
+        for (i, byte) in mantissa.to_be_bytes()[1..].iter().enumerate() {
+            match (32 + i).checked_sub(exponent) {
+                Some(position) => target[position] = *byte,
+                None if *byte != 0 => return Err(format!("Bits {} encode a target above 2^256", self)),
+                None => {}
+            }
+        }
+        Ok(target)
+    }
+}
+
+impl fmt::Display for CompactBits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:08x}", self.0)
+    }
+}
+
+impl FromStr for CompactBits {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.trim_start_matches("0x");
+        u32::from_str_radix(digits, 16)
+            .map(CompactBits)
+            .map_err(|_| format!("Bits must be 8 hex digits like 1d00ffff, got '{}'", s))
+    }
+}
+
+/// The block hash as a big-endian number, for comparing against a target
+pub fn hash_as_number(hash: &[u8; 32]) -> [u8; 32] {
+    let mut number = *hash;
+    number.reverse();
+    number
+}
+
+pub fn hash_meets_target(hash: &[u8; 32], target: &Target) -> bool {
+    hash_as_number(hash) <= *target
+}
+
+/// Leading zero bits of a big-endian 256-bit number
+pub fn leading_zero_bits(number: &[u8; 32]) -> u32 {
+    let zero_bytes = number.iter().take_while(|&&byte| byte == 0).count();
+    match number.get(zero_bytes) {
+        Some(byte) => 8 * zero_bytes as u32 + byte.leading_zeros(),
+        None => 256,
+    }
+}
+
+pub fn target_to_f64(target: &Target) -> f64 {
+    target.iter().fold(0.0, |value, &byte| value * 256.0 + byte as f64)
+}
+
+/// Difficulty-1 target divided by this target
+pub fn difficulty(target: &Target) -> f64 {
+    let difficulty_1 = CompactBits(DIFFICULTY_1_BITS).target().expect("valid difficulty-1 bits");
+    target_to_f64(&difficulty_1) / target_to_f64(target)
+}
+
+/// Mean number of hashes to find a block: 2^256 / (target + 1)
+pub fn expected_attempts(target: &Target) -> f64 {
+    2f64.powi(256) / (target_to_f64(target) + 1.0)
+}
@@ -0,0 +1,83 @@
+use crate::rsa::constants::COMMON_PUBLIC_EXPONENTS;
+use crate::rsa::encryption::encrypt;
+use crate::rsa::key_generation::generate_keypair;
+use crate::rsa::math_utils::{extended_gcd, gcd, mod_inverse, mod_mul, mod_pow};
+use crate::rsa::types::RsaPublicKey;
+
+/// The same message encrypted twice under one modulus with two different exponents
+#[derive(Debug, Clone)]
+pub struct CommonModulusScenario {
+    pub n: u64,
+    pub phi_n: u64,
+    pub e1: u64,
+    pub e2: u64,
+    pub c1: u64,
+    pub c2: u64,
+}
+
+/// Intermediate values of the common-modulus recovery
+#[derive(Debug, Clone)]
+pub struct CommonModulusRecovery {
+    /// Bézout coefficients with a·e₁ + b·e₂ = 1
+    pub a: i128,
+    pub b: i128,
+    /// c₁^a mod n and c₂^b mod n (negative powers go through the modular inverse)
+    pub c1_term: u64,
+    pub c2_term: u64,
+    pub message: u64,
+}
+
+/// Build a vulnerable setup: one key from `generate_keypair`, plus a second
+/// exponent from `COMMON_PUBLIC_EXPONENTS` reusing the same n
+///
+/// Returns None if no second exponent is coprime to both φ(n) and e₁.
+pub fn common_modulus_scenario(message: u64, p: u64, q: u64) -> Option<CommonModulusScenario> {
+    let key_pair = generate_keypair(p, q);
+    let n = key_pair.public_key.n;
+    let phi_n = (p - 1) * (q - 1);
+    let e1 = key_pair.public_key.e;
+
+    let e2 = COMMON_PUBLIC_EXPONENTS
+        .iter()
+        .copied()
+        .find(|&e| e != e1 && gcd(e, phi_n) == 1 && gcd(e, e1) == 1)?;
+
+    let second_key = RsaPublicKey { n, e: e2 };
+    Some(CommonModulusScenario {
+        n,
+        phi_n,
+        e1,
+        e2,
+        c1: encrypt(message, &key_pair.public_key),
+        c2: encrypt(message, &second_key),
+    })
+}
+
+/// Raise c to a possibly negative power mod n: c^(-k) = (c⁻¹)^k
+fn signed_pow(c: u64, exponent: i128, n: u64) -> Option<u64> {
+    let base = if exponent < 0 { mod_inverse(c, n)? } else { c };
+    Some(mod_pow(base, exponent.unsigned_abs() as u64, n))
+}
+
+/// Common-modulus attack
+///
+/// If gcd(e₁, e₂) = 1, Bézout gives a·e₁ + b·e₂ = 1, so
+/// c₁^a · c₂^b = m^(a·e₁ + b·e₂) = m (mod n) - no private key required.
+/// Returns None when e₁, e₂ share a factor or a ciphertext is not invertible mod n.
+pub fn common_modulus_attack(n: u64, e1: u64, e2: u64, c1: u64, c2: u64) -> Option<CommonModulusRecovery> {
+    let (g, a, b) = extended_gcd(e1 as i128, e2 as i128);
+    if g != 1 {
+        return None;
+    }
+
+    let c1_term = signed_pow(c1, a, n)?;
+    let c2_term = signed_pow(c2, b, n)?;
+
+    Some(CommonModulusRecovery {
+        a,
+        b,
+        c1_term,
+        c2_term,
+        message: mod_mul(c1_term, c2_term, n),
+    })
+}
@@ -0,0 +1,551 @@
+use super::common_modulus::{common_modulus_attack, common_modulus_scenario};
+use super::factor::{factor_all, recover_private_key, FactorResult, DEFAULT_MAX_ITERATIONS};
+use super::malleability::{homomorphic_multiply, padded_malleability, textbook_malleability};
+use super::related_message::{format_polynomial, franklin_reiter_attack, related_message_scenario};
+use super::small_exponent::{broadcast_scenario, cube_root_attack, hastad_attack};
+use super::timing::{
+    attack_context, collect_samples, recover_exponent, target_decrypt, TimingTarget, EMULATED_LIMBS,
+};
+use super::wiener::{generate_vulnerable_keypair, wiener_attack, WienerResult};
+use crate::common::random::DemoRng;
+use crate::rsa::encryption::{decrypt, encrypt};
+use crate::rsa::key_generation::generate_keypair;
+use crate::rsa::math_utils::{is_prime, mod_mul, wiener_bound};
+use crate::rsa::oaep::{max_message, oaep_decode, SEED_BYTES};
+use crate::rsa::text_encoding::{describe_plaintext, parse_message};
+use crate::rsa::types::{RsaPrivateKey, RsaPublicKey};
+use crate::rsa::validation::validate_primes;
+
+fn format_quotients(quotients: &[u64]) -> String {
+    match quotients.split_first() {
+        Some((first, rest)) => format!(
+            "[{}; {}]",
+            first,
+            rest.iter().map(|q| q.to_string()).collect::<Vec<_>>().join(", ")
+        ),
+        None => "[]".to_string(),
+    }
+}
+
+fn print_wiener_trace(public_key: &RsaPublicKey, result: &WienerResult) {
+    println!("\n=== Continued Fraction of e/n ===");
+    println!("  e/n = {}/{}", public_key.e, public_key.n);
+    println!("      = {}", format_quotients(&result.quotients));
+
+    println!("\n=== Testing Convergents k/d ===");
+    println!("  If k/d is right: φ(n) = (e·d - 1)/k and p, q solve x² - (n - φ(n) + 1)x + n = 0");
+    for step in &result.steps {
+        print!("  #{:<2} k/d = {}/{}", step.index, step.k, step.d);
+        match (step.phi_candidate, step.factors) {
+            (Some(phi), Some((p, q))) => println!(" → φ = {}, n = {} × {} ✓", phi, p, q),
+            (Some(phi), None) => println!(" → φ = {}, roots not integers ✗", phi),
+            _ if step.k == 0 => println!(" → k = 0, skip"),
+            _ => println!(" → k ∤ (e·d - 1) ✗"),
+        }
+    }
+}
+
+fn run_wiener_attack_internal(public_key: &RsaPublicKey) -> Option<u64> {
+    println!("  Public key: (n={}, e={})", public_key.n, public_key.e);
+    println!("  Wiener bound: d < n^¼ / 3 ≈ {}", wiener_bound(public_key.n));
+
+    let result = wiener_attack(public_key);
+    print_wiener_trace(public_key, &result);
+
+    let Some(recovery) = result.recovery else {
+        println!("\n❌ No convergent factored n - d is probably above the Wiener bound.");
+        return None;
+    };
+
+    println!("\n=== Recovered Private Key ===");
+    println!("  p = {}, q = {}", recovery.p, recovery.q);
+    println!("  φ(n) = {}", recovery.phi_n);
+    println!("  d = {}", recovery.d);
+
+    Some(recovery.d)
+}
+
+/// Bits of d listed in the timing table before it is cut short
+const MAX_TIMING_BITS_SHOWN: usize = 16;
+
+fn print_factor_result(result: &FactorResult) {
+    let outcome = match result.factors {
+        Some((p, q)) => format!("✓ {} × {}", p, q),
+        None => "✗ gave up".to_string(),
+    };
+    println!(
+        "  {:<16} {:<28} {:>10} iterations {:>12.3?}   {}",
+        result.method.name(),
+        outcome,
+        result.iterations,
+        result.elapsed,
+        result.detail
+    );
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+/// Generate a deliberately weak key from p and q, then break it with Wiener's attack
+pub fn run_wiener_demo(p: u64, q: u64) {
+    println!("🕵️ RSA Wiener Attack Demo (small private exponent)");
+    println!("Primes: p={}, q={}", p, q);
+
+    validate_primes(p, q);
+
+    let Some(n) = p.checked_mul(q) else {
+        eprintln!("\n❌ Error: n = {} × {} does not fit in 64 bits", p, q);
+        eprintln!("💡 Tip: Keep p and q below 2^32");
+        return;
+    };
+    let Some(key_pair) = generate_vulnerable_keypair(p, q) else {
+        eprintln!("\n❌ Error: n = {} is too small to choose d below n^¼ / 3", n);
+        eprintln!("💡 Tip: Use primes of at least a few thousand");
+        return;
+    };
+
+    println!("\n=== Vulnerable Key Generation ===");
+    println!("  n = p × q = {}", key_pair.public_key.n);
+    println!("  φ(n) = (p-1) × (q-1) = {}", (p - 1) * (q - 1));
+    println!("  Chosen small d = {} (below n^¼ / 3)", key_pair.private_key.d);
+    println!("  e = d⁻¹ mod φ(n) = {}", key_pair.public_key.e);
+
+    println!("\n=== Attack (attacker only knows n and e) ===");
+    let Some(d) = run_wiener_attack_internal(&key_pair.public_key) else {
+        return;
+    };
+
+    let message = 42 % key_pair.public_key.n;
+    let ciphertext = encrypt(message, &key_pair.public_key);
+    let recovered = decrypt(ciphertext, &RsaPrivateKey { n: key_pair.public_key.n, d });
+
+    println!("\n=== Verification ===");
+    println!("  Encrypt m = {} → c = {}", message, ciphertext);
+    println!("  Decrypt with recovered d → m = {}", recovered);
+
+    if d == key_pair.private_key.d && recovered == message {
+        println!("\n✅ Private key recovered from the public key alone!");
+    } else {
+        println!("\n❌ Recovered key does not match.");
+    }
+}
+
+/// Attack an existing public key (n, e)
+pub fn run_wiener_attack(n: u64, e: u64) {
+    println!("🕵️ RSA Wiener Attack");
+    if e < 3 {
+        eprintln!("\n❌ Error: e = {} is not a usable public exponent (need e ≥ 3)", e);
+        return;
+    }
+    if run_wiener_attack_internal(&RsaPublicKey { n, e }).is_some() {
+        println!("\n✅ Private exponent recovered!");
+    }
+}
+
+/// Factor n with every method, derive d and optionally decrypt a ciphertext
+///
+/// Returns false when no usable private key is recovered.
+pub fn run_crack_demo(n: u64, e: u64, ciphertext: Option<u64>) -> bool {
+    println!("🔨 RSA Key Cracking Demo");
+    println!("Public key: (n={}, e={})", n, e);
+
+    if is_prime(n) || n < 4 {
+        eprintln!("\n❌ Error: n = {} is not a product of two primes - not an RSA modulus", n);
+        return false;
+    }
+
+    println!("\n=== Factoring n ===");
+    println!("  Budget: {} iterations per method", DEFAULT_MAX_ITERATIONS);
+    let results = factor_all(n, DEFAULT_MAX_ITERATIONS);
+    results.iter().for_each(print_factor_result);
+
+    let Some((p, q)) = results.iter().find_map(|r| r.factors) else {
+        eprintln!("\n❌ Error: No method could factor n within the budget");
+        return false;
+    };
+
+    let public_key = RsaPublicKey { n, e };
+    let private_key = match recover_private_key(&public_key, p, q) {
+        Ok(private_key) => private_key,
+        Err(e) => {
+            eprintln!("\n❌ Error: {}", e);
+            return false;
+        }
+    };
+
+    println!("\n=== Deriving Private Key ===");
+    println!("  n = {} × {}", p, q);
+    println!("  φ(n) = (p-1) × (q-1) = {}", (p - 1) as u128 * (q - 1) as u128);
+    println!("  d = e⁻¹ mod φ(n) = {}", private_key.d);
+
+    if let Some(c) = ciphertext {
+        let m = decrypt(c, &private_key);
+        println!("\n=== Decryption ===");
+        println!("  m = c^d mod n = {}^{} mod {}", c, private_key.d, n);
+        println!("    = {}", describe_plaintext(m));
+    }
+
+    println!("\n✅ Private key recovered by factoring n!");
+    true
+}
+
+/// Encrypt with the default key generator and, if m^e < n, undo it with an ordinary e-th root
+pub fn run_cube_root_demo(message: &str, p: u64, q: u64) {
+    println!("🧊 RSA Cube-Root Attack Demo (small e, no padding)");
+    println!("Message: {}", message);
+    println!("Primes: p={}, q={}", p, q);
+
+    let Some(m) = parse_message(message) else {
+        eprintln!("\n❌ Error: Text is too long or empty. Maximum 8 characters.");
+        return;
+    };
+    validate_primes(p, q);
+
+    let key_pair = generate_keypair(p, q);
+    let public_key = &key_pair.public_key;
+    if m >= public_key.n {
+        eprintln!("\n❌ Error: Message ({}) must be smaller than modulus n ({})", m, public_key.n);
+        return;
+    }
+
+    println!("\n=== Key Generation ===");
+    println!("  n = {}, φ(n) = {}", public_key.n, (p - 1) * (q - 1));
+    println!("  e = {} (first entry of COMMON_PUBLIC_EXPONENTS coprime with φ(n))", public_key.e);
+
+    let ciphertext = encrypt(m, public_key);
+    println!("\n=== Encryption ===");
+    println!("  c = m^e mod n = {}^{} mod {} = {}", m, public_key.e, public_key.n, ciphertext);
+    match (m as u128).checked_pow(public_key.e as u32) {
+        Some(power) if power < public_key.n as u128 => {
+            println!("  m^e = {} < n, so the \"mod n\" never wrapped around!", power)
+        }
+        _ => println!("  m^e ≥ n, so c is a genuine residue mod n"),
+    }
+
+    println!("\n=== Attack (attacker only knows n, e and c) ===");
+    match cube_root_attack(ciphertext, public_key) {
+        Some(recovered) => {
+            println!("  m = {}√c = {}√{} = {}", public_key.e, public_key.e, ciphertext, describe_plaintext(recovered));
+            println!("  Check: {}^{} = {} ✓", recovered, public_key.e, ciphertext);
+            println!("\n✅ Message recovered without the private key!");
+        }
+        None => {
+            println!("  {}√c is not an integer - the attack does not apply", public_key.e);
+            println!("\n💡 Tip: Use a shorter message or larger primes so that m^e < n");
+        }
+    }
+}
+
+/// Broadcast one message to three e = 3 recipients and recover it with the CRT
+pub fn run_hastad_demo(message: &str, prime_start: u64) {
+    const RECIPIENTS: usize = 3;
+
+    println!("📡 Håstad Broadcast Attack Demo (e = 3, three recipients)");
+    println!("Message: {}", message);
+
+    let Some(m) = parse_message(message) else {
+        eprintln!("\n❌ Error: Text is too long or empty. Maximum 8 characters.");
+        return;
+    };
+
+    let recipients = broadcast_scenario(m, RECIPIENTS, prime_start);
+
+    println!("\n=== Broadcast ===");
+    println!("  generate_keypair picks e = 3 for every recipient because p, q ≡ 2 (mod 3)");
+    for (i, r) in recipients.iter().enumerate() {
+        println!(
+            "  Recipient {}: n{} = {} × {} = {}, e = {}, c{} = {}",
+            i + 1, i + 1, r.p, r.q, r.public_key.n, r.public_key.e, i + 1, r.ciphertext
+        );
+    }
+
+    if let Some(r) = recipients.iter().find(|r| m >= r.public_key.n) {
+        eprintln!("\n❌ Error: Message ({}) must be smaller than every modulus (n = {})", m, r.public_key.n);
+        return;
+    }
+
+    let congruences: Vec<(u64, u64)> = recipients.iter().map(|r| (r.ciphertext, r.public_key.n)).collect();
+
+    println!("\n=== Chinese Remainder Theorem ===");
+    for (c, n) in &congruences {
+        println!("  x ≡ {} (mod {})", c, n);
+    }
+
+    let Some(recovery) = hastad_attack(&congruences, RECIPIENTS as u32) else {
+        eprintln!("\n❌ Error: CRT result is not a perfect cube (moduli too large for u128?)");
+        return;
+    };
+
+    println!("  N = n₁ × n₂ × n₃ = {}", recovery.modulus_product);
+    println!("  x = {}", recovery.combined);
+    println!("  Since m < nᵢ for all i, m³ < N, so x = m³ exactly");
+
+    println!("\n=== Cube Root ===");
+    println!("  m = ∛x = {}", describe_plaintext(recovery.message));
+
+    if recovery.message == m {
+        println!("\n✅ Message recovered from three ciphertexts - no private key needed!");
+    } else {
+        println!("\n❌ Recovered message does not match.");
+    }
+}
+
+/// Encrypt one message under (n, e₁) and (n, e₂) and recover it with Bézout coefficients
+pub fn run_common_modulus_demo(message: &str, p: u64, q: u64) {
+    println!("🔁 RSA Common-Modulus Attack Demo");
+    println!("Message: {}", message);
+    println!("Primes: p={}, q={}", p, q);
+
+    let Some(m) = parse_message(message) else {
+        eprintln!("\n❌ Error: Text is too long or empty. Maximum 8 characters.");
+        return;
+    };
+    validate_primes(p, q);
+
+    if m >= p * q {
+        eprintln!("\n❌ Error: Message ({}) must be smaller than modulus n ({})", m, p * q);
+        return;
+    }
+    let Some(scenario) = common_modulus_scenario(m, p, q) else {
+        eprintln!("\n❌ Error: No second public exponent is coprime to both φ(n) and e₁");
+        return;
+    };
+
+    println!("\n=== Vulnerable Setup ===");
+    println!("  Two users share n = {} but have different exponents", scenario.n);
+    println!("  User 1: e₁ = {} → c₁ = m^e₁ mod n = {}", scenario.e1, scenario.c1);
+    println!("  User 2: e₂ = {} → c₂ = m^e₂ mod n = {}", scenario.e2, scenario.c2);
+
+    let Some(recovery) = common_modulus_attack(scenario.n, scenario.e1, scenario.e2, scenario.c1, scenario.c2) else {
+        eprintln!("\n❌ Error: Attack failed (e₁, e₂ not coprime or c not invertible mod n)");
+        return;
+    };
+
+    println!("\n=== Bézout Coefficients ===");
+    println!("  extended_gcd({}, {}) → a = {}, b = {}", scenario.e1, scenario.e2, recovery.a, recovery.b);
+    println!("  a·e₁ + b·e₂ = {}·{} + {}·{} = 1", recovery.a, scenario.e1, recovery.b, scenario.e2);
+
+    println!("\n=== Recombination ===");
+    println!("  c₁^a mod n = {}^{} mod {} = {}", scenario.c1, recovery.a, scenario.n, recovery.c1_term);
+    println!("  c₂^b mod n = {}^{} mod {} = {}", scenario.c2, recovery.b, scenario.n, recovery.c2_term);
+    println!("  (negative powers use the modular inverse of c)");
+    println!("  m = c₁^a · c₂^b = m^(a·e₁ + b·e₂) = m¹ mod n");
+    println!("    = {}", describe_plaintext(recovery.message));
+
+    if recovery.message == m {
+        println!("\n✅ Message recovered without either private key!");
+    } else {
+        println!("\n❌ Recovered message does not match.");
+    }
+}
+
+/// Encrypt m and α·m + β under one e = 3 key and recover m with a polynomial gcd
+pub fn run_franklin_reiter_demo(message: &str, p: u64, q: u64, alpha: u64, beta: u64) {
+    println!("🔗 Franklin-Reiter Related-Message Attack Demo (e = 3)");
+    println!("Message: {}", message);
+    println!("Primes: p={}, q={}", p, q);
+
+    let Some(m) = parse_message(message) else {
+        eprintln!("\n❌ Error: Text is too long or empty. Maximum 8 characters.");
+        return;
+    };
+    validate_primes(p, q);
+
+    if m >= p * q {
+        eprintln!("\n❌ Error: Message ({}) must be smaller than modulus n ({})", m, p * q);
+        return;
+    }
+    let Some(scenario) = related_message_scenario(m, p, q, alpha, beta) else {
+        eprintln!("\n❌ Error: generate_keypair did not choose e = 3 for these primes");
+        eprintln!("💡 Tip: Pick p, q ≡ 2 (mod 3), e.g. 4294967291 and 4294967279");
+        return;
+    };
+    let n = scenario.public_key.n;
+
+    println!("\n=== Related Messages ===");
+    println!("  n = {}, e = 3", n);
+    println!("  m₂ = α·m₁ + β = {}·{} + {} = {} (mod n)", alpha, scenario.m1, beta, scenario.m2);
+    println!("  c₁ = m₁³ mod n = {}", scenario.c1);
+    println!("  c₂ = m₂³ mod n = {}", scenario.c2);
+
+    let Some(recovery) = franklin_reiter_attack(n, alpha, beta, scenario.c1, scenario.c2) else {
+        eprintln!("\n❌ Error: Polynomial gcd was not linear");
+        return;
+    };
+
+    println!("\n=== Polynomials Sharing the Root m₁ ===");
+    println!("  g₁(x) = x³ - c₁ = {}", format_polynomial(&recovery.g1));
+    println!("  g₂(x) = (αx + β)³ - c₂ = {}", format_polynomial(&recovery.g2));
+
+    println!("\n=== Euclidean Algorithm over Z_n ===");
+    for (i, r) in recovery.remainders.iter().enumerate() {
+        println!("  r{} = {}", i + 1, format_polynomial(r));
+    }
+    println!("  gcd(g₁, g₂) = x - m₁  →  m₁ = {}", describe_plaintext(recovery.message));
+
+    if recovery.message == m {
+        println!("\n✅ Message recovered from two related ciphertexts!");
+    } else {
+        println!("\n❌ Recovered message does not match.");
+    }
+}
+
+/// Multiply ciphertexts, scale an encrypted amount in transit, then show OAEP rejecting the forgery
+pub fn run_malleability_demo(amount: u64, other: u64, factor: u64, p: u64, q: u64) {
+    println!("🧮 RSA Homomorphic Property and Malleability Demo");
+    println!("Amount: {}, second message: {}, scale factor: {}", amount, other, factor);
+    println!("Primes: p={}, q={}", p, q);
+
+    validate_primes(p, q);
+    let key_pair = generate_keypair(p, q);
+    let (n, e) = (key_pair.public_key.n, key_pair.public_key.e);
+    if amount >= n || other >= n || factor >= n {
+        eprintln!("\n❌ Error: Amount, second message and factor must be smaller than modulus n ({})", n);
+        return;
+    }
+    println!("  n = {}, e = {}", n, e);
+
+    println!("\n=== Homomorphic Multiplication ===");
+    let (c1, c2) = (encrypt(amount, &key_pair.public_key), encrypt(other, &key_pair.public_key));
+    let product = homomorphic_multiply(c1, c2, n);
+    let expected = mod_mul(amount, other, n);
+    println!("  c₁ = m₁^e mod n = {}^{} mod {} = {}", amount, e, n, c1);
+    println!("  c₂ = m₂^e mod n = {}^{} mod {} = {}", other, e, n, c2);
+    println!("  c₁ · c₂ mod n = {}", product);
+    println!("  (m₁·m₂)^e mod n = {} → the same ciphertext, since m₁^e · m₂^e = (m₁·m₂)^e", encrypt(expected, &key_pair.public_key));
+    println!("  Decrypt(c₁ · c₂) = {} = {} × {} mod n", decrypt(product, &key_pair.private_key), amount, other);
+
+    println!("\n=== Malleability: Scaling an Encrypted Amount ===");
+    let textbook = textbook_malleability(amount, factor, &key_pair);
+    println!("  Alice → bank:   c = E({}) = {}", amount, textbook.ciphertext);
+    println!("  Mallory knows only c and (n, e), and computes E(s) = {}^{} mod n = {}", factor, e, textbook.factor_ciphertext);
+    println!("  Mallory → bank: c' = c · E(s) mod n = {}", textbook.forged_ciphertext);
+    println!("  Bank decrypts c' = E(s·m) → {}", textbook.decrypted);
+    let forged = textbook.accepted.as_ref().is_ok_and(|&accepted| accepted == mod_mul(amount, factor, n));
+    if forged {
+        println!("  ⚠️  The bank accepts {} = {} × {} without Mallory ever learning the amount", textbook.decrypted, factor, amount);
+    }
+
+    println!("\n=== With OAEP-Style Padding ===");
+    let Some(max) = max_message(n) else {
+        eprintln!("  ❌ n = {} is too small to pad (needs n ≥ 2^40)", n);
+        eprintln!("💡 Tip: Use primes such as 4294967291 and 4294967279");
+        return;
+    };
+    let mut rng = DemoRng::from_entropy();
+    let (padded, oaep) = match padded_malleability(amount, factor, &key_pair, &mut rng) {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("  ❌ {} (amounts up to {} fit)", error, max);
+            return;
+        }
+    };
+    println!("  EM = OAEP(m) = 0x{:x} (masked {}-byte seed ‖ label check ‖ m)", padded, SEED_BYTES);
+    println!("  c = EM^e mod n = {}", oaep.ciphertext);
+    println!("  c' = c · E(s) mod n = {}", oaep.forged_ciphertext);
+    println!("  Bank decrypts c' → s · EM mod n = 0x{:x}", oaep.decrypted);
+    println!("  Multiplying by s scrambles the seed and the check bytes, not just m:");
+    match &oaep.accepted {
+        Ok(value) => println!("  ⚠️  Padding check passed by chance (1 in 65536), decoded {}", value),
+        Err(error) => println!("  🛑 {} → rejected", error),
+    }
+    let honest = oaep_decode(decrypt(oaep.ciphertext, &key_pair.private_key), n);
+    println!("  The untouched c still decodes to {}", honest.as_ref().map_or_else(|e| e.clone(), |m| m.to_string()));
+
+    if forged && oaep.accepted.is_err() && honest == Ok(amount) {
+        println!("\n✅ Textbook RSA let Mallory scale the amount; OAEP padding rejected the forgery.");
+    } else {
+        println!("\n❌ Unexpected outcome.");
+    }
+}
+
+/// Time three decryption implementations and try to read d out of each
+pub fn run_timing_attack_demo(p: u64, q: u64, samples: usize, repetitions: usize) {
+    println!("⏱️  RSA Timing Attack Demo");
+    println!("Primes: p={}, q={}", p, q);
+
+    validate_primes(p, q);
+    let key_pair = generate_keypair(p, q);
+    let n = key_pair.public_key.n;
+    let d = key_pair.private_key.d;
+    let bit_length = 64 - d.leading_zeros();
+
+    let Some(context) = attack_context(n) else {
+        eprintln!("\n❌ Error: n = {} must be below 2^63 for the demo's Montgomery arithmetic", n);
+        return;
+    };
+
+    println!("\n=== Setup ===");
+    println!("  Public key: (n={}, e={})", n, key_pair.public_key.e);
+    println!("  Secret d = {} = {:b} ({} bits)", d, d, bit_length);
+    println!("  Montgomery R = 2^{}, extra subtraction emulates {} limbs", context.r_bits, EMULATED_LIMBS);
+    println!("  Attacker: {} random ciphertexts, minimum of {} timings each", samples, repetitions);
+
+    let mut rng = DemoRng::from_entropy();
+    let check = 42 % n;
+    let ciphertext = encrypt(check, &key_pair.public_key);
+    for target in TimingTarget::ALL {
+        if target_decrypt(target, &context, &key_pair, ciphertext, &mut rng) != check {
+            eprintln!("\n❌ Error: {} decrypts incorrectly", target.name());
+            return;
+        }
+    }
+
+    let mut outcomes = Vec::new();
+    for target in TimingTarget::ALL {
+        println!("\n=== {} ===", target.name());
+        let result = collect_samples(target, &key_pair, samples, repetitions, &mut rng)
+            .and_then(|timings| {
+                let mean = timings.iter().map(|s| s.nanos).sum::<u64>() as f64 / timings.len().max(1) as f64;
+                println!("  Mean decryption time: {:.0} ns", mean);
+                recover_exponent(&timings, n, bit_length)
+            });
+        let result = match result {
+            Ok(result) => result,
+            Err(error) => {
+                eprintln!("\n❌ Error: {}", error);
+                return;
+            }
+        };
+
+        println!("  Bit  Δ if 1 (ns)  Δ if 0 (ns)  Guess  Actual");
+        for guess in result.guesses.iter().take(MAX_TIMING_BITS_SHOWN) {
+            let actual = d >> guess.position & 1;
+            println!(
+                "  {:>3}  {:>11.2}  {:>11.2}  {:>5}  {:>6} {}",
+                guess.position,
+                guess.difference_if_one,
+                guess.difference_if_zero,
+                guess.bit,
+                actual,
+                if guess.bit == actual { "✓" } else { "✗" }
+            );
+        }
+        if result.guesses.len() > MAX_TIMING_BITS_SHOWN {
+            println!("  … {} more bits", result.guesses.len() - MAX_TIMING_BITS_SHOWN);
+        }
+
+        let correct_prefix = (d ^ result.recovered_d).leading_zeros() - d.leading_zeros();
+        println!("  Recovered: {:b}", result.recovered_d);
+        println!("  Actual:    {:b}", d);
+        println!("  Leading bits correct: {}/{}", correct_prefix.min(bit_length), bit_length);
+        outcomes.push((target, result.recovered_d == d));
+    }
+
+    println!("\n=== Summary ===");
+    for (target, recovered) in &outcomes {
+        println!("  {:<32} {}", target.name(), if *recovered { "d recovered 🔓" } else { "d not recovered 🔒" });
+    }
+
+    match outcomes.as_slice() {
+        [(_, true), (_, false), (_, false)] => {
+            println!("\n✅ Timing leaked d from the branching code; the ladder and blinding stopped it!")
+        }
+        [(_, false), ..] => {
+            println!("\n⚠️  The leaky version was not broken this time; timing noise won.");
+            println!("💡 Tip: Increase --samples or --repetitions, or run on an idle machine");
+        }
+        _ => println!("\n⚠️  A protected version leaked d; try again with more samples to rule out luck."),
+    }
+}
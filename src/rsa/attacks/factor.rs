@@ -0,0 +1,400 @@
+use std::time::{Duration, Instant};
+
+use crate::rsa::math_utils::{gcd, is_prime, mod_inverse, mod_mul, mod_pow};
+use crate::rsa::types::{RsaPrivateKey, RsaPublicKey};
+
+/// Iteration budget given to each method before it gives up
+pub const DEFAULT_MAX_ITERATIONS: u64 = 5_000_000;
+
+/// Number of consecutive x values sieved at once by the quadratic sieve
+const SIEVE_INTERVAL: u64 = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorMethod {
+    TrialDivision,
+    Fermat,
+    PollardRho,
+    PollardPMinus1,
+    QuadraticSieve,
+}
+
+impl FactorMethod {
+    pub const ALL: [FactorMethod; 5] = [
+        FactorMethod::TrialDivision,
+        FactorMethod::Fermat,
+        FactorMethod::PollardRho,
+        FactorMethod::PollardPMinus1,
+        FactorMethod::QuadraticSieve,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FactorMethod::TrialDivision => "Trial division",
+            FactorMethod::Fermat => "Fermat",
+            FactorMethod::PollardRho => "Pollard's rho",
+            FactorMethod::PollardPMinus1 => "Pollard's p-1",
+            FactorMethod::QuadraticSieve => "Quadratic sieve",
+        }
+    }
+}
+
+/// Outcome of one factoring attempt, with the work it took
+#[derive(Debug, Clone)]
+pub struct FactorResult {
+    pub method: FactorMethod,
+    /// Non-trivial split n = p × q with p ≤ q
+    pub factors: Option<(u64, u64)>,
+    pub iterations: u64,
+    pub elapsed: Duration,
+    /// Method-specific parameters, e.g. the polynomial constant or smoothness bound
+    pub detail: String,
+}
+
+fn split(n: u64, factor: u64) -> Option<(u64, u64)> {
+    (factor > 1 && factor < n && n.is_multiple_of(factor)).then(|| {
+        let other = n / factor;
+        (factor.min(other), factor.max(other))
+    })
+}
+
+fn timed(method: FactorMethod, n: u64, run: impl FnOnce() -> (Option<u64>, u64, String)) -> FactorResult {
+    let start = Instant::now();
+    let (factor, iterations, detail) = run();
+    FactorResult {
+        method,
+        factors: factor.and_then(|f| split(n, f)),
+        iterations,
+        elapsed: start.elapsed(),
+        detail,
+    }
+}
+
+/// Run a single method with the given iteration budget
+pub fn factor(n: u64, method: FactorMethod, max_iterations: u64) -> FactorResult {
+    match method {
+        FactorMethod::TrialDivision => trial_division(n, max_iterations),
+        FactorMethod::Fermat => fermat(n, max_iterations),
+        FactorMethod::PollardRho => pollard_rho(n, max_iterations),
+        FactorMethod::PollardPMinus1 => pollard_p_minus_1(n, max_iterations),
+        FactorMethod::QuadraticSieve => quadratic_sieve(n, max_iterations),
+    }
+}
+
+/// Run every method on the same n so their costs can be compared
+pub fn factor_all(n: u64, max_iterations: u64) -> Vec<FactorResult> {
+    FactorMethod::ALL.iter().map(|&m| factor(n, m, max_iterations)).collect()
+}
+
+/// Trial division: try 2, 3, 5, 7, … up to √n
+///
+/// Cost grows with the smallest factor, so balanced RSA primes are the worst case.
+pub fn trial_division(n: u64, max_iterations: u64) -> FactorResult {
+    timed(FactorMethod::TrialDivision, n, || {
+        let limit = (n as u128).isqrt() as u64;
+        let mut iterations = 1;
+        if n.is_multiple_of(2) {
+            return (Some(2), iterations, "divisor 2".to_string());
+        }
+
+        let mut divisor = 3;
+        while divisor <= limit && iterations < max_iterations {
+            iterations += 1;
+            if n.is_multiple_of(divisor) {
+                return (Some(divisor), iterations, format!("divisor {}", divisor));
+            }
+            divisor += 2;
+        }
+        (None, iterations, format!("searched up to {}", divisor - 2))
+    })
+}
+
+/// Fermat's method: find a with a² - n = b², so n = (a - b)(a + b)
+///
+/// Starts at a = ⌈√n⌉; very fast when p and q are close together.
+pub fn fermat(n: u64, max_iterations: u64) -> FactorResult {
+    timed(FactorMethod::Fermat, n, || {
+        if n.is_multiple_of(2) {
+            return (Some(2), 0, "n is even".to_string());
+        }
+
+        let n = n as u128;
+        let start = n.isqrt() + u128::from(n.isqrt().pow(2) < n);
+        let mut a = start;
+        let mut iterations = 0;
+
+        while iterations < max_iterations {
+            iterations += 1;
+            let b_squared = a * a - n;
+            let b = b_squared.isqrt();
+            if b * b == b_squared {
+                let detail = format!("a = {}, b = {} (a - ⌈√n⌉ = {})", a, b, a - start);
+                return (Some((a - b) as u64), iterations, detail);
+            }
+            a += 1;
+        }
+        (None, iterations, format!("a reached {}", a))
+    })
+}
+
+/// Pollard's rho: iterate x ↦ x² + c mod n until two values collide modulo a factor
+///
+/// Uses Floyd's tortoise-and-hare cycle detection; expected cost is about √p steps.
+pub fn pollard_rho(n: u64, max_iterations: u64) -> FactorResult {
+    timed(FactorMethod::PollardRho, n, || {
+        if n.is_multiple_of(2) {
+            return (Some(2), 0, "n is even".to_string());
+        }
+
+        let mut iterations = 0;
+        for c in 1..=20u64 {
+            let f = |x: u64| ((mod_mul(x, x, n) as u128 + c as u128) % n as u128) as u64;
+            let (mut tortoise, mut hare, mut d) = (2u64, 2u64, 1u64);
+
+            while d == 1 && iterations < max_iterations {
+                iterations += 1;
+                tortoise = f(tortoise);
+                hare = f(f(hare));
+                d = gcd(tortoise.abs_diff(hare), n);
+            }
+
+            if d != 1 && d != n {
+                return (Some(d), iterations, format!("f(x) = x² + {}", c));
+            }
+            if iterations >= max_iterations {
+                break;
+            }
+        }
+        (None, iterations, "no collision found".to_string())
+    })
+}
+
+/// Pollard's p-1: if p - 1 is B-smooth then a^(B!) ≡ 1 (mod p), so gcd(a^(B!) - 1, n) reveals p
+pub fn pollard_p_minus_1(n: u64, max_iterations: u64) -> FactorResult {
+    timed(FactorMethod::PollardPMinus1, n, || {
+        if n.is_multiple_of(2) {
+            return (Some(2), 0, "n is even".to_string());
+        }
+
+        let mut a = 2u64;
+        let mut bound = 1;
+        while bound < max_iterations {
+            bound += 1;
+            a = mod_pow(a, bound, n);
+            let d = gcd(a.checked_sub(1).unwrap_or(n - 1), n);
+            if d == n {
+                return (None, bound, format!("gcd hit n at B = {}", bound));
+            }
+            if d > 1 {
+                return (Some(d), bound, format!("smoothness bound B = {}", bound));
+            }
+        }
+        (None, bound, format!("B = {} too small", bound))
+    })
+}
+
+// ============================================================================
+// Quadratic Sieve
+// ============================================================================
+
+/// A value x with Q(x) = x² - n fully factored over the factor base
+///
+/// `exponents[0]` is the power of -1 (the sign of Q(x)); the rest follow the factor base.
+struct Relation {
+    x: u64,
+    exponents: Vec<u32>,
+}
+
+fn smoothness_bound(n: u64) -> u64 {
+    let ln_n = (n as f64).ln();
+    let l = (ln_n * ln_n.ln()).sqrt();
+    ((0.6 * l).exp() as u64).max(50)
+}
+
+/// Primes p ≤ B for which n is a square mod p (Euler's criterion), with their square roots of n
+fn factor_base(n: u64, bound: u64) -> Vec<(u64, Vec<u64>)> {
+    (2..=bound)
+        .filter(|&p| is_prime(p))
+        .filter(|&p| p == 2 || mod_pow(n % p, (p - 1) / 2, p) == 1)
+        .map(|p| {
+            let roots = (0..p).filter(|&r| r * r % p == n % p).collect();
+            (p, roots)
+        })
+        .collect()
+}
+
+fn factor_over_base(q: i128, base: &[(u64, Vec<u64>)]) -> Option<Vec<u32>> {
+    let mut value = q.unsigned_abs();
+    let mut exponents = vec![0u32; base.len() + 1];
+    exponents[0] = u32::from(q < 0);
+    for (i, &(p, _)) in base.iter().enumerate() {
+        while value.is_multiple_of(p as u128) {
+            value /= p as u128;
+            exponents[i + 1] += 1;
+        }
+    }
+    (value == 1).then_some(exponents)
+}
+
+/// Sieve x in [start, start + INTERVAL) and keep every x whose Q(x) is smooth
+fn sieve_interval(n: u64, start: u64, base: &[(u64, Vec<u64>)], slack: f32, relations: &mut Vec<Relation>) {
+    let mut logs = vec![0f32; SIEVE_INTERVAL as usize];
+    for (p, roots) in base {
+        for &r in roots {
+            // First x ≥ start with x ≡ r (mod p)
+            let mut i = (r + p - start % p) % p;
+            while i < SIEVE_INTERVAL {
+                logs[i as usize] += (*p as f32).log2();
+                i += p;
+            }
+        }
+    }
+
+    for (i, &log) in logs.iter().enumerate() {
+        let x = start + i as u64;
+        let q = x as i128 * x as i128 - n as i128;
+        if q != 0
+            && log >= (q.unsigned_abs() as f32).log2() - slack
+            && let Some(exponents) = factor_over_base(q, base)
+        {
+            relations.push(Relation { x, exponents });
+        }
+    }
+}
+
+/// Find subsets of relations whose exponent vectors sum to zero mod 2 (Gaussian elimination over GF(2))
+fn find_dependencies(relations: &[Relation], columns: usize) -> Vec<Vec<usize>> {
+    let words = |bits: usize| bits.div_ceil(64);
+    let mut rows: Vec<(Vec<u64>, Vec<u64>)> = relations
+        .iter()
+        .enumerate()
+        .map(|(i, relation)| {
+            let mut parity = vec![0u64; words(columns)];
+            for (j, &e) in relation.exponents.iter().enumerate() {
+                if e % 2 == 1 {
+                    parity[j / 64] |= 1 << (j % 64);
+                }
+            }
+            let mut history = vec![0u64; words(relations.len())];
+            history[i / 64] |= 1 << (i % 64);
+            (parity, history)
+        })
+        .collect();
+
+    let mut pivot_row = 0;
+    for column in 0..columns {
+        let bit = |row: &(Vec<u64>, Vec<u64>)| row.0[column / 64] >> (column % 64) & 1 == 1;
+        let Some(found) = (pivot_row..rows.len()).find(|&r| bit(&rows[r])) else {
+            continue;
+        };
+        rows.swap(pivot_row, found);
+        let pivot = rows[pivot_row].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != pivot_row && bit(row) {
+                row.0.iter_mut().zip(&pivot.0).for_each(|(a, b)| *a ^= b);
+                row.1.iter_mut().zip(&pivot.1).for_each(|(a, b)| *a ^= b);
+            }
+        }
+        pivot_row += 1;
+    }
+
+    rows[pivot_row..]
+        .iter()
+        .map(|(_, history)| {
+            (0..relations.len())
+                .filter(|&i| history[i / 64] >> (i % 64) & 1 == 1)
+                .collect()
+        })
+        .collect()
+}
+
+/// Turn a dependency into X² ≡ Y² (mod n) and try gcd(X - Y, n)
+fn try_dependency(n: u64, base: &[(u64, Vec<u64>)], relations: &[Relation], subset: &[usize]) -> Option<u64> {
+    let mut x = 1u64;
+    let mut exponent_sum = vec![0u32; base.len() + 1];
+    for &i in subset {
+        x = mod_mul(x, relations[i].x % n, n);
+        exponent_sum.iter_mut().zip(&relations[i].exponents).for_each(|(s, e)| *s += e);
+    }
+
+    // The -1 column has an even total, so it contributes nothing to Y
+    let y = base
+        .iter()
+        .zip(&exponent_sum[1..])
+        .fold(1u64, |y, (&(p, _), &e)| mod_mul(y, mod_pow(p, (e / 2) as u64, n), n));
+
+    let d = gcd(x.abs_diff(y), n);
+    (d > 1 && d < n).then_some(d)
+}
+
+/// Quadratic sieve: collect x with smooth x² - n, then combine them into a congruence of squares
+///
+/// Each prime p in the factor base divides Q(x) exactly when x ≡ ±√n (mod p), so
+/// instead of trial-dividing every Q(x) we add log p at those positions and only
+/// inspect values whose accumulated logarithm is close to log Q(x).
+pub fn quadratic_sieve(n: u64, max_iterations: u64) -> FactorResult {
+    timed(FactorMethod::QuadraticSieve, n, || {
+        if n.is_multiple_of(2) {
+            return (Some(2), 0, "n is even".to_string());
+        }
+        let root = (n as u128).isqrt() as u64;
+        if root as u128 * root as u128 == n as u128 {
+            return (Some(root), 0, "n is a perfect square".to_string());
+        }
+
+        let bound = smoothness_bound(n);
+        if let Some(p) = (3..=bound.min(n - 1)).find(|&p| n.is_multiple_of(p)) {
+            return (Some(p), 0, format!("small prime {} divides n", p));
+        }
+        let base = factor_base(n, bound);
+
+        let wanted = base.len() + 11;
+        let slack = 2.0 * (bound as f32).log2();
+        let mut relations = Vec::new();
+        let mut iterations = 0;
+
+        // Work outwards from √n on both sides, where |Q(x)| is smallest
+        for k in 0.. {
+            if relations.len() >= wanted || iterations >= max_iterations {
+                break;
+            }
+            let above = root + 1 + k * SIEVE_INTERVAL;
+            sieve_interval(n, above, &base, slack, &mut relations);
+            iterations += SIEVE_INTERVAL;
+
+            if let Some(below) = root.checked_sub((k + 1) * SIEVE_INTERVAL) {
+                sieve_interval(n, below + 1, &base, slack, &mut relations);
+                iterations += SIEVE_INTERVAL;
+            }
+        }
+
+        let summary = format!(
+            "B = {}, factor base {} primes, {} relations",
+            bound,
+            base.len(),
+            relations.len()
+        );
+        let factor = find_dependencies(&relations, base.len() + 1)
+            .iter()
+            .find_map(|subset| try_dependency(n, &base, &relations, subset));
+        (factor, iterations, summary)
+    })
+}
+
+/// Rebuild the private key once n has been factored
+///
+/// Fails unless n = p·q with p, q distinct primes and e invertible mod φ(n).
+pub fn recover_private_key(public_key: &RsaPublicKey, p: u64, q: u64) -> Result<RsaPrivateKey, String> {
+    if p as u128 * q as u128 != public_key.n as u128 {
+        return Err(format!("{} × {} is not n = {}", p, q, public_key.n));
+    }
+    if !is_prime(p) || !is_prime(q) {
+        return Err(format!("n = {} × {} is not a product of two primes - not an RSA modulus", p, q));
+    }
+    if p == q {
+        return Err(format!("n = {}² is a prime square - not an RSA modulus", p));
+    }
+    let phi_n = u64::try_from((p - 1) as u128 * (q - 1) as u128).map_err(|_| "φ(n) does not fit in 64 bits".to_string())?;
+    mod_inverse(public_key.e, phi_n)
+        .map(|d| RsaPrivateKey { n: public_key.n, d })
+        .ok_or_else(|| format!("e = {} has no inverse mod φ(n) = {} - not a valid RSA key", public_key.e, phi_n))
+}
@@ -0,0 +1,70 @@
+use crate::common::random::DemoRng;
+use crate::rsa::encryption::{decrypt, encrypt};
+use crate::rsa::math_utils::mod_mul;
+use crate::rsa::oaep::{oaep_decode, oaep_encode};
+use crate::rsa::types::{RsaKeyPair, RsaPublicKey};
+
+/// E(m₁)·E(m₂) mod n, which is E(m₁·m₂ mod n) for textbook RSA
+///
+/// m₁^e · m₂^e = (m₁·m₂)^e, so anyone can combine ciphertexts without a key.
+pub fn homomorphic_multiply(c1: u64, c2: u64, n: u64) -> u64 {
+    mod_mul(c1, c2, n)
+}
+
+/// Turn E(m) into E(s·m) by multiplying with E(s), knowing neither m nor d
+pub fn scale_ciphertext(ciphertext: u64, factor: u64, public_key: &RsaPublicKey) -> u64 {
+    homomorphic_multiply(ciphertext, encrypt(factor, public_key), public_key.n)
+}
+
+/// An encrypted amount scaled in transit, and what the recipient ends up with
+#[derive(Debug, Clone)]
+pub struct MalleabilityResult {
+    pub ciphertext: u64,
+    /// E(s), computed from the public key alone
+    pub factor_ciphertext: u64,
+    pub forged_ciphertext: u64,
+    /// What the recipient decrypts (a padded block when OAEP is used)
+    pub decrypted: u64,
+    /// The amount the recipient accepts, or why it was rejected
+    pub accepted: Result<u64, String>,
+}
+
+/// Textbook RSA: the recipient accepts s·m
+pub fn textbook_malleability(amount: u64, factor: u64, key_pair: &RsaKeyPair) -> MalleabilityResult {
+    let ciphertext = encrypt(amount, &key_pair.public_key);
+    forge(ciphertext, factor, key_pair, Ok)
+}
+
+/// OAEP-padded RSA: the recipient decrypts s·EM, which fails the padding check
+///
+/// Returns the padded block as well as the forgery's outcome.
+pub fn padded_malleability(
+    amount: u64,
+    factor: u64,
+    key_pair: &RsaKeyPair,
+    rng: &mut DemoRng,
+) -> Result<(u64, MalleabilityResult), String> {
+    let n = key_pair.public_key.n;
+    let padded = oaep_encode(amount, n, rng)?;
+    let ciphertext = encrypt(padded, &key_pair.public_key);
+    Ok((padded, forge(ciphertext, factor, key_pair, |decrypted| oaep_decode(decrypted, n))))
+}
+
+fn forge(
+    ciphertext: u64,
+    factor: u64,
+    key_pair: &RsaKeyPair,
+    accept: impl Fn(u64) -> Result<u64, String>,
+) -> MalleabilityResult {
+    let factor_ciphertext = encrypt(factor, &key_pair.public_key);
+    let forged_ciphertext = homomorphic_multiply(ciphertext, factor_ciphertext, key_pair.public_key.n);
+    let decrypted = decrypt(forged_ciphertext, &key_pair.private_key);
+
+    MalleabilityResult {
+        ciphertext,
+        factor_ciphertext,
+        forged_ciphertext,
+        decrypted,
+        accepted: accept(decrypted),
+    }
+}
@@ -0,0 +1,8 @@
+pub mod common_modulus;
+pub mod factor;
+pub mod malleability;
+pub mod related_message;
+pub mod small_exponent;
+pub mod timing;
+pub mod wiener;
+pub mod demo;
@@ -0,0 +1,157 @@
+use crate::rsa::encryption::encrypt;
+use crate::rsa::key_generation::generate_keypair;
+use crate::rsa::math_utils::{mod_inverse, mod_mul};
+use crate::rsa::types::RsaPublicKey;
+
+/// Polynomial over Z_n, coefficients from lowest to highest degree
+pub type Polynomial = Vec<u64>;
+
+/// Two ciphertexts of linearly related messages m₂ = α·m₁ + β under one e = 3 key
+#[derive(Clone)]
+pub struct RelatedMessageScenario {
+    pub public_key: RsaPublicKey,
+    pub alpha: u64,
+    pub beta: u64,
+    pub m1: u64,
+    pub m2: u64,
+    pub c1: u64,
+    pub c2: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FranklinReiterRecovery {
+    /// g₁(x) = x³ - c₁
+    pub g1: Polynomial,
+    /// g₂(x) = (αx + β)³ - c₂
+    pub g2: Polynomial,
+    /// Successive remainders of the Euclidean algorithm, ending with the monic gcd
+    pub remainders: Vec<Polynomial>,
+    pub message: u64,
+}
+
+/// (a - b) mod n without overflowing when n is close to u64::MAX
+fn mod_sub(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 + n as u128 - b as u128) % n as u128) as u64
+}
+
+fn trim(mut poly: Polynomial) -> Polynomial {
+    while poly.len() > 1 && poly.last() == Some(&0) {
+        poly.pop();
+    }
+    poly
+}
+
+fn degree(poly: &Polynomial) -> usize {
+    poly.len().saturating_sub(1)
+}
+
+fn is_zero(poly: &Polynomial) -> bool {
+    poly.iter().all(|&c| c == 0)
+}
+
+/// a mod b over Z_n; None if b's leading coefficient is not invertible
+/// (which would mean gcd(lead, n) is a factor of n)
+fn poly_rem(a: &Polynomial, b: &Polynomial, n: u64) -> Option<Polynomial> {
+    let lead_inverse = mod_inverse(*b.last()?, n)?;
+    let mut remainder = a.clone();
+
+    while !is_zero(&remainder) && degree(&remainder) >= degree(b) {
+        let shift = degree(&remainder) - degree(b);
+        let factor = mod_mul(*remainder.last()?, lead_inverse, n);
+        for (i, &coefficient) in b.iter().enumerate() {
+            let subtract = mod_mul(factor, coefficient, n);
+            remainder[i + shift] = mod_sub(remainder[i + shift], subtract, n);
+        }
+        remainder = trim(remainder);
+    }
+    Some(remainder)
+}
+
+fn make_monic(poly: &Polynomial, n: u64) -> Option<Polynomial> {
+    let inverse = mod_inverse(*poly.last()?, n)?;
+    Some(poly.iter().map(|&c| mod_mul(c, inverse, n)).collect())
+}
+
+/// Euclidean algorithm for polynomials over Z_n, recording every remainder
+pub fn poly_gcd(a: &Polynomial, b: &Polynomial, n: u64) -> Option<(Polynomial, Vec<Polynomial>)> {
+    let (mut x, mut y) = (trim(a.clone()), trim(b.clone()));
+    let mut remainders = Vec::new();
+
+    while !is_zero(&y) {
+        let r = poly_rem(&x, &y, n)?;
+        remainders.push(r.clone());
+        (x, y) = (y, r);
+    }
+    let gcd = make_monic(&x, n)?;
+    Some((gcd, remainders))
+}
+
+/// (αx + β)³ - c expanded: β³ - c, 3αβ², 3α²β, α³
+fn cubic_related(alpha: u64, beta: u64, c: u64, n: u64) -> Polynomial {
+    let a2 = mod_mul(alpha, alpha, n);
+    let b2 = mod_mul(beta, beta, n);
+    vec![
+        mod_sub(mod_mul(b2, beta, n), c % n, n),
+        mod_mul(3, mod_mul(alpha, b2, n), n),
+        mod_mul(3, mod_mul(a2, beta, n), n),
+        mod_mul(a2, alpha, n),
+    ]
+}
+
+/// Build the scenario from `generate_keypair`; only applies when it picks e = 3
+pub fn related_message_scenario(message: u64, p: u64, q: u64, alpha: u64, beta: u64) -> Option<RelatedMessageScenario> {
+    let key_pair = generate_keypair(p, q);
+    if key_pair.public_key.e != 3 {
+        return None;
+    }
+
+    let n = key_pair.public_key.n;
+    let m2 = ((mod_mul(alpha, message, n) as u128 + beta as u128) % n as u128) as u64;
+    Some(RelatedMessageScenario {
+        c1: encrypt(message, &key_pair.public_key),
+        c2: encrypt(m2, &key_pair.public_key),
+        public_key: key_pair.public_key,
+        alpha,
+        beta,
+        m1: message,
+        m2,
+    })
+}
+
+/// Franklin–Reiter related-message attack for e = 3
+///
+/// m₁ is a root of both g₁(x) = x³ - c₁ and g₂(x) = (αx + β)³ - c₂, so
+/// (x - m₁) divides gcd(g₁, g₂). In practice the gcd is exactly x - m₁.
+pub fn franklin_reiter_attack(n: u64, alpha: u64, beta: u64, c1: u64, c2: u64) -> Option<FranklinReiterRecovery> {
+    let g1 = vec![(n - c1 % n) % n, 0, 0, 1];
+    let g2 = cubic_related(alpha, beta, c2, n);
+    let (gcd, remainders) = poly_gcd(&g2, &g1, n)?;
+
+    // Monic linear gcd x + g₀ means x ≡ -g₀
+    (gcd.len() == 2).then(|| FranklinReiterRecovery {
+        message: (n - gcd[0]) % n,
+        g1,
+        g2,
+        remainders,
+    })
+}
+
+/// Render a polynomial as e.g. "x³ + 5x + 7"
+pub fn format_polynomial(poly: &Polynomial) -> String {
+    const SUPERSCRIPTS: [&str; 4] = ["", "", "²", "³"];
+    let terms: Vec<String> = poly
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|&(_, &c)| c != 0)
+        .map(|(power, &c)| {
+            let coefficient = if c == 1 && power > 0 { String::new() } else { c.to_string() };
+            match power {
+                0 => coefficient,
+                1 => format!("{}x", coefficient),
+                _ => format!("{}x{}", coefficient, SUPERSCRIPTS.get(power).copied().unwrap_or("^?")),
+            }
+        })
+        .collect();
+    if terms.is_empty() { "0".to_string() } else { terms.join(" + ") }
+}
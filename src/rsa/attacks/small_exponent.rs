@@ -0,0 +1,71 @@
+use crate::rsa::encryption::encrypt;
+use crate::rsa::key_generation::generate_keypair;
+use crate::rsa::math_utils::{crt, integer_root, is_prime};
+use crate::rsa::types::RsaPublicKey;
+
+/// Cube-root (e-th root) attack on unpadded RSA
+///
+/// If m^e < n the modular reduction never happens, so c = m^e over the
+/// integers and m is simply the ordinary e-th root of c.
+pub fn cube_root_attack(ciphertext: u64, public_key: &RsaPublicKey) -> Option<u64> {
+    let exponent = u32::try_from(public_key.e).ok()?;
+    let root = integer_root(ciphertext as u128, exponent);
+    (root.checked_pow(exponent) == Some(ciphertext as u128)).then_some(root as u64)
+}
+
+/// One recipient in a broadcast: their public key and the ciphertext they received
+#[derive(Clone)]
+pub struct BroadcastRecipient {
+    pub p: u64,
+    pub q: u64,
+    pub public_key: RsaPublicKey,
+    pub ciphertext: u64,
+}
+
+/// Result of combining the broadcast ciphertexts
+#[derive(Debug, Clone)]
+pub struct HastadRecovery {
+    /// x ≡ cᵢ (mod nᵢ) for all i, which equals m^e because m^e < ∏ nᵢ
+    pub combined: u128,
+    pub modulus_product: u128,
+    pub message: u64,
+}
+
+/// Primes p ≥ start for which `generate_keypair` will pick e = 3 (i.e. p ≡ 2 mod 3)
+fn primes_for_e3(start: u64) -> impl Iterator<Item = u64> {
+    (start..).filter(|&p| p % 3 == 2 && is_prime(p))
+}
+
+/// Send the same message to `recipients` different key holders, each with e = 3
+///
+/// Keys come from the standard `generate_keypair`, which chooses e = 3 whenever
+/// gcd(3, φ(n)) = 1 because it is first in `COMMON_PUBLIC_EXPONENTS`.
+pub fn broadcast_scenario(message: u64, recipients: usize, prime_start: u64) -> Vec<BroadcastRecipient> {
+    let primes: Vec<u64> = primes_for_e3(prime_start).take(2 * recipients).collect();
+
+    primes
+        .chunks(2)
+        .map(|pair| {
+            let (p, q) = (pair[0], pair[1]);
+            let key_pair = generate_keypair(p, q);
+            let ciphertext = encrypt(message, &key_pair.public_key);
+            BroadcastRecipient { p, q, public_key: key_pair.public_key, ciphertext }
+        })
+        .collect()
+}
+
+/// Håstad's broadcast attack
+///
+/// With e ciphertexts of the same m under coprime moduli n₁…nₑ, the CRT gives
+/// x ≡ m^e (mod n₁⋯nₑ). Since m < every nᵢ, m^e < n₁⋯nₑ, so x = m^e exactly
+/// and m = ∛x (for e = 3) with no modular arithmetic involved.
+pub fn hastad_attack(ciphertexts: &[(u64, u64)], e: u32) -> Option<HastadRecovery> {
+    let (combined, modulus_product) = crt(ciphertexts)?;
+    let root = integer_root(combined, e);
+
+    (root.checked_pow(e) == Some(combined)).then_some(HastadRecovery {
+        combined,
+        modulus_product,
+        message: root as u64,
+    })
+}
@@ -0,0 +1,239 @@
+// ============================================================================
+// Timing Attack on Montgomery Square-and-Multiply
+// ============================================================================
+// Dhem et al., "A Practical Implementation of the Timing Attack" (1998).
+// REDC ends with a subtraction that only runs when its result is ≥ n. In a
+// left-to-right square-and-multiply the multiply by c̄ only happens for 1
+// bits, so once the high bits of d are known an attacker can simulate, per
+// ciphertext, whether the next multiply *would* need that subtraction. If
+// the bit really is 1, ciphertexts predicted to need it decrypt measurably
+// slower; if it is 0, the prediction is about an operation that never ran.
+// The multiply itself is a poor test: its extra subtraction mostly depends on
+// how large c̄ is, which every other multiply shares. The attack therefore
+// predicts the *next squaring* under both hypotheses; only the right one
+// lines up with the measured times.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::common::random::DemoRng;
+use crate::rsa::constant_time::montgomery_ladder;
+use crate::rsa::encryption::blinding_factor;
+use crate::rsa::math_utils::{mod_mul, mod_pow};
+use crate::rsa::montgomery::{Montgomery, MontgomeryContext};
+use crate::rsa::types::RsaKeyPair;
+
+/// A 2048-bit modulus is 32 limbs; the extra subtraction costs one pass over them
+pub const EMULATED_LIMBS: u64 = 32;
+pub const DEFAULT_SAMPLES: usize = 20_000;
+/// Each ciphertext is timed this many times and the minimum kept, to shed scheduler noise
+pub const DEFAULT_REPETITIONS: usize = 3;
+
+/// Decryption implementation under attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimingTarget {
+    /// Square-and-multiply with branching REDC
+    Leaky,
+    /// Montgomery ladder with masked REDC
+    ConstantTime,
+    /// The leaky implementation behind RSA blinding
+    Blinded,
+}
+
+impl TimingTarget {
+    pub const ALL: [TimingTarget; 3] = [TimingTarget::Leaky, TimingTarget::ConstantTime, TimingTarget::Blinded];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimingTarget::Leaky => "Leaky square-and-multiply",
+            TimingTarget::ConstantTime => "Constant-time Montgomery ladder",
+            TimingTarget::Blinded => "Leaky + RSA blinding",
+        }
+    }
+}
+
+/// Stand-in for the per-limb loop of a bignum subtraction; the u64 version is too fast to time
+fn emulate_limb_subtraction() {
+    for limb in 0..EMULATED_LIMBS {
+        black_box(black_box(limb).wrapping_sub(1));
+    }
+}
+
+fn leaky_mul(context: &MontgomeryContext, a: u64, b: u64) -> u64 {
+    let (product, extra_reduction) = context.redc_with_flag(a as u128 * b as u128);
+    if extra_reduction {
+        emulate_limb_subtraction();
+    }
+    product
+}
+
+fn constant_time_mul(context: &MontgomeryContext, a: u64, b: u64) -> u64 {
+    emulate_limb_subtraction();
+    context.redc_constant_time(a as u128 * b as u128)
+}
+
+/// R just above n, as a bignum library would choose
+pub fn attack_context(n: u64) -> Option<MontgomeryContext> {
+    MontgomeryContext::with_r_bits(n, 64 - n.leading_zeros())
+}
+
+/// Left-to-right square-and-multiply in Montgomery form: `if bit == 1 { multiply }`
+pub fn leaky_mod_pow(context: &MontgomeryContext, base: u64, exp: u64) -> u64 {
+    if exp == 0 {
+        return 1 % context.n;
+    }
+    let base = context.to_montgomery(base).0;
+    let mut result = base;
+    for i in (0..63 - exp.leading_zeros()).rev() {
+        result = leaky_mul(context, result, result);
+        if exp >> i & 1 == 1 {
+            result = leaky_mul(context, result, base);
+        }
+    }
+    context.from_montgomery(Montgomery(result))
+}
+
+pub fn constant_time_mod_pow(context: &MontgomeryContext, base: u64, exp: u64) -> u64 {
+    let base = context.to_montgomery(base).0;
+    let result = montgomery_ladder(context.one().0, base, exp, u64::BITS, |a, b| constant_time_mul(context, a, b));
+    context.from_montgomery(Montgomery(result))
+}
+
+/// Decrypt with the chosen implementation
+pub fn target_decrypt(
+    target: TimingTarget,
+    context: &MontgomeryContext,
+    key_pair: &RsaKeyPair,
+    ciphertext: u64,
+    rng: &mut DemoRng,
+) -> u64 {
+    let d = key_pair.private_key.d;
+    match target {
+        TimingTarget::Leaky => leaky_mod_pow(context, ciphertext, d),
+        TimingTarget::ConstantTime => constant_time_mod_pow(context, ciphertext, d),
+        TimingTarget::Blinded => {
+            let n = key_pair.public_key.n;
+            let (r, r_inverse) = blinding_factor(n, rng);
+            let blinded = mod_mul(ciphertext, mod_pow(r, key_pair.public_key.e, n), n);
+            mod_mul(leaky_mod_pow(context, blinded, d), r_inverse, n)
+        }
+    }
+}
+
+/// One chosen ciphertext and its fastest observed decryption time
+#[derive(Debug, Clone, Copy)]
+pub struct TimingSample {
+    pub ciphertext: u64,
+    pub nanos: u64,
+}
+
+/// Time decryption of random ciphertexts, keeping the minimum of `repetitions` runs each
+pub fn collect_samples(
+    target: TimingTarget,
+    key_pair: &RsaKeyPair,
+    count: usize,
+    repetitions: usize,
+    rng: &mut DemoRng,
+) -> Result<Vec<TimingSample>, String> {
+    let n = key_pair.public_key.n;
+    let context = attack_context(n).ok_or_else(|| format!("Modulus {} must be odd and below 2^63", n))?;
+
+    let samples = (0..count)
+        .map(|_| {
+            let ciphertext = rng.below(n);
+            let nanos = (0..repetitions.max(1))
+                .map(|_| {
+                    let start = Instant::now();
+                    black_box(target_decrypt(target, &context, key_pair, black_box(ciphertext), rng));
+                    start.elapsed().as_nanos() as u64
+                })
+                .min()
+                .unwrap_or(0);
+            TimingSample { ciphertext, nanos }
+        })
+        .collect();
+    Ok(samples)
+}
+
+/// The decision for one exponent bit and the evidence behind it
+#[derive(Debug, Clone, Copy)]
+pub struct BitGuess {
+    pub position: u32,
+    pub bit: u64,
+    /// Mean time of ciphertexts whose next squaring would need the extra subtraction if the bit
+    /// is 1, minus the rest
+    pub difference_if_one: f64,
+    /// The same split assuming the bit is 0
+    pub difference_if_zero: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimingAttackResult {
+    pub recovered_d: u64,
+    pub guesses: Vec<BitGuess>,
+}
+
+/// Mean time of samples where the predicate holds minus where it does not
+fn split_difference(times: &[f64], predicate: &[bool]) -> f64 {
+    let (mut with, mut with_count, mut without, mut without_count) = (0.0, 0usize, 0.0, 0usize);
+    for (&time, &flag) in times.iter().zip(predicate) {
+        if flag {
+            with += time;
+            with_count += 1;
+        } else {
+            without += time;
+            without_count += 1;
+        }
+    }
+    if with_count == 0 || without_count == 0 {
+        return 0.0;
+    }
+    with / with_count as f64 - without / without_count as f64
+}
+
+/// Times capped at the 95th percentile so a few preempted runs do not swamp the means
+fn winsorized_times(samples: &[TimingSample]) -> Vec<f64> {
+    let mut sorted: Vec<u64> = samples.iter().map(|s| s.nanos).collect();
+    sorted.sort_unstable();
+    let cap = sorted.get(sorted.len() * 95 / 100).copied().unwrap_or(u64::MAX);
+    samples.iter().map(|s| s.nanos.min(cap) as f64).collect()
+}
+
+/// Recover d from timings, most significant bit first, given n and the bit length of d
+pub fn recover_exponent(samples: &[TimingSample], n: u64, bit_length: u32) -> Result<TimingAttackResult, String> {
+    let context = attack_context(n).ok_or_else(|| format!("Modulus {} must be odd and below 2^63", n))?;
+    if !(2..=64).contains(&bit_length) {
+        return Err("Exponent must be 2 to 64 bits long".to_string());
+    }
+
+    let times = winsorized_times(samples);
+    let bases: Vec<u64> = samples.iter().map(|s| context.to_montgomery(s.ciphertext).0).collect();
+    // The top bit is 1 by definition, so every accumulator starts at c̄
+    let mut accumulators = bases.clone();
+    let mut recovered_d = 1u64;
+    let mut guesses = Vec::new();
+
+    for position in (0..bit_length - 1).rev() {
+        let squares: Vec<u64> = accumulators.iter().map(|&a| context.redc(a as u128 * a as u128)).collect();
+        let if_one: Vec<u64> = squares.iter().zip(&bases).map(|(&s, &b)| context.redc(s as u128 * b as u128)).collect();
+        let next_square_needs_extra =
+            |values: &[u64]| -> Vec<bool> { values.iter().map(|&v| context.redc_with_flag(v as u128 * v as u128).1).collect() };
+
+        // d is odd, so the last bit needs no test; there is also no next squaring to test it with
+        let (difference_if_one, difference_if_zero) = if position > 0 {
+            (
+                split_difference(&times, &next_square_needs_extra(&if_one)),
+                split_difference(&times, &next_square_needs_extra(&squares)),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let bit = u64::from(position == 0 || difference_if_one > difference_if_zero);
+
+        recovered_d = recovered_d << 1 | bit;
+        accumulators = if bit == 1 { if_one } else { squares };
+        guesses.push(BitGuess { position, bit, difference_if_one, difference_if_zero });
+    }
+
+    Ok(TimingAttackResult { recovered_d, guesses })
+}
@@ -0,0 +1,129 @@
+use crate::rsa::math_utils::{mod_inverse, wiener_bound};
+use crate::rsa::types::{RsaKeyPair, RsaPrivateKey, RsaPublicKey};
+
+/// One convergent k/d of e/n and what testing it revealed
+#[derive(Debug, Clone)]
+pub struct WienerStep {
+    pub index: usize,
+    pub k: u64,
+    pub d: u64,
+    /// φ(n) = (e·d - 1) / k, when k divides e·d - 1
+    pub phi_candidate: Option<u64>,
+    /// (p, q) when x² - (n - φ + 1)x + n = 0 has integer roots
+    pub factors: Option<(u64, u64)>,
+}
+
+/// Private key material recovered from a vulnerable public key
+#[derive(Debug, Clone)]
+pub struct WienerRecovery {
+    pub d: u64,
+    pub p: u64,
+    pub q: u64,
+    pub phi_n: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WienerResult {
+    /// Continued fraction expansion [a₀; a₁, a₂, …] of e/n
+    pub quotients: Vec<u64>,
+    /// Every convergent tried, up to and including the successful one
+    pub steps: Vec<WienerStep>,
+    pub recovery: Option<WienerRecovery>,
+}
+
+/// Continued fraction expansion of numerator/denominator
+pub fn continued_fraction(numerator: u64, denominator: u64) -> Vec<u64> {
+    let mut quotients = Vec::new();
+    let (mut a, mut b) = (numerator, denominator);
+    while b != 0 {
+        quotients.push(a / b);
+        (a, b) = (b, a % b);
+    }
+    quotients
+}
+
+/// Convergents hᵢ/kᵢ of a continued fraction, as (numerator, denominator) pairs
+pub fn convergents(quotients: &[u64]) -> Vec<(u64, u64)> {
+    let mut result = Vec::with_capacity(quotients.len());
+    let (mut h_prev, mut h) = (0u128, 1u128);
+    let (mut k_prev, mut k) = (1u128, 0u128);
+
+    for &a in quotients {
+        (h_prev, h) = (h, a as u128 * h + h_prev);
+        (k_prev, k) = (k, a as u128 * k + k_prev);
+        result.push((h as u64, k as u64));
+    }
+    result
+}
+
+/// Try to factor n from a guessed (k, d) pair
+///
+/// If k/d is the right convergent then φ(n) = (e·d - 1)/k, and p, q are the
+/// roots of x² - (n - φ(n) + 1)x + n = 0.
+fn test_convergent(public_key: &RsaPublicKey, k: u64, d: u64) -> (Option<u64>, Option<(u64, u64)>) {
+    let Some(ed_minus_one) = (public_key.e as u128 * d as u128).checked_sub(1) else {
+        return (None, None);
+    };
+    if k == 0 || !ed_minus_one.is_multiple_of(k as u128) {
+        return (None, None);
+    }
+
+    let phi = ed_minus_one / k as u128;
+    let n = public_key.n as i128;
+    let sum = n - phi as i128 + 1; // p + q
+    let discriminant = sum * sum - 4 * n; // (p - q)²
+    if phi > u64::MAX as u128 || sum <= 0 || discriminant < 0 {
+        return (Some(phi as u64), None);
+    }
+
+    let root = (discriminant as u128).isqrt() as i128;
+    let factors = (root * root == discriminant && (sum + root) % 2 == 0).then(|| {
+        let p = ((sum + root) / 2) as u64;
+        let q = ((sum - root) / 2) as u64;
+        (p, q)
+    });
+
+    (Some(phi as u64), factors.filter(|&(p, q)| p as u128 * q as u128 == public_key.n as u128))
+}
+
+/// Wiener's continued-fraction attack on a public key with small private exponent
+///
+/// Since e·d = k·φ(n) + 1 and φ(n) ≈ n, the fraction k/d is very close to
+/// e/n. When d < n^¼/3 it is guaranteed to appear among the convergents of e/n.
+pub fn wiener_attack(public_key: &RsaPublicKey) -> WienerResult {
+    let quotients = continued_fraction(public_key.e, public_key.n);
+    let mut steps = Vec::new();
+
+    for (index, (k, d)) in convergents(&quotients).into_iter().enumerate() {
+        let (phi_candidate, factors) = test_convergent(public_key, k, d);
+        steps.push(WienerStep { index, k, d, phi_candidate, factors });
+
+        if let (Some(phi_n), Some((p, q))) = (phi_candidate, factors) {
+            return WienerResult {
+                quotients,
+                steps,
+                recovery: Some(WienerRecovery { d, p, q, phi_n }),
+            };
+        }
+    }
+
+    WienerResult { quotients, steps, recovery: None }
+}
+
+/// Deliberately generate a key pair whose private exponent is small enough for Wiener's attack
+///
+/// Picks the largest d below n^¼/3 that is coprime to φ(n), then derives e = d⁻¹ mod φ(n).
+/// Returns None when the primes are too small to leave room for such a d,
+/// or when n = p·q does not fit in 64 bits.
+pub fn generate_vulnerable_keypair(p: u64, q: u64) -> Option<RsaKeyPair> {
+    let n = p.checked_mul(q)?;
+    let phi_n = (p - 1) * (q - 1);
+
+    (3..=wiener_bound(n))
+        .rev()
+        .find_map(|d| mod_inverse(d, phi_n).map(|e| (e, d)))
+        .map(|(e, d)| RsaKeyPair {
+            public_key: RsaPublicKey { n, e },
+            private_key: RsaPrivateKey { n, d },
+        })
+}
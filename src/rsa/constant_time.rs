@@ -0,0 +1,52 @@
+// ============================================================================
+// Constant-Time Exponentiation
+// ============================================================================
+// `mod_pow` multiplies only when an exponent bit is 1, so its running time
+// (and branch history) depends on d. The Montgomery ladder does one multiply
+// and one square for every bit, picks operands with masks instead of branches,
+// and always walks all 64 bit positions so even the length of d is hidden.
+
+use super::montgomery::{Montgomery, MontgomeryContext};
+
+/// Swap a and b when bit is 1, without branching on it
+pub fn ct_swap(bit: u64, a: &mut u64, b: &mut u64) {
+    let mask = (bit & 1).wrapping_neg();
+    let t = (*a ^ *b) & mask;
+    *a ^= t;
+    *b ^= t;
+}
+
+/// Montgomery ladder over `bits` exponent bits with a caller-supplied multiplication
+///
+/// Keeps the invariant R1 = R0 · base. Each step sets (R0, R1) to
+/// (R0², R0·R1) for a 0 bit or (R0·R1, R1²) for a 1 bit; the conditional
+/// swaps turn the second case into the first.
+pub fn montgomery_ladder(one: u64, base: u64, exp: u64, bits: u32, mul: impl Fn(u64, u64) -> u64) -> u64 {
+    let mut r0 = one;
+    let mut r1 = base;
+    for i in (0..bits).rev() {
+        let bit = exp >> i & 1;
+        ct_swap(bit, &mut r0, &mut r1);
+        r1 = mul(r0, r1);
+        r0 = mul(r0, r0);
+        ct_swap(bit, &mut r0, &mut r1);
+    }
+    r0
+}
+
+/// base^exp mod modulus in constant time (for a fixed modulus)
+///
+/// Panics on an even modulus: Montgomery form needs gcd(n, R) = 1, and RSA moduli are always odd.
+pub fn mod_pow_ladder(base: u64, exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let context = MontgomeryContext::new(modulus)
+        .unwrap_or_else(|| panic!("Constant-time exponentiation needs an odd modulus, got {}", modulus));
+
+    let base = context.to_montgomery(base);
+    let result = montgomery_ladder(context.one().0, base.0, exp, u64::BITS, |a, b| {
+        context.mul_constant_time(Montgomery(a), Montgomery(b)).0
+    });
+    context.from_montgomery(Montgomery(result))
+}
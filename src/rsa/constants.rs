@@ -11,5 +11,8 @@
 /// Ordered by preference: smaller exponents = faster encryption
 pub const COMMON_PUBLIC_EXPONENTS: [u64; 8] = [3, 5, 7, 11, 13, 17, 257, 65537];
 
+/// 2^16 + 1, the exponent `ExponentPolicy::F4` insists on
+pub const F4: u64 = 65537;
+
 /// Maximum text length (8 bytes to fit in u64)
 pub const MAX_TEXT_LENGTH: usize = 8;
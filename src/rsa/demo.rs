@@ -1,9 +1,36 @@
-use super::encryption::{decrypt, encrypt};
-use super::key_generation::generate_keypair;
-use super::text_encoding::{number_to_text, text_to_number};
-use super::validation::{is_valid_message_size, validate_primes};
+use super::encryption::{decrypt, decrypt_crt_traced, encrypt};
+use super::envelope::{derive_session_keys, open, seal, Envelope, HEADER_BYTES, TAG_BYTES};
+use super::exponentiation::{mod_pow_traced, mod_pow_with, ExpMethod, ExpTrace, MAX_WINDOW_BITS};
+use super::key_generation::{
+    generate_key_components_with_exponent, generate_keypair_with_exponent, generate_multi_prime_key, totient,
+};
+use super::jwk::jwk_thumbprint;
+use super::key_check::{validate_keypair, CheckStatus, KeyCheckInput};
+use super::math_utils::mod_pow;
+use super::montgomery::MontgomeryContext;
+use super::openssh::openssh_fingerprint;
+use super::serialization::{
+    decode_key, encode_private_key_pkcs1, encode_private_key_pkcs8, encode_public_key_pkcs1, encode_public_key_spki,
+    export_public_key, private_key_to_pem, public_key_to_pem, DecodedKey, KeyFormat, PublicKeyFormat,
+};
+use super::text_encoding::{describe_plaintext, number_to_text, parse_message, text_to_number};
+use super::types::{ExponentPolicy, RsaKeyPair, RsaPublicKey, Totient};
+use super::validation::{exponent_warnings, is_valid_message_size, validate_multi_primes, validate_primes};
+use crate::asn1::der::TAG_SEQUENCE;
+use crate::common::pem::is_pem;
+use crate::common::random::DemoRng;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
 
-fn run_rsa_demo_internal(message_text: Option<&str>, message_number: u64, p: u64, q: u64) {
+fn print_exponent_warnings(key_pair: &RsaKeyPair) {
+    for warning in exponent_warnings(key_pair) {
+        println!("  ⚠️  {}", warning);
+    }
+}
+
+fn run_rsa_demo_internal(message_text: Option<&str>, message_number: u64, p: u64, q: u64, policy: ExponentPolicy) {
     // Header
     if let Some(text) = message_text {
         println!("🔐 RSA Text Encryption Demo");
@@ -17,7 +44,14 @@ fn run_rsa_demo_internal(message_text: Option<&str>, message_number: u64, p: u64
     validate_primes(p, q);
 
     println!("\n⏳ Generating RSA key pair...");
-    let key_pair = generate_keypair(p, q);
+    let key_pair = match generate_keypair_with_exponent(p, q, Totient::Euler, policy) {
+        Ok(key_pair) => key_pair,
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            eprintln!("💡 Tip: e must be odd, 1 < e < φ(n) and coprime to φ(n)");
+            return;
+        }
+    };
 
     if !is_valid_message_size(message_number, key_pair.public_key.n) {
         eprintln!(
@@ -33,10 +67,12 @@ fn run_rsa_demo_internal(message_text: Option<&str>, message_number: u64, p: u64
     let phi_n = (p - 1) * (q - 1);
     println!("  n = p × q = {} × {} = {}", p, q, key_pair.public_key.n);
     println!("  φ(n) = (p-1) × (q-1) = {}", phi_n);
+    println!("  e = {} ({})", key_pair.public_key.e, policy.name());
     println!("  Public key:  (n={}, e={})", key_pair.public_key.n, key_pair.public_key.e);
     println!("  Private key: (n={}, d={})", key_pair.private_key.n, key_pair.private_key.d);
     let check = key_pair.public_key.e as u128 * key_pair.private_key.d as u128 % phi_n as u128;
     println!("  Verify: {} × {} ≡ {} (mod {})", key_pair.public_key.e, key_pair.private_key.d, check, phi_n);
+    print_exponent_warnings(&key_pair);
 
     // Encryption
     let ciphertext = encrypt(message_number, &key_pair.public_key);
@@ -75,14 +111,570 @@ fn run_rsa_demo_internal(message_text: Option<&str>, message_number: u64, p: u64
 // ============================================================================
 
 pub fn run_rsa_demo(message: u64, p: u64, q: u64) {
-    run_rsa_demo_internal(None, message, p, q);
+    run_rsa_demo_with_exponent(message, p, q, ExponentPolicy::Common);
+}
+
+pub fn run_rsa_demo_with_exponent(message: u64, p: u64, q: u64, policy: ExponentPolicy) {
+    run_rsa_demo_internal(None, message, p, q, policy);
 }
 
 pub fn run_rsa_demo_text(text: &str, p: u64, q: u64) {
+    run_rsa_demo_text_with_exponent(text, p, q, ExponentPolicy::Common);
+}
+
+pub fn run_rsa_demo_text_with_exponent(text: &str, p: u64, q: u64, policy: ExponentPolicy) {
     match text_to_number(text) {
-        Some(number) => run_rsa_demo_internal(Some(text), number, p, q),
+        Some(number) => run_rsa_demo_internal(Some(text), number, p, q, policy),
         None => {
             eprintln!("\n❌ Error: Text is too long or empty. Maximum 8 characters.");
         }
     }
 }
+
+// ============================================================================
+// Multi-Prime RSA
+// ============================================================================
+
+fn join_numbers(values: &[u64], separator: &str) -> String {
+    values.iter().map(u64::to_string).collect::<Vec<_>>().join(separator)
+}
+
+/// Build a key from k primes, decrypt with CRT and compare d mod φ(n) with d mod λ(n)
+pub fn run_multi_prime_demo(message: &str, primes: &[u64], kind: Totient) {
+    println!("🔐 Multi-Prime RSA Demo");
+    println!("Primes: {} (k={})", join_numbers(primes, ", "), primes.len());
+
+    validate_multi_primes(primes);
+    let Some(message_number) = parse_message(message) else {
+        eprintln!("\n❌ Error: Message must be a number or up to 8 characters of text");
+        return;
+    };
+
+    let key = generate_multi_prime_key(primes, kind);
+    let euler = generate_multi_prime_key(primes, Totient::Euler);
+    let carmichael = generate_multi_prime_key(primes, Totient::Carmichael);
+    let (phi_n, lambda_n) = (totient(primes, Totient::Euler), totient(primes, Totient::Carmichael));
+    let minus_one: Vec<u64> = primes.iter().map(|&r| r - 1).collect();
+
+    if !is_valid_message_size(message_number, key.n) {
+        eprintln!("\n❌ Error: Message ({}) must be smaller than modulus n ({})", message_number, key.n);
+        eprintln!("💡 Tip: Use more or larger primes, or a smaller message");
+        return;
+    }
+
+    println!("\n=== Key Generation ===");
+    println!("  n = {} = {}", join_numbers(primes, " × "), key.n);
+    println!("  φ(n) = {} = {}", join_numbers(&minus_one, " × "), phi_n);
+    println!("  λ(n) = lcm({}) = {}", join_numbers(&minus_one, ", "), lambda_n);
+    println!("  e = {} (coprime to both: they share the same prime factors)", key.e);
+
+    println!("\n=== d mod φ(n) vs d mod λ(n) ===");
+    println!("  d_φ = e⁻¹ mod φ(n) = {}", euler.d);
+    println!("  d_λ = e⁻¹ mod λ(n) = {}", carmichael.d);
+    println!("  d_φ - d_λ = {} × λ(n)", (euler.d - carmichael.d) / lambda_n);
+    println!("  e·d_φ = 1 + {} × φ(n)", (key.e as u128 * euler.d as u128 - 1) / phi_n as u128);
+    println!("  e·d_λ = 1 + {} × λ(n)", (key.e as u128 * carmichael.d as u128 - 1) / lambda_n as u128);
+    println!("  Every rᵢ-1 divides λ(n), so m^λ(n) ≡ 1 (mod rᵢ) for m coprime to rᵢ (Fermat),");
+    println!("  hence m^(e·d) = m·(m^λ(n))^k ≡ m (mod rᵢ) for either d, and mod n by CRT.");
+    println!("  φ(n) is a multiple of λ(n) ({} = {} × {}), so d_φ is just a larger choice.", phi_n, phi_n / lambda_n, lambda_n);
+    println!("  Using {} for this key", kind.symbol());
+
+    println!("\n=== CRT Values ===");
+    println!("  {:>12}  {:>12}  {:>12}  {:>12}", "prime", "d_φ mod r-1", "d_λ mod r-1", "coefficient");
+    for ((factor, phi_factor), lambda_factor) in key.factors.iter().zip(&euler.factors).zip(&carmichael.factors) {
+        println!(
+            "  {:>12}  {:>12}  {:>12}  {:>12}",
+            factor.prime, phi_factor.exponent, lambda_factor.exponent, factor.coefficient
+        );
+    }
+    println!("  dᵢ = d mod (rᵢ-1) is the same for d_φ and d_λ, so CRT decryption cannot tell them apart");
+
+    let ciphertext = encrypt(message_number, &key.public_key());
+    println!("\n=== Encryption ===");
+    println!("  c = m^e mod n = {}^{} mod {} = {}", message_number, key.e, key.n, ciphertext);
+
+    println!("\n=== CRT Decryption ===");
+    let steps = decrypt_crt_traced(ciphertext, &key);
+    let mut modulus = 1u64;
+    for step in &steps {
+        modulus *= step.prime;
+        println!(
+            "  m mod {:<6} : c^{} mod {} = {} → m ≡ {} (mod {})",
+            step.prime, step.exponent, step.prime, step.residue, step.partial, modulus
+        );
+    }
+    let crt_message = steps.last().map_or(0, |step| step.partial);
+    let direct_phi = decrypt(ciphertext, &euler.key_pair().private_key);
+    let direct_lambda = decrypt(ciphertext, &carmichael.key_pair().private_key);
+    println!("  Direct: c^d_φ mod n = {}, c^d_λ mod n = {}", direct_phi, direct_lambda);
+
+    println!("\n=== Verification ===");
+    println!("  Original:  {}", describe_plaintext(message_number));
+    println!("  Decrypted: {}", describe_plaintext(crt_message));
+
+    if crt_message == message_number && direct_phi == message_number && direct_lambda == message_number {
+        println!("\n✅ Success! CRT, d_φ and d_λ all recover the message.");
+    } else {
+        println!("\n❌ Failed! Something went wrong.");
+    }
+}
+
+// ============================================================================
+// Key Files
+// ============================================================================
+
+fn read_key_file(path: &Path) -> Result<DecodedKey, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    decode_key(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// One component per line in the same layout as `openssl rsa -text`
+fn print_key_field(name: &str, value: u64) {
+    println!("  {}: {} (0x{:x})", name, value, value);
+}
+
+fn print_key(key: &DecodedKey) {
+    match key {
+        DecodedKey::Public(public_key) => {
+            println!("  Public-Key: ({} bit)", 64 - public_key.n.leading_zeros());
+            print_key_field("Modulus", public_key.n);
+            print_key_field("Exponent", public_key.e);
+        }
+        DecodedKey::Private(components) => {
+            println!("  Private-Key: ({} bit, 2 primes)", 64 - components.n.leading_zeros());
+            print_key_field("modulus", components.n);
+            print_key_field("publicExponent", components.e);
+            print_key_field("privateExponent", components.d);
+            print_key_field("prime1", components.p);
+            print_key_field("prime2", components.q);
+            print_key_field("exponent1", components.dp);
+            print_key_field("exponent2", components.dq);
+            print_key_field("coefficient", components.qinv);
+        }
+    }
+}
+
+fn write_key(path: &Path, pem: String, der: Vec<u8>, as_der: bool) -> Result<(), String> {
+    let contents = if as_der { der } else { pem.into_bytes() };
+    fs::write(path, contents).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+/// Generate a key from p and q and save it, optionally with the public half in a second file
+#[allow(clippy::too_many_arguments)]
+pub fn run_keygen(
+    p: u64,
+    q: u64,
+    kind: Totient,
+    policy: ExponentPolicy,
+    out: &Path,
+    public_out: Option<&Path>,
+    format: KeyFormat,
+    as_der: bool,
+) {
+    println!("🔑 RSA Key Generation");
+    println!("Primes: p={}, q={}", p, q);
+    println!("Private exponent: d = e⁻¹ mod {}", kind.symbol());
+
+    validate_primes(p, q);
+    let components = match generate_key_components_with_exponent(p, q, kind, policy) {
+        Ok(components) => components,
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+    let public_key = components.public_key();
+
+    let (private_der, public_der, private_name, public_name) = match format {
+        KeyFormat::Pkcs1 => (
+            encode_private_key_pkcs1(&components),
+            encode_public_key_pkcs1(&public_key),
+            "PKCS#1 RSAPrivateKey",
+            "PKCS#1 RSAPublicKey",
+        ),
+        KeyFormat::Pkcs8 => (
+            encode_private_key_pkcs8(&components),
+            encode_public_key_spki(&public_key),
+            "PKCS#8 PrivateKeyInfo",
+            "SubjectPublicKeyInfo",
+        ),
+    };
+    let encoding = if as_der { "DER" } else { "PEM" };
+
+    println!("\n=== Key Components ===");
+    print_key(&DecodedKey::Private(components.clone()));
+    print_exponent_warnings(&components.key_pair());
+
+    println!("\n=== Files ===");
+    if let Err(error) = write_key(out, private_key_to_pem(&components, format), private_der.clone(), as_der) {
+        eprintln!("\n❌ Error: {}", error);
+        return;
+    }
+    println!("  Private key: {} ({}, {}, {} bytes of DER)", out.display(), private_name, encoding, private_der.len());
+
+    if let Some(public_out) = public_out {
+        if let Err(error) = write_key(public_out, public_key_to_pem(&public_key, format), public_der.clone(), as_der) {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+        println!("  Public key:  {} ({}, {}, {} bytes of DER)", public_out.display(), public_name, encoding, public_der.len());
+    }
+
+    let inform = if as_der { " -inform DER" } else { "" };
+    println!("\n💡 Tip: Compare with `openssl rsa -text -noout{} -in {}`", inform, out.display());
+}
+
+fn print_fingerprints(key: &RsaPublicKey) {
+    println!("\n=== Fingerprints ===");
+    println!("  OpenSSH:        {}", openssh_fingerprint(key));
+    println!("  JWK thumbprint: {}", jwk_thumbprint(key));
+}
+
+/// Print the components of a key file
+pub fn run_show_key(key_path: &Path) {
+    println!("🔑 RSA Key File: {}", key_path.display());
+
+    match read_key_file(key_path) {
+        Ok(key) => {
+            println!();
+            print_key(&key);
+            print_fingerprints(&key.public_key());
+            if fs::read(key_path).is_ok_and(|bytes| is_pem(&bytes) || bytes.first() == Some(&TAG_SEQUENCE)) {
+                println!("\n💡 Tip: `asn1-dump {}` shows the DER structure behind these numbers", key_path.display());
+            }
+        }
+        Err(error) => eprintln!("\n❌ Error: {}", error),
+    }
+}
+
+/// Convert the public half of any key file to PKCS#1, SPKI, JWK or an OpenSSH line
+pub fn run_export_key(key_path: &Path, format: PublicKeyFormat, comment: Option<&str>, out: Option<&Path>) {
+    println!("📤 RSA Public Key Export: {} → {:?}", key_path.display(), format);
+
+    let public_key = match read_key_file(key_path) {
+        Ok(key) => key.public_key(),
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+    let exported = export_public_key(&public_key, format, comment);
+
+    println!("\n=== Public Key ===");
+    println!("  (n={}, e={})", public_key.n, public_key.e);
+    print_fingerprints(&public_key);
+
+    match out {
+        Some(out) => match fs::write(out, &exported) {
+            Ok(()) => println!("\n✅ Written to {}", out.display()),
+            Err(error) => eprintln!("\n❌ Error: Cannot write {}: {}", out.display(), error),
+        },
+        None => print!("\n{}", exported),
+    }
+}
+
+/// Check a key file and/or individual values for consistency and known weaknesses
+///
+/// Values given directly take precedence over those read from `key_path`.
+/// Returns false if any check failed.
+pub fn run_check_key(key_path: Option<&Path>, given: KeyCheckInput, other_moduli: &[u64]) -> bool {
+    println!("🩺 RSA Key Check");
+
+    let from_file = match key_path.map(read_key_file) {
+        Some(Ok(DecodedKey::Public(public_key))) => KeyCheckInput::from(&public_key),
+        Some(Ok(DecodedKey::Private(components))) => KeyCheckInput::from(&components),
+        Some(Err(error)) => {
+            eprintln!("\n❌ Error: {}", error);
+            return false;
+        }
+        None => KeyCheckInput::default(),
+    };
+    let input = KeyCheckInput {
+        n: given.n.or(from_file.n),
+        e: given.e.or(from_file.e),
+        d: given.d.or(from_file.d),
+        p: given.p.or(from_file.p),
+        q: given.q.or(from_file.q),
+        dp: given.dp.or(from_file.dp),
+        dq: given.dq.or(from_file.dq),
+        qinv: given.qinv.or(from_file.qinv),
+    };
+
+    let show = |value: Option<u64>| value.map_or("?".to_string(), |value| value.to_string());
+    println!("  n={}, e={}, d={}, p={}, q={}", show(input.n), show(input.e), show(input.d), show(input.p), show(input.q));
+    if !other_moduli.is_empty() {
+        println!("  Batch: {}", join_numbers(other_moduli, ", "));
+    }
+
+    let report = validate_keypair(&input, other_moduli);
+    println!("\n=== Checks ===");
+    for check in &report.checks {
+        let icon = match check.status {
+            CheckStatus::Pass => "✅",
+            CheckStatus::Warn => "⚠️ ",
+            CheckStatus::Fail => "❌",
+            CheckStatus::Skipped => "⏭️ ",
+        };
+        println!("  {} {}: {}", icon, check.name, check.detail);
+    }
+
+    println!(
+        "\n{} passed, {} warned, {} failed, {} skipped",
+        report.count(CheckStatus::Pass),
+        report.count(CheckStatus::Warn),
+        report.count(CheckStatus::Fail),
+        report.count(CheckStatus::Skipped)
+    );
+    if report.is_valid() {
+        println!("✅ The key is consistent{}", if report.count(CheckStatus::Warn) > 0 { ", but see the warnings" } else { "" });
+    } else {
+        println!("❌ The key is inconsistent or invalid");
+    }
+    report.is_valid()
+}
+
+// ============================================================================
+// Hybrid Envelopes
+// ============================================================================
+
+/// Encrypt a file of any size: RSA wraps a random secret, ChaCha20 and HMAC-SHA256 protect the data
+pub fn run_seal(key_path: &Path, input: &Path, out: &Path, armor: bool) {
+    println!("✉️  RSA-KEM Envelope: Seal {} → {}", input.display(), out.display());
+
+    let public_key = match read_key_file(key_path) {
+        Ok(key) => key.public_key(),
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+    let plaintext = match fs::read(input) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!("\n❌ Error: Cannot read {}: {}", input.display(), error);
+            return;
+        }
+    };
+    let (envelope, secret) = match seal(&plaintext, &public_key, &mut DemoRng::from_entropy()) {
+        Ok(sealed) => sealed,
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+    let keys = derive_session_keys(secret);
+
+    println!("\n=== Key Encapsulation ===");
+    println!("  Public key: (n={}, e={})", public_key.n, public_key.e);
+    println!("  z = random in [2, n-1] = {}", secret);
+    println!("  c = z^e mod n = {}", envelope.wrapped_secret);
+    println!("  K_enc = SHA-256(z ‖ 00000001) = {}", hex::encode(keys.encryption_key));
+    println!("  K_mac = SHA-256(z ‖ 00000002) = {}", hex::encode(keys.mac_key));
+    println!("  ⚠️  z has only {} bits here; real RSA-KEM draws it below a 2048-bit n", 64 - public_key.n.leading_zeros());
+
+    println!("\n=== Data Encapsulation ===");
+    println!("  Plaintext:  {} bytes", plaintext.len());
+    println!("  ChaCha20(K_enc, nonce = 0) → {} bytes", envelope.ciphertext.len());
+    println!("  HMAC-SHA256(K_mac, header ‖ ciphertext) = {}", hex::encode(envelope.tag));
+
+    let (contents, form) = if armor { (envelope.to_pem().into_bytes(), "PEM") } else { (envelope.to_bytes(), "binary") };
+    if let Err(error) = fs::write(out, &contents) {
+        eprintln!("\n❌ Error: Cannot write {}: {}", out.display(), error);
+        return;
+    }
+    println!("\n=== Container ===");
+    println!(
+        "  {} header + {} ciphertext + {} tag = {} bytes ({}, {} bytes on disk)",
+        HEADER_BYTES,
+        envelope.ciphertext.len(),
+        TAG_BYTES,
+        envelope.to_bytes().len(),
+        form,
+        contents.len()
+    );
+    println!("\n✅ Sealed to {}", out.display());
+}
+
+/// Decrypt an envelope written by `run_seal`, refusing it if the tag does not verify
+pub fn run_open(key_path: &Path, input: &Path, out: &Path) {
+    println!("📬 RSA-KEM Envelope: Open {} → {}", input.display(), out.display());
+
+    let components = match read_key_file(key_path) {
+        Ok(DecodedKey::Private(components)) => components,
+        Ok(DecodedKey::Public(_)) => {
+            eprintln!("\n❌ Error: {} holds only a public key", key_path.display());
+            eprintln!("💡 Tip: Opening an envelope needs the private key file written by `rsa keygen --out`");
+            return;
+        }
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+    let envelope = match fs::read(input)
+        .map_err(|e| format!("Cannot read {}: {}", input.display(), e))
+        .and_then(|bytes| Envelope::decode(&bytes))
+    {
+        Ok(envelope) => envelope,
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+    let private_key = components.key_pair().private_key;
+
+    println!("\n=== Key Decapsulation ===");
+    println!("  c = {}", envelope.wrapped_secret);
+    if envelope.wrapped_secret < private_key.n {
+        println!("  z = c^d mod n = {}", decrypt(envelope.wrapped_secret, &private_key));
+    }
+
+    match open(&envelope, &private_key) {
+        Ok(plaintext) => {
+            println!("  Tag verified over header ‖ ciphertext");
+            if let Err(error) = fs::write(out, &plaintext) {
+                eprintln!("\n❌ Error: Cannot write {}: {}", out.display(), error);
+                return;
+            }
+            println!("\n✅ Opened {} bytes to {}", plaintext.len(), out.display());
+        }
+        Err(error) => eprintln!("\n❌ Error: {}", error),
+    }
+}
+
+/// Encrypt with the public key from a key file (either half works)
+pub fn run_encrypt_with_key(key_path: &Path, message: &str) {
+    println!("🔐 RSA Encryption with {}", key_path.display());
+
+    let public_key = match read_key_file(key_path) {
+        Ok(key) => key.public_key(),
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+
+    let Some(number) = parse_message(message) else {
+        eprintln!("\n❌ Error: Text is too long or empty. Maximum 8 characters.");
+        return;
+    };
+    if !is_valid_message_size(number, public_key.n) {
+        eprintln!("\n❌ Error: Message ({}) must be smaller than modulus n ({})", number, public_key.n);
+        return;
+    }
+
+    let ciphertext = encrypt(number, &public_key);
+    println!("\n=== Encryption ===");
+    println!("  Public key: (n={}, e={})", public_key.n, public_key.e);
+    println!("  c = m^e mod n = {}^{} mod {} = {}", number, public_key.e, public_key.n, ciphertext);
+    println!("\n✅ Ciphertext: {}", ciphertext);
+}
+
+/// Decrypt with the private key from a key file
+pub fn run_decrypt_with_key(key_path: &Path, ciphertext: u64) {
+    println!("🔓 RSA Decryption with {}", key_path.display());
+
+    let components = match read_key_file(key_path) {
+        Ok(DecodedKey::Private(components)) => components,
+        Ok(DecodedKey::Public(_)) => {
+            eprintln!("\n❌ Error: {} holds only a public key", key_path.display());
+            eprintln!("💡 Tip: Decryption needs the private key file written by `rsa keygen --out`");
+            return;
+        }
+        Err(error) => {
+            eprintln!("\n❌ Error: {}", error);
+            return;
+        }
+    };
+
+    if ciphertext >= components.n {
+        eprintln!("\n❌ Error: Ciphertext ({}) must be smaller than modulus n ({})", ciphertext, components.n);
+        return;
+    }
+
+    let private_key = components.key_pair().private_key;
+    let message = decrypt(ciphertext, &private_key);
+    println!("\n=== Decryption ===");
+    println!("  m = c^d mod n = {}^{} mod {} = {}", ciphertext, private_key.d, private_key.n, message);
+    println!("\n✅ Message: {}", describe_plaintext(message));
+}
+
+// ============================================================================
+// Exponentiation Methods
+// ============================================================================
+
+/// Windows are listed in full up to this many, then summarised
+const MAX_WINDOWS_SHOWN: usize = 24;
+
+fn print_exponentiation_trace(trace: &ExpTrace) {
+    println!("\n=== {} ===", trace.method.name());
+    println!("  Precomputed powers: {}", trace.table_size);
+
+    let shown: Vec<String> = trace
+        .windows
+        .iter()
+        .take(MAX_WINDOWS_SHOWN)
+        .map(|window| match (window.squarings, window.multiplied) {
+            (0, _) => window.bits.clone(),
+            (s, true) => format!("{}(²{}·)", window.bits, s),
+            (s, false) => format!("{}(²{})", window.bits, s),
+        })
+        .collect();
+    let more = trace.windows.len().saturating_sub(MAX_WINDOWS_SHOWN);
+    let suffix = if more > 0 { format!(" … {} more", more) } else { String::new() };
+    println!("  Windows: {}{}", shown.join(" "), suffix);
+    println!(
+        "  Squarings: {}, Multiplications: {}, Reductions: {}",
+        trace.counts.squarings, trace.counts.multiplications, trace.counts.reductions
+    );
+    println!("  Result: {}", trace.result);
+}
+
+/// Compare square-and-multiply, Montgomery, fixed-window and sliding-window exponentiation
+pub fn run_exponentiation_demo(base: u64, exp: u64, modulus: u64, window_bits: u32, iterations: u32) {
+    println!("⚡ Modular Exponentiation Methods");
+    println!("Computing {}^{} mod {} ({}-bit exponent)", base, exp, modulus, 64 - exp.leading_zeros());
+
+    if modulus < 2 {
+        eprintln!("\n❌ Error: Modulus must be at least 2");
+        return;
+    }
+    if !(1..=MAX_WINDOW_BITS).contains(&window_bits) {
+        eprintln!("\n❌ Error: Window must be 1 to {} bits", MAX_WINDOW_BITS);
+        return;
+    }
+
+    match MontgomeryContext::new(modulus) {
+        Some(context) => {
+            println!("\n=== Montgomery Constants ===");
+            println!("  R = 2^64");
+            println!("  n' = −n⁻¹ mod R = {}", context.n_prime);
+            println!("  R mod n = {} (Montgomery form of 1)", context.r_mod_n);
+            println!("  R² mod n = {} (converts a into a·R mod n)", context.r2_mod_n);
+        }
+        None => println!("\n⚠️  Even modulus: Montgomery needs gcd(n, R) = 1, so those methods fall back to %"),
+    }
+
+    let methods = ExpMethod::all(window_bits);
+    let traces: Vec<ExpTrace> = methods.iter().map(|&method| mod_pow_traced(base, exp, modulus, method)).collect();
+    println!("\n  Windows read most significant first: bits(²squarings·multiply)");
+    traces.iter().for_each(print_exponentiation_trace);
+
+    println!("\n=== Timing ({} iterations each) ===", iterations);
+    let mut baseline = None;
+    for &method in &methods {
+        let start = Instant::now();
+        for i in 0..iterations as u64 {
+            black_box(mod_pow_with(black_box(base ^ i), black_box(exp), black_box(modulus), method));
+        }
+        let per_call = start.elapsed().as_nanos() as f64 / iterations.max(1) as f64;
+        let baseline = *baseline.get_or_insert(per_call);
+        println!("  {:<24} {:>10.1} ns/op  ({:.2}× vs binary)", method.name(), per_call, baseline / per_call);
+    }
+
+    if traces.iter().all(|trace| trace.result == mod_pow(base, exp, modulus)) {
+        println!("\n✅ All methods agree with mod_pow!");
+    } else {
+        println!("\n❌ Methods disagree! Something went wrong.");
+    }
+}
@@ -1,5 +1,7 @@
-use super::math_utils::mod_pow;
-use super::types::{RsaPublicKey, RsaPrivateKey};
+use super::constant_time::mod_pow_ladder;
+use super::math_utils::{mod_inverse, mod_mul, mod_pow};
+use super::types::{MultiPrimeKey, RsaPublicKey, RsaPrivateKey};
+use crate::common::random::DemoRng;
 
 pub fn encrypt(message: u64, public_key: &RsaPublicKey) -> u64 {
     mod_pow(message, public_key.e, public_key.n)
@@ -8,3 +10,69 @@ pub fn encrypt(message: u64, public_key: &RsaPublicKey) -> u64 {
 pub fn decrypt(ciphertext: u64, private_key: &RsaPrivateKey) -> u64 {
     mod_pow(ciphertext, private_key.d, private_key.n)
 }
+
+/// Decrypt with the Montgomery ladder, whose timing does not depend on d
+pub fn decrypt_constant_time(ciphertext: u64, private_key: &RsaPrivateKey) -> u64 {
+    mod_pow_ladder(ciphertext, private_key.d, private_key.n)
+}
+
+/// Pick a random r with gcd(r, n) = 1, returning r and r⁻¹ mod n
+pub fn blinding_factor(n: u64, rng: &mut DemoRng) -> (u64, u64) {
+    loop {
+        let r = rng.below(n);
+        if let Some(r_inverse) = mod_inverse(r, n).filter(|_| r > 1) {
+            return (r, r_inverse);
+        }
+    }
+}
+
+/// RSA blinding: decrypt c·r^e instead of c, then divide out r
+///
+/// (c·r^e)^d = m·r, so the exponentiation runs on a value the attacker
+/// neither chose nor knows, and its timing no longer correlates with c.
+pub fn decrypt_blinded(ciphertext: u64, public_key: &RsaPublicKey, private_key: &RsaPrivateKey, rng: &mut DemoRng) -> u64 {
+    let (r, r_inverse) = blinding_factor(public_key.n, rng);
+    let blinded = mod_mul(ciphertext, mod_pow(r, public_key.e, public_key.n), public_key.n);
+    mod_mul(decrypt(blinded, private_key), r_inverse, public_key.n)
+}
+
+/// One prime's share of a CRT decryption
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrtStep {
+    pub prime: u64,
+    pub exponent: u64,
+    /// c^dᵢ mod rᵢ
+    pub residue: u64,
+    /// m mod r₁ × … × rᵢ after folding this prime in
+    pub partial: u64,
+}
+
+/// Decrypt prime by prime and combine with Garner's formula, keeping every intermediate value
+///
+/// With residues mᵢ = c^dᵢ mod rᵢ, each step computes
+/// h = (mᵢ - m)·tᵢ mod rᵢ and m = m + (r₁ × … × rᵢ₋₁)·h.
+/// RFC 8017 §5.1.2 does the same but treats the first two primes in the opposite order.
+pub fn decrypt_crt_traced(ciphertext: u64, key: &MultiPrimeKey) -> Vec<CrtStep> {
+    let mut message = 0u64;
+    let mut preceding = 1u64;
+    key.factors
+        .iter()
+        .map(|factor| {
+            let r = factor.prime;
+            let residue = mod_pow(ciphertext % r, factor.exponent, r);
+            message = if preceding == 1 {
+                residue
+            } else {
+                let h = mod_mul((residue + r - message % r) % r, factor.coefficient, r);
+                message + preceding * h
+            };
+            preceding *= r;
+            CrtStep { prime: r, exponent: factor.exponent, residue, partial: message }
+        })
+        .collect()
+}
+
+/// Decrypt with one small exponentiation per prime instead of one large one
+pub fn decrypt_crt(ciphertext: u64, key: &MultiPrimeKey) -> u64 {
+    decrypt_crt_traced(ciphertext, key).last().map_or(0, |step| step.partial)
+}
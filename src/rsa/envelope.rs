@@ -0,0 +1,157 @@
+// ============================================================================
+// Hybrid Encryption Envelope: RSA-KEM + ChaCha20 + HMAC-SHA256
+// ============================================================================
+// RSA only ever encrypts a random number z < n (ISO 18033-2 RSA-KEM). Both
+// sides derive the session keys from z with SHA-256, so the bulk data never
+// touches RSA and can be any length.
+//
+//   z       random in [2, n-1]
+//   c       z^e mod n
+//   K_enc   SHA-256(Z ‖ 00000001)       Z = z as 8 big-endian bytes (KDF2)
+//   K_mac   SHA-256(Z ‖ 00000002)
+//
+// Container (all integers big-endian):
+//
+//   offset  size  field
+//   0       4     magic "RKEM"
+//   4       1     version = 1
+//   5       8     c, the wrapped session secret
+//   13      8     payload length L
+//   21      L     ChaCha20(K_enc, nonce = 0, counter = 0) of the plaintext
+//   21 + L  32    HMAC-SHA256(K_mac, bytes 0 .. 21 + L)
+//
+// The keys are fresh for every envelope, so the all-zero nonce is never reused.
+// The armored form is the same bytes in PEM with the label "RSA KEM ENVELOPE".
+
+use super::encryption::{decrypt, encrypt};
+use super::types::{RsaPrivateKey, RsaPublicKey};
+use crate::chacha20::cipher::{apply_keystream, KEY_BYTES, NONCE_BYTES};
+use crate::common::pem::{decode_pem, encode_pem, is_pem};
+use crate::common::random::DemoRng;
+use crate::sha256::hash::sha256_bytes;
+use crate::sha256::hmac::{hmac_sha256, verify_hmac_sha256};
+
+pub const MAGIC: &[u8; 4] = b"RKEM";
+pub const VERSION: u8 = 1;
+pub const PEM_LABEL_ENVELOPE: &str = "RSA KEM ENVELOPE";
+
+/// Bytes before the payload: magic, version, c and the payload length
+pub const HEADER_BYTES: usize = 21;
+pub const TAG_BYTES: usize = 32;
+
+const NONCE: [u8; NONCE_BYTES] = [0; NONCE_BYTES];
+
+/// Keys derived from the KEM secret z
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionKeys {
+    pub encryption_key: [u8; KEY_BYTES],
+    pub mac_key: [u8; 32],
+}
+
+/// A parsed envelope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Envelope {
+    /// c = z^e mod n
+    pub wrapped_secret: u64,
+    pub ciphertext: Vec<u8>,
+    pub tag: [u8; TAG_BYTES],
+}
+
+/// KDF2 with SHA-256 over the 8-byte encoding of z
+pub fn derive_session_keys(secret: u64) -> SessionKeys {
+    let block = |counter: u32| sha256_bytes(&[secret.to_be_bytes().as_slice(), &counter.to_be_bytes()].concat());
+    SessionKeys { encryption_key: block(1), mac_key: block(2) }
+}
+
+fn header(wrapped_secret: u64, payload_length: usize) -> Vec<u8> {
+    [MAGIC.as_slice(), &[VERSION], &wrapped_secret.to_be_bytes(), &(payload_length as u64).to_be_bytes()].concat()
+}
+
+/// Seal with a chosen KEM secret, so the steps can be reproduced
+pub fn seal_with_secret(plaintext: &[u8], public_key: &RsaPublicKey, secret: u64) -> Envelope {
+    let keys = derive_session_keys(secret);
+    let wrapped_secret = encrypt(secret, public_key);
+    let ciphertext = apply_keystream(&keys.encryption_key, 0, &NONCE, plaintext);
+    let tag = hmac_sha256(&keys.mac_key, &[header(wrapped_secret, ciphertext.len()), ciphertext.clone()].concat());
+    Envelope { wrapped_secret, ciphertext, tag }
+}
+
+/// Encrypt any amount of data to the holder of the private key for `public_key`
+///
+/// Returns the envelope and the secret z, which the caller should discard.
+pub fn seal(plaintext: &[u8], public_key: &RsaPublicKey, rng: &mut DemoRng) -> Result<(Envelope, u64), String> {
+    if public_key.n < 4 {
+        return Err(format!("n = {} leaves no room for a secret in [2, n-1]", public_key.n));
+    }
+    let secret = 2 + rng.below(public_key.n - 2);
+    Ok((seal_with_secret(plaintext, public_key, secret), secret))
+}
+
+/// Unwrap z, check the tag over header and ciphertext, then decrypt
+///
+/// The tag is checked before anything is decrypted, so a tampered or
+/// wrong-key envelope yields an error and no plaintext.
+pub fn open(envelope: &Envelope, private_key: &RsaPrivateKey) -> Result<Vec<u8>, String> {
+    if envelope.wrapped_secret >= private_key.n {
+        return Err(format!("Wrapped secret {} is not below n = {}", envelope.wrapped_secret, private_key.n));
+    }
+    let keys = derive_session_keys(decrypt(envelope.wrapped_secret, private_key));
+    let authenticated = [header(envelope.wrapped_secret, envelope.ciphertext.len()), envelope.ciphertext.clone()].concat();
+    if !verify_hmac_sha256(&keys.mac_key, &authenticated, &envelope.tag) {
+        return Err("Authentication failed: wrong private key or the envelope was modified".to_string());
+    }
+    Ok(apply_keystream(&keys.encryption_key, 0, &NONCE, &envelope.ciphertext))
+}
+
+impl Envelope {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [header(self.wrapped_secret, self.ciphertext.len()), self.ciphertext.clone(), self.tag.to_vec()].concat()
+    }
+
+    pub fn to_pem(&self) -> String {
+        encode_pem(PEM_LABEL_ENVELOPE, &self.to_bytes())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Envelope, String> {
+        if bytes.len() < HEADER_BYTES + TAG_BYTES {
+            return Err(format!("Envelope is {} bytes, shorter than the {}-byte minimum", bytes.len(), HEADER_BYTES + TAG_BYTES));
+        }
+        if &bytes[..4] != MAGIC {
+            return Err("Not an RSA-KEM envelope (missing RKEM magic)".to_string());
+        }
+        if bytes[4] != VERSION {
+            return Err(format!("Unsupported envelope version {}", bytes[4]));
+        }
+
+        let word = |offset: usize| {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(&bytes[offset..offset + 8]);
+            u64::from_be_bytes(value)
+        };
+        let payload_length = word(13);
+        if payload_length != (bytes.len() - HEADER_BYTES - TAG_BYTES) as u64 {
+            return Err(format!(
+                "Payload length {} does not match the {} bytes present",
+                payload_length,
+                bytes.len() - HEADER_BYTES - TAG_BYTES
+            ));
+        }
+
+        let (body, tag) = bytes.split_at(bytes.len() - TAG_BYTES);
+        let mut tag_bytes = [0u8; TAG_BYTES];
+        tag_bytes.copy_from_slice(tag);
+        Ok(Envelope { wrapped_secret: word(5), ciphertext: body[HEADER_BYTES..].to_vec(), tag: tag_bytes })
+    }
+
+    /// Parse either the binary container or its PEM armor
+    pub fn decode(bytes: &[u8]) -> Result<Envelope, String> {
+        if !is_pem(bytes) {
+            return Envelope::from_bytes(bytes);
+        }
+        let text = std::str::from_utf8(bytes).map_err(|_| "PEM envelope is not valid UTF-8".to_string())?;
+        match decode_pem(text)? {
+            (label, der) if label == PEM_LABEL_ENVELOPE => Envelope::from_bytes(&der),
+            (label, _) => Err(format!("Expected a {} PEM block, found {}", PEM_LABEL_ENVELOPE, label)),
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use super::montgomery::{Montgomery, MontgomeryContext};
+
+/// Default window width; 4 bits is near optimal for 64-bit exponents
+pub const DEFAULT_WINDOW_BITS: u32 = 4;
+pub const MAX_WINDOW_BITS: u32 = 8;
+
+/// Work done by one exponentiation
+///
+/// Every squaring and multiplication is followed by one modular reduction;
+/// Montgomery methods also spend reductions converting into and out of form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpCounts {
+    pub squarings: u64,
+    pub multiplications: u64,
+    pub reductions: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpMethod {
+    /// Left-to-right square-and-multiply with `%` reduction
+    Binary,
+    /// Square-and-multiply in Montgomery form
+    Montgomery,
+    /// k-ary: consume k exponent bits at a time using base^0 … base^(2^k − 1)
+    FixedWindow(u32),
+    /// Skip runs of zeros and use windows ending in a 1, so only odd powers are stored
+    SlidingWindow(u32),
+}
+
+impl ExpMethod {
+    pub fn all(window_bits: u32) -> [ExpMethod; 4] {
+        [
+            ExpMethod::Binary,
+            ExpMethod::Montgomery,
+            ExpMethod::FixedWindow(window_bits),
+            ExpMethod::SlidingWindow(window_bits),
+        ]
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            ExpMethod::Binary => "Binary (% reduction)".to_string(),
+            ExpMethod::Montgomery => "Binary (Montgomery)".to_string(),
+            ExpMethod::FixedWindow(k) => format!("Fixed window, k={}", k),
+            ExpMethod::SlidingWindow(k) => format!("Sliding window, k={}", k),
+        }
+    }
+}
+
+/// One group of exponent bits processed together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpWindow {
+    pub bits: String,
+    pub value: u64,
+    /// Squarings performed to shift the accumulator before this window
+    pub squarings: u32,
+    /// Whether a table entry was multiplied in (false for an all-zero window)
+    pub multiplied: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExpTrace {
+    pub method: ExpMethod,
+    pub result: u64,
+    pub counts: OpCounts,
+    /// Number of precomputed powers
+    pub table_size: usize,
+    pub windows: Vec<ExpWindow>,
+}
+
+// ============================================================================
+// Reduction Strategy
+// ============================================================================
+
+/// Division-based or Montgomery arithmetic behind one interface, counting as it goes
+enum Arithmetic {
+    Division(u64),
+    Montgomery(MontgomeryContext),
+}
+
+impl Arithmetic {
+    /// Montgomery when requested and the modulus is odd, otherwise `%`
+    fn new(modulus: u64, montgomery: bool) -> Self {
+        match MontgomeryContext::new(modulus) {
+            Some(context) if montgomery => Arithmetic::Montgomery(context),
+            _ => Arithmetic::Division(modulus),
+        }
+    }
+
+    fn enter(&self, a: u64, counts: &mut OpCounts) -> u64 {
+        match self {
+            Arithmetic::Division(n) => a % n,
+            Arithmetic::Montgomery(context) => {
+                counts.reductions += 1;
+                context.to_montgomery(a).0
+            }
+        }
+    }
+
+    fn leave(&self, a: u64, counts: &mut OpCounts) -> u64 {
+        match self {
+            Arithmetic::Division(_) => a,
+            Arithmetic::Montgomery(context) => {
+                counts.reductions += 1;
+                context.from_montgomery(Montgomery(a))
+            }
+        }
+    }
+
+    fn one(&self) -> u64 {
+        match self {
+            Arithmetic::Division(n) => 1 % n,
+            Arithmetic::Montgomery(context) => context.one().0,
+        }
+    }
+
+    fn product(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Arithmetic::Division(n) => (a as u128 * b as u128 % *n as u128) as u64,
+            Arithmetic::Montgomery(context) => context.redc(a as u128 * b as u128),
+        }
+    }
+
+    fn mul(&self, a: u64, b: u64, counts: &mut OpCounts) -> u64 {
+        counts.multiplications += 1;
+        counts.reductions += 1;
+        self.product(a, b)
+    }
+
+    fn square(&self, a: u64, counts: &mut OpCounts) -> u64 {
+        counts.squarings += 1;
+        counts.reductions += 1;
+        self.product(a, a)
+    }
+}
+
+// ============================================================================
+// Exponentiation Methods
+// ============================================================================
+
+fn bit_length(exp: u64) -> u32 {
+    64 - exp.leading_zeros()
+}
+
+/// Appends to the trace only when one was requested, so untraced calls stay allocation-free
+fn record(windows: &mut Option<&mut Vec<ExpWindow>>, window: impl FnOnce() -> ExpWindow) {
+    if let Some(windows) = windows {
+        windows.push(window());
+    }
+}
+
+fn binary(
+    base: u64,
+    exp: u64,
+    arithmetic: &Arithmetic,
+    counts: &mut OpCounts,
+    mut windows: Option<&mut Vec<ExpWindow>>,
+) -> (u64, usize) {
+    let base = arithmetic.enter(base, counts);
+    let top = bit_length(exp) - 1;
+    // The leading bit is always 1: start from base instead of squaring 1
+    let mut result = base;
+    record(&mut windows, || ExpWindow { bits: "1".to_string(), value: 1, squarings: 0, multiplied: false });
+
+    for i in (0..top).rev() {
+        let bit = exp >> i & 1;
+        result = arithmetic.square(result, counts);
+        if bit == 1 {
+            result = arithmetic.mul(result, base, counts);
+        }
+        record(&mut windows, || ExpWindow { bits: bit.to_string(), value: bit, squarings: 1, multiplied: bit == 1 });
+    }
+    (arithmetic.leave(result, counts), 1)
+}
+
+fn fixed_window(
+    base: u64,
+    exp: u64,
+    k: u32,
+    arithmetic: &Arithmetic,
+    counts: &mut OpCounts,
+    mut windows: Option<&mut Vec<ExpWindow>>,
+) -> (u64, usize) {
+    // table[i] = base^i
+    let base = arithmetic.enter(base, counts);
+    let mut table = vec![arithmetic.one(), base];
+    while table.len() < 1 << k {
+        let next = arithmetic.mul(table[table.len() - 1], base, counts);
+        table.push(next);
+    }
+
+    let digits = bit_length(exp).div_ceil(k);
+    let mut result = arithmetic.one();
+    let mut started = false;
+
+    for i in (0..digits).rev() {
+        let digit = exp >> (i * k) & ((1 << k) - 1);
+        let squarings = if started { k } else { 0 };
+        for _ in 0..squarings {
+            result = arithmetic.square(result, counts);
+        }
+        let multiplied = digit != 0 && started;
+        if digit != 0 {
+            result = if started { arithmetic.mul(result, table[digit as usize], counts) } else { table[digit as usize] };
+            started = true;
+        }
+        record(&mut windows, || ExpWindow {
+            bits: format!("{:0width$b}", digit, width = k as usize),
+            value: digit,
+            squarings,
+            multiplied,
+        });
+    }
+    (arithmetic.leave(result, counts), table.len())
+}
+
+fn sliding_window(
+    base: u64,
+    exp: u64,
+    k: u32,
+    arithmetic: &Arithmetic,
+    counts: &mut OpCounts,
+    mut windows: Option<&mut Vec<ExpWindow>>,
+) -> (u64, usize) {
+    // table[j] = base^(2j + 1)
+    let base = arithmetic.enter(base, counts);
+    let mut table = vec![base];
+    if k > 1 {
+        let base_squared = arithmetic.square(base, counts);
+        while table.len() < 1 << (k - 1) {
+            let next = arithmetic.mul(table[table.len() - 1], base_squared, counts);
+            table.push(next);
+        }
+    }
+
+    let mut result = arithmetic.one();
+    let mut started = false;
+    let mut i = bit_length(exp) as i64 - 1;
+
+    while i >= 0 {
+        if exp >> i & 1 == 0 {
+            if started {
+                result = arithmetic.square(result, counts);
+            }
+            record(&mut windows, || ExpWindow {
+                bits: "0".to_string(),
+                value: 0,
+                squarings: u32::from(started),
+                multiplied: false,
+            });
+            i -= 1;
+            continue;
+        }
+
+        // Longest window of at most k bits starting at i and ending in a 1
+        let mut low = (i - k as i64 + 1).max(0);
+        while exp >> low & 1 == 0 {
+            low += 1;
+        }
+        let width = (i - low + 1) as u32;
+        let value = exp >> low & ((1 << width) - 1);
+
+        let squarings = if started { width } else { 0 };
+        for _ in 0..squarings {
+            result = arithmetic.square(result, counts);
+        }
+        let entry = table[(value >> 1) as usize];
+        result = if started { arithmetic.mul(result, entry, counts) } else { entry };
+        record(&mut windows, || ExpWindow {
+            bits: format!("{:0width$b}", value, width = width as usize),
+            value,
+            squarings,
+            multiplied: started,
+        });
+        started = true;
+        i = low - 1;
+    }
+    (arithmetic.leave(result, counts), table.len())
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+fn run(
+    base: u64,
+    exp: u64,
+    modulus: u64,
+    method: ExpMethod,
+    counts: &mut OpCounts,
+    windows: Option<&mut Vec<ExpWindow>>,
+) -> (u64, usize) {
+    assert!(modulus > 0, "Modulus must be positive");
+    if exp == 0 {
+        return (1 % modulus, 0);
+    }
+
+    match method {
+        ExpMethod::Binary => binary(base, exp, &Arithmetic::new(modulus, false), counts, windows),
+        ExpMethod::Montgomery => binary(base, exp, &Arithmetic::new(modulus, true), counts, windows),
+        ExpMethod::FixedWindow(k) => {
+            assert!((1..=MAX_WINDOW_BITS).contains(&k), "Window must be 1 to {} bits", MAX_WINDOW_BITS);
+            fixed_window(base, exp, k, &Arithmetic::new(modulus, true), counts, windows)
+        }
+        ExpMethod::SlidingWindow(k) => {
+            assert!((1..=MAX_WINDOW_BITS).contains(&k), "Window must be 1 to {} bits", MAX_WINDOW_BITS);
+            sliding_window(base, exp, k, &Arithmetic::new(modulus, true), counts, windows)
+        }
+    }
+}
+
+/// base^exp mod modulus by the chosen method, recording every window and operation
+pub fn mod_pow_traced(base: u64, exp: u64, modulus: u64, method: ExpMethod) -> ExpTrace {
+    let mut counts = OpCounts::default();
+    let mut windows = Vec::new();
+    let (result, table_size) = run(base, exp, modulus, method, &mut counts, Some(&mut windows));
+    ExpTrace { method, result, counts, table_size, windows }
+}
+
+/// base^exp mod modulus by the chosen method without keeping a trace
+pub fn mod_pow_with(base: u64, exp: u64, modulus: u64, method: ExpMethod) -> u64 {
+    run(base, exp, modulus, method, &mut OpCounts::default(), None).0
+}
+
+/// Square-and-multiply using Montgomery reduction (falls back to `%` for even moduli)
+pub fn mod_pow_montgomery(base: u64, exp: u64, modulus: u64) -> u64 {
+    mod_pow_with(base, exp, modulus, ExpMethod::Montgomery)
+}
+
+pub fn mod_pow_fixed_window(base: u64, exp: u64, modulus: u64, window_bits: u32) -> u64 {
+    mod_pow_with(base, exp, modulus, ExpMethod::FixedWindow(window_bits))
+}
+
+pub fn mod_pow_sliding_window(base: u64, exp: u64, modulus: u64, window_bits: u32) -> u64 {
+    mod_pow_with(base, exp, modulus, ExpMethod::SlidingWindow(window_bits))
+}
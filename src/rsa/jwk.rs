@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::serialization::{from_unsigned_bytes, to_unsigned_bytes};
+use super::types::RsaPublicKey;
+use crate::common::base64::{decode_url, encode_url};
+use crate::sha256::hash::sha256_bytes;
+
+/// An RSA public JSON Web Key (RFC 7517, parameters from RFC 7518 §6.3.1)
+///
+/// Unknown members such as `use` or `alg` are ignored when reading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RsaJwk {
+    pub kty: String,
+    /// Modulus as unpadded base64url of its big-endian bytes
+    pub n: String,
+    /// Public exponent, encoded the same way (65537 is "AQAB")
+    pub e: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub kid: Option<String>,
+}
+
+pub fn public_key_to_jwk(key: &RsaPublicKey) -> RsaJwk {
+    RsaJwk {
+        kty: "RSA".to_string(),
+        n: encode_url(&to_unsigned_bytes(key.n)),
+        e: encode_url(&to_unsigned_bytes(key.e)),
+        kid: Some(jwk_thumbprint(key)),
+    }
+}
+
+pub fn jwk_to_public_key(jwk: &RsaJwk) -> Result<RsaPublicKey, String> {
+    if jwk.kty != "RSA" {
+        return Err(format!("JWK kty is \"{}\", expected \"RSA\"", jwk.kty));
+    }
+    let n = from_unsigned_bytes(&decode_url(&jwk.n).map_err(|e| format!("JWK n: {}", e))?)?;
+    let e = from_unsigned_bytes(&decode_url(&jwk.e).map_err(|e| format!("JWK e: {}", e))?)?;
+    Ok(RsaPublicKey { n, e })
+}
+
+/// JSON with the thumbprint as `kid`, pretty-printed for reading
+pub fn public_key_to_jwk_json(key: &RsaPublicKey) -> String {
+    serde_json::to_string_pretty(&public_key_to_jwk(key)).unwrap_or_else(|_| "{}".to_string())
+}
+
+pub fn public_key_from_jwk_json(json: &str) -> Result<RsaPublicKey, String> {
+    let jwk: RsaJwk = serde_json::from_str(json).map_err(|e| format!("Invalid JWK: {}", e))?;
+    jwk_to_public_key(&jwk)
+}
+
+/// RFC 7638 thumbprint: SHA-256 over the required members in lexicographic order, no whitespace
+pub fn jwk_thumbprint(key: &RsaPublicKey) -> String {
+    let canonical = format!(
+        r#"{{"e":"{}","kty":"RSA","n":"{}"}}"#,
+        encode_url(&to_unsigned_bytes(key.e)),
+        encode_url(&to_unsigned_bytes(key.n))
+    );
+    encode_url(&sha256_bytes(canonical.as_bytes()))
+}
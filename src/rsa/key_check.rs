@@ -0,0 +1,379 @@
+use super::key_generation::totient;
+use super::math_utils::{gcd, integer_root, is_prime, mod_inverse, mod_mul, mod_pow};
+use super::types::{RsaKeyComponents, RsaKeyPair, RsaPrivateKey, RsaPublicKey, Totient};
+use super::validation::{exponent_warnings, validate_public_exponent};
+
+/// p - 1 whose prime factors are all at most this is flagged as smooth
+pub const SMOOTHNESS_BOUND: u64 = 10_000;
+
+/// |p - q| small enough that Fermat's method needs at most this many steps is flagged
+pub const FERMAT_STEP_LIMIT: u64 = 1_000;
+
+/// Messages round-tripped through e and d when the primes are unknown
+const ROUND_TRIP_MESSAGES: [u64; 4] = [2, 3, 5, 7];
+
+/// Whatever key material is at hand; every field is optional
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyCheckInput {
+    pub n: Option<u64>,
+    pub e: Option<u64>,
+    pub d: Option<u64>,
+    pub p: Option<u64>,
+    pub q: Option<u64>,
+    pub dp: Option<u64>,
+    pub dq: Option<u64>,
+    pub qinv: Option<u64>,
+}
+
+impl From<&RsaPublicKey> for KeyCheckInput {
+    fn from(key: &RsaPublicKey) -> Self {
+        KeyCheckInput { n: Some(key.n), e: Some(key.e), ..Default::default() }
+    }
+}
+
+impl From<&RsaKeyComponents> for KeyCheckInput {
+    fn from(key: &RsaKeyComponents) -> Self {
+        KeyCheckInput {
+            n: Some(key.n),
+            e: Some(key.e),
+            d: Some(key.d),
+            p: Some(key.p),
+            q: Some(key.q),
+            dp: Some(key.dp),
+            dq: Some(key.dq),
+            qinv: Some(key.qinv),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    /// The key works but is weak
+    Warn,
+    /// The key is inconsistent or invalid
+    Fail,
+    /// Not enough material to run the check
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct KeyCheckReport {
+    pub checks: Vec<KeyCheck>,
+}
+
+impl KeyCheckReport {
+    fn push(&mut self, name: &'static str, status: CheckStatus, detail: String) {
+        self.checks.push(KeyCheck { name, status, detail });
+    }
+
+    fn skip(&mut self, name: &'static str, needs: &str) {
+        self.push(name, CheckStatus::Skipped, format!("needs {}", needs));
+    }
+
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|check| check.status == status).count()
+    }
+
+    /// True when no check failed; warnings do not make a key invalid
+    pub fn is_valid(&self) -> bool {
+        self.count(CheckStatus::Fail) == 0
+    }
+
+    pub fn find(&self, name: &str) -> Option<&KeyCheck> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+}
+
+/// Bernstein's batch GCD: gcd(nᵢ, ∏ⱼ≠ᵢ nⱼ) for every modulus
+///
+/// The real algorithm computes P = ∏ nⱼ with a product tree and gcd(nᵢ, (P mod nᵢ²)/nᵢ)
+/// with a remainder tree, which needs bignums. Since (P mod nᵢ²)/nᵢ = ∏ⱼ≠ᵢ nⱼ mod nᵢ,
+/// this accumulates that product directly: quadratic rather than quasi-linear, same answer.
+/// A result of 1 means nᵢ shares no prime with the batch; nᵢ itself means nᵢ is fully factored,
+/// either repeated or with each prime shared with a different modulus.
+pub fn batch_gcd(moduli: &[u64]) -> Result<Vec<u64>, String> {
+    if let Some((i, m)) = moduli.iter().enumerate().find(|&(_, &m)| m < 2) {
+        return Err(format!("Modulus {} at position {} must be at least 2", m, i + 1));
+    }
+    Ok(moduli
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| {
+            let others = moduli
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(1 % n, |acc, (_, &m)| mod_mul(acc, m % n, n));
+            gcd(others, n)
+        })
+        .collect())
+}
+
+/// Largest prime factor of `value` if every prime factor is at most `bound`
+fn smooth_part(value: u64, bound: u64) -> Option<u64> {
+    let mut remaining = value;
+    let mut largest = 1;
+    let mut divisor = 2;
+    while divisor <= bound && remaining > 1 {
+        while remaining.is_multiple_of(divisor) {
+            remaining /= divisor;
+            largest = divisor;
+        }
+        divisor += 1;
+    }
+    (remaining == 1).then_some(largest)
+}
+
+/// Fill in whichever of n, p, q can be derived from the others
+fn resolve_factors(input: &KeyCheckInput, report: &mut KeyCheckReport) -> (Option<u64>, Option<(u64, u64)>) {
+    const NAME: &str = "n = p × q";
+    match (input.n, input.p, input.q) {
+        (Some(n), Some(p), Some(q)) => match p.checked_mul(q) {
+            Some(product) if product == n => {
+                report.push(NAME, CheckStatus::Pass, format!("{} × {} = {}", p, q, n));
+                (Some(n), Some((p, q)))
+            }
+            product => {
+                let shown = product.map_or("overflows u64".to_string(), |product| product.to_string());
+                report.push(NAME, CheckStatus::Fail, format!("{} × {} = {}, not n = {}", p, q, shown, n));
+                (Some(n), None)
+            }
+        },
+        (None, Some(p), Some(q)) => match p.checked_mul(q) {
+            Some(n) => {
+                report.push(NAME, CheckStatus::Pass, format!("n derived as {} × {} = {}", p, q, n));
+                (Some(n), Some((p, q)))
+            }
+            None => {
+                report.push(NAME, CheckStatus::Fail, format!("{} × {} overflows u64", p, q));
+                (None, None)
+            }
+        },
+        (Some(n), Some(r), None) | (Some(n), None, Some(r)) => {
+            if r > 1 && r < n && n.is_multiple_of(r) {
+                let other = n / r;
+                let (p, q) = if input.p.is_some() { (r, other) } else { (other, r) };
+                report.push(NAME, CheckStatus::Pass, format!("other prime derived as {} / {} = {}", n, r, other));
+                (Some(n), Some((p, q)))
+            } else {
+                report.push(NAME, CheckStatus::Fail, format!("{} does not divide n = {}", r, n));
+                (Some(n), None)
+            }
+        }
+        (n, _, _) => {
+            report.skip(NAME, "two of n, p, q");
+            (n, None)
+        }
+    }
+}
+
+fn check_primes(p: u64, q: u64, report: &mut KeyCheckReport) -> bool {
+    for (name, value) in [("p is prime", p), ("q is prime", q)] {
+        if is_prime(value) {
+            report.push(name, CheckStatus::Pass, format!("{} is prime", value));
+        } else {
+            report.push(name, CheckStatus::Fail, format!("{} is composite", value));
+        }
+    }
+    if p == q {
+        report.push("p ≠ q", CheckStatus::Fail, format!("p = q = {}, so φ(n) is not (p-1)(q-1)", p));
+    } else {
+        report.push("p ≠ q", CheckStatus::Pass, "distinct".to_string());
+    }
+    is_prime(p) && is_prime(q) && p != q
+}
+
+fn check_exponents(input: &KeyCheckInput, n: Option<u64>, primes: Option<(u64, u64)>, report: &mut KeyCheckReport) {
+    match (input.e, primes) {
+        (Some(e), Some((p, q))) => match validate_public_exponent(e, totient(&[p, q], Totient::Euler)) {
+            Ok(()) => report.push("e", CheckStatus::Pass, format!("e={} is odd, below φ(n) and coprime to it", e)),
+            Err(error) => report.push("e", CheckStatus::Fail, error),
+        },
+        (Some(e), None) => match n {
+            Some(n) if e > 1 && e < n && e % 2 == 1 => {
+                report.push("e", CheckStatus::Pass, format!("e={} is odd and 1 < e < n; gcd with φ(n) needs p, q", e));
+            }
+            Some(n) => report.push("e", CheckStatus::Fail, format!("e={} must be odd with 1 < e < n = {}", e, n)),
+            None => report.skip("e", "n or p, q"),
+        },
+        (None, _) => report.skip("e", "e"),
+    }
+
+    const INVERSE: &str = "e·d ≡ 1 (mod λ(n))";
+    match (input.e, input.d, primes, n) {
+        (Some(e), Some(d), Some((p, q)), _) => {
+            let lambda_n = totient(&[p, q], Totient::Carmichael);
+            match e as u128 * d as u128 % lambda_n as u128 {
+                1 => report.push(INVERSE, CheckStatus::Pass, format!("{} × {} ≡ 1 (mod {})", e, d, lambda_n)),
+                residue => {
+                    let expected = mod_inverse(e % lambda_n, lambda_n)
+                        .map_or("no inverse exists".to_string(), |inverse| format!("expected d ≡ {}", inverse));
+                    report.push(INVERSE, CheckStatus::Fail, format!("{} × {} ≡ {} (mod {}); {}", e, d, residue, lambda_n, expected));
+                }
+            }
+        }
+        // Without the primes, check that decryption undoes encryption on a few messages
+        (Some(e), Some(d), None, Some(n)) => {
+            let failed = ROUND_TRIP_MESSAGES.iter().find(|&&m| m < n && mod_pow(mod_pow(m, e, n), d, n) != m);
+            match failed {
+                None => report.push(INVERSE, CheckStatus::Pass, "(m^e)^d ≡ m (mod n) for m = 2, 3, 5, 7; λ(n) needs p, q".to_string()),
+                Some(m) => report.push(INVERSE, CheckStatus::Fail, format!("(m^e)^d ≢ m (mod n) for m = {}", m)),
+            }
+        }
+        _ => report.skip(INVERSE, "e, d and n or p, q"),
+    }
+}
+
+fn check_crt(input: &KeyCheckInput, primes: Option<(u64, u64)>, report: &mut KeyCheckReport) {
+    let Some((p, q)) = primes else {
+        for name in ["dp = d mod (p-1)", "dq = d mod (q-1)", "qinv = q⁻¹ mod p"] {
+            report.skip(name, "p, q");
+        }
+        return;
+    };
+
+    let mut compare = |name: &'static str, given: Option<u64>, expected: Option<u64>| match (given, expected) {
+        (Some(given), Some(expected)) if given == expected => report.push(name, CheckStatus::Pass, given.to_string()),
+        (Some(given), Some(expected)) => {
+            report.push(name, CheckStatus::Fail, format!("{} given, {} expected", given, expected));
+        }
+        (Some(given), None) => report.push(name, CheckStatus::Fail, format!("{} given, but no value exists", given)),
+        (None, expected) => report.push(
+            name,
+            CheckStatus::Skipped,
+            expected.map_or("not given".to_string(), |expected| format!("not given (would be {})", expected)),
+        ),
+    };
+    compare("dp = d mod (p-1)", input.dp, input.d.map(|d| d % (p - 1)));
+    compare("dq = d mod (q-1)", input.dq, input.d.map(|d| d % (q - 1)));
+    compare("qinv = q⁻¹ mod p", input.qinv, mod_inverse(q % p, p));
+}
+
+fn check_weaknesses(input: &KeyCheckInput, n: Option<u64>, primes: Option<(u64, u64)>, report: &mut KeyCheckReport) {
+    match (n, primes) {
+        (Some(n), Some((p, q))) => {
+            // Fermat starts at a = ⌈√n⌉ and needs about (p-q)² / (8√n) steps to reach (p+q)/2
+            let distance = p.abs_diff(q) as u128;
+            let steps = distance * distance / (8 * integer_root(n as u128, 2)).max(1);
+            if steps <= FERMAT_STEP_LIMIT as u128 {
+                report.push(
+                    "|p - q|",
+                    CheckStatus::Warn,
+                    format!("|p - q| = {}: Fermat's method factors n after about {} iteration(s)", distance, steps.max(1)),
+                );
+            } else {
+                report.push("|p - q|", CheckStatus::Pass, format!("|p - q| = {}, about {} Fermat steps", distance, steps));
+            }
+        }
+        _ => report.skip("|p - q|", "p, q"),
+    }
+
+    for (name, prime) in [("p - 1 smoothness", primes.map(|(p, _)| p)), ("q - 1 smoothness", primes.map(|(_, q)| q))] {
+        match prime {
+            Some(prime) => match smooth_part(prime - 1, SMOOTHNESS_BOUND) {
+                Some(largest) => report.push(
+                    name,
+                    CheckStatus::Warn,
+                    format!("{} - 1 has no prime factor above {}: Pollard's p-1 finds it quickly", prime, largest),
+                ),
+                None => report.push(name, CheckStatus::Pass, format!("{} - 1 has a prime factor above {}", prime, SMOOTHNESS_BOUND)),
+            },
+            None => report.skip(name, "p, q"),
+        }
+    }
+
+    match (n, input.e, input.d) {
+        (Some(n), Some(e), Some(d)) => {
+            let key_pair = RsaKeyPair {
+                public_key: RsaPublicKey { n, e },
+                private_key: RsaPrivateKey { n, d },
+            };
+            let warnings = exponent_warnings(&key_pair);
+            if warnings.is_empty() {
+                report.push("Exponents", CheckStatus::Pass, "no small e, d = e or small d".to_string());
+            }
+            for warning in warnings {
+                report.push("Exponents", CheckStatus::Warn, warning);
+            }
+        }
+        _ => report.skip("Exponents", "n, e, d"),
+    }
+}
+
+fn check_shared_factors(n: Option<u64>, other_moduli: &[u64], report: &mut KeyCheckReport) {
+    const NAME: &str = "Shared factors (batch GCD)";
+    let (others, ignored): (Vec<u64>, Vec<u64>) = other_moduli.iter().partition(|&&m| m >= 2);
+    match n.filter(|_| !others.is_empty()) {
+        None => report.skip(NAME, "n and other moduli"),
+        Some(n) => {
+            let moduli: Vec<u64> = std::iter::once(n).chain(others.iter().copied()).collect();
+            match batch_gcd(&moduli) {
+                Err(error) => report.push(NAME, CheckStatus::Fail, error),
+                Ok(shared) => report_shared_factor(n, shared[0], &others, report),
+            }
+        }
+    }
+    if !ignored.is_empty() {
+        let shown: Vec<String> = ignored.iter().map(u64::to_string).collect();
+        report.push(NAME, CheckStatus::Warn, format!("ignored moduli below 2: {}", shown.join(", ")));
+    }
+}
+
+fn report_shared_factor(n: u64, shared: u64, others: &[u64], report: &mut KeyCheckReport) {
+    const NAME: &str = "Shared factors (batch GCD)";
+    match shared {
+        1 => report.push(NAME, CheckStatus::Pass, format!("no prime shared with {} other moduli", others.len())),
+        // Both primes are shared, possibly with different moduli: find where each came from
+        shared if shared == n => {
+            let sources: Vec<String> = others
+                .iter()
+                .filter_map(|&m| match gcd(m, n) {
+                    1 => None,
+                    g if g == n => Some(format!("all of n with {} (repeated modulus)", m)),
+                    g => Some(format!("{} with {}", g, m)),
+                })
+                .collect();
+            report.push(NAME, CheckStatus::Fail, format!("n = {} is fully factored by the batch: {}", n, sources.join(", ")));
+        }
+        shared => {
+            let sharing: Vec<String> = others
+                .iter()
+                .filter(|&&m| gcd(m, n) > 1)
+                .map(u64::to_string)
+                .collect();
+            report.push(
+                NAME,
+                CheckStatus::Fail,
+                format!("n = {} × {} shares {} with {}", shared, n / shared, shared, sharing.join(", ")),
+            );
+        }
+    }
+}
+
+/// Check any combination of n, e, d, p, q (and the CRT values) for consistency and weaknesses
+///
+/// `other_moduli` are run through a batch GCD with n to catch primes reused across keys.
+pub fn validate_keypair(input: &KeyCheckInput, other_moduli: &[u64]) -> KeyCheckReport {
+    let mut report = KeyCheckReport::default();
+
+    let (n, primes) = resolve_factors(input, &mut report);
+    let primes = match primes {
+        Some((p, q)) if check_primes(p, q, &mut report) => Some((p, q)),
+        _ => None,
+    };
+
+    check_exponents(input, n, primes, &mut report);
+    check_crt(input, primes, &mut report);
+    check_weaknesses(input, n, primes, &mut report);
+    check_shared_factors(n, other_moduli, &mut report);
+
+    report
+}
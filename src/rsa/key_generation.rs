@@ -1,24 +1,131 @@
-use super::constants::COMMON_PUBLIC_EXPONENTS;
-use super::math_utils::mod_inverse;
-use super::types::{RsaKeyPair, RsaPrivateKey, RsaPublicKey};
+use super::constants::{COMMON_PUBLIC_EXPONENTS, F4};
+use super::math_utils::{gcd, lcm, mod_inverse};
+use super::types::{
+    ExponentPolicy, MultiPrimeKey, PrimeFactor, RsaKeyComponents, RsaKeyPair, RsaPrivateKey, RsaPublicKey, Totient,
+};
+use super::validation::validate_public_exponent;
+use crate::common::random::DemoRng;
 
+/// φ(n) or λ(n) for n = the product of distinct primes
+pub fn totient(primes: &[u64], kind: Totient) -> u64 {
+    primes.iter().map(|&r| r - 1).fold(1, |acc, r| match kind {
+        Totient::Euler => acc * r,
+        Totient::Carmichael => lcm(acc, r),
+    })
+}
 
-fn find_exponent_pair(phi_n: u64) -> Option<(u64, u64)> {
-    COMMON_PUBLIC_EXPONENTS
-        .iter()
-        .find_map(|&e| mod_inverse(e, phi_n).map(|d| (e, d)))
+/// Pick e for a key with Euler totient `phi_n` according to `policy`
+///
+/// Every policy returns an e that passes `validate_public_exponent`; an
+/// explicit e that does not is reported rather than replaced.
+pub fn select_public_exponent(phi_n: u64, policy: ExponentPolicy, rng: &mut DemoRng) -> Result<u64, String> {
+    let not_found = || format!("Could not find suitable public exponent for φ(n)={}. Try different primes.", phi_n);
+    let is_valid = |e: u64| validate_public_exponent(e, phi_n).is_ok();
+
+    match policy {
+        ExponentPolicy::Common => COMMON_PUBLIC_EXPONENTS.iter().copied().find(|&e| is_valid(e)).ok_or_else(not_found),
+        ExponentPolicy::Smallest => (3..phi_n).step_by(2).find(|&e| gcd(e, phi_n) == 1).ok_or_else(not_found),
+        ExponentPolicy::F4 => validate_public_exponent(F4, phi_n).map(|_| F4),
+        ExponentPolicy::Random => {
+            // Some odd e is coprime whenever the smallest one exists, so the loop ends
+            select_public_exponent(phi_n, ExponentPolicy::Smallest, rng)?;
+            loop {
+                let e = rng.below(phi_n) | 1;
+                if is_valid(e) {
+                    return Ok(e);
+                }
+            }
+        }
+        ExponentPolicy::Explicit(e) => validate_public_exponent(e, phi_n).map(|_| e),
+    }
+}
+
+fn find_exponent_pair_for(primes: &[u64], kind: Totient, policy: ExponentPolicy) -> Result<(u64, u64), String> {
+    // Only the Random policy draws from the RNG, so the others never touch the clock
+    let mut rng = match policy {
+        ExponentPolicy::Random => DemoRng::from_entropy(),
+        _ => DemoRng::new(0),
+    };
+    let e = select_public_exponent(totient(primes, Totient::Euler), policy, &mut rng)?;
+    // φ(n) and λ(n) have the same prime factors, so e is invertible modulo either
+    let d = mod_inverse(e, totient(primes, kind)).ok_or_else(|| format!("e={} has no inverse mod {}", e, kind.symbol()))?;
+    Ok((e, d))
 }
 
 pub fn generate_keypair(p: u64, q: u64) -> RsaKeyPair {
-    let n = p * q;
-    let phi_n = (p - 1) * (q - 1);
+    generate_keypair_with_totient(p, q, Totient::Euler)
+}
+
+/// Like `generate_keypair`, with d = e⁻¹ mod φ(n) or mod λ(n)
+///
+/// Both choices give the same e, since φ(n) and λ(n) have the same prime factors.
+pub fn generate_keypair_with_totient(p: u64, q: u64, kind: Totient) -> RsaKeyPair {
+    generate_keypair_with_exponent(p, q, kind, ExponentPolicy::Common).unwrap_or_else(|error| panic!("{}", error))
+}
 
-    let (e, d) = find_exponent_pair(phi_n).unwrap_or_else(|| {
-        panic!("Could not find suitable public exponent for φ(n)={}. Try different primes.", phi_n)
-    });
+/// Like `generate_keypair_with_totient`, choosing e by `policy`
+pub fn generate_keypair_with_exponent(p: u64, q: u64, kind: Totient, policy: ExponentPolicy) -> Result<RsaKeyPair, String> {
+    let n = p * q;
+    let (e, d) = find_exponent_pair_for(&[p, q], kind, policy)?;
 
-    RsaKeyPair {
+    Ok(RsaKeyPair {
         public_key: RsaPublicKey { n, e },
         private_key: RsaPrivateKey { n, d },
-    }
+    })
+}
+
+/// Generate a key pair and keep the primes and CRT values needed for key files
+pub fn generate_key_components(p: u64, q: u64) -> RsaKeyComponents {
+    generate_key_components_with_totient(p, q, Totient::Euler)
+}
+
+pub fn generate_key_components_with_totient(p: u64, q: u64, kind: Totient) -> RsaKeyComponents {
+    generate_key_components_with_exponent(p, q, kind, ExponentPolicy::Common).unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn generate_key_components_with_exponent(
+    p: u64,
+    q: u64,
+    kind: Totient,
+    policy: ExponentPolicy,
+) -> Result<RsaKeyComponents, String> {
+    let key_pair = generate_keypair_with_exponent(p, q, kind, policy)?;
+    let d = key_pair.private_key.d;
+
+    Ok(RsaKeyComponents {
+        n: key_pair.public_key.n,
+        e: key_pair.public_key.e,
+        d,
+        p,
+        q,
+        dp: d % (p - 1),
+        dq: d % (q - 1),
+        qinv: mod_inverse(q % p, p).ok_or_else(|| format!("p={} and q={} must be distinct primes", p, q))?,
+    })
+}
+
+/// Generate a k-prime key (RFC 8017 multi-prime RSA) with the CRT values for each prime
+pub fn generate_multi_prime_key(primes: &[u64], kind: Totient) -> MultiPrimeKey {
+    let n = primes
+        .iter()
+        .try_fold(1u64, |acc, &r| acc.checked_mul(r))
+        .unwrap_or_else(|| panic!("The product of {:?} does not fit in 64 bits", primes));
+    let (e, d) = find_exponent_pair_for(primes, kind, ExponentPolicy::Common).unwrap_or_else(|error| panic!("{}", error));
+
+    let mut preceding = 1u64;
+    let factors = primes
+        .iter()
+        .enumerate()
+        .map(|(i, &r)| {
+            let coefficient = if i == 0 {
+                0
+            } else {
+                mod_inverse(preceding % r, r).unwrap_or_else(|| panic!("Primes {:?} must be distinct", primes))
+            };
+            preceding *= r;
+            PrimeFactor { prime: r, exponent: d % (r - 1), coefficient }
+        })
+        .collect();
+
+    MultiPrimeKey { n, e, d, totient: kind, factors }
 }
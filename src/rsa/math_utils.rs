@@ -1,3 +1,16 @@
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple; assumes the result fits in u64
+pub fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
 pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
     if b == 0 {
         (a, 1, 0)
@@ -20,6 +33,11 @@ pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
     }
 }
 
+/// Calculate (a × b) mod m without overflowing u64
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
 pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
     if modulus == 1 {
         return 0;
@@ -46,7 +64,7 @@ pub fn is_prime(n: u64) -> bool {
     match n {
         0..=1 => false,
         2 => true,
-        n if n % 2 == 0 => false,
+        n if n.is_multiple_of(2) => false,
         n => is_prime_helper(n, 3, (n as f64).sqrt() as u64),
     }
 }
@@ -54,9 +72,46 @@ pub fn is_prime(n: u64) -> bool {
 fn is_prime_helper(n: u64, divisor: u64, sqrt_n: u64) -> bool {
     if divisor > sqrt_n {
         true
-    } else if n % divisor == 0 {
+    } else if n.is_multiple_of(divisor) {
         false
     } else {
         is_prime_helper(n, divisor + 2, sqrt_n)
     }
 }
+
+/// Integer k-th root: the largest r with r^k ≤ n
+pub fn integer_root(n: u128, k: u32) -> u128 {
+    if k <= 1 {
+        return if k == 0 { 1 } else { n };
+    }
+    let (mut low, mut high) = (0u128, 1u128 << (128 / k + 1));
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        match mid.checked_pow(k) {
+            Some(power) if power <= n => low = mid,
+            _ => high = mid - 1,
+        }
+    }
+    low
+}
+
+/// Chinese Remainder Theorem: find x with x ≡ rᵢ (mod mᵢ) for every (rᵢ, mᵢ)
+///
+/// Returns (x, M) where M = ∏ mᵢ, or None if the moduli are not pairwise
+/// coprime or M does not fit in u128. Uses Garner's incremental form so no
+/// intermediate value exceeds M.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u128, u128)> {
+    congruences.iter().try_fold((0u128, 1u128), |(x, modulus), &(r, m)| {
+        let m_wide = m as u128;
+        let inverse = mod_inverse((modulus % m_wide) as u64, m)?;
+        let difference = ((r as u128 % m_wide) + m_wide - x % m_wide) % m_wide;
+        let t = mod_mul(difference as u64, inverse, m);
+        let next_modulus = modulus.checked_mul(m_wide)?;
+        Some((x + modulus * t as u128, next_modulus))
+    })
+}
+
+/// Largest d for which Wiener's theorem guarantees recovery: d < n^¼ / 3
+pub fn wiener_bound(n: u64) -> u64 {
+    ((n as f64).powf(0.25) / 3.0) as u64
+}
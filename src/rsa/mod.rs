@@ -1,8 +1,18 @@
 pub mod types;
 pub mod constants;
 pub mod math_utils;
+pub mod montgomery;
+pub mod exponentiation;
+pub mod constant_time;
 pub mod text_encoding;
 pub mod validation;
 pub mod encryption;
+pub mod oaep;
+pub mod envelope;
 pub mod key_generation;
+pub mod key_check;
+pub mod serialization;
+pub mod jwk;
+pub mod openssh;
+pub mod attacks;
 pub mod demo;
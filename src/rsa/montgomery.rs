@@ -0,0 +1,111 @@
+// ============================================================================
+// Montgomery Arithmetic (R = 2^k, normally 2^64)
+// ============================================================================
+// Values are kept as ā = a·R mod n. A product of two such values is reduced
+// with REDC, which replaces the division in `% n` by a multiply, an add and
+// a shift. Montgomery, "Modular Multiplication Without Trial Division" (1985).
+
+/// Precomputed constants for one odd modulus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontgomeryContext {
+    pub n: u64,
+    /// k in R = 2^k
+    pub r_bits: u32,
+    /// n' = −n⁻¹ mod R, so that n·n' ≡ −1 (mod R)
+    pub n_prime: u64,
+    /// R mod n: the Montgomery form of 1
+    pub r_mod_n: u64,
+    /// R² mod n: multiplying by it and reducing converts into Montgomery form
+    pub r2_mod_n: u64,
+}
+
+/// A residue in Montgomery form; only meaningful with the context that produced it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Montgomery(pub u64);
+
+/// n⁻¹ mod 2^64 by Newton iteration; each step doubles the correct low bits
+fn inverse_mod_r(n: u64) -> u64 {
+    // n·n ≡ 1 (mod 8) for odd n, so n is correct to 3 bits
+    (0..5).fold(n, |inv, _| inv.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(inv))))
+}
+
+impl MontgomeryContext {
+    /// R = 2^64, the machine word. None for even moduli (and 1), where R has no inverse
+    pub fn new(n: u64) -> Option<Self> {
+        Self::with_r_bits(n, 64)
+    }
+
+    /// R = 2^k for any k with n < R ≤ 2^64
+    ///
+    /// Bignum libraries pick R = 2^(64·limbs), just above n; a tight R makes
+    /// the final subtraction in REDC frequent, which is what timing attacks measure.
+    pub fn with_r_bits(n: u64, r_bits: u32) -> Option<Self> {
+        if n.is_multiple_of(2) || n == 1 || r_bits > 64 || r_bits < 64 - n.leading_zeros() {
+            return None;
+        }
+        let r_mod_n = if r_bits == 64 { (u64::MAX % n + 1) % n } else { ((1u128 << r_bits) % n as u128) as u64 };
+        let mask = if r_bits == 64 { u64::MAX } else { (1 << r_bits) - 1 };
+        Some(MontgomeryContext {
+            n,
+            r_bits,
+            n_prime: inverse_mod_r(n).wrapping_neg() & mask,
+            r_mod_n,
+            r2_mod_n: (r_mod_n as u128 * r_mod_n as u128 % n as u128) as u64,
+        })
+    }
+
+    /// (t + m·n) / R, which is congruent to t·R⁻¹ and below 2n
+    fn redc_unreduced(&self, t: u128) -> u128 {
+        let mask = if self.r_bits == 64 { u64::MAX } else { (1 << self.r_bits) - 1 };
+        let m = (t as u64 & mask).wrapping_mul(self.n_prime) & mask;
+        let (sum, carry) = t.overflowing_add(m as u128 * self.n as u128);
+        if self.r_bits == 64 { (sum >> 64) | (carry as u128) << 64 } else { sum >> self.r_bits }
+    }
+
+    /// REDC(t) = t·R⁻¹ mod n for any t < n·R, and whether the extra subtraction ran
+    ///
+    /// m = t·n' mod R makes t + m·n divisible by R; the quotient is below 2n,
+    /// so one conditional subtraction finishes the reduction.
+    pub fn redc_with_flag(&self, t: u128) -> (u64, bool) {
+        let u = self.redc_unreduced(t);
+        if u >= self.n as u128 { ((u - self.n as u128) as u64, true) } else { (u as u64, false) }
+    }
+
+    pub fn redc(&self, t: u128) -> u64 {
+        self.redc_with_flag(t).0
+    }
+
+    /// REDC with the final subtraction always computed and selected by a mask, not a branch
+    pub fn redc_constant_time(&self, t: u128) -> u64 {
+        let u = self.redc_unreduced(t);
+        let difference = u.wrapping_sub(self.n as u128);
+        // All ones when u < n (the subtraction borrowed), zero otherwise
+        let keep_u = ((difference >> 127) as u64).wrapping_neg();
+        (u as u64 & keep_u) | (difference as u64 & !keep_u)
+    }
+
+    pub fn to_montgomery(&self, a: u64) -> Montgomery {
+        Montgomery(self.redc((a % self.n) as u128 * self.r2_mod_n as u128))
+    }
+
+    pub fn from_montgomery(&self, a: Montgomery) -> u64 {
+        self.redc(a.0 as u128)
+    }
+
+    pub fn one(&self) -> Montgomery {
+        Montgomery(self.r_mod_n)
+    }
+
+    /// ā·b̄·R⁻¹ = (ab)·R: the Montgomery form of the product
+    pub fn mul(&self, a: Montgomery, b: Montgomery) -> Montgomery {
+        Montgomery(self.redc(a.0 as u128 * b.0 as u128))
+    }
+
+    pub fn square(&self, a: Montgomery) -> Montgomery {
+        self.mul(a, a)
+    }
+
+    pub fn mul_constant_time(&self, a: Montgomery, b: Montgomery) -> Montgomery {
+        Montgomery(self.redc_constant_time(a.0 as u128 * b.0 as u128))
+    }
+}
@@ -0,0 +1,99 @@
+// ============================================================================
+// Toy OAEP Padding
+// ============================================================================
+// The layout of RFC 8017 §7.1.1 scaled down to a 64-bit modulus:
+//
+//   DB = lHash[..2] ‖ message          seed = 2 random bytes
+//   EM = (seed ⊕ MGF1(maskedDB)) ‖ (DB ⊕ MGF1(seed))
+//
+// EM is one byte shorter than n so it is always below n. With a 2-byte seed
+// and a 2-byte check this shows *why* OAEP stops malleability, but a forgery
+// still passes the check once in 65536 tries: it is not secure.
+
+use crate::common::random::DemoRng;
+use crate::sha256::hash::sha256_bytes;
+
+/// Bytes of randomness mixed into every encoding
+pub const SEED_BYTES: usize = 2;
+
+/// Bytes of SHA-256("") kept in DB and checked on decoding
+pub const CHECK_BYTES: usize = 2;
+
+/// MGF1 (RFC 8017 B.2.1) with SHA-256: SHA-256(seed ‖ counter) blocks, truncated
+pub fn mgf1(seed: &[u8], length: usize) -> Vec<u8> {
+    (0u32..)
+        .flat_map(|counter| sha256_bytes(&[seed, &counter.to_be_bytes()].concat()))
+        .take(length)
+        .collect()
+}
+
+fn xor_in_place(target: &mut [u8], mask: &[u8]) {
+    target.iter_mut().zip(mask).for_each(|(byte, mask)| *byte ^= mask);
+}
+
+/// Bytes in an encoded block for modulus n: the largest k with 256^k ≤ n
+pub fn block_bytes(n: u64) -> usize {
+    ((64 - n.leading_zeros()).saturating_sub(1) / 8) as usize
+}
+
+/// Largest message `oaep_encode` accepts for modulus n, or None if n is too small to pad
+pub fn max_message(n: u64) -> Option<u64> {
+    let message_bytes = block_bytes(n).checked_sub(SEED_BYTES + CHECK_BYTES).filter(|&bytes| bytes > 0)?;
+    Some(u64::MAX >> (64 - 8 * message_bytes as u32))
+}
+
+fn label_check() -> [u8; CHECK_BYTES] {
+    let mut check = [0u8; CHECK_BYTES];
+    check.copy_from_slice(&sha256_bytes(b"")[..CHECK_BYTES]);
+    check
+}
+
+/// Pad a message into a block below n with a fresh random seed
+pub fn oaep_encode(message: u64, n: u64, rng: &mut DemoRng) -> Result<u64, String> {
+    let mut seed = [0u8; SEED_BYTES];
+    rng.fill_bytes(&mut seed);
+    oaep_encode_with_seed(message, n, seed)
+}
+
+/// `oaep_encode` with a chosen seed, so the steps can be reproduced
+pub fn oaep_encode_with_seed(message: u64, n: u64, seed: [u8; SEED_BYTES]) -> Result<u64, String> {
+    let max = max_message(n).ok_or_else(|| format!("n = {} is too small for OAEP (needs n ≥ 2^40)", n))?;
+    if message > max {
+        return Err(format!("Message ({}) does not fit in the padded block (max {})", message, max));
+    }
+    let k = block_bytes(n);
+    let db_bytes = k - SEED_BYTES;
+
+    let mut db = label_check().to_vec();
+    db.extend_from_slice(&message.to_be_bytes()[8 - (db_bytes - CHECK_BYTES)..]);
+    xor_in_place(&mut db, &mgf1(&seed, db_bytes));
+
+    let mut masked_seed = seed;
+    xor_in_place(&mut masked_seed, &mgf1(&db, SEED_BYTES));
+
+    Ok([masked_seed.as_slice(), &db].concat().iter().fold(0, |acc, &byte| acc << 8 | byte as u64))
+}
+
+/// Undo the masks and check the label hash; any tampering shows up as a bad check
+pub fn oaep_decode(block: u64, n: u64) -> Result<u64, String> {
+    let k = block_bytes(n);
+    if max_message(n).is_none() {
+        return Err(format!("n = {} is too small for OAEP (needs n ≥ 2^40)", n));
+    }
+    if block >> (8 * k) != 0 {
+        return Err(format!("Block {} is longer than {} bytes", block, k));
+    }
+
+    let bytes = &block.to_be_bytes()[8 - k..];
+    let (masked_seed, masked_db) = bytes.split_at(SEED_BYTES);
+    let mut seed = masked_seed.to_vec();
+    xor_in_place(&mut seed, &mgf1(masked_db, SEED_BYTES));
+    let mut db = masked_db.to_vec();
+    xor_in_place(&mut db, &mgf1(&seed, masked_db.len()));
+
+    let (check, message) = db.split_at(CHECK_BYTES);
+    if check != label_check() {
+        return Err(format!("Padding check failed: got {}, expected {}", hex::encode(check), hex::encode(label_check())));
+    }
+    Ok(message.iter().fold(0, |acc, &byte| acc << 8 | byte as u64))
+}
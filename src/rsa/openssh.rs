@@ -0,0 +1,104 @@
+use super::serialization::{from_unsigned_bytes, to_unsigned_bytes};
+use super::types::RsaPublicKey;
+use crate::common::base64::{decode, encode, encode_unpadded};
+use crate::sha256::hash::sha256_bytes;
+
+pub const SSH_RSA: &str = "ssh-rsa";
+
+// ============================================================================
+// SSH Wire Format (RFC 4251 §5)
+// ============================================================================
+
+/// `string`: uint32 length followed by the bytes
+fn write_string(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// `mpint`: two's complement, so a leading 0x00 when the top bit is set; zero is empty
+fn write_mpint(out: &mut Vec<u8>, value: u64) {
+    let mut bytes = if value == 0 { Vec::new() } else { to_unsigned_bytes(value) };
+    if bytes.first().is_some_and(|&b| b & 0x80 != 0) {
+        bytes.insert(0, 0);
+    }
+    write_string(out, &bytes);
+}
+
+fn read_string<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    if input.len() < 4 {
+        return Err("Truncated SSH string length".to_string());
+    }
+    let length = u32::from_be_bytes([input[0], input[1], input[2], input[3]]) as usize;
+    let rest = &input[4..];
+    if rest.len() < length {
+        return Err(format!("SSH string claims {} bytes but only {} remain", length, rest.len()));
+    }
+    let (value, remaining) = rest.split_at(length);
+    *input = remaining;
+    Ok(value)
+}
+
+fn read_mpint(input: &mut &[u8]) -> Result<u64, String> {
+    let bytes = read_string(input)?;
+    if bytes.first().is_some_and(|&b| b & 0x80 != 0) {
+        return Err("Negative mpint is not a valid key component".to_string());
+    }
+    from_unsigned_bytes(bytes)
+}
+
+/// The key blob: string "ssh-rsa", mpint e, mpint n (RFC 4253 §6.6)
+pub fn encode_ssh_blob(key: &RsaPublicKey) -> Vec<u8> {
+    let mut blob = Vec::new();
+    write_string(&mut blob, SSH_RSA.as_bytes());
+    write_mpint(&mut blob, key.e);
+    write_mpint(&mut blob, key.n);
+    blob
+}
+
+pub fn decode_ssh_blob(blob: &[u8]) -> Result<RsaPublicKey, String> {
+    let mut input = blob;
+    let key_type = read_string(&mut input)?;
+    if key_type != SSH_RSA.as_bytes() {
+        return Err(format!("Key type is \"{}\", expected \"{}\"", String::from_utf8_lossy(key_type), SSH_RSA));
+    }
+    let e = read_mpint(&mut input)?;
+    let n = read_mpint(&mut input)?;
+    if !input.is_empty() {
+        return Err(format!("{} trailing bytes after ssh-rsa key", input.len()));
+    }
+    Ok(RsaPublicKey { n, e })
+}
+
+// ============================================================================
+// authorized_keys / .pub Lines
+// ============================================================================
+
+/// `ssh-rsa <base64 blob> [comment]`, as in `id_rsa.pub`
+pub fn public_key_to_openssh(key: &RsaPublicKey, comment: Option<&str>) -> String {
+    let line = format!("{} {}", SSH_RSA, encode(&encode_ssh_blob(key)));
+    match comment {
+        Some(comment) if !comment.is_empty() => format!("{} {}", line, comment),
+        _ => line,
+    }
+}
+
+/// Parse a public key line, skipping any authorized_keys options before the key type
+///
+/// Returns the key and the trailing comment, if any.
+pub fn public_key_from_openssh(line: &str) -> Result<(RsaPublicKey, Option<String>), String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let position = fields
+        .iter()
+        .position(|&field| field == SSH_RSA)
+        .ok_or_else(|| format!("No \"{}\" key type found", SSH_RSA))?;
+    let blob = fields.get(position + 1).ok_or("Missing base64 key data after ssh-rsa")?;
+    let key = decode_ssh_blob(&decode(blob)?)?;
+
+    let comment = fields[position + 2..].join(" ");
+    Ok((key, (!comment.is_empty()).then_some(comment)))
+}
+
+/// `ssh-keygen -l` style fingerprint: SHA-256 of the blob, unpadded base64
+pub fn openssh_fingerprint(key: &RsaPublicKey) -> String {
+    format!("SHA256:{}", encode_unpadded(&sha256_bytes(&encode_ssh_blob(key))))
+}
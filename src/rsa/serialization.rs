@@ -0,0 +1,260 @@
+use super::jwk::{public_key_from_jwk_json, public_key_to_jwk_json};
+use super::openssh::{public_key_from_openssh, public_key_to_openssh, SSH_RSA};
+use super::types::{RsaKeyComponents, RsaPublicKey};
+use crate::asn1::der::{
+    decode_integer, decode_oid, encode_bit_string, encode_integer, encode_null, encode_octet_string, encode_oid,
+    encode_sequence, expect_element, read_children, DerElement, TAG_BIT_STRING, TAG_OCTET_STRING, TAG_SEQUENCE,
+};
+use crate::asn1::oid::OID_RSA_ENCRYPTION;
+use crate::common::pem::{decode_pem, encode_pem, is_pem};
+
+pub const PEM_LABEL_RSA_PUBLIC_KEY: &str = "RSA PUBLIC KEY";
+pub const PEM_LABEL_PUBLIC_KEY: &str = "PUBLIC KEY";
+pub const PEM_LABEL_RSA_PRIVATE_KEY: &str = "RSA PRIVATE KEY";
+pub const PEM_LABEL_PRIVATE_KEY: &str = "PRIVATE KEY";
+
+/// Container format for a key file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFormat {
+    /// PKCS#1 `RSAPublicKey` / `RSAPrivateKey` (RFC 8017 Appendix A.1)
+    Pkcs1,
+    /// SubjectPublicKeyInfo (RFC 5280) for public keys, PKCS#8 `PrivateKeyInfo` (RFC 5208) for private keys
+    Pkcs8,
+}
+
+/// Text formats a public key can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicKeyFormat {
+    /// PEM `RSA PUBLIC KEY`
+    Pkcs1,
+    /// PEM `PUBLIC KEY` (SubjectPublicKeyInfo)
+    Spki,
+    /// JSON Web Key
+    Jwk,
+    /// `ssh-rsa` line for `authorized_keys`
+    OpenSsh,
+}
+
+/// A key read from a file, which may hold either half
+#[derive(Clone)]
+pub enum DecodedKey {
+    Public(RsaPublicKey),
+    Private(RsaKeyComponents),
+}
+
+impl DecodedKey {
+    pub fn public_key(&self) -> RsaPublicKey {
+        match self {
+            DecodedKey::Public(key) => key.clone(),
+            DecodedKey::Private(components) => components.public_key(),
+        }
+    }
+}
+
+/// AlgorithmIdentifier { rsaEncryption, NULL }
+fn rsa_algorithm_identifier() -> Vec<u8> {
+    encode_sequence(&[encode_oid(OID_RSA_ENCRYPTION), encode_null()])
+}
+
+// ============================================================================
+// DER Encoding
+// ============================================================================
+
+/// RSAPublicKey ::= SEQUENCE { modulus INTEGER, publicExponent INTEGER }
+pub fn encode_public_key_pkcs1(key: &RsaPublicKey) -> Vec<u8> {
+    encode_sequence(&[encode_integer(key.n), encode_integer(key.e)])
+}
+
+/// SubjectPublicKeyInfo ::= SEQUENCE { algorithm AlgorithmIdentifier, subjectPublicKey BIT STRING }
+pub fn encode_public_key_spki(key: &RsaPublicKey) -> Vec<u8> {
+    encode_sequence(&[rsa_algorithm_identifier(), encode_bit_string(&encode_public_key_pkcs1(key))])
+}
+
+/// RSAPrivateKey ::= SEQUENCE { version, n, e, d, p, q, d mod (p-1), d mod (q-1), q⁻¹ mod p }
+pub fn encode_private_key_pkcs1(key: &RsaKeyComponents) -> Vec<u8> {
+    encode_sequence(&[
+        encode_integer(0),
+        encode_integer(key.n),
+        encode_integer(key.e),
+        encode_integer(key.d),
+        encode_integer(key.p),
+        encode_integer(key.q),
+        encode_integer(key.dp),
+        encode_integer(key.dq),
+        encode_integer(key.qinv),
+    ])
+}
+
+/// PrivateKeyInfo ::= SEQUENCE { version 0, privateKeyAlgorithm, privateKey OCTET STRING (RSAPrivateKey) }
+pub fn encode_private_key_pkcs8(key: &RsaKeyComponents) -> Vec<u8> {
+    encode_sequence(&[
+        encode_integer(0),
+        rsa_algorithm_identifier(),
+        encode_octet_string(&encode_private_key_pkcs1(key)),
+    ])
+}
+
+pub fn public_key_to_pem(key: &RsaPublicKey, format: KeyFormat) -> String {
+    match format {
+        KeyFormat::Pkcs1 => encode_pem(PEM_LABEL_RSA_PUBLIC_KEY, &encode_public_key_pkcs1(key)),
+        KeyFormat::Pkcs8 => encode_pem(PEM_LABEL_PUBLIC_KEY, &encode_public_key_spki(key)),
+    }
+}
+
+pub fn private_key_to_pem(key: &RsaKeyComponents, format: KeyFormat) -> String {
+    match format {
+        KeyFormat::Pkcs1 => encode_pem(PEM_LABEL_RSA_PRIVATE_KEY, &encode_private_key_pkcs1(key)),
+        KeyFormat::Pkcs8 => encode_pem(PEM_LABEL_PRIVATE_KEY, &encode_private_key_pkcs8(key)),
+    }
+}
+
+/// Render a public key in any supported text format; `comment` only applies to OpenSSH
+pub fn export_public_key(key: &RsaPublicKey, format: PublicKeyFormat, comment: Option<&str>) -> String {
+    match format {
+        PublicKeyFormat::Pkcs1 => public_key_to_pem(key, KeyFormat::Pkcs1),
+        PublicKeyFormat::Spki => public_key_to_pem(key, KeyFormat::Pkcs8),
+        PublicKeyFormat::Jwk => public_key_to_jwk_json(key) + "\n",
+        PublicKeyFormat::OpenSsh => public_key_to_openssh(key, comment) + "\n",
+    }
+}
+
+// ============================================================================
+// DER Decoding
+// ============================================================================
+
+fn expect_whole_sequence(der: &[u8]) -> Result<Vec<DerElement<'_>>, String> {
+    let (sequence, rest) = expect_element(der, TAG_SEQUENCE)?;
+    if !rest.is_empty() {
+        return Err(format!("{} trailing bytes after key", rest.len()));
+    }
+    read_children(sequence.content)
+}
+
+fn check_rsa_algorithm(content: &[u8]) -> Result<(), String> {
+    let children = read_children(content)?;
+    let oid = children.first().ok_or("Empty AlgorithmIdentifier")?;
+    if decode_oid(oid)? != OID_RSA_ENCRYPTION {
+        return Err("Key algorithm is not rsaEncryption".to_string());
+    }
+    Ok(())
+}
+
+pub fn decode_public_key_pkcs1(der: &[u8]) -> Result<RsaPublicKey, String> {
+    let fields = expect_whole_sequence(der)?;
+    if fields.len() != 2 {
+        return Err(format!("RSAPublicKey has {} fields, expected 2", fields.len()));
+    }
+    Ok(RsaPublicKey {
+        n: decode_integer(&fields[0])?,
+        e: decode_integer(&fields[1])?,
+    })
+}
+
+pub fn decode_public_key_spki(der: &[u8]) -> Result<RsaPublicKey, String> {
+    let fields = expect_whole_sequence(der)?;
+    let [algorithm, key] = fields.as_slice() else {
+        return Err("SubjectPublicKeyInfo must have 2 fields".to_string());
+    };
+    if algorithm.tag != TAG_SEQUENCE || key.tag != TAG_BIT_STRING {
+        return Err("Malformed SubjectPublicKeyInfo".to_string());
+    }
+    check_rsa_algorithm(algorithm.content)?;
+
+    match key.content.split_first() {
+        Some((0, inner)) => decode_public_key_pkcs1(inner),
+        _ => Err("subjectPublicKey BIT STRING must have 0 unused bits".to_string()),
+    }
+}
+
+pub fn decode_private_key_pkcs1(der: &[u8]) -> Result<RsaKeyComponents, String> {
+    let fields = expect_whole_sequence(der)?;
+    if fields.len() != 9 {
+        return Err(format!("RSAPrivateKey has {} fields, expected 9 (two-prime)", fields.len()));
+    }
+    let values: Vec<u64> = fields.iter().map(decode_integer).collect::<Result<_, _>>()?;
+    if values[0] != 0 {
+        return Err(format!("Unsupported RSAPrivateKey version {}", values[0]));
+    }
+
+    Ok(RsaKeyComponents {
+        n: values[1],
+        e: values[2],
+        d: values[3],
+        p: values[4],
+        q: values[5],
+        dp: values[6],
+        dq: values[7],
+        qinv: values[8],
+    })
+}
+
+pub fn decode_private_key_pkcs8(der: &[u8]) -> Result<RsaKeyComponents, String> {
+    let fields = expect_whole_sequence(der)?;
+    if fields.len() < 3 {
+        return Err("PrivateKeyInfo must have at least 3 fields".to_string());
+    }
+    if decode_integer(&fields[0])? != 0 {
+        return Err("Unsupported PrivateKeyInfo version".to_string());
+    }
+    if fields[1].tag != TAG_SEQUENCE || fields[2].tag != TAG_OCTET_STRING {
+        return Err("Malformed PrivateKeyInfo".to_string());
+    }
+    check_rsa_algorithm(fields[1].content)?;
+    decode_private_key_pkcs1(fields[2].content)
+}
+
+/// Decode PEM by its label, a JWK or `ssh-rsa` line by its text, or raw DER by trying each structure in turn
+pub fn decode_key(bytes: &[u8]) -> Result<DecodedKey, String> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        let text = text.trim_start();
+        if text.starts_with('{') {
+            return public_key_from_jwk_json(text).map(DecodedKey::Public);
+        }
+        if text.split_whitespace().any(|field| field == SSH_RSA) {
+            return public_key_from_openssh(text).map(|(key, _)| DecodedKey::Public(key));
+        }
+    }
+
+    if is_pem(bytes) {
+        let text = std::str::from_utf8(bytes).map_err(|_| "PEM file is not valid UTF-8".to_string())?;
+        let (label, der) = decode_pem(text)?;
+        return match label.as_str() {
+            PEM_LABEL_RSA_PUBLIC_KEY => decode_public_key_pkcs1(&der).map(DecodedKey::Public),
+            PEM_LABEL_PUBLIC_KEY => decode_public_key_spki(&der).map(DecodedKey::Public),
+            PEM_LABEL_RSA_PRIVATE_KEY => decode_private_key_pkcs1(&der).map(DecodedKey::Private),
+            PEM_LABEL_PRIVATE_KEY => decode_private_key_pkcs8(&der).map(DecodedKey::Private),
+            other => Err(format!("Unsupported PEM label \"{}\"", other)),
+        };
+    }
+
+    decode_private_key_pkcs8(bytes)
+        .or_else(|_| decode_private_key_pkcs1(bytes))
+        .map(DecodedKey::Private)
+        .or_else(|_| decode_public_key_spki(bytes).map(DecodedKey::Public))
+        .or_else(|_| decode_public_key_pkcs1(bytes).map(DecodedKey::Public))
+        .map_err(|_| "Input is not a PKCS#1, PKCS#8, SubjectPublicKeyInfo, JWK or OpenSSH RSA key".to_string())
+}
+
+// ============================================================================
+// Unsigned Big-Endian Integers
+// ============================================================================
+// JWK and the SSH wire format carry key components as raw byte strings
+// rather than DER INTEGERs.
+
+/// Minimal big-endian bytes of a value (a single 0x00 for zero)
+pub fn to_unsigned_bytes(value: u64) -> Vec<u8> {
+    let bytes: Vec<u8> = value.to_be_bytes().into_iter().skip_while(|&b| b == 0).collect();
+    if bytes.is_empty() { vec![0] } else { bytes }
+}
+
+/// Read big-endian bytes, ignoring leading zeros, into a u64
+pub fn from_unsigned_bytes(bytes: &[u8]) -> Result<u64, String> {
+    let significant: Vec<u8> = bytes.iter().copied().skip_while(|&b| b == 0).collect();
+    if significant.len() > 8 {
+        return Err(format!(
+            "Value of {} bits is too large for this demo (max 64 bits)",
+            significant.len() * 8
+        ));
+    }
+    Ok(significant.iter().fold(0u64, |acc, &b| acc << 8 | b as u64))
+}
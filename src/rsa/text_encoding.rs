@@ -1,4 +1,5 @@
 use super::constants::MAX_TEXT_LENGTH;
+use crate::common::validation::is_printable_ascii;
 
 /// Convert text to a number for RSA encryption
 /// Returns None if text is empty or longer than MAX_TEXT_LENGTH
@@ -35,3 +36,19 @@ pub fn number_to_text(number: u64, length: usize) -> String {
     bytes.reverse();
     String::from_utf8_lossy(&bytes).to_string()
 }
+
+/// Parse a message the way the CLI does: a number if it parses, otherwise text
+pub fn parse_message(message: &str) -> Option<u64> {
+    message.parse().ok().or_else(|| text_to_number(message))
+}
+
+/// Show a decrypted number as text when all of its bytes are printable
+pub fn describe_plaintext(number: u64) -> String {
+    let length = (8 - number.leading_zeros() as usize / 8).max(1);
+    let text = number_to_text(number, length);
+    if is_printable_ascii(text.as_bytes()) {
+        format!("{} (text: \"{}\")", number, text)
+    } else {
+        number.to_string()
+    }
+}
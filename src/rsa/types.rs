@@ -22,3 +22,130 @@ pub struct RsaPrivateKey {
     pub n: u64,
     pub d: u64,
 }
+
+/// Complete private key material, as stored in a PKCS#1 `RSAPrivateKey`
+///
+/// Unlike `RsaPrivateKey` this keeps the primes and the CRT exponents, which
+/// key files carry so that decryption can be done modulo p and q separately.
+#[derive(Clone)]
+pub struct RsaKeyComponents {
+    pub n: u64,
+    pub e: u64,
+    pub d: u64,
+    pub p: u64,
+    pub q: u64,
+    /// d mod (p-1)
+    pub dp: u64,
+    /// d mod (q-1)
+    pub dq: u64,
+    /// q⁻¹ mod p
+    pub qinv: u64,
+}
+
+impl RsaKeyComponents {
+    pub fn public_key(&self) -> RsaPublicKey {
+        RsaPublicKey { n: self.n, e: self.e }
+    }
+
+    pub fn key_pair(&self) -> RsaKeyPair {
+        RsaKeyPair {
+            public_key: self.public_key(),
+            private_key: RsaPrivateKey { n: self.n, d: self.d },
+        }
+    }
+}
+
+/// Which totient the private exponent is computed modulo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Totient {
+    /// φ(n) = ∏(rᵢ-1), as in the original RSA paper
+    Euler,
+    /// λ(n) = lcm(rᵢ-1), the smallest t with m^t ≡ 1 (mod n) for every unit m; used by RFC 8017 and OpenSSL
+    Carmichael,
+}
+
+impl Totient {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Totient::Euler => "φ(n)",
+            Totient::Carmichael => "λ(n)",
+        }
+    }
+}
+
+/// One prime of a multi-prime key with its CRT values (RFC 8017 §3.2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeFactor {
+    pub prime: u64,
+    /// d mod (r-1)
+    pub exponent: u64,
+    /// (r₁ × … × rᵢ₋₁)⁻¹ mod rᵢ, used to fold this prime into the CRT result; 0 for the first prime
+    pub coefficient: u64,
+}
+
+/// Private key for n = r₁ × r₂ × … × r_k
+#[derive(Clone)]
+pub struct MultiPrimeKey {
+    pub n: u64,
+    pub e: u64,
+    pub d: u64,
+    pub totient: Totient,
+    pub factors: Vec<PrimeFactor>,
+}
+
+impl MultiPrimeKey {
+    pub fn public_key(&self) -> RsaPublicKey {
+        RsaPublicKey { n: self.n, e: self.e }
+    }
+
+    pub fn key_pair(&self) -> RsaKeyPair {
+        RsaKeyPair {
+            public_key: self.public_key(),
+            private_key: RsaPrivateKey { n: self.n, d: self.d },
+        }
+    }
+}
+
+/// How key generation picks the public exponent e
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExponentPolicy {
+    /// First of `COMMON_PUBLIC_EXPONENTS` that is coprime to the totient
+    Common,
+    /// Smallest odd e > 1 coprime to the totient
+    Smallest,
+    /// Only 65537 (F4), as OpenSSL, GPG and SSH do
+    F4,
+    /// A random odd e coprime to the totient
+    Random,
+    /// Exactly this e, rejected if it is not a valid exponent
+    Explicit(u64),
+}
+
+impl ExponentPolicy {
+    pub fn name(&self) -> String {
+        match self {
+            ExponentPolicy::Common => "first common exponent".to_string(),
+            ExponentPolicy::Smallest => "smallest coprime".to_string(),
+            ExponentPolicy::F4 => "65537 only".to_string(),
+            ExponentPolicy::Random => "random coprime".to_string(),
+            ExponentPolicy::Explicit(e) => format!("explicit e={}", e),
+        }
+    }
+}
+
+impl std::str::FromStr for ExponentPolicy {
+    type Err = String;
+
+    /// Accepts `common`, `smallest`, `f4`/`65537-only`, `random` or a number
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "common" => Ok(ExponentPolicy::Common),
+            "smallest" => Ok(ExponentPolicy::Smallest),
+            "f4" | "65537-only" => Ok(ExponentPolicy::F4),
+            "random" => Ok(ExponentPolicy::Random),
+            number => number.parse().map(ExponentPolicy::Explicit).map_err(|_| {
+                format!("Invalid public exponent '{}': use a number, common, smallest, f4 or random", value)
+            }),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use super::math_utils::is_prime;
+use super::constants::F4;
+use super::math_utils::{gcd, is_prime, wiener_bound};
+use super::types::RsaKeyPair;
 
 pub(super) fn validate_primes(p: u64, q: u64) {
     assert!(
@@ -10,7 +12,59 @@ pub(super) fn validate_primes(p: u64, q: u64) {
     assert_ne!(p, q, "p and q must be different (got p={}, q={})", p, q);
 }
 
+pub(super) fn validate_multi_primes(primes: &[u64]) {
+    assert!(primes.len() >= 2, "Multi-prime RSA needs at least 2 primes (got {})", primes.len());
+    for (i, &r) in primes.iter().enumerate() {
+        assert!(is_prime(r), "r{}={} must be a prime number", i + 1, r);
+        assert!(!primes[..i].contains(&r), "Primes must be different (r{}={} repeats)", i + 1, r);
+    }
+    assert!(
+        primes.iter().try_fold(1u64, |acc, &r| acc.checked_mul(r)).is_some(),
+        "n = {} must fit in 64 bits",
+        primes.iter().map(u64::to_string).collect::<Vec<_>>().join(" × ")
+    );
+}
 
 pub(super) fn is_valid_message_size(message: u64, n: u64) -> bool {
     message < n
 }
+
+/// Check that e is usable with this φ(n): odd, 1 < e < φ(n) and gcd(e, φ(n)) = 1
+pub fn validate_public_exponent(e: u64, phi_n: u64) -> Result<(), String> {
+    if e <= 1 {
+        return Err(format!("e={} must be greater than 1", e));
+    }
+    if e >= phi_n {
+        return Err(format!("e={} must be smaller than φ(n)={}", e, phi_n));
+    }
+    if e.is_multiple_of(2) {
+        return Err(format!("e={} must be odd: φ(n) is even, so an even e is never coprime to it", e));
+    }
+    match gcd(e, phi_n) {
+        1 => Ok(()),
+        common => Err(format!("e={} shares the factor {} with φ(n)={}, so it has no inverse", e, common, phi_n)),
+    }
+}
+
+/// Weaknesses of a valid key pair that do not stop it from working
+pub fn exponent_warnings(key_pair: &RsaKeyPair) -> Vec<String> {
+    let (n, e, d) = (key_pair.public_key.n, key_pair.public_key.e, key_pair.private_key.d);
+    let mut warnings = Vec::new();
+
+    if e < F4 {
+        warnings.push(format!(
+            "e={} is small: textbook RSA with a small e is open to the cube-root, Håstad and Franklin-Reiter attacks (65537 is the usual choice)",
+            e
+        ));
+    }
+    if e > u32::MAX as u64 {
+        warnings.push(format!("e={} does not fit in 32 bits, which some implementations (e.g. Windows CryptoAPI) reject", e));
+    }
+    if d == e {
+        warnings.push(format!("d = e = {}: the private exponent equals the public one, so anyone can decrypt", d));
+    }
+    if d <= wiener_bound(n) {
+        warnings.push(format!("d={} is below n^¼/3 = {}: Wiener's attack recovers it from (n, e)", d, wiener_bound(n)));
+    }
+    warnings
+}
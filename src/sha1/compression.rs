@@ -0,0 +1,99 @@
+use super::constants::ROUND_CONSTANTS;
+use super::types::{Block, Hash, Word};
+use crate::sha256::math_utils::{ch, maj};
+
+/// Name of the boolean function used in each 20-round stage
+pub const STAGE_FUNCTIONS: [&str; 4] = ["Ch", "Parity", "Maj", "Parity"];
+
+pub fn parity(x: Word, y: Word, z: Word) -> Word {
+    x ^ y ^ z
+}
+
+/// f_t from FIPS 180-4 §4.1.1
+pub fn round_function(t: usize, b: Word, c: Word, d: Word) -> Word {
+    match t / 20 {
+        0 => ch(b, c, d),
+        2 => maj(b, c, d),
+        _ => parity(b, c, d),
+    }
+}
+
+pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
+    // Prepare message schedule
+    let mut w = [0u32; 80];
+
+    // Copy block into first 16 words
+    w[0..16].copy_from_slice(block);
+
+    // Extend to 80 words; the rotate by one is the only change from SHA-0
+    for t in 16..80 {
+        w[t] = (w[t - 3] ^ w[t - 8] ^ w[t - 14] ^ w[t - 16]).rotate_left(1);
+    }
+
+    if show_steps {
+        println!("\n  Message Schedule (W[0] to W[79]):");
+        for (i, word) in w.iter().enumerate() {
+            if i < 16 {
+                println!("    W[{:2}] = 0x{:08x} (from block)", i, word);
+            } else {
+                println!("    W[{:2}] = 0x{:08x} (computed)", i, word);
+            }
+        }
+    }
+
+    // Initialize working variables
+    let [mut a, mut b, mut c, mut d, mut e] = hash;
+
+    if show_steps {
+        println!("\n  Initial working variables:");
+        println!("    a = 0x{:08x}, b = 0x{:08x}, c = 0x{:08x}, d = 0x{:08x}, e = 0x{:08x}", a, b, c, d, e);
+    }
+
+    // Main loop (80 rounds in four stages of 20)
+    for t in 0..80 {
+        let f = round_function(t, b, c, d);
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(ROUND_CONSTANTS[t / 20])
+            .wrapping_add(w[t]);
+
+        if show_steps && (!(5..75).contains(&t) || t % 20 == 0 || t % 20 == 19) {
+            println!("\n  Round {} ({}):", t + 1, STAGE_FUNCTIONS[t / 20]);
+            println!("    T = ROTL⁵(a) + {}(b,c,d) + e + K[{}] + W[{}]", STAGE_FUNCTIONS[t / 20], t / 20, t);
+            println!("      = 0x{:08x} + 0x{:08x} + 0x{:08x} + 0x{:08x} + 0x{:08x}",
+                     a.rotate_left(5), f, e, ROUND_CONSTANTS[t / 20], w[t]);
+            println!("      = 0x{:08x}", temp);
+        }
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+
+        if show_steps && (!(5..75).contains(&t) || t % 20 == 0 || t % 20 == 19) {
+            println!("    New values: a=0x{:08x}, c=ROTL³⁰(b)=0x{:08x}", a, c);
+        }
+    }
+
+    // Add compressed chunk to current hash value
+    let result = [
+        hash[0].wrapping_add(a),
+        hash[1].wrapping_add(b),
+        hash[2].wrapping_add(c),
+        hash[3].wrapping_add(d),
+        hash[4].wrapping_add(e),
+    ];
+
+    if show_steps {
+        println!("\n  Final addition:");
+        for i in 0..5 {
+            println!("    H[{}] = 0x{:08x} + 0x{:08x} = 0x{:08x}",
+                     i, hash[i], [a, b, c, d, e][i], result[i]);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,7 @@
+use super::types::{Hash, Word};
+
+// Initial hash values (FIPS 180-4 §5.3.1), the counting pattern 0123456789abcdeffedcba9876543210f0e1d2c3
+pub const INITIAL_HASH: Hash = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+// One constant per 20-round stage: ⌊2³⁰ × √2⌋, ⌊2³⁰ × √3⌋, ⌊2³⁰ × √5⌋, ⌊2³⁰ × √10⌋
+pub const ROUND_CONSTANTS: [Word; 4] = [0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xca62c1d6];
@@ -0,0 +1,63 @@
+use super::constants::INITIAL_HASH;
+use super::compression::compress_block;
+use crate::sha256::math_utils::words_to_hex;
+use crate::sha256::preprocessing::{preprocess_message, print_preprocessing_steps};
+use crate::sha256::validation::validate_message;
+
+fn run_sha1_demo_internal(message_text: Option<&str>, message_bytes: &[u8]) {
+    // Header
+    println!("🔐 SHA-1 Hash Algorithm Demo");
+    println!("⚠️  SHA-1 is broken: real collisions exist (SHAttered, 2017). Do not use it for signatures.");
+    if let Some(text) = message_text {
+        println!("Message: \"{}\"", text);
+    }
+    println!("Message bytes: {:02x?}", message_bytes);
+    println!("Message length: {} bytes", message_bytes.len());
+
+    if let Err(e) = validate_message(message_bytes) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+
+    // Initial values
+    println!("\n=== Initial Hash Values ===");
+    for (i, word) in INITIAL_HASH.iter().enumerate() {
+        println!("  H{} = 0x{:08x}", ['₀', '₁', '₂', '₃', '₄'][i], word);
+    }
+
+    // Preprocess message (identical to SHA-256)
+    let processed = preprocess_message(message_bytes);
+    print_preprocessing_steps(message_bytes, &processed);
+
+    // Process each block
+    let mut hash = INITIAL_HASH;
+
+    for (i, block) in processed.blocks.iter().enumerate() {
+        println!("\n=== Compression Function (Block {}/{}) ===", i + 1, processed.blocks.len());
+        hash = compress_block(hash, block, true);
+
+        println!("\n  Hash after block {}:", i + 1);
+        for (j, word) in hash.iter().enumerate() {
+            println!("    H[{}] = 0x{:08x}", j, word);
+        }
+    }
+
+    // Final hash
+    println!("\n=== Final Hash ===");
+    println!("  SHA-1: {}", words_to_hex(&hash));
+
+    println!("\n✅ SHA-1 hash computation completed!");
+    println!("💡 Tip: Run `hash-collisions` to see two different files with this same kind of digest");
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+pub fn run_sha1_demo(message_bytes: Vec<u8>) {
+    run_sha1_demo_internal(None, &message_bytes);
+}
+
+pub fn run_sha1_demo_text(text: &str) {
+    run_sha1_demo_internal(Some(text), text.as_bytes());
+}
@@ -0,0 +1,24 @@
+use super::compression::compress_block;
+use super::constants::INITIAL_HASH;
+use super::types::Hash;
+use crate::sha256::preprocessing::preprocess_message;
+
+/// Compute the SHA-1 hash of a message without printing any steps
+///
+/// SHA-1 pads and splits messages exactly like SHA-256, so the blocks come
+/// from `sha256::preprocessing`.
+pub fn sha1(message: &[u8]) -> Hash {
+    preprocess_message(message)
+        .blocks
+        .iter()
+        .fold(INITIAL_HASH, |hash, block| compress_block(hash, block, false))
+}
+
+/// Compute the SHA-1 digest as 20 big-endian bytes
+pub fn sha1_bytes(message: &[u8]) -> [u8; 20] {
+    let mut digest = [0u8; 20];
+    for (chunk, word) in digest.chunks_mut(4).zip(sha1(message)) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
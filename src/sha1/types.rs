@@ -0,0 +1,3 @@
+pub use crate::sha256::types::{Block, Word};
+
+pub type Hash = [Word; 5];
@@ -1,9 +1,9 @@
 use super::constants::ROUND_CONSTANTS;
-use super::types::{Block, Hash};
+use super::types::{Block, Hash, Word};
 use super::math_utils::{ch, maj, sigma0, sigma1, gamma0, gamma1};
 
-pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
-    // Prepare message schedule
+/// Expand a block into the 64-word message schedule W
+pub fn message_schedule(block: &Block) -> [Word; 64] {
     let mut w = [0u32; 64];
 
     // Copy block into first 16 words
@@ -16,6 +16,12 @@ pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
             .wrapping_add(gamma0(w[t - 15]))
             .wrapping_add(w[t - 16]);
     }
+    w
+}
+
+pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
+    // Prepare message schedule
+    let w = message_schedule(block);
 
     if show_steps {
         println!("\n  Message Schedule (W[0] to W[63]):");
@@ -29,9 +35,10 @@ pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
     }
 
     // Initialize working variables
-    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = hash;
+    let mut state = hash;
 
     if show_steps {
+        let [a, b, c, d, e, f, g, h] = state;
         println!("\n  Initial working variables:");
         println!("    a = 0x{:08x}, b = 0x{:08x}, c = 0x{:08x}, d = 0x{:08x}", a, b, c, d);
         println!("    e = 0x{:08x}, f = 0x{:08x}, g = 0x{:08x}, h = 0x{:08x}", e, f, g, h);
@@ -39,53 +46,59 @@ pub fn compress_block(hash: Hash, block: &Block, show_steps: bool) -> Hash {
 
     // Main loop (64 rounds)
     for t in 0..64 {
-        let t1 = h
-            .wrapping_add(sigma1(e))
-            .wrapping_add(ch(e, f, g))
-            .wrapping_add(ROUND_CONSTANTS[t])
-            .wrapping_add(w[t]);
+        let [_, _, _, d, e, f, g, h] = state;
+        state = round(state, ROUND_CONSTANTS[t], w[t]);
 
-        let t2 = sigma0(a).wrapping_add(maj(a, b, c));
-
-        h = g;
-        g = f;
-        f = e;
-        e = d.wrapping_add(t1);
-        d = c;
-        c = b;
-        b = a;
-        a = t1.wrapping_add(t2);
-
-        if show_steps && (t < 8 || t >= 56 || t % 8 == 7) {
+        if show_steps && (!(8..56).contains(&t) || t % 8 == 7) {
+            // New e = d + T1 and new a = T1 + T2
+            let t1 = state[4].wrapping_sub(d);
+            let t2 = state[0].wrapping_sub(t1);
             println!("\n  Round {}:", t + 1);
             println!("    T1 = h + Σ₁(e) + Ch(e,f,g) + K[{}] + W[{}]", t, t);
             println!("       = 0x{:08x} + Σ₁(0x{:08x}) + Ch(0x{:08x},0x{:08x},0x{:08x}) + 0x{:08x} + 0x{:08x}",
-                     hash[7 - (t % 8)], e, e, f, g, ROUND_CONSTANTS[t], w[t]);
+                     h, e, e, f, g, ROUND_CONSTANTS[t], w[t]);
             println!("       = 0x{:08x}", t1);
             println!("    T2 = Σ₀(a) + Maj(a,b,c) = 0x{:08x}", t2);
-            println!("    New values: a=0x{:08x}, e=0x{:08x}", a, e);
+            println!("    New values: a=0x{:08x}, e=0x{:08x}", state[0], state[4]);
         }
     }
 
     // Add compressed chunk to current hash value
-    let result = [
-        hash[0].wrapping_add(a),
-        hash[1].wrapping_add(b),
-        hash[2].wrapping_add(c),
-        hash[3].wrapping_add(d),
-        hash[4].wrapping_add(e),
-        hash[5].wrapping_add(f),
-        hash[6].wrapping_add(g),
-        hash[7].wrapping_add(h),
-    ];
+    let result: Hash = std::array::from_fn(|i| hash[i].wrapping_add(state[i]));
 
     if show_steps {
         println!("\n  Final addition:");
         for i in 0..8 {
             println!("    H[{}] = 0x{:08x} + 0x{:08x} = 0x{:08x}",
-                     i, hash[i], [a, b, c, d, e, f, g, h][i], result[i]);
+                     i, hash[i], state[i], result[i]);
         }
     }
 
     result
-}
\ No newline at end of file
+}
+
+/// Working variables a..h after each of the 64 rounds, before the final addition
+///
+/// Lets analysis code watch a difference spread through the state round by round.
+pub fn round_states(hash: Hash, block: &Block) -> Vec<Hash> {
+    let w = message_schedule(block);
+    let mut state = hash;
+    (0..64)
+        .map(|t| {
+            state = round(state, ROUND_CONSTANTS[t], w[t]);
+            state
+        })
+        .collect()
+}
+
+/// One round on the working variables a..h with round constant `k` and schedule word `w`
+pub fn round(state: Hash, k: Word, w: Word) -> Hash {
+    let [a, b, c, d, e, f, g, h] = state;
+    let t1 = h
+        .wrapping_add(sigma1(e))
+        .wrapping_add(ch(e, f, g))
+        .wrapping_add(k)
+        .wrapping_add(w);
+    let t2 = sigma0(a).wrapping_add(maj(a, b, c));
+    [t1.wrapping_add(t2), a, b, c, d.wrapping_add(t1), e, f, g]
+}
@@ -0,0 +1,21 @@
+use super::compression::compress_block;
+use super::constants::INITIAL_HASH;
+use super::preprocessing::preprocess_message;
+use super::types::Hash;
+
+/// Compute the SHA-256 hash of a message without printing any steps
+pub fn sha256(message: &[u8]) -> Hash {
+    preprocess_message(message)
+        .blocks
+        .iter()
+        .fold(INITIAL_HASH, |hash, block| compress_block(hash, block, false))
+}
+
+/// Compute the SHA-256 digest as 32 big-endian bytes
+pub fn sha256_bytes(message: &[u8]) -> [u8; 32] {
+    let mut digest = [0u8; 32];
+    for (chunk, word) in digest.chunks_mut(4).zip(sha256(message)) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
@@ -0,0 +1,26 @@
+use super::hash::sha256_bytes;
+
+/// SHA-256 processes 64-byte blocks, so HMAC pads keys to this length
+pub const BLOCK_BYTES: usize = 64;
+
+/// HMAC-SHA256 (RFC 2104): H((K ⊕ opad) ‖ H((K ⊕ ipad) ‖ message))
+///
+/// Keys longer than a block are hashed first; shorter ones are zero-padded.
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block_key = [0u8; BLOCK_BYTES];
+    if key.len() > BLOCK_BYTES {
+        block_key[..32].copy_from_slice(&sha256_bytes(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let pad = |byte: u8| block_key.iter().map(|k| k ^ byte).collect::<Vec<u8>>();
+    let inner = sha256_bytes(&[pad(0x36).as_slice(), message].concat());
+    sha256_bytes(&[pad(0x5c).as_slice(), &inner].concat())
+}
+
+/// Compare a received tag without stopping at the first differing byte
+pub fn verify_hmac_sha256(key: &[u8], message: &[u8], tag: &[u8]) -> bool {
+    let expected = hmac_sha256(key, message);
+    tag.len() == expected.len() && tag.iter().zip(expected).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
@@ -0,0 +1,38 @@
+// ============================================================================
+// Length Extension
+// ============================================================================
+// A SHA-256 digest *is* the chaining value after the last block. Anyone who
+// knows H(secret ‖ message) and the length of secret ‖ message can keep
+// compressing from it, and so compute H(secret ‖ message ‖ glue ‖ extension)
+// without the secret. `glue` is the padding the original message received.
+
+use super::compression::compress_block;
+use super::math_utils::bytes_to_words;
+use super::preprocessing::{pad_message, LengthEncoding};
+use super::types::Hash;
+
+/// The padding SHA-256 appended to a message of `original_length` bytes
+pub fn glue_padding(original_length: usize) -> Vec<u8> {
+    pad_message(&vec![0; original_length], LengthEncoding::BigEndian).split_off(original_length)
+}
+
+/// Forge H(secret ‖ message ‖ glue ‖ extension) from H(secret ‖ message) alone
+///
+/// Returns the glue padding and the forged digest.
+pub fn extend(digest: Hash, original_length: usize, extension: &[u8]) -> (Vec<u8>, Hash) {
+    let glue = glue_padding(original_length);
+    let total_bits = ((original_length + glue.len() + extension.len()) as u64) * 8;
+
+    // The prefix is whole blocks, so the extension pads as if it stood alone,
+    // except that the length field counts the whole forged message
+    let mut tail = pad_message(extension, LengthEncoding::BigEndian);
+    let length_offset = tail.len() - 8;
+    tail[length_offset..].copy_from_slice(&total_bits.to_be_bytes());
+
+    let forged = bytes_to_words(&tail).chunks(16).fold(digest, |hash, words| {
+        let mut block = [0u32; 16];
+        block.copy_from_slice(words);
+        compress_block(hash, &block, false)
+    });
+    (glue, forged)
+}
@@ -1,7 +1,7 @@
 use super::types::Word;
 
 pub fn rotr(x: Word, n: u32) -> Word {
-    (x >> n) | (x << (32 - n))
+    x.rotate_right(n)
 }
 
 pub fn ch(x: Word, y: Word, z: Word) -> Word {
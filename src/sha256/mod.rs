@@ -1,7 +1,11 @@
 pub mod constants;
 pub mod demo;
 pub mod compression;
+pub mod hash;
+pub mod hmac;
+pub mod length_extension;
 pub mod preprocessing;
+pub mod reduced;
 pub mod math_utils;
 pub mod types;
-pub mod validation;
\ No newline at end of file
+pub mod validation;
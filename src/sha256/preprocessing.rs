@@ -1,9 +1,21 @@
 use super::types::{Block, ProcessedMessage};
 use super::math_utils::bytes_to_words;
 
-pub fn preprocess_message(message: &[u8]) -> ProcessedMessage {
-    let original_length = message.len() as u64;
-    let bit_length = original_length * 8;
+/// Byte order of the 64-bit length field at the end of the padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthEncoding {
+    /// SHA-1 and SHA-2
+    BigEndian,
+    /// MD5
+    LittleEndian,
+}
+
+/// Merkle–Damgård strengthening shared by SHA-256, SHA-1 and MD5
+///
+/// Appends 0x80, zeros up to 56 (mod 64) bytes and the message length in bits,
+/// so the result is a whole number of 64-byte blocks.
+pub fn pad_message(message: &[u8], length_encoding: LengthEncoding) -> Vec<u8> {
+    let bit_length = (message.len() as u64) * 8;
 
     let mut padded = message.to_vec();
 
@@ -15,8 +27,17 @@ pub fn preprocess_message(message: &[u8]) -> ProcessedMessage {
         padded.push(0x00);
     }
 
-    // Append original length as 64-bit big-endian integer
-    padded.extend_from_slice(&bit_length.to_be_bytes());
+    // Append original length as a 64-bit integer
+    match length_encoding {
+        LengthEncoding::BigEndian => padded.extend_from_slice(&bit_length.to_be_bytes()),
+        LengthEncoding::LittleEndian => padded.extend_from_slice(&bit_length.to_le_bytes()),
+    }
+
+    padded
+}
+
+pub fn preprocess_message(message: &[u8]) -> ProcessedMessage {
+    let padded = pad_message(message, LengthEncoding::BigEndian);
 
     // Convert to 32-bit words and group into 512-bit blocks
     let words = bytes_to_words(&padded);
@@ -31,11 +52,24 @@ pub fn preprocess_message(message: &[u8]) -> ProcessedMessage {
 
     ProcessedMessage {
         blocks,
-        original_length,
+        original_length: message.len() as u64,
     }
 }
 
 pub fn print_preprocessing_steps(message: &[u8], processed: &ProcessedMessage) {
+    print_padding_steps(message, processed.blocks.len());
+
+    // Show first block in detail
+    if !processed.blocks.is_empty() {
+        println!("\n  First block (16 × 32-bit words):");
+        for (i, word) in processed.blocks[0].iter().enumerate() {
+            println!("    W[{:2}] = 0x{:08x}", i, word);
+        }
+    }
+}
+
+/// Print the padding arithmetic, which is the same for every Merkle–Damgård hash here
+pub fn print_padding_steps(message: &[u8], block_count: usize) {
     println!("\n=== Message Preprocessing ===");
     println!("  Original message: {:?}", String::from_utf8_lossy(message));
     println!("  Original bytes: {:02x?}", message);
@@ -44,20 +78,12 @@ pub fn print_preprocessing_steps(message: &[u8], processed: &ProcessedMessage) {
     // Show padding calculation
     let bit_length = message.len() * 8;
     let after_one_bit = bit_length + 8; // +8 for the '1' bit (0x80 byte)
-    let target_length = ((after_one_bit + 64 + 511) / 512) * 512; // Round up to next 512-bit boundary
+    let target_length = (after_one_bit + 64).div_ceil(512) * 512; // Round up to next 512-bit boundary
     let zero_padding_bits = target_length - 64 - after_one_bit; // -64 for length field
 
     println!("  After adding '1' bit: {} bits", after_one_bit);
     println!("  Zero padding needed: {} bits ({} bytes)", zero_padding_bits, zero_padding_bits / 8);
     println!("  Final length field: 64 bits (8 bytes)");
     println!("  Total padded length: {} bits ({} bytes)", target_length, target_length / 8);
-    println!("  Number of 512-bit blocks: {}", processed.blocks.len());
-
-    // Show first block in detail
-    if !processed.blocks.is_empty() {
-        println!("\n  First block (16 × 32-bit words):");
-        for (i, word) in processed.blocks[0].iter().enumerate() {
-            println!("    W[{:2}] = 0x{:08x}", i, word);
-        }
-    }
+    println!("  Number of 512-bit blocks: {}", block_count);
 }
\ No newline at end of file
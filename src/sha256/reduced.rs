@@ -0,0 +1,124 @@
+// ============================================================================
+// NON-STANDARD SHA-256: Reduced Rounds and Substitute Constants
+// ============================================================================
+// For cryptanalysis experiments only. With fewer than 64 rounds, or with any
+// other IV or round constants, the output is NOT SHA-256 and offers none of
+// its security. Every digest printed from this module is labelled as such.
+//
+// Padding, the message schedule and the feed-forward addition are unchanged;
+// only the number of rounds run and the K[t] / H(0) values differ.
+
+use super::compression::{message_schedule, round};
+use super::constants::{INITIAL_HASH, ROUND_CONSTANTS};
+use super::preprocessing::preprocess_message;
+use super::types::{Block, Hash, Word};
+
+/// Rounds in standard SHA-256
+pub const FULL_ROUNDS: usize = 64;
+
+/// A SHA-256 variant with its own round count, IV and round constants
+///
+/// `NonStandardSha256::new(64)` with no substitutions computes real SHA-256;
+/// `is_standard` tells the two cases apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonStandardSha256 {
+    pub rounds: usize,
+    pub initial_hash: Hash,
+    pub round_constants: [Word; 64],
+}
+
+impl NonStandardSha256 {
+    /// Run only the first `rounds` rounds (1 to 64) with the standard IV and constants
+    pub fn new(rounds: usize) -> Result<NonStandardSha256, String> {
+        if !(1..=FULL_ROUNDS).contains(&rounds) {
+            return Err(format!("Rounds must be 1 to {}, got {}", FULL_ROUNDS, rounds));
+        }
+        Ok(NonStandardSha256 { rounds, initial_hash: INITIAL_HASH, round_constants: ROUND_CONSTANTS })
+    }
+
+    /// Start from a different H(0)
+    pub fn with_initial_hash(mut self, initial_hash: Hash) -> NonStandardSha256 {
+        self.initial_hash = initial_hash;
+        self
+    }
+
+    /// Replace K[0], K[1], … with `constants`; the rest stay standard
+    pub fn with_round_constants(mut self, constants: &[Word]) -> Result<NonStandardSha256, String> {
+        if constants.len() > FULL_ROUNDS {
+            return Err(format!("At most {} round constants, got {}", FULL_ROUNDS, constants.len()));
+        }
+        self.round_constants[..constants.len()].copy_from_slice(constants);
+        Ok(self)
+    }
+
+    /// True only for 64 rounds with the FIPS 180-4 IV and constants
+    pub fn is_standard(&self) -> bool {
+        self.rounds == FULL_ROUNDS && self.initial_hash == INITIAL_HASH && self.round_constants == ROUND_CONSTANTS
+    }
+
+    /// One-line label listing every departure from SHA-256
+    pub fn describe(&self) -> String {
+        if self.is_standard() {
+            return "SHA-256 (standard parameters)".to_string();
+        }
+        let mut changes = Vec::new();
+        if self.rounds != FULL_ROUNDS {
+            changes.push(format!("{} of {} rounds", self.rounds, FULL_ROUNDS));
+        }
+        if self.initial_hash != INITIAL_HASH {
+            changes.push("custom IV".to_string());
+        }
+        let replaced = self.round_constants.iter().zip(ROUND_CONSTANTS).filter(|(k, standard)| **k != *standard).count();
+        if replaced > 0 {
+            changes.push(format!("{} custom round constant(s)", replaced));
+        }
+        format!("NON-STANDARD SHA-256 variant: {}", changes.join(", "))
+    }
+
+    /// Working variables after `rounds` rounds, before the feed-forward addition
+    pub fn round_output(&self, hash: Hash, block: &Block) -> Hash {
+        let w = message_schedule(block);
+        (0..self.rounds).fold(hash, |state, t| round(state, self.round_constants[t], w[t]))
+    }
+
+    /// Compress one block: the reduced rounds, then add the chaining value as SHA-256 does
+    pub fn compress(&self, hash: Hash, block: &Block) -> Hash {
+        let state = self.round_output(hash, block);
+        std::array::from_fn(|i| hash[i].wrapping_add(state[i]))
+    }
+
+    /// Hash a message with standard padding and this variant's compression function
+    pub fn hash(&self, message: &[u8]) -> Hash {
+        preprocess_message(message)
+            .blocks
+            .iter()
+            .fold(self.initial_hash, |hash, block| self.compress(hash, block))
+    }
+
+    /// The digest as 32 big-endian bytes
+    pub fn hash_bytes(&self, message: &[u8]) -> [u8; 32] {
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_mut(4).zip(self.hash(message)) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
+/// Parse one 32-bit word from hex, with or without 0x
+pub fn parse_word(text: &str) -> Result<Word, String> {
+    let digits = text.trim().trim_start_matches("0x");
+    if digits.is_empty() || digits.len() > 8 {
+        return Err(format!("'{}' is not a 32-bit hex word", text));
+    }
+    Word::from_str_radix(digits, 16).map_err(|_| format!("'{}' is not a 32-bit hex word", text))
+}
+
+/// Parse an IV given as 64 hex digits (eight words, big-endian)
+pub fn parse_initial_hash(text: &str) -> Result<Hash, String> {
+    let bytes = hex::decode(text.trim().trim_start_matches("0x")).map_err(|_| "IV must be hex".to_string())?;
+    if bytes.len() != 32 {
+        return Err(format!("IV must be 32 bytes (64 hex digits), got {} bytes", bytes.len()));
+    }
+    Ok(std::array::from_fn(|i| Word::from_be_bytes(bytes[4 * i..4 * i + 4].try_into().expect("4 bytes"))))
+}
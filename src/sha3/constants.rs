@@ -0,0 +1,27 @@
+use super::types::Lane;
+
+/// Rounds of Keccak-f[1600]: 12 + 2ℓ with ℓ = log₂(64)
+pub const ROUNDS: usize = 24;
+
+// ρ rotation offsets, indexed x + 5y (FIPS 202 Table 2)
+pub const RHO_OFFSETS: [u32; 25] = [
+     0,  1, 62, 28, 27,
+    36, 44,  6, 55, 20,
+     3, 10, 43, 25, 39,
+    41, 45, 15, 21,  8,
+    18,  2, 61, 56, 14,
+];
+
+// ι round constants, the output of a degree-8 LFSR (FIPS 202 §3.2.5)
+pub const ROUND_CONSTANTS: [Lane; ROUNDS] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// SHA3-d appends the bits 01, SHAKE appends 1111; with the first pad bit this is the byte shown
+pub const SHA3_SUFFIX: u8 = 0x06;
+pub const SHAKE_SUFFIX: u8 = 0x1f;
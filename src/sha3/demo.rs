@@ -0,0 +1,161 @@
+use super::hash::sha3_hash;
+use super::keccak::{keccak_f, print_state};
+use super::sponge::{absorb_block, pad, squeeze_block};
+use super::types::{Sha3Variant, State};
+use crate::sha256::hash::{sha256, sha256_bytes};
+use crate::sha256::length_extension::extend;
+use crate::sha256::math_utils::words_to_hex;
+use crate::sha256::validation::validate_message;
+
+/// Secret prefix used to contrast the MAC constructions H(secret ‖ message)
+const DEMO_SECRET: &[u8] = b"demo-secret";
+const DEMO_EXTENSION: &[u8] = b"&admin=true";
+
+/// Show which lanes belong to the rate (R) and which to the capacity (C)
+fn print_lane_map(rate_bytes: usize) {
+    let rate_lanes = rate_bytes / 8;
+    println!("  Lane map (x →, y ↓), R = rate, C = capacity:");
+    for y in 0..5 {
+        let row: Vec<&str> = (0..5).map(|x| if x + 5 * y < rate_lanes { "R" } else { "C" }).collect();
+        println!("    {}", row.join(" "));
+    }
+}
+
+fn print_length_extension(variant: Sha3Variant, message: &[u8]) {
+    println!("\n=== Length Extension: Merkle–Damgård vs Sponge ===");
+    println!("  Naive MAC: tag = H(secret ‖ message), secret = {:?}", String::from_utf8_lossy(DEMO_SECRET));
+    println!("  The attacker knows the tag and len(secret ‖ message), wants a tag for ... ‖ {:?}",
+             String::from_utf8_lossy(DEMO_EXTENSION));
+
+    let original = [DEMO_SECRET, message].concat();
+
+    // SHA-256: the digest is the full chaining value, so compression just continues
+    let (glue, forged) = extend(sha256(&original), original.len(), DEMO_EXTENSION);
+    let forged_message = [original.as_slice(), &glue, DEMO_EXTENSION].concat();
+    let genuine = sha256_bytes(&forged_message);
+    println!("\n  SHA-256:");
+    println!("    Tag:            {}", hex::encode(sha256_bytes(&original)));
+    println!("    Glue padding:   {} bytes ({}…)", glue.len(), hex::encode(&glue[..glue.len().min(8)]));
+    println!("    Forged tag:     {}", words_to_hex(&forged));
+    println!("    Real H(secret ‖ message ‖ glue ‖ ext): {}", hex::encode(genuine));
+    if words_to_hex(&forged) == hex::encode(genuine) {
+        println!("    ⚠️  Forgery succeeds: the digest is the whole internal state");
+    }
+
+    // SHA-3: the digest is only part of the rate; the capacity never leaves the sponge
+    let tag = sha3_hash(variant, &original, 32);
+    let padded = pad(&original, variant.rate_bytes(), variant.suffix());
+    let extended = [padded.as_slice(), DEMO_EXTENSION].concat();
+    let genuine = sha3_hash(variant, &extended, tag.len());
+
+    let mut guess: State = [0; 25];
+    absorb_block(&mut guess, &tag);
+    for block in pad(DEMO_EXTENSION, variant.rate_bytes(), variant.suffix()).chunks(variant.rate_bytes()) {
+        absorb_block(&mut guess, block);
+        keccak_f(&mut guess, false);
+    }
+    let attempt = squeeze_block(&guess, variant.rate_bytes());
+
+    println!("\n  {}:", variant.name());
+    println!("    Tag:            {}", hex::encode(&tag));
+    println!("    The tag is {} of the 1600 state bits; the {} capacity bits are never output",
+             8 * tag.len(), variant.capacity_bits());
+    println!("    Best guess (unknown lanes set to 0): {}", hex::encode(&attempt[..tag.len()]));
+    println!("    Real H(secret ‖ message ‖ pad ‖ ext):  {}", hex::encode(&genuine));
+    if attempt[..tag.len()] != genuine[..] {
+        println!("    ✅ Forgery fails: continuing needs the hidden capacity, 2^{} guesses", variant.capacity_bits());
+    }
+    println!("\n  💡 With SHA-256 use HMAC; with SHA-3 the plain prefix MAC (or KMAC) is already safe.");
+}
+
+fn run_sha3_demo_internal(message_text: Option<&str>, message_bytes: &[u8], variant: Sha3Variant, output_bytes: usize) {
+    // Header
+    println!("🔐 {} (Keccak Sponge) Demo", variant.name());
+    if let Some(text) = message_text {
+        println!("Message: \"{}\"", text);
+    }
+    println!("Message bytes: {:02x?}", message_bytes);
+    println!("Message length: {} bytes", message_bytes.len());
+
+    if let Err(e) = validate_message(message_bytes) {
+        eprintln!("\n❌ Error: {}", e);
+        return;
+    }
+    let output_bytes = variant.digest_bytes().unwrap_or(output_bytes);
+    if output_bytes == 0 {
+        eprintln!("\n❌ Error: Output length must be at least 1 byte");
+        return;
+    }
+
+    let rate = variant.rate_bytes();
+
+    println!("\n=== Sponge Parameters ===");
+    println!("  State width b = 1600 bits (25 lanes × 64 bits)");
+    println!("  Rate r = {} bits ({} bytes), capacity c = {} bits", rate * 8, rate, variant.capacity_bits());
+    println!("  Domain suffix: 0x{:02x} ({})", variant.suffix(), if variant.digest_bytes().is_some() { "SHA3-d: bits 01" } else { "SHAKE: bits 1111" });
+    match variant.digest_bytes() {
+        Some(bytes) => println!("  Output: fixed {} bits", bytes * 8),
+        None => println!("  Output: {} bytes requested (extendable output)", output_bytes),
+    }
+    print_lane_map(rate);
+
+    let padded = pad(message_bytes, rate, variant.suffix());
+    println!("\n=== Padding (pad10*1) ===");
+    println!("  Message: {} bytes → padded: {} bytes ({} block{} of {} bytes)",
+             message_bytes.len(), padded.len(), padded.len() / rate, if padded.len() / rate == 1 { "" } else { "s" }, rate);
+    println!("  Padding bytes: {}", hex::encode(&padded[message_bytes.len()..]));
+    println!("  First padding byte 0x{:02x} carries the suffix and the first 1 bit; the last byte has 0x80", variant.suffix());
+
+    // Absorb
+    let mut state: State = [0; 25];
+    let blocks: Vec<&[u8]> = padded.chunks(rate).collect();
+    println!("\n=== Absorb Phase ===");
+    for (i, block) in blocks.iter().enumerate() {
+        println!("\n  ┌─ Block {}/{}: rate lanes ⊕= P{} ─┬─ capacity untouched ─┐", i + 1, blocks.len(), i + 1);
+        absorb_block(&mut state, block);
+        if i == 0 {
+            print_state("State after absorbing block 1", &state);
+            println!("\n  Keccak-f[1600] (block 1):");
+            keccak_f(&mut state, true);
+        } else {
+            keccak_f(&mut state, false);
+            println!("  └─ Keccak-f[1600] → A[0,0] = 0x{:016x}", state[0]);
+        }
+    }
+    print_state("State after absorbing", &state);
+
+    // Squeeze
+    println!("\n=== Squeeze Phase ===");
+    let mut output = Vec::new();
+    let mut squeezes = 0;
+    loop {
+        squeezes += 1;
+        let take = (output_bytes - output.len()).min(rate);
+        output.extend_from_slice(&squeeze_block(&state, rate)[..take]);
+        println!("  Squeeze {}: read {} bytes from the rate ({} of {} total)", squeezes, take, output.len(), output_bytes);
+        if output.len() == output_bytes {
+            break;
+        }
+        keccak_f(&mut state, false);
+        println!("    → Keccak-f[1600] again for more output");
+    }
+
+    println!("\n=== Final Hash ===");
+    println!("  {}: {}", variant.name(), hex::encode(&output));
+
+    print_length_extension(variant, message_bytes);
+
+    println!("\n✅ {} computation completed!", variant.name());
+}
+
+// ============================================================================
+// Public API - Clean interface for main.rs
+// ============================================================================
+
+pub fn run_sha3_demo(message_bytes: Vec<u8>, variant: Sha3Variant, output_bytes: usize) {
+    run_sha3_demo_internal(None, &message_bytes, variant, output_bytes);
+}
+
+pub fn run_sha3_demo_text(text: &str, variant: Sha3Variant, output_bytes: usize) {
+    run_sha3_demo_internal(Some(text), text.as_bytes(), variant, output_bytes);
+}
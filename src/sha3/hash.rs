@@ -0,0 +1,34 @@
+use super::sponge::keccak;
+use super::types::Sha3Variant;
+
+/// Hash with any variant; `output_bytes` is ignored for the fixed-length SHA3-d functions
+pub fn sha3_hash(variant: Sha3Variant, message: &[u8], output_bytes: usize) -> Vec<u8> {
+    let length = variant.digest_bytes().unwrap_or(output_bytes);
+    keccak(variant.rate_bytes(), variant.suffix(), message, length)
+}
+
+pub fn sha3_224(message: &[u8]) -> Vec<u8> {
+    sha3_hash(Sha3Variant::Sha3_224, message, 0)
+}
+
+pub fn sha3_256(message: &[u8]) -> Vec<u8> {
+    sha3_hash(Sha3Variant::Sha3_256, message, 0)
+}
+
+pub fn sha3_384(message: &[u8]) -> Vec<u8> {
+    sha3_hash(Sha3Variant::Sha3_384, message, 0)
+}
+
+pub fn sha3_512(message: &[u8]) -> Vec<u8> {
+    sha3_hash(Sha3Variant::Sha3_512, message, 0)
+}
+
+/// SHAKE128 with an arbitrary output length
+pub fn shake128(message: &[u8], output_bytes: usize) -> Vec<u8> {
+    sha3_hash(Sha3Variant::Shake128, message, output_bytes)
+}
+
+/// SHAKE256 with an arbitrary output length
+pub fn shake256(message: &[u8], output_bytes: usize) -> Vec<u8> {
+    sha3_hash(Sha3Variant::Shake256, message, output_bytes)
+}
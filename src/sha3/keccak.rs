@@ -0,0 +1,88 @@
+use super::constants::{RHO_OFFSETS, ROUND_CONSTANTS};
+use super::types::State;
+
+/// θ: XOR each lane with the parities of two neighbouring columns
+pub fn theta(state: &mut State) {
+    let column: [u64; 5] = std::array::from_fn(|x| (0..5).fold(0, |parity, y| parity ^ state[x + 5 * y]));
+    for x in 0..5 {
+        let d = column[(x + 4) % 5] ^ column[(x + 1) % 5].rotate_left(1);
+        for y in 0..5 {
+            state[x + 5 * y] ^= d;
+        }
+    }
+}
+
+/// ρ: rotate each lane by its own triangular-number offset
+pub fn rho(state: &mut State) {
+    for (lane, &offset) in state.iter_mut().zip(RHO_OFFSETS.iter()) {
+        *lane = lane.rotate_left(offset);
+    }
+}
+
+/// π: move lane (x, y) to position (y, 2x + 3y)
+pub fn pi(state: &mut State) {
+    let source = *state;
+    for x in 0..5 {
+        for y in 0..5 {
+            state[y + 5 * ((2 * x + 3 * y) % 5)] = source[x + 5 * y];
+        }
+    }
+}
+
+/// χ: the only non-linear step, a ⊕ (¬b ∧ c) along each row
+pub fn chi(state: &mut State) {
+    for y in 0..5 {
+        let row: [u64; 5] = std::array::from_fn(|x| state[x + 5 * y]);
+        for x in 0..5 {
+            state[x + 5 * y] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+        }
+    }
+}
+
+/// ι: XOR a round constant into lane (0, 0) so the rounds differ
+pub fn iota(state: &mut State, round: usize) {
+    state[0] ^= ROUND_CONSTANTS[round];
+}
+
+/// Print the lanes as a 5×5 grid, rows y = 0..4, columns x = 0..4
+pub fn print_state(label: &str, state: &State) {
+    println!("\n  {}:", label);
+    for y in 0..5 {
+        let row: Vec<String> = (0..5).map(|x| format!("{:016x}", state[x + 5 * y])).collect();
+        println!("    y={} {}", y, row.join(" "));
+    }
+}
+
+/// Keccak-f[1600]: 24 rounds of θ, ρ, π, χ, ι
+///
+/// With `show_steps` the first round prints the state after every step and
+/// the remaining rounds print lane (0, 0) only.
+pub fn keccak_f(state: &mut State, show_steps: bool) {
+    for (round, &constant) in ROUND_CONSTANTS.iter().enumerate() {
+        let detailed = show_steps && round == 0;
+
+        theta(state);
+        if detailed {
+            print_state("Round 1 after θ (column parity mixing)", state);
+        }
+        rho(state);
+        if detailed {
+            print_state("Round 1 after ρ (lane rotations)", state);
+        }
+        pi(state);
+        if detailed {
+            print_state("Round 1 after π (lane permutation)", state);
+        }
+        chi(state);
+        if detailed {
+            print_state("Round 1 after χ (non-linear row mixing)", state);
+        }
+        iota(state, round);
+        if detailed {
+            print_state(&format!("Round 1 after ι (A[0,0] ⊕= RC[0] = 0x{:016x})", constant), state);
+            println!();
+        } else if show_steps {
+            println!("  Round {:2}: RC = 0x{:016x}, A[0,0] = 0x{:016x}", round + 1, constant, state[0]);
+        }
+    }
+}
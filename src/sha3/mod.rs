@@ -0,0 +1,6 @@
+pub mod constants;
+pub mod demo;
+pub mod hash;
+pub mod keccak;
+pub mod sponge;
+pub mod types;
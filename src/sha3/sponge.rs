@@ -0,0 +1,46 @@
+use super::keccak::keccak_f;
+use super::types::State;
+
+/// pad10*1 with the domain suffix: suffix byte, zeros, then 0x80 in the last rate byte
+///
+/// When only one byte of padding fits, the suffix and the final bit share it.
+pub fn pad(message: &[u8], rate_bytes: usize, suffix: u8) -> Vec<u8> {
+    let mut padded = message.to_vec();
+    padded.push(suffix);
+    while !padded.len().is_multiple_of(rate_bytes) {
+        padded.push(0x00);
+    }
+    *padded.last_mut().unwrap() |= 0x80;
+    padded
+}
+
+/// XOR one rate-sized block into the first lanes of the state (little-endian lanes)
+pub fn absorb_block(state: &mut State, block: &[u8]) {
+    for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+        let mut word = [0u8; 8];
+        word[..bytes.len()].copy_from_slice(bytes);
+        *lane ^= u64::from_le_bytes(word);
+    }
+}
+
+/// The rate part of the state as bytes: what one squeeze step can output
+pub fn squeeze_block(state: &State, rate_bytes: usize) -> Vec<u8> {
+    state.iter().flat_map(|lane| lane.to_le_bytes()).take(rate_bytes).collect()
+}
+
+/// The whole sponge without tracing: absorb the padded message, squeeze `output_bytes`
+pub fn keccak(rate_bytes: usize, suffix: u8, message: &[u8], output_bytes: usize) -> Vec<u8> {
+    let mut state: State = [0; 25];
+    for block in pad(message, rate_bytes, suffix).chunks(rate_bytes) {
+        absorb_block(&mut state, block);
+        keccak_f(&mut state, false);
+    }
+
+    let mut output = squeeze_block(&state, rate_bytes);
+    while output.len() < output_bytes {
+        keccak_f(&mut state, false);
+        output.extend(squeeze_block(&state, rate_bytes));
+    }
+    output.truncate(output_bytes);
+    output
+}
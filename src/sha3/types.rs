@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use super::constants::{SHA3_SUFFIX, SHAKE_SUFFIX};
+
+pub type Lane = u64;
+
+/// The 5×5 lane state, lane (x, y) at index x + 5y (FIPS 202 §3.1.2)
+pub type State = [Lane; 25];
+
+/// The six FIPS 202 instances of the sponge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sha3Variant {
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake128,
+    Shake256,
+}
+
+impl Sha3Variant {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Sha3Variant::Sha3_224 => "SHA3-224",
+            Sha3Variant::Sha3_256 => "SHA3-256",
+            Sha3Variant::Sha3_384 => "SHA3-384",
+            Sha3Variant::Sha3_512 => "SHA3-512",
+            Sha3Variant::Shake128 => "SHAKE128",
+            Sha3Variant::Shake256 => "SHAKE256",
+        }
+    }
+
+    /// Capacity c in bits: twice the security level, never output
+    pub fn capacity_bits(&self) -> usize {
+        match self {
+            Sha3Variant::Sha3_224 => 448,
+            Sha3Variant::Sha3_256 | Sha3Variant::Shake256 => 512,
+            Sha3Variant::Sha3_384 => 768,
+            Sha3Variant::Sha3_512 => 1024,
+            Sha3Variant::Shake128 => 256,
+        }
+    }
+
+    /// Rate r in bytes: the part of the state that message and output pass through
+    pub fn rate_bytes(&self) -> usize {
+        (1600 - self.capacity_bits()) / 8
+    }
+
+    /// Fixed digest length, or None for the extendable-output functions
+    pub fn digest_bytes(&self) -> Option<usize> {
+        match self {
+            Sha3Variant::Shake128 | Sha3Variant::Shake256 => None,
+            _ => Some(self.capacity_bits() / 16),
+        }
+    }
+
+    /// Domain-separation bits appended before pad10*1, as the first padding byte
+    pub fn suffix(&self) -> u8 {
+        match self.digest_bytes() {
+            Some(_) => SHA3_SUFFIX,
+            None => SHAKE_SUFFIX,
+        }
+    }
+}
+
+impl FromStr for Sha3Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "sha3224" => Ok(Sha3Variant::Sha3_224),
+            "sha3256" | "sha3" => Ok(Sha3Variant::Sha3_256),
+            "sha3384" => Ok(Sha3Variant::Sha3_384),
+            "sha3512" => Ok(Sha3Variant::Sha3_512),
+            "shake128" => Ok(Sha3Variant::Shake128),
+            "shake256" => Ok(Sha3Variant::Shake256),
+            _ => Err(format!(
+                "Unknown SHA-3 variant '{}' (expected sha3-224, sha3-256, sha3-384, sha3-512, shake128 or shake256)",
+                s
+            )),
+        }
+    }
+}
@@ -6,10 +6,18 @@
 use wasm_bindgen::prelude::*;
 use serde::{Serialize, Deserialize};
 
+use crate::analysis::avalanche::{analyze, OUTPUT_BITS};
+use crate::merkle::hash::HashScheme;
+use crate::merkle::proof::{inclusion_proof, sibling_ranges, verify_inclusion};
+use crate::merkle::tree::{MerkleNode, MerkleTree};
+use crate::rsa::attacks::common_modulus::{common_modulus_attack, common_modulus_scenario};
+use crate::rsa::attacks::related_message::{format_polynomial, franklin_reiter_attack, related_message_scenario};
 use crate::rsa::encryption::{decrypt, encrypt};
-use crate::rsa::key_generation::generate_keypair;
+use crate::rsa::key_generation::generate_keypair_with_exponent;
 use crate::rsa::math_utils::is_prime;
-use crate::rsa::text_encoding::{number_to_text, text_to_number};
+use crate::rsa::text_encoding::{number_to_text, parse_message, text_to_number};
+use crate::rsa::types::{ExponentPolicy, Totient};
+use crate::rsa::validation::exponent_warnings;
 use crate::sha256::compression::compress_block;
 use crate::sha256::constants::INITIAL_HASH;
 use crate::sha256::math_utils::words_to_hex;
@@ -49,6 +57,18 @@ pub struct RsaDemoResult {
     pub decrypted_number: u64,
     pub decrypted_text: Option<String>,
     pub steps: Vec<RsaStep>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RsaAttackResult {
+    pub success: bool,
+    pub error: Option<String>,
+    pub attack: String,
+    pub original_message: String,
+    pub recovered_message: Option<u64>,
+    pub recovered_text: Option<String>,
+    pub steps: Vec<RsaStep>,
 }
 
 // ============================================================================
@@ -74,21 +94,85 @@ pub struct Sha256DemoResult {
     pub steps: Vec<Sha256Step>,
 }
 
+// ============================================================================
+// Merkle Tree Types for WASM
+// ============================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct MerkleStep {
+    pub step_number: u32,
+    pub title: String,
+    pub description: String,
+    pub data: Vec<String>,
+}
+
+/// One node in drawing order (pre-order), with its role in the proof
+#[derive(Serialize, Deserialize)]
+pub struct MerkleTreeNode {
+    pub depth: u32,
+    pub label: String,
+    pub hash: String,
+    /// "proven", "path", "sibling" or "" when no proof was asked for
+    pub role: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MerkleDemoResult {
+    pub success: bool,
+    pub error: Option<String>,
+    pub scheme: String,
+    pub leaf_count: usize,
+    pub root: String,
+    pub nodes: Vec<MerkleTreeNode>,
+    pub proof: Vec<String>,
+    pub verified: Option<bool>,
+    pub steps: Vec<MerkleStep>,
+}
+
+// ============================================================================
+// Avalanche Types for WASM
+// ============================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct AvalancheStep {
+    pub step_number: u32,
+    pub title: String,
+    pub description: String,
+    pub data: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AvalancheDemoResult {
+    pub success: bool,
+    pub error: Option<String>,
+    pub input_bits: usize,
+    pub trials: usize,
+    pub mean_distance: f64,
+    /// Flip probability per cell; each row groups `heatmap_row_bits` input bits
+    pub heatmap: Vec<Vec<f64>>,
+    pub heatmap_row_bits: usize,
+    pub heatmap_column_bits: usize,
+    /// Mean state bits differing after each of the 64 rounds
+    pub round_mean: Vec<f64>,
+    pub steps: Vec<AvalancheStep>,
+}
+
 // ============================================================================
 // RSA WASM Functions
 // ============================================================================
 
+/// `e` is a number or one of `common`, `smallest`, `f4`, `random`; empty means `common`
 #[wasm_bindgen]
-pub fn rsa_demo_number(message: u64, p: u64, q: u64) -> String {
-    let result = run_rsa_demo_internal(None, message, p, q);
+pub fn rsa_demo_number(message: u64, p: u64, q: u64, e: &str) -> String {
+    let result = run_rsa_demo_internal(None, message, p, q, e);
     serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
 }
 
 #[wasm_bindgen]
-pub fn rsa_demo_text(text: &str, p: u64, q: u64) -> String {
+pub fn rsa_demo_text(text: &str, p: u64, q: u64, e: &str) -> String {
     match text_to_number(text) {
         Some(number) => {
-            let result = run_rsa_demo_internal(Some(text.to_string()), number, p, q);
+            let result = run_rsa_demo_internal(Some(text.to_string()), number, p, q, e);
             serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
         }
         None => {
@@ -102,6 +186,7 @@ pub fn rsa_demo_text(text: &str, p: u64, q: u64) -> String {
                 decrypted_number: 0,
                 decrypted_text: None,
                 steps: vec![],
+                warnings: vec![],
             };
             serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
         }
@@ -113,7 +198,7 @@ pub fn validate_prime(n: u64) -> bool {
     is_prime(n)
 }
 
-fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u64, q: u64) -> RsaDemoResult {
+fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u64, q: u64, e: &str) -> RsaDemoResult {
     let mut steps = Vec::new();
 
     // Validate primes
@@ -128,6 +213,7 @@ fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u
             decrypted_number: 0,
             decrypted_text: None,
             steps: vec![],
+            warnings: vec![],
         };
     }
 
@@ -142,6 +228,7 @@ fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u
             decrypted_number: 0,
             decrypted_text: None,
             steps: vec![],
+            warnings: vec![],
         };
     }
 
@@ -156,6 +243,7 @@ fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u
             decrypted_number: 0,
             decrypted_text: None,
             steps: vec![],
+            warnings: vec![],
         };
     }
 
@@ -180,14 +268,32 @@ fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u
     });
 
     // Generate key pair
-    let key_pair = generate_keypair(p, q);
+    let (policy, key_pair) = match e.parse::<ExponentPolicy>().and_then(|policy| {
+        generate_keypair_with_exponent(p, q, Totient::Euler, policy).map(|key_pair| (policy, key_pair))
+    }) {
+        Ok(result) => result,
+        Err(error) => {
+            return RsaDemoResult {
+                success: false,
+                error: Some(error),
+                original_message: message_text.unwrap_or_else(|| message_number.to_string()),
+                message_number,
+                key_pair: None,
+                ciphertext: 0,
+                decrypted_number: 0,
+                decrypted_text: None,
+                steps,
+                warnings: vec![],
+            };
+        }
+    };
 
     // Step 3: Select e
     steps.push(RsaStep {
         step_number: 3,
         title: "Select public exponent e".to_string(),
         description: "Choose e such that 1 < e < φ(n) and gcd(e, φ(n)) = 1".to_string(),
-        formula: format!("e = {} (coprime with φ(n) = {})", key_pair.public_key.e, phi_n),
+        formula: format!("e = {} ({}, coprime with φ(n) = {})", key_pair.public_key.e, policy.name(), phi_n),
         result: key_pair.public_key.e.to_string(),
     });
 
@@ -229,6 +335,7 @@ fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u
             decrypted_number: 0,
             decrypted_text: None,
             steps,
+            warnings: exponent_warnings(&key_pair),
         };
     }
 
@@ -281,6 +388,196 @@ fn run_rsa_demo_internal(message_text: Option<String>, message_number: u64, p: u
         decrypted_number,
         decrypted_text,
         steps,
+        warnings: exponent_warnings(&key_pair),
+    }
+}
+
+// ============================================================================
+// RSA Attack WASM Functions
+// ============================================================================
+
+#[wasm_bindgen]
+pub fn rsa_common_modulus_demo(message: &str, p: u64, q: u64) -> String {
+    let result = run_common_modulus_internal(message, p, q);
+    serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+}
+
+#[wasm_bindgen]
+pub fn rsa_franklin_reiter_demo(message: &str, p: u64, q: u64, alpha: u64, beta: u64) -> String {
+    let result = run_franklin_reiter_internal(message, p, q, alpha, beta);
+    serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+}
+
+fn attack_error(attack: &str, message: &str, error: String) -> RsaAttackResult {
+    RsaAttackResult {
+        success: false,
+        error: Some(error),
+        attack: attack.to_string(),
+        original_message: message.to_string(),
+        recovered_message: None,
+        recovered_text: None,
+        steps: vec![],
+    }
+}
+
+/// Shared input checks for the attack demos: message encoding, primality and m < n
+fn validate_attack_inputs(message: &str, p: u64, q: u64) -> Result<u64, String> {
+    let number = parse_message(message)
+        .ok_or_else(|| "Text is too long or empty. Maximum 8 characters.".to_string())?;
+    if !is_prime(p) {
+        return Err(format!("{} is not a prime number", p));
+    }
+    if !is_prime(q) {
+        return Err(format!("{} is not a prime number", q));
+    }
+    if p == q {
+        return Err("p and q must be different prime numbers".to_string());
+    }
+    let n = p.checked_mul(q).ok_or_else(|| "p × q does not fit in 64 bits".to_string())?;
+    if number >= n {
+        return Err(format!("Message ({}) must be smaller than modulus n ({})", number, n));
+    }
+    Ok(number)
+}
+
+fn recovered_text(message: &str, recovered: u64) -> Option<String> {
+    message
+        .parse::<u64>()
+        .is_err()
+        .then(|| number_to_text(recovered, message.len()))
+}
+
+fn run_common_modulus_internal(message: &str, p: u64, q: u64) -> RsaAttackResult {
+    const ATTACK: &str = "Common modulus";
+
+    let m = match validate_attack_inputs(message, p, q) {
+        Ok(m) => m,
+        Err(e) => return attack_error(ATTACK, message, e),
+    };
+    let Some(scenario) = common_modulus_scenario(m, p, q) else {
+        return attack_error(ATTACK, message, "No second public exponent is coprime to both φ(n) and e₁".to_string());
+    };
+    let Some(recovery) = common_modulus_attack(scenario.n, scenario.e1, scenario.e2, scenario.c1, scenario.c2) else {
+        return attack_error(ATTACK, message, "Ciphertext is not invertible modulo n".to_string());
+    };
+
+    let steps = vec![
+        RsaStep {
+            step_number: 1,
+            title: "Shared modulus".to_string(),
+            description: "Two users are issued keys with the same n but different public exponents".to_string(),
+            formula: format!("n = {} × {}, e₁ = {}, e₂ = {}", p, q, scenario.e1, scenario.e2),
+            result: scenario.n.to_string(),
+        },
+        RsaStep {
+            step_number: 2,
+            title: "Same message sent to both".to_string(),
+            description: "The attacker intercepts both ciphertexts".to_string(),
+            formula: format!("c₁ = m^{} mod n, c₂ = m^{} mod n", scenario.e1, scenario.e2),
+            result: format!("c₁ = {}, c₂ = {}", scenario.c1, scenario.c2),
+        },
+        RsaStep {
+            step_number: 3,
+            title: "Bézout coefficients".to_string(),
+            description: "Extended Euclid on the public exponents, since gcd(e₁, e₂) = 1".to_string(),
+            formula: format!("{}·{} + {}·{} = 1", recovery.a, scenario.e1, recovery.b, scenario.e2),
+            result: format!("a = {}, b = {}", recovery.a, recovery.b),
+        },
+        RsaStep {
+            step_number: 4,
+            title: "Raise ciphertexts to a and b".to_string(),
+            description: "A negative power is computed with the modular inverse of the ciphertext".to_string(),
+            formula: format!("c₁^{} mod n, c₂^{} mod n", recovery.a, recovery.b),
+            result: format!("{}, {}", recovery.c1_term, recovery.c2_term),
+        },
+        RsaStep {
+            step_number: 5,
+            title: "Recover message".to_string(),
+            description: "The exponents combine to a·e₁ + b·e₂ = 1".to_string(),
+            formula: "m = c₁^a · c₂^b = m^(a·e₁ + b·e₂) mod n".to_string(),
+            result: recovery.message.to_string(),
+        },
+    ];
+
+    RsaAttackResult {
+        success: recovery.message == m,
+        error: None,
+        attack: ATTACK.to_string(),
+        original_message: message.to_string(),
+        recovered_message: Some(recovery.message),
+        recovered_text: recovered_text(message, recovery.message),
+        steps,
+    }
+}
+
+fn run_franklin_reiter_internal(message: &str, p: u64, q: u64, alpha: u64, beta: u64) -> RsaAttackResult {
+    const ATTACK: &str = "Franklin-Reiter related message";
+
+    let m = match validate_attack_inputs(message, p, q) {
+        Ok(m) => m,
+        Err(e) => return attack_error(ATTACK, message, e),
+    };
+    let Some(scenario) = related_message_scenario(m, p, q, alpha, beta) else {
+        return attack_error(
+            ATTACK,
+            message,
+            "Key generation did not choose e = 3; pick p, q ≡ 2 (mod 3)".to_string(),
+        );
+    };
+    let n = scenario.public_key.n;
+    let Some(recovery) = franklin_reiter_attack(n, alpha, beta, scenario.c1, scenario.c2) else {
+        return attack_error(ATTACK, message, "Polynomial gcd was not linear".to_string());
+    };
+
+    let mut steps = vec![
+        RsaStep {
+            step_number: 1,
+            title: "Related messages".to_string(),
+            description: "Two messages with a known linear relation are encrypted under the same e = 3 key".to_string(),
+            formula: format!("m₂ = {}·m₁ + {} mod {}", alpha, beta, n),
+            result: format!("c₁ = {}, c₂ = {}", scenario.c1, scenario.c2),
+        },
+        RsaStep {
+            step_number: 2,
+            title: "Polynomials with common root m₁".to_string(),
+            description: "Both vanish at x = m₁ modulo n".to_string(),
+            formula: format!(
+                "g₁(x) = {}, g₂(x) = {}",
+                format_polynomial(&recovery.g1),
+                format_polynomial(&recovery.g2)
+            ),
+            result: "g₁(m₁) ≡ g₂(m₁) ≡ 0 (mod n)".to_string(),
+        },
+    ];
+    // Euclid runs on (g₂, g₁), then on successive remainders
+    let operand = |k: usize| match k {
+        0 => "g₂".to_string(),
+        1 => "g₁".to_string(),
+        k => format!("r{}", k - 1),
+    };
+    steps.extend(recovery.remainders.iter().enumerate().map(|(i, r)| RsaStep {
+        step_number: 3 + i as u32,
+        title: format!("Euclidean step {}", i + 1),
+        description: "Polynomial remainder over Z_n".to_string(),
+        formula: format!("r{} = {} mod {}", i + 1, operand(i), operand(i + 1)),
+        result: format_polynomial(r),
+    }));
+    steps.push(RsaStep {
+        step_number: 3 + recovery.remainders.len() as u32,
+        title: "Recover message".to_string(),
+        description: "The monic gcd is linear: x - m₁".to_string(),
+        formula: "gcd(g₁, g₂) = x - m₁".to_string(),
+        result: recovery.message.to_string(),
+    });
+
+    RsaAttackResult {
+        success: recovery.message == m,
+        error: None,
+        attack: ATTACK.to_string(),
+        original_message: message.to_string(),
+        recovered_message: Some(recovery.message),
+        recovered_text: recovered_text(message, recovery.message),
+        steps,
     }
 }
 
@@ -391,3 +688,223 @@ fn run_sha256_demo_internal(message_text: Option<String>, message_bytes: &[u8])
     }
 }
 
+
+// ============================================================================
+// Merkle Tree WASM Functions
+// ============================================================================
+
+/// One leaf per line; `prove` is the leaf index to prove, if any; `scheme` is `rfc6962` or `plain`
+#[wasm_bindgen]
+pub fn merkle_demo(leaves_text: &str, prove: Option<u32>, scheme: &str) -> String {
+    let leaves: Vec<&str> = leaves_text.lines().collect();
+    let result = match scheme.parse::<HashScheme>() {
+        Ok(scheme) => run_merkle_demo_internal(&leaves, prove.map(|index| index as usize), scheme),
+        Err(e) => merkle_error(e),
+    };
+    serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+}
+
+fn merkle_error(error: String) -> MerkleDemoResult {
+    MerkleDemoResult {
+        success: false,
+        error: Some(error),
+        scheme: String::new(),
+        leaf_count: 0,
+        root: String::new(),
+        nodes: Vec::new(),
+        proof: Vec::new(),
+        verified: None,
+        steps: Vec::new(),
+    }
+}
+
+fn collect_merkle_nodes(
+    node: &MerkleNode,
+    leaves: &[&str],
+    highlight: Option<(usize, &[(usize, usize)])>,
+    depth: u32,
+    nodes: &mut Vec<MerkleTreeNode>,
+) {
+    let (start, end) = node.range();
+    let role = match highlight {
+        Some((leaf, _)) if start == leaf && end == leaf + 1 => "proven",
+        Some((leaf, _)) if (start..end).contains(&leaf) => "path",
+        Some((_, siblings)) if siblings.contains(&(start, end)) => "sibling",
+        _ => "",
+    };
+    let label = match node {
+        MerkleNode::Leaf { index, .. } => format!("leaf {} \"{}\"", index, leaves[*index]),
+        MerkleNode::Node { .. } => format!("node [{}..{})", start, end),
+    };
+    nodes.push(MerkleTreeNode { depth, label, hash: hex::encode(node.hash()), role: role.to_string() });
+
+    if let MerkleNode::Node { left, right, .. } = node {
+        collect_merkle_nodes(left, leaves, highlight, depth + 1, nodes);
+        collect_merkle_nodes(right, leaves, highlight, depth + 1, nodes);
+    }
+}
+
+fn run_merkle_demo_internal(leaves: &[&str], prove: Option<usize>, scheme: HashScheme) -> MerkleDemoResult {
+    let tree = MerkleTree::new(scheme, leaves);
+    let root = tree.root();
+    let proof = match prove.map(|index| inclusion_proof(&tree, index)).transpose() {
+        Ok(proof) => proof,
+        Err(e) => return merkle_error(e),
+    };
+
+    let (leaf_rule, node_rule) = match scheme {
+        HashScheme::Rfc6962 => ("SHA-256(0x00 ‖ data)", "SHA-256(0x01 ‖ left ‖ right)"),
+        HashScheme::Plain => ("SHA-256(data)", "SHA-256(left ‖ right)"),
+    };
+    let mut steps = vec![MerkleStep {
+        step_number: 1,
+        title: "Leaf Hashes".to_string(),
+        description: format!("leaf = {}", leaf_rule),
+        data: tree
+            .leaf_hashes
+            .iter()
+            .zip(leaves)
+            .enumerate()
+            .map(|(i, (hash, leaf))| format!("leaf {} \"{}\" = {}", i, leaf, hex::encode(hash)))
+            .collect(),
+    }];
+    steps.push(MerkleStep {
+        step_number: 2,
+        title: "Tree Root".to_string(),
+        description: format!("node = {}, splitting at the largest power of two below n", node_rule),
+        data: vec![format!("Root over {} leaves: {}", tree.size(), hex::encode(root))],
+    });
+
+    let mut nodes = Vec::new();
+    let mut verified = None;
+    let mut proof_hashes = Vec::new();
+    if let Some(root_node) = &tree.root_node {
+        let siblings = proof.as_ref().map(|proof| sibling_ranges(root_node, proof.leaf_index)).unwrap_or_default();
+        let highlight = proof.as_ref().map(|proof| (proof.leaf_index, siblings.as_slice()));
+        collect_merkle_nodes(root_node, leaves, highlight, 0, &mut nodes);
+    }
+
+    if let Some(proof) = &proof {
+        proof_hashes = proof.path.iter().map(hex::encode).collect();
+        steps.push(MerkleStep {
+            step_number: 3,
+            title: format!("Inclusion Proof for Leaf {}", proof.leaf_index),
+            description: format!("{} sibling hashes, bottom first, instead of all {} leaves", proof.path.len(), proof.tree_size),
+            data: proof_hashes.iter().enumerate().map(|(i, hash)| format!("proof[{}] = {}", i, hash)).collect(),
+        });
+
+        let leaf = leaves[proof.leaf_index];
+        let valid = verify_inclusion(scheme, leaf.as_bytes(), proof, &root);
+        let tampered = format!("{}!", leaf);
+        steps.push(MerkleStep {
+            step_number: 4,
+            title: "Verification".to_string(),
+            description: "Rebuild the root from the leaf and the proof, then compare".to_string(),
+            data: vec![
+                format!("\"{}\" → {}", leaf, if valid { "root matches" } else { "root differs" }),
+                format!(
+                    "\"{}\" → {}",
+                    tampered,
+                    if verify_inclusion(scheme, tampered.as_bytes(), proof, &root) { "root matches" } else { "root differs" }
+                ),
+            ],
+        });
+        verified = Some(valid);
+    }
+
+    MerkleDemoResult {
+        success: true,
+        error: None,
+        scheme: scheme.name().to_string(),
+        leaf_count: tree.size(),
+        root: hex::encode(root),
+        nodes,
+        proof: proof_hashes,
+        verified,
+        steps,
+    }
+}
+
+// ============================================================================
+// Avalanche WASM Functions
+// ============================================================================
+
+/// Most heat map rows sent to the page; input bits are grouped to fit
+const AVALANCHE_MAX_ROWS: usize = 64;
+
+/// Output bits per heat map column
+const AVALANCHE_COLUMN_BITS: usize = 4;
+
+#[wasm_bindgen]
+pub fn avalanche_demo(text: &str, samples: u32) -> String {
+    let result = run_avalanche_demo_internal(text.as_bytes(), samples as usize);
+    serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
+}
+
+fn run_avalanche_demo_internal(message: &[u8], samples: usize) -> AvalancheDemoResult {
+    let report = match analyze(message, samples, 1) {
+        Ok(report) => report,
+        Err(e) => {
+            return AvalancheDemoResult {
+                success: false,
+                error: Some(e),
+                input_bits: 0,
+                trials: 0,
+                mean_distance: 0.0,
+                heatmap: Vec::new(),
+                heatmap_row_bits: 0,
+                heatmap_column_bits: 0,
+                round_mean: Vec::new(),
+                steps: Vec::new(),
+            }
+        }
+    };
+
+    let probabilities = report.output_bit_probability();
+    let (min, max) = probabilities.iter().fold((1.0f64, 0.0f64), |(lo, hi), &p| (lo.min(p), hi.max(p)));
+    let round_mean = report.round_mean_distance();
+    let seen: Vec<usize> = (0..=OUTPUT_BITS).filter(|&d| report.distance_histogram[d] > 0).collect();
+
+    let steps = vec![
+        AvalancheStep {
+            step_number: 1,
+            title: "Hamming Distance Between Digests".to_string(),
+            description: format!("{} single-bit flips over {} message(s); ideal is mean 128, σ = 8", report.trials(), samples),
+            data: vec![
+                format!("Mean: {:.2} bits", report.mean_distance()),
+                format!("Standard deviation: {:.2} bits", report.distance_std_dev()),
+                format!("Range: {} to {} bits", seen.first().unwrap_or(&0), seen.last().unwrap_or(&0)),
+            ],
+        },
+        AvalancheStep {
+            step_number: 2,
+            title: "Output Bit Flip Probability".to_string(),
+            description: "How often each of the 256 output bits flipped".to_string(),
+            data: vec![
+                format!("Lowest: {:.3}", min),
+                format!("Highest: {:.3}", max),
+                format!("Sampling noise σ = {:.3}", (0.25 / report.trials() as f64).sqrt()),
+            ],
+        },
+        AvalancheStep {
+            step_number: 3,
+            title: "Round-by-Round Diffusion".to_string(),
+            description: "Mean bits of the working state a..h that differ after each round (first block)".to_string(),
+            data: (0..8).map(|t| format!("Round {}: {:.1} bits", t + 1, round_mean[t])).collect(),
+        },
+    ];
+
+    let row_bits = report.input_bits.div_ceil(AVALANCHE_MAX_ROWS);
+    AvalancheDemoResult {
+        success: true,
+        error: None,
+        input_bits: report.input_bits,
+        trials: report.trials(),
+        mean_distance: report.mean_distance(),
+        heatmap: report.heatmap(row_bits, AVALANCHE_COLUMN_BITS),
+        heatmap_row_bits: row_bits,
+        heatmap_column_bits: AVALANCHE_COLUMN_BITS,
+        round_mean,
+        steps,
+    }
+}
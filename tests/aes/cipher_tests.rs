@@ -0,0 +1,50 @@
+use encryption_demo::aes::cipher::{gf_mul, Aes, Block, INV_SBOX, SBOX};
+
+const PLAINTEXT: &str = "00112233445566778899aabbccddeeff";
+
+fn block(hex: &str) -> Block {
+    hex::decode(hex).unwrap().try_into().unwrap()
+}
+
+fn sequential_key(len: usize) -> Vec<u8> {
+    (0..len as u8).collect()
+}
+
+#[test]
+fn test_fips197_appendix_c() {
+    // FIPS 197 Appendix C.1-C.3
+    let cases = [
+        (16, 10, "69c4e0d86a7b0430d8cdb78070b4c55a"),
+        (24, 12, "dda97ca4864cdfe06eaf70a0ec0d7191"),
+        (32, 14, "8ea2b7ca516745bfeafc49904b496089"),
+    ];
+    for (key_len, rounds, expected) in cases {
+        let aes = Aes::new(&sequential_key(key_len)).unwrap();
+        assert_eq!(aes.rounds(), rounds);
+        let ciphertext = aes.encrypt_block(&block(PLAINTEXT));
+        assert_eq!(hex::encode(ciphertext), expected);
+        assert_eq!(aes.decrypt_block(&ciphertext), block(PLAINTEXT));
+    }
+}
+
+#[test]
+fn test_key_expansion_appendix_a1() {
+    // FIPS 197 Appendix A.1: w[40..44] is the last round key
+    let aes = Aes::new(&hex::decode("2b7e151628aed2a6abf7158809cf4f3c").unwrap()).unwrap();
+    assert_eq!(hex::encode(aes.round_key(10)), "d014f9a8c9ee2589e13f0cc8b6630ca6");
+}
+
+#[test]
+fn test_rejects_bad_key_length() {
+    assert!(Aes::new(&[0; 15]).is_err());
+    assert!(Aes::new(&[0; 20]).is_err());
+}
+
+#[test]
+fn test_sbox_tables() {
+    // FIPS 197 §4.2 and Figure 7
+    assert_eq!(gf_mul(0x57, 0x83), 0xc1);
+    assert_eq!(gf_mul(0x57, 0x13), 0xfe);
+    assert_eq!(SBOX[0x53], 0xed);
+    assert!((0..=255u8).all(|b| INV_SBOX[SBOX[b as usize] as usize] == b));
+}
@@ -0,0 +1,2 @@
+mod cipher_tests;
+mod modes_tests;
@@ -0,0 +1,32 @@
+use encryption_demo::aes::cipher::Aes;
+use encryption_demo::aes::modes::{cbc_decrypt, cbc_encrypt, ecb_decrypt, ecb_encrypt};
+
+fn aes() -> Aes {
+    Aes::new(&hex::decode("2b7e151628aed2a6abf7158809cf4f3c").unwrap()).unwrap()
+}
+
+const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51";
+
+#[test]
+fn test_ecb_sp800_38a() {
+    // SP 800-38A F.1.1, first two blocks
+    let ciphertext = ecb_encrypt(&aes(), &hex::decode(PLAINTEXT).unwrap()).unwrap();
+    assert_eq!(hex::encode(&ciphertext), "3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf");
+    assert_eq!(hex::encode(ecb_decrypt(&aes(), &ciphertext).unwrap()), PLAINTEXT);
+}
+
+#[test]
+fn test_cbc_sp800_38a() {
+    // SP 800-38A F.2.1, first two blocks
+    let iv = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let ciphertext = cbc_encrypt(&aes(), &iv, &hex::decode(PLAINTEXT).unwrap()).unwrap();
+    assert_eq!(hex::encode(&ciphertext), "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2");
+    assert_eq!(hex::encode(cbc_decrypt(&aes(), &iv, &ciphertext).unwrap()), PLAINTEXT);
+}
+
+#[test]
+fn test_rejects_partial_blocks_and_bad_iv() {
+    assert!(ecb_encrypt(&aes(), &[0; 17]).is_err());
+    assert!(cbc_decrypt(&aes(), &[0; 16], &[0; 8]).is_err());
+    assert!(cbc_encrypt(&aes(), &[0; 12], &[0; 16]).is_err());
+}
@@ -0,0 +1,93 @@
+use encryption_demo::analysis::avalanche::{analyze, flip_bit, hamming_distance, MAX_INPUT_BYTES, OUTPUT_BITS, ROUNDS};
+
+#[test]
+fn test_flip_bit_numbers_from_msb_of_first_byte() {
+    assert_eq!(flip_bit(&[0x00, 0x00], 0), vec![0x80, 0x00]);
+    assert_eq!(flip_bit(&[0x00, 0x00], 7), vec![0x01, 0x00]);
+    assert_eq!(flip_bit(&[0xff, 0x00], 8), vec![0xff, 0x80]);
+}
+
+#[test]
+fn test_hamming_distance() {
+    assert_eq!(hamming_distance(&[0x00, 0xff], &[0x00, 0xff]), 0);
+    assert_eq!(hamming_distance(&[0x0f, 0x00], &[0x00, 0x01]), 5);
+}
+
+#[test]
+fn test_report_shape() {
+    let report = analyze(b"abc", 4, 1).unwrap();
+    assert_eq!(report.input_bits, 24);
+    assert_eq!(report.trials(), 96);
+    assert_eq!(report.flip_counts.len(), 24);
+    assert_eq!(report.distance_histogram.len(), OUTPUT_BITS + 1);
+    assert_eq!(report.distance_histogram.iter().sum::<u64>(), 96);
+    assert_eq!(report.round_word_distance.len(), ROUNDS);
+}
+
+#[test]
+fn test_sha256_shows_strong_avalanche() {
+    let report = analyze(b"The quick brown fox", 8, 7).unwrap();
+    assert!((report.mean_distance() - 128.0).abs() < 2.0, "mean {}", report.mean_distance());
+    assert!((report.distance_std_dev() - 8.0).abs() < 1.5, "σ {}", report.distance_std_dev());
+    for p in report.output_bit_probability() {
+        assert!((0.35..0.65).contains(&p), "output bit probability {}", p);
+    }
+}
+
+#[test]
+fn test_first_round_changes_only_a_and_e() {
+    let report = analyze(b"abcd", 2, 1).unwrap();
+    let first = report.round_word_distance[0];
+    assert!(first[0] > 0.0 && first[4] > 0.0);
+    for word in [1, 2, 3, 5, 6, 7] {
+        assert_eq!(first[word], 0.0, "word {} after round 1", word);
+    }
+    // One round later the changed a and e have shifted into b and f
+    assert!(report.round_word_distance[1][1] > 0.0 && report.round_word_distance[1][5] > 0.0);
+}
+
+#[test]
+fn test_diffusion_is_complete_long_before_round_64() {
+    let report = analyze(b"abc", 8, 1).unwrap();
+    let means = report.round_mean_distance();
+    assert!(means[0] < 8.0, "round 1 mean {}", means[0]);
+    assert!((means[ROUNDS - 1] - 128.0).abs() < 4.0, "round 64 mean {}", means[ROUNDS - 1]);
+    assert!(report.round_min_distance[ROUNDS - 1] > 64);
+}
+
+#[test]
+fn test_heatmap_groups_rows_and_columns() {
+    let report = analyze(b"abcdef", 4, 3).unwrap();
+    let heatmap = report.heatmap(8, 4);
+    assert_eq!(heatmap.len(), 6);
+    assert!(heatmap.iter().all(|row| row.len() == OUTPUT_BITS / 4));
+    assert!(heatmap.iter().flatten().all(|p| (0.0..=1.0).contains(p)));
+
+    let full = report.heatmap(1, 1);
+    assert_eq!(full[3][17], report.flip_probability(3, 17));
+}
+
+#[test]
+fn test_csv_has_one_row_per_input_bit() {
+    let report = analyze(b"hi", 2, 1).unwrap();
+    let csv = report.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 1 + 16);
+    assert!(lines[0].starts_with("input_bit,out_0,out_1,"));
+    assert_eq!(lines[1].split(',').count(), 1 + OUTPUT_BITS);
+    assert!(lines[16].starts_with("15,"));
+}
+
+#[test]
+fn test_same_seed_gives_same_report() {
+    let a = analyze(b"seed", 3, 42).unwrap();
+    let b = analyze(b"seed", 3, 42).unwrap();
+    assert_eq!(a.flip_counts, b.flip_counts);
+}
+
+#[test]
+fn test_rejects_bad_input() {
+    assert!(analyze(b"", 4, 1).is_err());
+    assert!(analyze(&[0; MAX_INPUT_BYTES + 1], 4, 1).is_err());
+    assert!(analyze(b"abc", 0, 1).is_err());
+}
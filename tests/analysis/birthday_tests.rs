@@ -0,0 +1,91 @@
+use encryption_demo::analysis::birthday::{
+    collision_probability, distinguished_point_search, expected_hashes, floyd_search, table_search,
+    validate_distinguished_bits, Collision, TruncatedSha256, MAX_BITS, MIN_BITS,
+};
+use encryption_demo::sha256::hash::sha256_bytes;
+
+fn assert_genuine(h: &TruncatedSha256, collision: &Collision) {
+    assert_ne!(collision.first, collision.second);
+    assert_eq!(h.hash(&collision.first), collision.hash);
+    assert_eq!(h.hash(&collision.second), collision.hash);
+    assert_ne!(sha256_bytes(&collision.first), sha256_bytes(&collision.second));
+}
+
+#[test]
+fn test_truncation_bounds() {
+    assert!(TruncatedSha256::new(MIN_BITS - 1, b"").is_err());
+    assert!(TruncatedSha256::new(MAX_BITS + 1, b"").is_err());
+    assert!(TruncatedSha256::new(MIN_BITS, b"").is_ok());
+    assert!(TruncatedSha256::new(MAX_BITS, b"").is_ok());
+}
+
+#[test]
+fn test_hash_keeps_leading_bits() {
+    // SHA-256("abc") = ba7816bf 8f01cfea ...
+    let h = TruncatedSha256::new(16, b"").unwrap();
+    assert_eq!(h.hash(b"abc"), 0xba78);
+    let h = TruncatedSha256::new(36, b"").unwrap();
+    assert_eq!(h.hash(b"abc"), 0xba7816bf8);
+}
+
+#[test]
+fn test_encode_is_prefix_and_fixed_width_hex() {
+    let h = TruncatedSha256::new(20, b"msg-").unwrap();
+    assert_eq!(h.encode(0xab), b"msg-000ab".to_vec());
+    assert_eq!(h.step(0xab), h.hash(b"msg-000ab"));
+}
+
+#[test]
+fn test_expected_hashes_and_probability() {
+    assert!((expected_hashes(32) - 82137.0).abs() < 1.0);
+    assert!((collision_probability(1.1774 * 65536.0, 32) - 0.5).abs() < 0.001);
+    assert!(collision_probability(1.0, 16) == 0.0);
+}
+
+#[test]
+fn test_table_search_finds_collision() {
+    let h = TruncatedSha256::new(20, b"table-").unwrap();
+    let outcome = table_search(&h, 0, 1 << 16);
+    assert_genuine(&h, outcome.collision.as_ref().unwrap());
+    assert_eq!(outcome.stored as u64, outcome.hashes - 1);
+}
+
+#[test]
+fn test_floyd_search_finds_collision() {
+    let h = TruncatedSha256::new(20, b"floyd-").unwrap();
+    for start in 0..4 {
+        let outcome = floyd_search(&h, start, 1 << 18);
+        assert_genuine(&h, outcome.collision.as_ref().unwrap());
+        assert_eq!(outcome.stored, 2);
+    }
+}
+
+#[test]
+fn test_distinguished_point_search_finds_collision() {
+    let h = TruncatedSha256::new(20, b"dp-").unwrap();
+    for seed in 1..4 {
+        let outcome = distinguished_point_search(&h, 4, seed, 1 << 18);
+        assert_genuine(&h, outcome.collision.as_ref().unwrap());
+        assert!(outcome.stored < 1 << 10);
+    }
+}
+
+#[test]
+fn test_search_respects_budget() {
+    let h = TruncatedSha256::new(40, b"").unwrap();
+    let outcome = table_search(&h, 0, 100);
+    assert!(outcome.collision.is_none());
+    assert_eq!(outcome.hashes, 100);
+    assert!(floyd_search(&h, 0, 100).collision.is_none());
+    assert!(distinguished_point_search(&h, 10, 1, 100).collision.is_none());
+}
+
+#[test]
+fn test_distinguished_bits_must_stay_below_half() {
+    assert!(validate_distinguished_bits(32, 8).is_ok());
+    assert!(validate_distinguished_bits(32, 15).is_ok());
+    assert!(validate_distinguished_bits(32, 16).is_err());
+    assert!(validate_distinguished_bits(32, 0).is_err());
+    assert!(validate_distinguished_bits(9, 4).is_ok());
+    assert!(validate_distinguished_bits(9, 5).is_err());
+}
@@ -0,0 +1,3 @@
+mod avalanche_tests;
+mod birthday_tests;
+mod reduced_tests;
@@ -0,0 +1,68 @@
+use encryption_demo::analysis::reduced::{
+    brute_force_preimage, diffusion, invert_rounds, leading_bits, words_to_message, INVERTIBLE_ROUNDS,
+};
+use encryption_demo::sha256::reduced::NonStandardSha256;
+
+#[test]
+fn test_inversion_gives_full_preimage() {
+    for rounds in 1..=INVERTIBLE_ROUNDS {
+        let variant = NonStandardSha256::new(rounds).unwrap();
+        let target = variant.hash(b"The quick brown fox");
+        let words = invert_rounds(&variant, &target).unwrap();
+        assert_eq!(words.len(), rounds);
+        assert_eq!(variant.hash(&words_to_message(&words)), target, "{} rounds", rounds);
+    }
+}
+
+#[test]
+fn test_inversion_recovers_message_words() {
+    // Only W[0..r] enter r rounds, and for "abc" those are 0x61626380 then zeros
+    let variant = NonStandardSha256::new(3).unwrap();
+    let words = invert_rounds(&variant, &variant.hash(b"abc")).unwrap();
+    assert_eq!(words, vec![0x61626380, 0, 0]);
+}
+
+#[test]
+fn test_inversion_with_substituted_parameters() {
+    let variant = NonStandardSha256::new(4)
+        .unwrap()
+        .with_initial_hash([1, 2, 3, 4, 5, 6, 7, 8])
+        .with_round_constants(&[0; 4])
+        .unwrap();
+    let target = variant.hash(b"custom");
+    let words = invert_rounds(&variant, &target).unwrap();
+    assert_eq!(variant.hash(&words_to_message(&words)), target);
+}
+
+#[test]
+fn test_inversion_limits() {
+    let variant = NonStandardSha256::new(INVERTIBLE_ROUNDS + 1).unwrap();
+    assert!(invert_rounds(&variant, &variant.hash(b"abc")).is_err());
+
+    // With one round, six output words are fixed by the IV, so a random target is unreachable
+    let variant = NonStandardSha256::new(1).unwrap();
+    assert!(invert_rounds(&variant, &[0x12345678; 8]).is_err());
+}
+
+#[test]
+fn test_brute_force_matches_prefix() {
+    let variant = NonStandardSha256::new(2).unwrap();
+    let target = variant.hash_bytes(b"abc");
+    let outcome = brute_force_preimage(&variant, &target, 12, 1 << 20).unwrap();
+    let message = outcome.message.unwrap();
+    assert_eq!(leading_bits(&variant.hash_bytes(&message), 12), leading_bits(&target, 12));
+    assert!(outcome.attempts <= 1 << 20);
+
+    assert!(brute_force_preimage(&variant, &target, 7, 10).is_err());
+    assert!(brute_force_preimage(&variant, &target, 41, 10).is_err());
+}
+
+#[test]
+fn test_diffusion_grows_with_rounds() {
+    let (one, one_reached) = diffusion(&NonStandardSha256::new(1).unwrap(), b"abcd");
+    let (full, full_reached) = diffusion(&NonStandardSha256::new(64).unwrap(), b"abcd");
+    assert!(one < 16.0, "1 round: {}", one);
+    assert_eq!(one_reached, 64);
+    assert!((full - 128.0).abs() < 12.0, "64 rounds: {}", full);
+    assert_eq!(full_reached, 256);
+}
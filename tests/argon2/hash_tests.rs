@@ -0,0 +1,97 @@
+use encryption_demo::argon2::compression::compress;
+use encryption_demo::argon2::hash::{argon2, argon2_with_trace, h_prime};
+use encryption_demo::argon2::types::{Argon2Params, Argon2Variant};
+use encryption_demo::blake2::hash::blake2b;
+use encryption_demo::blake2::types::Blake2Params;
+
+/// RFC 9106 §5 test inputs: m = 32 KiB, t = 3, p = 4 with secret and associated data
+fn rfc9106_params(variant: Argon2Variant) -> Argon2Params {
+    Argon2Params {
+        variant,
+        memory_kib: 32,
+        passes: 3,
+        lanes: 4,
+        tag_length: 32,
+        secret: vec![0x03; 8],
+        associated_data: vec![0x04; 12],
+    }
+}
+
+fn rfc9106_tag(variant: Argon2Variant) -> String {
+    hex::encode(argon2(&rfc9106_params(variant), &[0x01; 32], &[0x02; 16]).unwrap())
+}
+
+fn reference_params(variant: Argon2Variant, passes: u32, log_memory: u32, lanes: u32) -> Argon2Params {
+    Argon2Params { variant, memory_kib: 1 << log_memory, passes, lanes, ..Default::default() }
+}
+
+#[test]
+fn test_argon2_rfc9106_vectors() {
+    assert_eq!(rfc9106_tag(Argon2Variant::Argon2d), "512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb");
+    assert_eq!(rfc9106_tag(Argon2Variant::Argon2i), "c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8");
+    assert_eq!(rfc9106_tag(Argon2Variant::Argon2id), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659");
+}
+
+#[test]
+fn test_argon2_reference_vectors() {
+    let cases = [
+        (Argon2Variant::Argon2i, 2, 8, 1, "89e9029f4637b295beb027056a7336c414fadd43f6b208645281cb214a56452f"),
+        (Argon2Variant::Argon2i, 2, 8, 2, "4ff5ce2769a1d7f4c8a491df09d41a9fbe90e5eb02155a13e4c01e20cd4eab61"),
+        (Argon2Variant::Argon2id, 2, 8, 1, "9dfeb910e80bad0311fee20f9c0e2b12c17987b4cac90c2ef54d5b3021c68bfe"),
+        (Argon2Variant::Argon2id, 2, 8, 2, "6d093c501fd5999645e0ea3bf620d7b8be7fd2db59c20d9fff9539da2bf57037"),
+    ];
+    for (variant, passes, log_memory, lanes, expected) in cases {
+        let params = reference_params(variant, passes, log_memory, lanes);
+        assert_eq!(hex::encode(argon2(&params, b"password", b"somesalt").unwrap()), expected, "{:?}", params);
+    }
+}
+
+#[test]
+fn test_h_prime_short_is_prefixed_blake2b() {
+    let expected = blake2b(&[&40u32.to_le_bytes()[..], b"input"].concat(), &Blake2Params::new(40)).unwrap();
+    assert_eq!(h_prime(b"input", 40), expected);
+}
+
+#[test]
+fn test_h_prime_long_output() {
+    for length in [65, 96, 100, 1024] {
+        assert_eq!(h_prime(b"input", length).len(), length);
+    }
+    assert_ne!(h_prime(b"input", 96)[..64], h_prime(b"input", 100)[..64]);
+}
+
+#[test]
+fn test_compress_depends_on_xor_of_inputs() {
+    let x = std::array::from_fn(|i| i as u64);
+    let zero = [0u64; 128];
+    assert_ne!(compress(&x, &zero), x);
+    assert_eq!(compress(&x, &zero), compress(&zero, &x));
+}
+
+#[test]
+fn test_references_only_point_backwards_in_first_pass() {
+    let params = Argon2Params { memory_kib: 64, passes: 2, lanes: 2, ..Default::default() };
+    let output = argon2_with_trace(&params, b"password", b"somesalt", true).unwrap();
+    assert_eq!(output.references.len(), 2 * 64 - 2 * 2);
+
+    let segment = params.segment_length();
+    for reference in output.references.iter().filter(|r| r.pass == 0) {
+        if reference.ref_lane == reference.lane {
+            assert!(reference.ref_index < reference.index, "{:?}", reference);
+        } else {
+            assert!(reference.ref_index < reference.slice * segment, "{:?}", reference);
+        }
+        assert_eq!(reference.data_independent, reference.slice < 2);
+    }
+}
+
+#[test]
+fn test_argon2_rejects_bad_parameters() {
+    let salt = b"somesalt";
+    assert!(argon2(&Argon2Params { lanes: 0, ..Default::default() }, b"pw", salt).is_err());
+    assert!(argon2(&Argon2Params { memory_kib: 15, lanes: 2, ..Default::default() }, b"pw", salt).is_err());
+    assert!(argon2(&Argon2Params { passes: 0, ..Default::default() }, b"pw", salt).is_err());
+    assert!(argon2(&Argon2Params { tag_length: 3, ..Default::default() }, b"pw", salt).is_err());
+    assert!(argon2(&Argon2Params { memory_kib: u32::MAX, ..Default::default() }, b"pw", salt).is_err());
+    assert!(argon2(&Argon2Params::default(), b"pw", b"short").is_err());
+}
@@ -0,0 +1,3 @@
+mod hash_tests;
+mod password_tests;
+mod phc_tests;
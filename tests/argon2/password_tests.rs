@@ -0,0 +1,62 @@
+use encryption_demo::argon2::password::{hash_password, verify_password, weaknesses};
+use encryption_demo::argon2::types::{Argon2Params, Argon2Variant};
+
+const SALT: &[u8; 16] = b"0123456789abcdef";
+
+#[test]
+fn test_verify_reference_hashes() {
+    let argon2i = "$argon2i$v=19$m=256,t=2,p=1$c29tZXNhbHQ$iekCn0Y3spW+sCcFanM2xBT63UP2sghkUoHLIUpWRS8";
+    let argon2id = "$argon2id$v=19$m=256,t=2,p=2$c29tZXNhbHQ$bQk8UB/VmZZF4Oo79iDXuL5/0ttZwg2f/5U52iv1cDc";
+    assert_eq!(verify_password(b"password", argon2i, &[]), Ok(true));
+    assert_eq!(verify_password(b"password", argon2id, &[]), Ok(true));
+    assert_eq!(verify_password(b"passwore", argon2id, &[]), Ok(false));
+}
+
+#[test]
+fn test_hash_then_verify() {
+    let params = Argon2Params { memory_kib: 7168, passes: 5, ..Default::default() };
+    let phc = hash_password(b"correct horse", &params, SALT).unwrap();
+    assert!(phc.starts_with("$argon2id$v=19$m=7168,t=5,p=1$"));
+    assert_eq!(verify_password(b"correct horse", &phc, &[]), Ok(true));
+    assert_eq!(verify_password(b"battery staple", &phc, &[]), Ok(false));
+}
+
+#[test]
+fn test_secret_changes_the_hash() {
+    let phc = "$argon2id$v=19$m=256,t=2,p=2$c29tZXNhbHQ$bQk8UB/VmZZF4Oo79iDXuL5/0ttZwg2f/5U52iv1cDc";
+    assert_eq!(verify_password(b"password", phc, b"pepper"), Ok(false));
+}
+
+#[test]
+fn test_default_parameters_are_accepted() {
+    assert!(weaknesses(&Argon2Params::default(), SALT).is_empty());
+    for (memory_kib, passes) in [(47_104, 1), (12_288, 3), (9_216, 4)] {
+        assert!(weaknesses(&Argon2Params { memory_kib, passes, ..Default::default() }, SALT).is_empty());
+    }
+}
+
+#[test]
+fn test_weak_parameters_are_refused() {
+    let low_memory = Argon2Params { memory_kib: 19_455, ..Default::default() };
+    assert_eq!(weaknesses(&low_memory, SALT).len(), 1);
+    assert!(hash_password(b"pw", &low_memory, SALT).is_err());
+
+    let one_pass = Argon2Params { passes: 1, ..Default::default() };
+    assert_eq!(weaknesses(&one_pass, SALT).len(), 1);
+
+    let argon2d = Argon2Params { variant: Argon2Variant::Argon2d, ..Default::default() };
+    assert_eq!(weaknesses(&argon2d, SALT).len(), 1);
+
+    let short_tag = Argon2Params { tag_length: 8, ..Default::default() };
+    assert_eq!(weaknesses(&short_tag, SALT).len(), 1);
+
+    assert_eq!(weaknesses(&Argon2Params::default(), b"8 bytes!").len(), 1);
+}
+
+#[test]
+fn test_variant_from_str() {
+    assert_eq!("argon2id".parse::<Argon2Variant>(), Ok(Argon2Variant::Argon2id));
+    assert_eq!("I".parse::<Argon2Variant>(), Ok(Argon2Variant::Argon2i));
+    assert_eq!("argon2d".parse::<Argon2Variant>(), Ok(Argon2Variant::Argon2d));
+    assert!("argon2".parse::<Argon2Variant>().is_err());
+}
@@ -0,0 +1,44 @@
+use encryption_demo::argon2::phc::{encode_phc, parse_phc};
+use encryption_demo::argon2::types::{Argon2Params, Argon2Variant};
+
+const REFERENCE: &str = "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc";
+
+#[test]
+fn test_parse_reference_phc_string() {
+    let parsed = parse_phc(REFERENCE).unwrap();
+    assert_eq!(parsed.variant, Argon2Variant::Argon2id);
+    assert_eq!((parsed.memory_kib, parsed.passes, parsed.lanes), (65536, 2, 1));
+    assert_eq!(parsed.salt, b"somesalt");
+    assert_eq!(hex::encode(&parsed.hash), "09316115d5cf24ed5a15a31a3ba326e5cf32edc24702987c02b6566f61913cf7");
+}
+
+#[test]
+fn test_encode_round_trip() {
+    let parsed = parse_phc(REFERENCE).unwrap();
+    assert_eq!(encode_phc(&parsed.params(), &parsed.salt, &parsed.hash), REFERENCE);
+}
+
+#[test]
+fn test_params_take_tag_length_from_hash() {
+    let params = Argon2Params { tag_length: 20, ..Default::default() };
+    let parsed = parse_phc(&encode_phc(&params, b"somesalt", &[7; 20])).unwrap();
+    assert_eq!(parsed.params().tag_length, 20);
+}
+
+#[test]
+fn test_parse_rejects_malformed_strings() {
+    assert!(parse_phc("argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFh").is_err());
+    assert!(parse_phc("$argon2x$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFh").is_err());
+    assert!(parse_phc("$argon2id$v=16$m=65536,t=2,p=1$c29tZXNhbHQ$CTFh").is_err());
+    assert!(parse_phc("$argon2id$v=19$t=2,m=65536,p=1$c29tZXNhbHQ$CTFh").is_err());
+    assert!(parse_phc("$argon2id$v=19$m=65536,t=2$c29tZXNhbHQ$CTFh").is_err());
+    assert!(parse_phc("$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ").is_err());
+    assert!(parse_phc("$argon2id$v=19$m=65536,t=2,p=1$c29t!XNhbHQ$CTFh").is_err());
+}
+
+#[test]
+fn test_parse_rejects_hostile_costs() {
+    assert!(parse_phc("$argon2id$v=19$m=8,t=4294967295,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc").is_err());
+    assert!(parse_phc("$argon2id$v=19$m=1048576,t=8,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc").is_err());
+    assert!(parse_phc("$argon2id$v=19$m=4294967295,t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc").is_err());
+}
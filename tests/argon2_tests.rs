@@ -0,0 +1 @@
+mod argon2;
@@ -0,0 +1,53 @@
+use encryption_demo::asn1::der::{
+    decode_integer, decode_oid, encode_integer, encode_length, encode_oid, read_element, read_header,
+};
+use encryption_demo::asn1::oid::OID_RSA_ENCRYPTION;
+use encryption_demo::asn1::types::TagClass;
+
+#[test]
+fn test_encode_length_forms() {
+    assert_eq!(encode_length(0x7f), vec![0x7f]);
+    assert_eq!(encode_length(0x80), vec![0x81, 0x80]);
+    assert_eq!(encode_length(0x0360), vec![0x82, 0x03, 0x60]);
+}
+
+#[test]
+fn test_integer_round_trip() {
+    for value in [0, 1, 127, 128, 65537, u64::MAX] {
+        let encoded = encode_integer(value);
+        let (element, rest) = read_element(&encoded).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(decode_integer(&element).unwrap(), value);
+    }
+    // 128 needs a leading zero to stay positive
+    assert_eq!(encode_integer(128), vec![0x02, 0x02, 0x00, 0x80]);
+}
+
+#[test]
+fn test_oid_round_trip() {
+    let encoded = encode_oid(OID_RSA_ENCRYPTION);
+    assert_eq!(hex::encode(&encoded), "06092a864886f70d010101");
+    let (element, _) = read_element(&encoded).unwrap();
+    assert_eq!(decode_oid(&element).unwrap(), OID_RSA_ENCRYPTION);
+}
+
+#[test]
+fn test_read_header_high_tag_number() {
+    // [APPLICATION 33] constructed, two-octet tag number form
+    let header = read_header(&[0x7f, 0x21, 0x02, 0x05, 0x00]).unwrap();
+    assert_eq!(header.tag.class, TagClass::Application);
+    assert!(header.tag.constructed);
+    assert_eq!(header.tag.number, 33);
+    assert_eq!(header.header_len, 3);
+    assert_eq!(header.length, 2);
+}
+
+#[test]
+fn test_read_header_rejects_indefinite_length() {
+    assert!(read_header(&[0x30, 0x80, 0x00, 0x00]).is_err());
+}
+
+#[test]
+fn test_read_element_rejects_truncated_content() {
+    assert!(read_element(&[0x04, 0x05, 0x01, 0x02]).is_err());
+}
@@ -0,0 +1,49 @@
+use encryption_demo::asn1::dump::{describe_value, explain, format_tree};
+use encryption_demo::asn1::parser::parse_der;
+use encryption_demo::common::pem::decode_pem;
+
+const CERTIFICATE: &str = include_str!("../vectors/asn1/selfsigned-rsa.pem");
+
+fn describe(der: &[u8]) -> String {
+    describe_value(&parse_der(der).unwrap()[0])
+}
+
+#[test]
+fn test_describe_integers() {
+    assert_eq!(describe(&[0x02, 0x01, 0x00]), "0");
+    assert_eq!(describe(&[0x02, 0x03, 0x01, 0x00, 0x01]), "65537 (0x10001)");
+    assert_eq!(describe(&[0x02, 0x01, 0xff]), "-1");
+}
+
+#[test]
+fn test_describe_oid_with_name() {
+    let der = [0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+    assert_eq!(describe(&der), "1.2.840.113549.1.1.11 (sha256WithRSAEncryption)");
+}
+
+#[test]
+fn test_describe_strings_and_times() {
+    assert_eq!(describe(&[0x13, 0x02, b'A', b'U']), "\"AU\"");
+    assert_eq!(describe(b"\x17\x0d991231235959Z"), "\"991231235959Z\" = 1999-12-31 23:59:59 UTC");
+    assert_eq!(describe(&[0x01, 0x01, 0xff]), "TRUE");
+}
+
+#[test]
+fn test_format_tree_matches_asn1parse_columns() {
+    let nodes = parse_der(&[0x30, 0x05, 0x02, 0x01, 0x07, 0x05, 0x00]).unwrap();
+    let lines = format_tree(&nodes);
+    assert_eq!(lines[0], "    0: d=0 hl=2 l=   5  SEQUENCE  2 element(s)");
+    assert_eq!(lines[1], "    2: d=1 hl=2 l=   1    INTEGER  7");
+    assert_eq!(lines[2], "    5: d=1 hl=2 l=   0    NULL");
+}
+
+#[test]
+fn test_explain_certificate() {
+    let (_, der) = decode_pem(CERTIFICATE).unwrap();
+    let notes = explain(&parse_der(&der).unwrap());
+
+    assert!(notes[0].starts_with("0x30 = 00 1 10000: universal, constructed, tag 16 → SEQUENCE"));
+    assert!(notes.iter().any(|note| note.starts_with("Length 0x82 ")));
+    assert!(notes.iter().any(|note| note.starts_with("2.5.4.3 = commonName")));
+    assert!(notes.iter().any(|note| note.contains("BIT STRING content is itself DER")));
+}
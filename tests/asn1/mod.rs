@@ -0,0 +1,3 @@
+mod der_tests;
+mod dump_tests;
+mod parser_tests;
@@ -0,0 +1,63 @@
+use encryption_demo::asn1::der::{encode_null, encode_tlv, TAG_SEQUENCE};
+use encryption_demo::asn1::parser::{parse_der, MAX_DEPTH};
+use encryption_demo::common::pem::decode_pem;
+
+const CERTIFICATE: &str = include_str!("../vectors/asn1/selfsigned-rsa.pem");
+
+#[test]
+fn test_parse_nested_offsets() {
+    // SEQUENCE { INTEGER 5, SEQUENCE { NULL } }
+    let nodes = parse_der(&[0x30, 0x07, 0x02, 0x01, 0x05, 0x30, 0x02, 0x05, 0x00]).unwrap();
+    assert_eq!(nodes.len(), 1);
+
+    let root = &nodes[0];
+    assert!(root.tag.is_universal(16));
+    assert_eq!(root.children.len(), 2);
+    assert_eq!(root.children[0].offset, 2);
+    assert_eq!(root.children[1].offset, 5);
+    assert_eq!(root.children[1].children[0].offset, 7);
+}
+
+#[test]
+fn test_parse_bit_string_encapsulation() {
+    // BIT STRING { 0 unused bits, SEQUENCE { INTEGER 1 } }
+    let nodes = parse_der(&[0x03, 0x06, 0x00, 0x30, 0x03, 0x02, 0x01, 0x01]).unwrap();
+    assert!(nodes[0].encapsulates);
+    assert_eq!(nodes[0].children[0].offset, 3);
+
+    // Content that does not parse stays a plain string
+    let nodes = parse_der(&[0x04, 0x03, 0x30, 0x05, 0x00]).unwrap();
+    assert!(!nodes[0].encapsulates);
+    assert!(nodes[0].children.is_empty());
+}
+
+#[test]
+fn test_parse_certificate() {
+    let (label, der) = decode_pem(CERTIFICATE).unwrap();
+    assert_eq!(label, "CERTIFICATE");
+
+    let nodes = parse_der(&der).unwrap();
+    let certificate = &nodes[0];
+    // tbsCertificate, signatureAlgorithm, signatureValue
+    assert_eq!(certificate.children.len(), 3);
+    assert_eq!(certificate.header.len() + certificate.content.len(), der.len());
+
+    let all = certificate.depth_first();
+    assert!(all.iter().any(|node| node.tag.is_universal(6)));
+    assert!(all.iter().any(|node| node.encapsulates));
+}
+
+#[test]
+fn test_parse_rejects_trailing_garbage_in_constructed() {
+    // SEQUENCE claims 3 bytes but contains a truncated INTEGER
+    assert!(parse_der(&[0x30, 0x03, 0x02, 0x05, 0x00]).is_err());
+}
+
+#[test]
+fn test_parse_depth_limit() {
+    let mut der = encode_null();
+    for _ in 0..MAX_DEPTH + 2 {
+        der = encode_tlv(TAG_SEQUENCE, &der);
+    }
+    assert!(parse_der(&der).is_err());
+}
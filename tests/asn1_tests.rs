@@ -0,0 +1 @@
+mod asn1;
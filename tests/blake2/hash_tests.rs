@@ -0,0 +1,81 @@
+use encryption_demo::blake2::hash::{blake2b, blake2b_512, blake2s, blake2s_256};
+use encryption_demo::blake2::types::Blake2Params;
+
+#[test]
+fn test_blake2_rfc7693_abc() {
+    assert_eq!(
+        hex::encode(blake2b_512(b"abc")),
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+         7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+    );
+    assert_eq!(
+        hex::encode(blake2s_256(b"abc")),
+        "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+    );
+}
+
+#[test]
+fn test_blake2s_empty_message() {
+    assert_eq!(
+        hex::encode(blake2s_256(b"")),
+        "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+    );
+}
+
+#[test]
+fn test_blake2b_keyed_truncated() {
+    let params = Blake2Params { key: b"secret key".to_vec(), ..Blake2Params::new(32) };
+    assert_eq!(
+        hex::encode(blake2b(b"abc", &params).unwrap()),
+        "66c28e9d1dcd69d6756fc52125fe1838cf0c6a87d058545a9ff676bf51beaa6f"
+    );
+}
+
+#[test]
+fn test_blake2b_keyed_multi_block() {
+    let message: Vec<u8> = (0..300).map(|i| (i % 251) as u8).collect();
+    let params = Blake2Params { key: b"k".to_vec(), ..Blake2Params::new(64) };
+    assert_eq!(
+        hex::encode(blake2b(&message, &params).unwrap()),
+        "04c9b66f99ab6076d7c47da94f0a59663020d7b04de989c282f1413f1fdb2017\
+         1eef67b13e7f7d358afdf35fb111f9a33a7b49ed1ff57d7e8bc268e00143f732"
+    );
+}
+
+#[test]
+fn test_blake2b_salt_and_personal() {
+    let params = Blake2Params { salt: b"NaCl".to_vec(), personal: b"demo app".to_vec(), ..Blake2Params::new(64) };
+    assert_eq!(
+        hex::encode(blake2b(b"abc", &params).unwrap()),
+        "b083c5f2fa649de78aef30cd7017e0566fe126668fd6299731b730f3633a75a5\
+         b806a3d9924d0b0c8870b6bc8b70a504c9cfd63e4be30fd50527f7d8a9600077"
+    );
+}
+
+#[test]
+fn test_blake2s_all_parameters() {
+    let params = Blake2Params {
+        digest_length: 20,
+        key: b"k".to_vec(),
+        salt: b"s".to_vec(),
+        personal: b"p".to_vec(),
+    };
+    assert_eq!(hex::encode(blake2s(b"abc", &params).unwrap()), "5627c6bd9d46169384f5a5d096204f4c4b2676a3");
+}
+
+#[test]
+fn test_blake2_digest_length_changes_whole_output() {
+    let short = blake2b(b"abc", &Blake2Params::new(32)).unwrap();
+    assert_ne!(short[..], blake2b_512(b"abc")[..32]);
+}
+
+#[test]
+fn test_blake2_rejects_bad_parameters() {
+    assert!(blake2b(b"abc", &Blake2Params::new(0)).is_err());
+    assert!(blake2b(b"abc", &Blake2Params::new(65)).is_err());
+    assert!(blake2s(b"abc", &Blake2Params::new(33)).is_err());
+    let long_key = Blake2Params { key: vec![0; 33], ..Blake2Params::new(32) };
+    assert!(blake2s(b"abc", &long_key).is_err());
+    let long_salt = Blake2Params { salt: vec![0; 9], ..Blake2Params::new(32) };
+    assert!(blake2s(b"abc", &long_salt).is_err());
+}
@@ -0,0 +1 @@
+mod blake2;
@@ -0,0 +1,48 @@
+use encryption_demo::blake3::hash::{blake3, blake3_derive_key, blake3_keyed, blake3_xof};
+use serde_json::Value;
+
+const VECTORS: &str = include_str!("../vectors/blake3/test_vectors.json");
+
+fn input(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn test_blake3_official_vectors() {
+    let vectors: Value = serde_json::from_str(VECTORS).unwrap();
+    let key: [u8; 32] = vectors["key"].as_str().unwrap().as_bytes().try_into().unwrap();
+    let context = vectors["context_string"].as_str().unwrap();
+
+    for case in vectors["cases"].as_array().unwrap() {
+        let length = case["input_len"].as_u64().unwrap() as usize;
+        let message = input(length);
+        let expected = |field: &str| hex::decode(case[field].as_str().unwrap()).unwrap();
+
+        let hash = expected("hash");
+        assert_eq!(blake3_xof(&message, hash.len()), hash, "hash, input_len {}", length);
+        assert_eq!(blake3(&message)[..], hash[..32], "hash prefix, input_len {}", length);
+
+        let keyed = expected("keyed_hash");
+        assert_eq!(blake3_keyed(&key, &message, keyed.len()), keyed, "keyed_hash, input_len {}", length);
+        assert_eq!(blake3_keyed(&key, &message, 32)[..], keyed[..32]);
+
+        let derived = expected("derive_key");
+        assert_eq!(blake3_derive_key(context, &message, derived.len()), derived, "derive_key, input_len {}", length);
+        assert_eq!(blake3_derive_key(context, &message, 32)[..], derived[..32]);
+    }
+}
+
+#[test]
+fn test_blake3_abc() {
+    assert_eq!(
+        hex::encode(blake3(b"abc")),
+        "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+    );
+}
+
+#[test]
+fn test_blake3_xof_is_prefix_consistent() {
+    let long = blake3_xof(b"abc", 200);
+    assert_eq!(blake3_xof(b"abc", 65)[..], long[..65]);
+    assert_eq!(blake3_xof(b"abc", 0), Vec::<u8>::new());
+}
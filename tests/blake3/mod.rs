@@ -0,0 +1,2 @@
+mod hash_tests;
+mod tree_tests;
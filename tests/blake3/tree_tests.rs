@@ -0,0 +1,43 @@
+use encryption_demo::blake3::compression::IV;
+use encryption_demo::blake3::constants::CHUNK_LEN;
+use encryption_demo::blake3::hash::blake3;
+use encryption_demo::blake3::tree::{hash_subtree, left_subtree_len, TreeNode};
+
+#[test]
+fn test_left_subtree_len_is_largest_power_of_two() {
+    assert_eq!(left_subtree_len(CHUNK_LEN + 1), CHUNK_LEN);
+    assert_eq!(left_subtree_len(2 * CHUNK_LEN), CHUNK_LEN);
+    assert_eq!(left_subtree_len(3 * CHUNK_LEN), 2 * CHUNK_LEN);
+    assert_eq!(left_subtree_len(4 * CHUNK_LEN), 2 * CHUNK_LEN);
+    assert_eq!(left_subtree_len(4 * CHUNK_LEN + 1), 4 * CHUNK_LEN);
+    assert_eq!(left_subtree_len(31 * CHUNK_LEN), 16 * CHUNK_LEN);
+}
+
+#[test]
+fn test_tree_shape() {
+    let input = vec![0u8; 5 * CHUNK_LEN + 10];
+    let (tree, _) = hash_subtree(&input, 0, &IV, 0);
+    assert_eq!(tree.chunk_count(), 6);
+    assert_eq!(tree.depth(), 3);
+
+    match tree {
+        TreeNode::Parent { left, right, .. } => {
+            assert_eq!(left.chunk_count(), 4);
+            assert_eq!(right.chunk_count(), 2);
+        }
+        TreeNode::Chunk { .. } => panic!("six chunks must give a parent root"),
+    }
+}
+
+#[test]
+fn test_single_chunk_tree() {
+    let (tree, _) = hash_subtree(b"abc", 0, &IV, 0);
+    assert_eq!(tree.depth(), 0);
+    assert!(matches!(tree, TreeNode::Chunk { index: 0, start: 0, end: 3, .. }));
+}
+
+#[test]
+fn test_chunk_order_matters() {
+    let (a, b) = (vec![1u8; CHUNK_LEN], vec![2u8; CHUNK_LEN]);
+    assert_ne!(blake3(&[a.clone(), b.clone()].concat()), blake3(&[b, a].concat()));
+}
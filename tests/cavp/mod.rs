@@ -0,0 +1,2 @@
+mod parser_tests;
+mod runner_tests;
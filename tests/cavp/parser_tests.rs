@@ -0,0 +1,56 @@
+use encryption_demo::cavp::parser::{describe, detect_kind, parse_rsp};
+use encryption_demo::cavp::types::VectorKind;
+
+const SHA_SAMPLE: &str = "#  CAVS 11.0\r\n#  \"SHA-256 ShortMsg\" information\r\n\r\n[L = 32]\r\n\r\nLen = 0\r\nMsg = 00\r\nMD = e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855\r\n\r\nLen = 8\r\nMsg = d3\r\nMD = 28969cdfa74a12c82f3bad960b0b000aca2ac329deea5c2328ebc6f2ba9802c1\r\n";
+
+const AES_SAMPLE: &str = "# CAVS 11.1
+# Config info for aes_values
+# AESVS GFSbox test data for ECB
+# State : Encrypt and Decrypt
+# Key Length : 128
+
+[ENCRYPT]
+
+COUNT = 0
+KEY = 00000000000000000000000000000000
+PLAINTEXT = f34481ec3cc627bacd5dc3fb08f273e6
+CIPHERTEXT = 0336763e966d92595a567cc9ce537f5e
+
+[DECRYPT]
+
+COUNT = 0
+KEY = 00000000000000000000000000000000
+CIPHERTEXT = 0336763e966d92595a567cc9ce537f5e
+PLAINTEXT = f34481ec3cc627bacd5dc3fb08f273e6
+";
+
+#[test]
+fn test_parse_sha_sections_and_records() {
+    let file = parse_rsp(SHA_SAMPLE).unwrap();
+    assert_eq!(file.sections.len(), 1);
+    assert_eq!(file.sections[0].name, "L = 32");
+    assert_eq!(file.sections[0].records.len(), 2);
+    assert_eq!(file.sections[0].records[1].get("Msg"), Some("d3"));
+}
+
+#[test]
+fn test_parse_header_description() {
+    let file = parse_rsp(SHA_SAMPLE).unwrap();
+    assert_eq!(describe(&file), "SHA-256 ShortMsg");
+    assert_eq!(detect_kind("SHA256ShortMsg.rsp", &file), VectorKind::HashMessage);
+}
+
+#[test]
+fn test_parse_aes_encrypt_decrypt_sections() {
+    let file = parse_rsp(AES_SAMPLE).unwrap();
+    let names: Vec<_> = file.sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["ENCRYPT", "DECRYPT"]);
+    assert_eq!(file.sections[1].records[0].get("CIPHERTEXT"), Some("0336763e966d92595a567cc9ce537f5e"));
+    assert_eq!(detect_kind("ECBGFSbox128.rsp", &file), VectorKind::AesKnownAnswer);
+}
+
+#[test]
+fn test_parse_rejects_malformed_line() {
+    let result = parse_rsp("[L = 32]\n\nLen 8\n");
+    assert!(result.unwrap_err().contains("Line 3"));
+}
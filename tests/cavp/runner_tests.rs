@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use encryption_demo::cavp::runner::{run_directory, run_file};
+use encryption_demo::cavp::types::VectorKind;
+
+const VECTOR_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors/cavp");
+
+#[test]
+fn test_vendored_vectors_all_pass() {
+    let reports = run_directory(Path::new(VECTOR_DIR)).unwrap();
+    assert!(!reports.is_empty());
+
+    for report in &reports {
+        assert!(report.is_success(), "{}: {:?}", report.file_name, report.failures);
+        assert!(report.passed > 0, "{} ran no vectors", report.file_name);
+    }
+}
+
+#[test]
+fn test_vendored_aes_files_run() {
+    let cases = [
+        ("ECBGFSbox128.rsp", VectorKind::AesKnownAnswer, 14),
+        ("ECBMMT128.rsp", VectorKind::AesMultiBlock, 3),
+        ("CBCMMT128.rsp", VectorKind::AesMultiBlock, 3),
+    ];
+    for (name, kind, records) in cases {
+        let contents = std::fs::read_to_string(Path::new(VECTOR_DIR).join(name)).unwrap();
+        let report = run_file(name, &contents);
+        assert_eq!(report.kind, kind, "{}", name);
+        assert_eq!(report.passed, records, "{}: {:?}", name, report.failures);
+        assert_eq!(report.skipped, 0, "{}", name);
+    }
+}
+
+#[test]
+fn test_wrong_aes_ciphertext_is_reported() {
+    let contents = "# AESVS GFSbox test data for ECB\n\n[ENCRYPT]\n\nCOUNT = 0\nKEY = 00000000000000000000000000000000\nPLAINTEXT = f34481ec3cc627bacd5dc3fb08f273e6\nCIPHERTEXT = 00000000000000000000000000000000\n";
+    let report = run_file("ECBGFSbox128.rsp", contents);
+    assert_eq!(report.passed, 0);
+    assert_eq!(report.failed, 1);
+}
+
+#[test]
+fn test_unsupported_aes_mode_is_skipped() {
+    let contents = "# AESVS MMT test data for OFB\n\n[ENCRYPT]\n\nCOUNT = 0\nKEY = 00000000000000000000000000000000\nIV = 00000000000000000000000000000000\nPLAINTEXT = 00\nCIPHERTEXT = 00\n";
+    let report = run_file("OFBMMT128.rsp", contents);
+    assert_eq!(report.skipped, 1);
+    assert!(report.is_success());
+}
+
+#[test]
+fn test_wrong_digest_is_reported() {
+    let contents = "#  \"SHA-256 ShortMsg\" information\n\n[L = 32]\n\nLen = 8\nMsg = d3\nMD = 00\n";
+    let report = run_file("SHA256ShortMsg.rsp", contents);
+    assert_eq!(report.passed, 0);
+    assert_eq!(report.failed, 1);
+}
+
+#[test]
+fn test_message_shorter_than_len_is_reported() {
+    let contents = "#  \"SHA-256 ShortMsg\" information\n\n[L = 32]\n\nLen = 64\nMsg = 00\nMD = 00\n";
+    let report = run_file("SHA256ShortMsg.rsp", contents);
+    assert_eq!(report.failed, 1);
+    assert!(report.failures[0].contains("malformed record"), "{:?}", report.failures);
+}
+
+#[test]
+fn test_unimplemented_algorithm_is_skipped() {
+    let contents = "#  \"SHA-512 ShortMsg\" information\n\n[L = 64]\n\nLen = 0\nMsg = 00\nMD = 00\n";
+    let report = run_file("SHA512ShortMsg.rsp", contents);
+    assert_eq!(report.skipped, 1);
+    assert!(report.is_success());
+    assert!(report.note.is_some());
+}
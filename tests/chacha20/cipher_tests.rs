@@ -0,0 +1,49 @@
+use encryption_demo::chacha20::cipher::{apply_keystream, block, quarter_round, State};
+
+fn key() -> [u8; 32] {
+    std::array::from_fn(|i| i as u8)
+}
+
+fn nonce(hex: &str) -> [u8; 12] {
+    hex::decode(hex).unwrap().try_into().unwrap()
+}
+
+#[test]
+fn test_quarter_round_rfc8439() {
+    // RFC 8439 §2.1.1
+    let mut state: State = [0; 16];
+    state[..4].copy_from_slice(&[0x11111111, 0x01020304, 0x9b8d6f43, 0x01234567]);
+    quarter_round(&mut state, 0, 1, 2, 3);
+    assert_eq!(state[..4], [0xea2a92f4, 0xcb1cf8ce, 0x4581472e, 0x5881c4bb]);
+}
+
+#[test]
+fn test_block_rfc8439() {
+    // RFC 8439 §2.3.2
+    let output = block(&key(), 1, &nonce("000000090000004a00000000"));
+    assert_eq!(
+        hex::encode(output),
+        "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+         d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+    );
+}
+
+#[test]
+fn test_encrypt_rfc8439() {
+    // RFC 8439 §2.4.2: 114 bytes, so the second block is partial
+    let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+    let ciphertext = apply_keystream(&key(), 1, &nonce("000000000000004a00000000"), plaintext);
+    assert_eq!(
+        hex::encode(&ciphertext),
+        "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+         f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+         07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+         5af90bbf74a35be6b40b8eedf2785e42874d"
+    );
+    assert_eq!(apply_keystream(&key(), 1, &nonce("000000000000004a00000000"), &ciphertext), plaintext);
+}
+
+#[test]
+fn test_empty_input() {
+    assert!(apply_keystream(&key(), 0, &[0; 12], b"").is_empty());
+}
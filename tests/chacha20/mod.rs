@@ -0,0 +1 @@
+mod cipher_tests;
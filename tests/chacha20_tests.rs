@@ -0,0 +1 @@
+mod chacha20;
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use encryption_demo::collisions::known::{
+    compare_digests, differing_blocks, differing_offsets, load_pair, Digest, KNOWN_COLLISIONS,
+};
+
+const VECTOR_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors/collisions");
+
+#[test]
+fn test_vendored_pairs_collide_only_under_broken_hash() {
+    for collision in &KNOWN_COLLISIONS {
+        let (first, second) = load_pair(Path::new(VECTOR_DIR), collision).unwrap();
+        assert_ne!(first, second, "{}", collision.name);
+
+        for comparison in compare_digests(&first, &second) {
+            assert_eq!(
+                comparison.collides(),
+                comparison.digest == collision.broken,
+                "{} under {}",
+                collision.name,
+                comparison.digest.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_collision_survives_common_suffix() {
+    for collision in &KNOWN_COLLISIONS {
+        let (mut first, mut second) = load_pair(Path::new(VECTOR_DIR), collision).unwrap();
+        first.extend_from_slice(b"suffix");
+        second.extend_from_slice(b"suffix");
+        assert_eq!(collision.broken.hex(&first), collision.broken.hex(&second), "{}", collision.name);
+    }
+}
+
+#[test]
+fn test_shattered_differences_are_in_blocks_3_and_4() {
+    let (first, second) = load_pair(Path::new(VECTOR_DIR), &KNOWN_COLLISIONS[1]).unwrap();
+    assert_eq!(differing_blocks(&first, &second), vec![3, 4]);
+    assert_eq!(Digest::Sha1.hex(&first), "f92d74e3874587aaf443d1db961d4e26dde13e9c");
+}
+
+#[test]
+fn test_differing_offsets() {
+    assert_eq!(differing_offsets(b"abcd", b"abXd"), vec![2]);
+    assert_eq!(differing_offsets(b"ab", b"abcd"), vec![2, 3]);
+    assert!(differing_offsets(b"same", b"same").is_empty());
+}
+
+#[test]
+fn test_missing_directory_is_an_error() {
+    assert!(load_pair(Path::new("/nonexistent"), &KNOWN_COLLISIONS[0]).unwrap_err().contains("Cannot read"));
+}
@@ -0,0 +1 @@
+mod known_tests;
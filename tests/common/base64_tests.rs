@@ -0,0 +1,40 @@
+use encryption_demo::common::base64::{decode, decode_url, encode, encode_unpadded, encode_url};
+
+#[test]
+fn test_encode_rfc4648_vectors() {
+    // RFC 4648 §10
+    assert_eq!(encode(b""), "");
+    assert_eq!(encode(b"f"), "Zg==");
+    assert_eq!(encode(b"fo"), "Zm8=");
+    assert_eq!(encode(b"foo"), "Zm9v");
+    assert_eq!(encode(b"foob"), "Zm9vYg==");
+    assert_eq!(encode(b"fooba"), "Zm9vYmE=");
+    assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+}
+
+#[test]
+fn test_decode_round_trip() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(decode(&encode(&bytes)).unwrap(), bytes);
+}
+
+#[test]
+fn test_decode_ignores_whitespace_and_padding() {
+    assert_eq!(decode("Zm9v\nYmE=").unwrap(), b"fooba");
+    assert_eq!(decode("Zm9vYg").unwrap(), b"foob");
+}
+
+#[test]
+fn test_decode_rejects_invalid_input() {
+    assert!(decode("Zm9v!").is_err());
+    assert!(decode("Z").is_err());
+}
+
+#[test]
+fn test_url_safe_alphabet_and_no_padding() {
+    assert_eq!(encode_url(&[0xfb, 0xff]), "-_8");
+    assert_eq!(decode_url("-_8").unwrap(), vec![0xfb, 0xff]);
+    assert_eq!(encode_unpadded(b"f"), "Zg");
+    // The standard alphabet characters are not valid base64url
+    assert!(decode_url("+/8=").is_err());
+}
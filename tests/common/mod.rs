@@ -1 +1,4 @@
+mod base64_tests;
+mod pem_tests;
+mod random_tests;
 mod validation_tests;
@@ -0,0 +1,33 @@
+use encryption_demo::common::pem::{decode_pem, encode_pem, is_pem};
+
+#[test]
+fn test_pem_round_trip() {
+    let der: Vec<u8> = (0..100).collect();
+    let pem = encode_pem("TEST DATA", &der);
+
+    assert!(pem.starts_with("-----BEGIN TEST DATA-----\n"));
+    assert!(pem.ends_with("-----END TEST DATA-----\n"));
+    assert!(pem.lines().all(|line| line.len() <= 64));
+
+    let (label, decoded) = decode_pem(&pem).unwrap();
+    assert_eq!(label, "TEST DATA");
+    assert_eq!(decoded, der);
+}
+
+#[test]
+fn test_decode_pem_skips_surrounding_text() {
+    let text = "Some comment\n-----BEGIN X-----\nAQID\n-----END X-----\ntrailer";
+    assert_eq!(decode_pem(text).unwrap(), ("X".to_string(), vec![1, 2, 3]));
+}
+
+#[test]
+fn test_decode_pem_requires_matching_end() {
+    assert!(decode_pem("-----BEGIN A-----\nAQID\n-----END B-----\n").is_err());
+    assert!(decode_pem("AQID").is_err());
+}
+
+#[test]
+fn test_is_pem() {
+    assert!(is_pem(b"-----BEGIN PUBLIC KEY-----"));
+    assert!(!is_pem(&[0x30, 0x0e, 0x02, 0x09]));
+}
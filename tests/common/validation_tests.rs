@@ -0,0 +1,22 @@
+use encryption_demo::common::validation::parse_hex_message;
+
+#[test]
+fn test_hex_message_decodes_bare_and_prefixed_hex() {
+    assert_eq!(parse_hex_message("616263"), Ok(Some(b"abc".to_vec())));
+    assert_eq!(parse_hex_message("0x0102"), Ok(Some(vec![0x01, 0x02])));
+    assert_eq!(parse_hex_message("0XFFfe"), Ok(Some(vec![0xff, 0xfe])));
+}
+
+#[test]
+fn test_hex_message_keeps_text() {
+    assert_eq!(parse_hex_message("abc"), Ok(None));
+    assert_eq!(parse_hex_message("Hello, World!"), Ok(None));
+    assert_eq!(parse_hex_message(""), Ok(None));
+}
+
+#[test]
+fn test_hex_message_rejects_bad_prefixed_hex() {
+    assert!(parse_hex_message("0xzz").is_err());
+    assert!(parse_hex_message("0xabc").is_err());
+    assert!(parse_hex_message("0x").is_err());
+}
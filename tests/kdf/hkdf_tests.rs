@@ -0,0 +1,51 @@
+use encryption_demo::kdf::hkdf::{hkdf, hkdf_expand, hkdf_expand_blocks, hkdf_extract, MAX_OUTPUT_BYTES};
+
+#[test]
+fn test_hkdf_rfc5869_case_1() {
+    let ikm = [0x0b; 22];
+    let salt: Vec<u8> = (0x00..=0x0c).collect();
+    let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+    let prk = hkdf_extract(&salt, &ikm);
+    assert_eq!(hex::encode(prk), "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+    assert_eq!(
+        hex::encode(hkdf_expand(&prk, &info, 42).unwrap()),
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+    );
+}
+
+#[test]
+fn test_hkdf_rfc5869_case_3_empty_salt_and_info() {
+    let ikm = [0x0b; 22];
+    assert_eq!(
+        hex::encode(hkdf_extract(b"", &ikm)),
+        "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04"
+    );
+    assert_eq!(
+        hex::encode(hkdf(b"", &ikm, b"", 42).unwrap()),
+        "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8"
+    );
+}
+
+#[test]
+fn test_hkdf_expand_blocks_chain() {
+    let prk = hkdf_extract(b"salt", b"secret");
+    let blocks = hkdf_expand_blocks(&prk, b"info", 65).unwrap();
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(blocks.concat()[..65], hkdf_expand(&prk, b"info", 65).unwrap()[..]);
+}
+
+#[test]
+fn test_hkdf_info_separates_keys() {
+    let a = hkdf(b"salt", b"secret", b"client", 32).unwrap();
+    let b = hkdf(b"salt", b"secret", b"server", 32).unwrap();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_hkdf_output_length_limits() {
+    let prk = hkdf_extract(b"", b"secret");
+    assert!(hkdf_expand(&prk, b"", 0).is_err());
+    assert!(hkdf_expand(&prk, b"", MAX_OUTPUT_BYTES + 1).is_err());
+    assert_eq!(hkdf_expand(&prk, b"", MAX_OUTPUT_BYTES).unwrap().len(), MAX_OUTPUT_BYTES);
+}
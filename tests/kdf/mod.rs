@@ -0,0 +1,3 @@
+mod hkdf_tests;
+mod pbkdf2_tests;
+mod scrypt_tests;
@@ -0,0 +1,36 @@
+use encryption_demo::kdf::pbkdf2::{pbkdf2_block, pbkdf2_hmac_sha256};
+
+#[test]
+fn test_pbkdf2_sha256_vectors() {
+    assert_eq!(
+        hex::encode(pbkdf2_hmac_sha256(b"password", b"salt", 1, 32).unwrap()),
+        "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+    );
+    assert_eq!(
+        hex::encode(pbkdf2_hmac_sha256(b"password", b"salt", 4096, 32).unwrap()),
+        "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+    );
+}
+
+#[test]
+fn test_pbkdf2_multi_block_output() {
+    assert_eq!(
+        hex::encode(
+            pbkdf2_hmac_sha256(b"passwordPASSWORDpassword", b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 4096, 40).unwrap()
+        ),
+        "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9"
+    );
+}
+
+#[test]
+fn test_pbkdf2_blocks_are_independent() {
+    let output = pbkdf2_hmac_sha256(b"password", b"salt", 10, 64).unwrap();
+    assert_eq!(output[..32], pbkdf2_block(b"password", b"salt", 10, 1, false));
+    assert_eq!(output[32..], pbkdf2_block(b"password", b"salt", 10, 2, false));
+}
+
+#[test]
+fn test_pbkdf2_rejects_zero_iterations_and_length() {
+    assert!(pbkdf2_hmac_sha256(b"password", b"salt", 0, 32).is_err());
+    assert!(pbkdf2_hmac_sha256(b"password", b"salt", 1, 0).is_err());
+}
@@ -0,0 +1,70 @@
+use encryption_demo::kdf::scrypt::{salsa20_8, scrypt, validate_params};
+use encryption_demo::kdf::types::{KdfAlgorithm, ScryptParams};
+
+fn words(hex: &str) -> [u32; 16] {
+    let bytes = hex::decode(hex).unwrap();
+    let mut words = [0u32; 16];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+#[test]
+fn test_salsa20_8_rfc7914_vector() {
+    let input = words(
+        "7e879a214f3ec9867ca940e641718f26baee555b8c61c1b50df846116dcd3b1d\
+         ee24f319df9b3d8514121e4b5ac5aa3276021d2909c74829edebc68db8b8c25e",
+    );
+    let output = words(
+        "a41f859c6608cc993b81cacb020cef05044b2181a2fd337dfd7b1c6396682f29\
+         b4393168e3c9e6bcfe6bc5b7a06d96bae424cc102c91745c24ad673dc7618f81",
+    );
+    assert_eq!(salsa20_8(&input), output);
+}
+
+#[test]
+fn test_scrypt_rfc7914_empty() {
+    let params = ScryptParams { log_n: 4, r: 1, p: 1 };
+    assert_eq!(
+        hex::encode(scrypt(b"", b"", &params, 64).unwrap()),
+        "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+         fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+    );
+}
+
+#[test]
+fn test_scrypt_rfc7914_password_nacl() {
+    let params = ScryptParams { log_n: 10, r: 8, p: 16 };
+    assert_eq!(
+        hex::encode(scrypt(b"password", b"NaCl", &params, 64).unwrap()),
+        "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+         2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+    );
+}
+
+#[test]
+fn test_scrypt_memory_cost() {
+    assert_eq!(ScryptParams::default().memory_bytes(), 16 << 20);
+    assert_eq!(ScryptParams { log_n: 10, r: 8, p: 1 }.memory_bytes(), 1 << 20);
+}
+
+#[test]
+fn test_scrypt_rejects_bad_parameters() {
+    assert!(validate_params(&ScryptParams { log_n: 0, r: 8, p: 1 }).is_err());
+    assert!(validate_params(&ScryptParams { log_n: 10, r: 0, p: 1 }).is_err());
+    assert!(validate_params(&ScryptParams { log_n: 10, r: 8, p: 0 }).is_err());
+    assert!(validate_params(&ScryptParams { log_n: 16, r: 1, p: 1 }).is_err());
+    assert!(validate_params(&ScryptParams { log_n: 30, r: 8, p: 1 }).is_err());
+    // Small N, but p lanes of B alone need about 24 GiB
+    assert!(validate_params(&ScryptParams { log_n: 1, r: 1, p: 200_000_000 }).is_err());
+    assert!(validate_params(&ScryptParams::default()).is_ok());
+}
+
+#[test]
+fn test_kdf_algorithm_from_str() {
+    assert_eq!("scrypt".parse::<KdfAlgorithm>(), Ok(KdfAlgorithm::Scrypt));
+    assert_eq!("PBKDF2-HMAC-SHA256".parse::<KdfAlgorithm>(), Ok(KdfAlgorithm::Pbkdf2));
+    assert_eq!("hkdf".parse::<KdfAlgorithm>(), Ok(KdfAlgorithm::Hkdf));
+    assert!("bcrypt".parse::<KdfAlgorithm>().is_err());
+}
@@ -0,0 +1,37 @@
+use encryption_demo::md5::hash::{md5, md5_bytes, md5_hex};
+use encryption_demo::md5::preprocessing::preprocess_message;
+
+#[test]
+fn test_md5_rfc1321_suite() {
+    let cases = [
+        ("", "d41d8cd98f00b204e9800998ecf8427e"),
+        ("a", "0cc175b9c0f1b6a831c399e269772661"),
+        ("abc", "900150983cd24fb0d6963f7d28e17f72"),
+        ("message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+        ("abcdefghijklmnopqrstuvwxyz", "c3fcd3d76192e4007dfb496cca67e13b"),
+        ("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", "d174ab98d277d9f5a5611c2c9f419d9f"),
+        (
+            "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            "57edf4a22be3c955ac49da2e2107b67a",
+        ),
+    ];
+    for (message, expected) in cases {
+        assert_eq!(md5_hex(message.as_bytes()), expected, "MD5({:?})", message);
+    }
+}
+
+#[test]
+fn test_md5_little_endian_output() {
+    let words = md5(b"abc");
+    assert_eq!(words[0], 0x98500190);
+    assert_eq!(md5_bytes(b"abc")[..4], [0x90, 0x01, 0x50, 0x98]);
+}
+
+#[test]
+fn test_preprocess_message_little_endian() {
+    let blocks = preprocess_message(b"abc");
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0][0], 0x80636261);
+    assert_eq!(blocks[0][14], 24);
+    assert_eq!(blocks[0][15], 0);
+}
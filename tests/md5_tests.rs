@@ -0,0 +1 @@
+mod md5;
@@ -0,0 +1,2 @@
+mod proof_tests;
+mod tree_tests;
@@ -0,0 +1,108 @@
+use encryption_demo::merkle::hash::HashScheme;
+use encryption_demo::merkle::proof::{inclusion_proof, root_from_proof, sibling_ranges, verify_inclusion, InclusionProof};
+use encryption_demo::merkle::tree::MerkleTree;
+
+const CT_LEAVES: [&str; 8] = ["", "00", "10", "2021", "3031", "40414243", "5051525354555657", "606162636465666768696a6b6c6d6e6f"];
+
+fn ct_tree(size: usize) -> (Vec<Vec<u8>>, MerkleTree) {
+    let leaves: Vec<Vec<u8>> = CT_LEAVES[..size].iter().map(|leaf| hex::decode(leaf).unwrap()).collect();
+    let tree = MerkleTree::new(HashScheme::Rfc6962, &leaves);
+    (leaves, tree)
+}
+
+fn path_hex(proof: &InclusionProof) -> Vec<String> {
+    proof.path.iter().map(hex::encode).collect()
+}
+
+#[test]
+fn test_ct_reference_path_leaf_2_of_7() {
+    let (_, tree) = ct_tree(7);
+    let proof = inclusion_proof(&tree, 2).unwrap();
+    assert_eq!(
+        path_hex(&proof),
+        [
+            "07506a85fd9dd2f120eb694f86011e5bb4662e5c415a62917033d4a9624487e7",
+            "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+            "837dbb152e9b079010717e84e865da4ebc0fa198a806d59d31bf15accef22d0e",
+        ]
+    );
+}
+
+#[test]
+fn test_ct_reference_path_leaf_5_of_8() {
+    let (_, tree) = ct_tree(8);
+    let proof = inclusion_proof(&tree, 5).unwrap();
+    assert_eq!(
+        path_hex(&proof),
+        [
+            "bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b",
+            "ca854ea128ed050b41b35ffc1b87b8eb2bde461e9e3b5596ece6b9d5975a0ae0",
+            "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+        ]
+    );
+}
+
+#[test]
+fn test_every_leaf_verifies_in_every_tree_size() {
+    for size in 1..=CT_LEAVES.len() {
+        let (leaves, tree) = ct_tree(size);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = inclusion_proof(&tree, index).unwrap();
+            assert!(verify_inclusion(HashScheme::Rfc6962, leaf, &proof, &tree.root()), "leaf {} of {}", index, size);
+        }
+    }
+}
+
+#[test]
+fn test_single_leaf_tree_has_empty_path() {
+    let (_, tree) = ct_tree(1);
+    let proof = inclusion_proof(&tree, 0).unwrap();
+    assert!(proof.path.is_empty());
+    assert_eq!(root_from_proof(HashScheme::Rfc6962, &tree.leaf_hashes[0], &proof, false), Ok(tree.root()));
+}
+
+#[test]
+fn test_proof_rejects_out_of_range_leaf() {
+    let (_, tree) = ct_tree(5);
+    assert!(inclusion_proof(&tree, 5).is_err());
+    assert!(inclusion_proof(&MerkleTree::new::<&[u8]>(HashScheme::Rfc6962, &[]), 0).is_err());
+}
+
+#[test]
+fn test_tampered_leaf_or_path_fails() {
+    let (leaves, tree) = ct_tree(6);
+    let proof = inclusion_proof(&tree, 3).unwrap();
+    assert!(!verify_inclusion(HashScheme::Rfc6962, b"not a leaf", &proof, &tree.root()));
+
+    let mut bad_path = proof.clone();
+    bad_path.path[1][0] ^= 1;
+    assert!(!verify_inclusion(HashScheme::Rfc6962, &leaves[3], &bad_path, &tree.root()));
+
+    let wrong_index = InclusionProof { leaf_index: 2, ..proof.clone() };
+    assert!(!verify_inclusion(HashScheme::Rfc6962, &leaves[3], &wrong_index, &tree.root()));
+}
+
+#[test]
+fn test_wrong_tree_size_is_rejected() {
+    let (leaves, tree) = ct_tree(8);
+    let proof = inclusion_proof(&tree, 5).unwrap();
+
+    let too_small = InclusionProof { tree_size: 6, ..proof.clone() };
+    assert!(!verify_inclusion(HashScheme::Rfc6962, &leaves[5], &too_small, &tree.root()));
+
+    let mut too_long = proof.clone();
+    too_long.path.push([0; 32]);
+    assert!(root_from_proof(HashScheme::Rfc6962, &tree.leaf_hashes[5], &too_long, false).is_err());
+
+    let mut too_short = proof;
+    too_short.path.pop();
+    assert!(root_from_proof(HashScheme::Rfc6962, &tree.leaf_hashes[5], &too_short, false).is_err());
+}
+
+#[test]
+fn test_sibling_ranges_follow_path_order() {
+    let (_, tree) = ct_tree(7);
+    let root = tree.root_node.as_ref().unwrap();
+    assert_eq!(sibling_ranges(root, 2), vec![(3, 4), (0, 2), (4, 7)]);
+    assert_eq!(sibling_ranges(root, 6), vec![(4, 6), (0, 4)]);
+}
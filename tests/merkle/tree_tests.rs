@@ -0,0 +1,94 @@
+use encryption_demo::merkle::hash::HashScheme;
+use encryption_demo::merkle::tree::{chunk_leaves, merkle_root, split_point, MerkleTree};
+
+/// Leaves of the Certificate Transparency reference test data
+const CT_LEAVES: [&str; 8] = ["", "00", "10", "2021", "3031", "40414243", "5051525354555657", "606162636465666768696a6b6c6d6e6f"];
+
+/// Roots of the first n CT leaves, n = 1..=8
+const CT_ROOTS: [&str; 8] = [
+    "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+    "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+    "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+    "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+    "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+    "76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef",
+    "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+    "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+];
+
+fn ct_leaves() -> Vec<Vec<u8>> {
+    CT_LEAVES.iter().map(|leaf| hex::decode(leaf).unwrap()).collect()
+}
+
+#[test]
+fn test_rfc6962_roots_match_ct_reference() {
+    let leaves = ct_leaves();
+    for (n, expected) in CT_ROOTS.iter().enumerate() {
+        assert_eq!(hex::encode(merkle_root(HashScheme::Rfc6962, &leaves[..=n])), *expected, "{} leaves", n + 1);
+    }
+}
+
+#[test]
+fn test_empty_tree_root_is_hash_of_nothing() {
+    let tree = MerkleTree::new::<&[u8]>(HashScheme::Rfc6962, &[]);
+    assert_eq!(tree.size(), 0);
+    assert!(tree.root_node.is_none());
+    assert_eq!(hex::encode(tree.root()), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+}
+
+#[test]
+fn test_single_leaf_root_is_leaf_hash() {
+    assert_eq!(merkle_root(HashScheme::Rfc6962, &[b""]), HashScheme::Rfc6962.leaf_hash(b""));
+}
+
+#[test]
+fn test_split_point_is_largest_power_of_two_below_n() {
+    let expected = [(2, 1), (3, 2), (4, 2), (5, 4), (8, 4), (9, 8), (1000, 512)];
+    for (n, k) in expected {
+        assert_eq!(split_point(n), k, "n = {}", n);
+    }
+}
+
+#[test]
+fn test_odd_leaf_is_promoted_not_duplicated() {
+    let scheme = HashScheme::Rfc6962;
+    assert_ne!(merkle_root(scheme, &["a", "b", "c"]), merkle_root(scheme, &["a", "b", "c", "c"]));
+
+    let ab = scheme.node_hash(&scheme.leaf_hash(b"a"), &scheme.leaf_hash(b"b"));
+    assert_eq!(merkle_root(scheme, &["a", "b", "c"]), scheme.node_hash(&ab, &scheme.leaf_hash(b"c")));
+}
+
+#[test]
+fn test_tree_depth_and_ranges() {
+    let tree = MerkleTree::new(HashScheme::Rfc6962, &ct_leaves()[..7]);
+    let root = tree.root_node.as_ref().unwrap();
+    assert_eq!(root.depth(), 3);
+    assert_eq!(root.range(), (0, 7));
+}
+
+#[test]
+fn test_plain_scheme_allows_node_as_leaf_second_preimage() {
+    let leaves = ["a", "b"];
+    let plain = HashScheme::Plain;
+    let forged = [plain.leaf_hash(b"a"), plain.leaf_hash(b"b")].concat();
+    assert_eq!(merkle_root(plain, &leaves), merkle_root(plain, &[&forged]));
+
+    let ct = HashScheme::Rfc6962;
+    let forged = [ct.leaf_hash(b"a"), ct.leaf_hash(b"b")].concat();
+    assert_ne!(merkle_root(ct, &leaves), merkle_root(ct, &[&forged]));
+}
+
+#[test]
+fn test_chunk_leaves_splits_with_short_last_chunk() {
+    let chunks = chunk_leaves(&[7; 10], 4).unwrap();
+    assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 4, 2]);
+    assert!(chunk_leaves(&[7; 10], 0).is_err());
+}
+
+#[test]
+fn test_scheme_from_str() {
+    assert_eq!("rfc6962".parse::<HashScheme>(), Ok(HashScheme::Rfc6962));
+    assert_eq!("ct".parse::<HashScheme>(), Ok(HashScheme::Rfc6962));
+    assert_eq!("plain".parse::<HashScheme>(), Ok(HashScheme::Plain));
+    assert!("sha1".parse::<HashScheme>().is_err());
+}
@@ -0,0 +1,43 @@
+use encryption_demo::pow::header::{display_hash, genesis_header, hash_from_display, sha256d, BlockHeader};
+
+const GENESIS_HASH: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+#[test]
+fn test_genesis_header_bytes() {
+    assert_eq!(
+        hex::encode(genesis_header().to_bytes()),
+        "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c"
+    );
+}
+
+#[test]
+fn test_genesis_block_hash() {
+    assert_eq!(display_hash(&genesis_header().hash()), GENESIS_HASH);
+}
+
+#[test]
+fn test_block_1_hash_chains_to_genesis() {
+    let block_1 = BlockHeader {
+        version: 1,
+        prev_block: hash_from_display(GENESIS_HASH).unwrap(),
+        merkle_root: hash_from_display("0e3e2357e806b6cdb1f70b54c3a3a17b6714ee1f0e68bebb44a74b1efd512098").unwrap(),
+        time: 1231469665,
+        bits: 0x1d00ffff,
+        nonce: 2573394689,
+    };
+    assert_eq!(display_hash(&block_1.hash()), "00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048");
+}
+
+#[test]
+fn test_sha256d_of_empty_input() {
+    assert_eq!(hex::encode(sha256d(b"")), "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456");
+}
+
+#[test]
+fn test_hash_from_display_round_trip_and_errors() {
+    let hash = hash_from_display(GENESIS_HASH).unwrap();
+    assert_eq!(hash[31], 0);
+    assert_eq!(display_hash(&hash), GENESIS_HASH);
+    assert!(hash_from_display("00ff").is_err());
+    assert!(hash_from_display("zz").is_err());
+}
@@ -0,0 +1,60 @@
+use encryption_demo::pow::header::{genesis_header, BlockHeader};
+use encryption_demo::pow::mining::{hash_with_nonce, mine, Midstate};
+use encryption_demo::pow::target::{hash_meets_target, CompactBits};
+
+fn easy_header() -> BlockHeader {
+    BlockHeader { merkle_root: [7; 32], time: 1700000000, bits: 0x2001_0000, nonce: 0, ..genesis_header() }
+}
+
+#[test]
+fn test_midstate_matches_full_hash() {
+    let bytes = genesis_header().to_bytes();
+    let midstate = Midstate::new(&bytes);
+    for nonce in [0, 1, 0xdead_beef, 2083236893, u32::MAX] {
+        assert_eq!(midstate.hash(nonce), hash_with_nonce(&bytes, nonce), "nonce {}", nonce);
+    }
+}
+
+#[test]
+fn test_midstate_reproduces_genesis_hash() {
+    let genesis = genesis_header();
+    assert_eq!(Midstate::new(&genesis.to_bytes()).hash(genesis.nonce), genesis.hash());
+}
+
+#[test]
+fn test_mine_finds_nonce_meeting_target() {
+    let header = easy_header();
+    let target = CompactBits::from_zero_bits(8).unwrap().target().unwrap();
+    let outcome = mine(&header, &target, 1 << 16, true);
+
+    let nonce = outcome.nonce.expect("8 zero bits take about 256 attempts");
+    assert_eq!(outcome.attempts, nonce as u64 + 1);
+    let found = BlockHeader { nonce, ..header };
+    assert_eq!(found.hash(), outcome.best_hash);
+    assert!(hash_meets_target(&found.hash(), &target));
+}
+
+#[test]
+fn test_both_methods_find_the_same_nonce() {
+    let header = easy_header();
+    let target = CompactBits::from_zero_bits(10).unwrap().target().unwrap();
+    assert_eq!(mine(&header, &target, 1 << 16, true), mine(&header, &target, 1 << 16, false));
+}
+
+#[test]
+fn test_mine_stops_at_budget_and_keeps_best_hash() {
+    let header = easy_header();
+    let target = CompactBits::from_zero_bits(64).unwrap().target().unwrap();
+    let outcome = mine(&header, &target, 100, true);
+    assert_eq!(outcome.nonce, None);
+    assert_eq!(outcome.attempts, 100);
+    assert!(outcome.best_nonce < 100);
+    assert_eq!(BlockHeader { nonce: outcome.best_nonce, ..header }.hash(), outcome.best_hash);
+}
+
+#[test]
+fn test_mine_stops_at_end_of_nonce_space() {
+    let header = BlockHeader { nonce: u32::MAX - 2, ..easy_header() };
+    let target = CompactBits::from_zero_bits(64).unwrap().target().unwrap();
+    assert_eq!(mine(&header, &target, 1000, true).attempts, 3);
+}
@@ -0,0 +1,3 @@
+mod header_tests;
+mod mining_tests;
+mod target_tests;
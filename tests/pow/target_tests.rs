@@ -0,0 +1,53 @@
+use encryption_demo::pow::target::{
+    difficulty, expected_attempts, hash_meets_target, leading_zero_bits, CompactBits, DIFFICULTY_1_BITS,
+};
+
+#[test]
+fn test_difficulty_1_target() {
+    let target = CompactBits(DIFFICULTY_1_BITS).target().unwrap();
+    assert_eq!(hex::encode(target), "00000000ffff0000000000000000000000000000000000000000000000000000");
+    assert_eq!(difficulty(&target), 1.0);
+}
+
+#[test]
+fn test_small_exponent_shifts_mantissa_right() {
+    let target = CompactBits(0x0212_3456).target().unwrap();
+    assert_eq!(hex::encode(&target[28..]), "00001234");
+    assert!(target[..28].iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn test_invalid_bits_are_rejected() {
+    assert!(CompactBits(0x0480_0000).target().is_err(), "sign bit set");
+    assert!(CompactBits(0x2201_0000).target().is_err(), "above 2^256");
+}
+
+#[test]
+fn test_from_zero_bits_is_exact_power_of_two() {
+    for zero_bits in [1, 8, 16, 20, 31, 32, 100, 248] {
+        let target = CompactBits::from_zero_bits(zero_bits).unwrap().target().unwrap();
+        assert_eq!(leading_zero_bits(&target), zero_bits - 1, "{} zero bits", zero_bits);
+        assert_eq!(target.iter().map(|byte| byte.count_ones()).sum::<u32>(), 1);
+        assert_eq!(expected_attempts(&target).log2().round(), zero_bits as f64);
+    }
+    assert!(CompactBits::from_zero_bits(0).is_err());
+    assert!(CompactBits::from_zero_bits(249).is_err());
+}
+
+#[test]
+fn test_bits_from_str() {
+    assert_eq!("1d00ffff".parse::<CompactBits>(), Ok(CompactBits(0x1d00ffff)));
+    assert_eq!("0x1f00ffff".parse::<CompactBits>(), Ok(CompactBits(0x1f00ffff)));
+    assert!("difficulty".parse::<CompactBits>().is_err());
+}
+
+#[test]
+fn test_hash_is_compared_as_little_endian_number() {
+    let target = CompactBits::from_zero_bits(16).unwrap().target().unwrap();
+    let mut hash = [0xff; 32];
+    hash[30] = 0;
+    hash[31] = 0;
+    assert!(hash_meets_target(&hash, &target));
+    hash[31] = 1;
+    assert!(!hash_meets_target(&hash, &target));
+}
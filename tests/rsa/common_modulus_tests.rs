@@ -0,0 +1,23 @@
+use encryption_demo::rsa::attacks::common_modulus::{common_modulus_attack, common_modulus_scenario};
+
+#[test]
+fn test_scenario_uses_distinct_coprime_exponents() {
+    let scenario = common_modulus_scenario(42, 61, 53).unwrap();
+    assert_eq!(scenario.n, 3233);
+    assert_eq!((scenario.e1, scenario.e2), (7, 11));
+}
+
+#[test]
+fn test_common_modulus_recovers_message() {
+    let message = 18537; // "Hi"
+    let s = common_modulus_scenario(message, 251, 241).unwrap();
+    let recovery = common_modulus_attack(s.n, s.e1, s.e2, s.c1, s.c2).unwrap();
+
+    assert_eq!(recovery.a * s.e1 as i128 + recovery.b * s.e2 as i128, 1);
+    assert_eq!(recovery.message, message);
+}
+
+#[test]
+fn test_common_modulus_requires_coprime_exponents() {
+    assert!(common_modulus_attack(3233, 3, 9, 100, 200).is_none());
+}
@@ -0,0 +1,68 @@
+use encryption_demo::common::random::DemoRng;
+use encryption_demo::rsa::constant_time::{ct_swap, montgomery_ladder, mod_pow_ladder};
+use encryption_demo::rsa::encryption::{blinding_factor, decrypt, decrypt_blinded, decrypt_constant_time, encrypt};
+use encryption_demo::rsa::key_generation::generate_keypair;
+use encryption_demo::rsa::math_utils::{mod_mul, mod_pow};
+use encryption_demo::rsa::montgomery::MontgomeryContext;
+
+#[test]
+fn test_ct_swap() {
+    let (mut a, mut b) = (5, 9);
+    ct_swap(0, &mut a, &mut b);
+    assert_eq!((a, b), (5, 9));
+    ct_swap(1, &mut a, &mut b);
+    assert_eq!((a, b), (9, 5));
+}
+
+#[test]
+fn test_ladder_with_plain_multiplication() {
+    let ladder = montgomery_ladder(1, 3, 13, 8, |a, b| mod_mul(a, b, 1000));
+    assert_eq!(ladder, mod_pow(3, 13, 1000));
+}
+
+#[test]
+fn test_mod_pow_ladder_matches_mod_pow() {
+    for (base, exp, modulus) in [(65, 17, 3233), (2790, 2753, 3233), (7, 0, 13), (0, 5, 13), (123456789, u64::MAX, u64::MAX)] {
+        assert_eq!(mod_pow_ladder(base, exp, modulus), mod_pow(base, exp, modulus), "{}^{} mod {}", base, exp, modulus);
+    }
+    assert_eq!(mod_pow_ladder(5, 3, 1), 0);
+}
+
+#[test]
+#[should_panic(expected = "odd modulus")]
+fn test_mod_pow_ladder_rejects_even_modulus() {
+    mod_pow_ladder(3, 5, 100);
+}
+
+#[test]
+fn test_mul_constant_time_matches_mul() {
+    let context = MontgomeryContext::new(18446743979220271189).unwrap();
+    for (a, b) in [(0, 0), (1, u64::MAX / 3), (18446743979220271188, 18446743979220271188)] {
+        let (a, b) = (context.to_montgomery(a), context.to_montgomery(b));
+        assert_eq!(context.mul_constant_time(a, b), context.mul(a, b));
+    }
+    assert_eq!(context.redc_constant_time(0), context.redc(0));
+    assert_eq!(context.from_montgomery(context.mul_constant_time(context.one(), context.one())), 1);
+}
+
+#[test]
+fn test_constant_time_and_blinded_decryption() {
+    let key_pair = generate_keypair(61, 53);
+    let mut rng = DemoRng::new(7);
+    for message in [0, 1, 65, 3232] {
+        let ciphertext = encrypt(message, &key_pair.public_key);
+        assert_eq!(decrypt_constant_time(ciphertext, &key_pair.private_key), message);
+        assert_eq!(decrypt_blinded(ciphertext, &key_pair.public_key, &key_pair.private_key, &mut rng), message);
+        assert_eq!(decrypt(ciphertext, &key_pair.private_key), message);
+    }
+}
+
+#[test]
+fn test_blinding_factor_is_invertible() {
+    let mut rng = DemoRng::new(1);
+    for _ in 0..100 {
+        let (r, r_inverse) = blinding_factor(3233, &mut rng);
+        assert!(r > 1 && r < 3233);
+        assert_eq!(mod_mul(r, r_inverse, 3233), 1);
+    }
+}
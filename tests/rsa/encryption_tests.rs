@@ -1,5 +1,6 @@
-use encryption_demo::rsa::encryption::{decrypt, encrypt};
-use encryption_demo::rsa::types::{RsaPrivateKey, RsaPublicKey};
+use encryption_demo::rsa::encryption::{decrypt, decrypt_crt, decrypt_crt_traced, encrypt};
+use encryption_demo::rsa::key_generation::generate_multi_prime_key;
+use encryption_demo::rsa::types::{RsaPrivateKey, RsaPublicKey, Totient};
 
 #[test]
 fn test_encrypt_basic() {
@@ -27,3 +28,35 @@ fn test_encrypt_edge_cases() {
     // Test one
     assert_eq!(encrypt(1, &public_key), 1);
 }
+
+#[test]
+fn test_multi_prime_crt_decryption() {
+    for kind in [Totient::Euler, Totient::Carmichael] {
+        let key = generate_multi_prime_key(&[65521, 65519, 4093], kind);
+        for message in [0, 1, 65521, 310939249775, key.n - 1] {
+            let ciphertext = encrypt(message, &key.public_key());
+            assert_eq!(decrypt_crt(ciphertext, &key), message);
+            assert_eq!(decrypt(ciphertext, &key.key_pair().private_key), message);
+        }
+    }
+}
+
+#[test]
+fn test_crt_trace_partials() {
+    let key = generate_multi_prime_key(&[61, 53, 47], Totient::Carmichael);
+    let steps = decrypt_crt_traced(98777, &key);
+
+    let partials: Vec<u64> = steps.iter().map(|s| s.partial).collect();
+    assert_eq!(partials, [54, 2372, 18537]);
+    for step in &steps {
+        assert_eq!(step.residue, 18537 % step.prime);
+    }
+}
+
+#[test]
+fn test_two_prime_crt_matches_direct() {
+    let key = generate_multi_prime_key(&[61, 53], Totient::Euler);
+    for ciphertext in 0..key.n {
+        assert_eq!(decrypt_crt(ciphertext, &key), decrypt(ciphertext, &key.key_pair().private_key));
+    }
+}
@@ -0,0 +1,76 @@
+use encryption_demo::common::random::DemoRng;
+use encryption_demo::rsa::envelope::{
+    derive_session_keys, open, seal, seal_with_secret, Envelope, HEADER_BYTES, PEM_LABEL_ENVELOPE, TAG_BYTES,
+};
+use encryption_demo::rsa::key_generation::generate_keypair;
+
+#[test]
+fn test_seal_open_round_trip() {
+    let key_pair = generate_keypair(4294967291, 4294967279);
+    let mut rng = DemoRng::new(5);
+
+    for length in [0, 1, 63, 64, 65, 1000] {
+        let plaintext: Vec<u8> = (0..length).map(|i| (i * 7) as u8).collect();
+        let (envelope, secret) = seal(&plaintext, &key_pair.public_key, &mut rng).unwrap();
+        assert!(secret >= 2 && secret < key_pair.public_key.n);
+        assert_eq!(envelope.ciphertext.len(), length);
+        assert_eq!(open(&envelope, &key_pair.private_key).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn test_session_keys_are_distinct() {
+    let keys = derive_session_keys(12345);
+    assert_ne!(keys.encryption_key, keys.mac_key);
+    assert_ne!(keys, derive_session_keys(12346));
+}
+
+#[test]
+fn test_container_layout() {
+    let key_pair = generate_keypair(65521, 65519);
+    let envelope = seal_with_secret(b"hello", &key_pair.public_key, 42);
+    let bytes = envelope.to_bytes();
+
+    assert_eq!(bytes.len(), HEADER_BYTES + 5 + TAG_BYTES);
+    assert_eq!(&bytes[..5], b"RKEM\x01");
+    assert_eq!(bytes[5..13], envelope.wrapped_secret.to_be_bytes());
+    assert_eq!(bytes[13..21], 5u64.to_be_bytes());
+    assert_eq!(Envelope::decode(&bytes).unwrap(), envelope);
+
+    let pem = envelope.to_pem();
+    assert!(pem.starts_with(&format!("-----BEGIN {}-----", PEM_LABEL_ENVELOPE)));
+    assert_eq!(Envelope::decode(pem.as_bytes()).unwrap(), envelope);
+}
+
+#[test]
+fn test_tampering_is_detected() {
+    let key_pair = generate_keypair(65521, 65519);
+    let envelope = seal_with_secret(b"pay 100", &key_pair.public_key, 42);
+
+    let mut flipped = envelope.clone();
+    flipped.ciphertext[4] ^= 1;
+    assert!(open(&flipped, &key_pair.private_key).unwrap_err().contains("Authentication failed"));
+
+    let mut rewrapped = envelope.clone();
+    rewrapped.wrapped_secret = (rewrapped.wrapped_secret + 1) % key_pair.public_key.n;
+    assert!(open(&rewrapped, &key_pair.private_key).is_err());
+
+    let other = generate_keypair(4294967291, 4294967279);
+    assert!(open(&seal_with_secret(b"x", &other.public_key, 42), &key_pair.private_key).is_err());
+}
+
+#[test]
+fn test_decode_rejects_malformed_containers() {
+    assert!(Envelope::decode(b"short").unwrap_err().contains("shorter"));
+
+    let key_pair = generate_keypair(65521, 65519);
+    let mut bytes = seal_with_secret(b"hello", &key_pair.public_key, 42).to_bytes();
+    bytes[4] = 2;
+    assert!(Envelope::decode(&bytes).unwrap_err().contains("version"));
+    bytes[0] = b'X';
+    assert!(Envelope::decode(&bytes).unwrap_err().contains("magic"));
+
+    let mut truncated = seal_with_secret(b"hello", &key_pair.public_key, 42).to_bytes();
+    truncated.remove(HEADER_BYTES);
+    assert!(Envelope::decode(&truncated).unwrap_err().contains("Payload length"));
+}
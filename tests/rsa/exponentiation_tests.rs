@@ -0,0 +1,94 @@
+use encryption_demo::rsa::exponentiation::{
+    mod_pow_fixed_window, mod_pow_montgomery, mod_pow_sliding_window, mod_pow_traced, ExpMethod, OpCounts,
+};
+use encryption_demo::rsa::math_utils::mod_pow;
+
+/// Deterministic pseudo-random values so failures are reproducible
+fn lcg_values(count: usize) -> Vec<u64> {
+    let mut state = 0x2545f4914f6cdd1du64;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            state
+        })
+        .collect()
+}
+
+#[test]
+fn test_all_methods_match_mod_pow() {
+    let values = lcg_values(60);
+    for chunk in values.chunks(3) {
+        let (base, exp, modulus) = (chunk[0], chunk[1], chunk[2] | 1);
+        let expected = mod_pow(base, exp, modulus);
+        for k in 1..=6 {
+            for method in ExpMethod::all(k) {
+                assert_eq!(mod_pow_traced(base, exp, modulus, method).result, expected, "{:?}", method);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_even_modulus_and_edge_cases() {
+    for method in ExpMethod::all(4) {
+        assert_eq!(mod_pow_traced(7, 560, 3234, method).result, mod_pow(7, 560, 3234));
+        assert_eq!(mod_pow_traced(5, 0, 3233, method).result, 1);
+        assert_eq!(mod_pow_traced(5, 17, 1, method).result, 0);
+        assert_eq!(mod_pow_traced(0, 17, 3233, method).result, 0);
+    }
+}
+
+#[test]
+fn test_binary_counts() {
+    // 65537 = 1 0000 0000 0000 0001: 16 squarings, 1 multiplication
+    let trace = mod_pow_traced(42, 65537, 3233, ExpMethod::Binary);
+    assert_eq!(trace.counts, OpCounts { squarings: 16, multiplications: 1, reductions: 17 });
+    assert_eq!(trace.windows.len(), 17);
+
+    // Montgomery does the same work plus one conversion in and one out
+    let trace = mod_pow_traced(42, 65537, 3233, ExpMethod::Montgomery);
+    assert_eq!(trace.counts, OpCounts { squarings: 16, multiplications: 1, reductions: 19 });
+}
+
+#[test]
+fn test_fixed_window_digits() {
+    // 0xb7 = 1011 0111: two 4-bit digits
+    let trace = mod_pow_traced(3, 0xb7, 3233, ExpMethod::FixedWindow(4));
+    let digits: Vec<&str> = trace.windows.iter().map(|w| w.bits.as_str()).collect();
+    assert_eq!(digits, ["1011", "0111"]);
+    assert_eq!(trace.table_size, 16);
+    // 14 table multiplications + 1 window multiplication, 4 squarings
+    assert_eq!(trace.counts.multiplications, 15);
+    assert_eq!(trace.counts.squarings, 4);
+}
+
+#[test]
+fn test_sliding_window_skips_zeros() {
+    // 1011 0000 0111 with k=3: windows 101, 1, five single zeros, 111
+    let trace = mod_pow_traced(3, 0b1011_0000_0111, 3233, ExpMethod::SlidingWindow(3));
+    let windows: Vec<&str> = trace.windows.iter().map(|w| w.bits.as_str()).collect();
+    assert_eq!(windows, ["101", "1", "0", "0", "0", "0", "0", "111"]);
+    assert_eq!(trace.table_size, 4);
+    // Odd powers 3, 5, 7 plus base² for the table; three window multiplications
+    assert_eq!(trace.counts.multiplications, 3 + 2);
+    // base² for the table, then one squaring per bit after the first window
+    assert_eq!(trace.counts.squarings, 1 + 9);
+}
+
+#[test]
+fn test_sliding_window_fewer_multiplications_for_large_exponent() {
+    let exp = 12297829313753557747;
+    let binary = mod_pow_traced(5, exp, 18446743979220271189, ExpMethod::Binary).counts;
+    let sliding = mod_pow_traced(5, exp, 18446743979220271189, ExpMethod::SlidingWindow(4)).counts;
+    assert!(sliding.multiplications < binary.multiplications);
+    assert!(sliding.squarings <= binary.squarings);
+}
+
+#[test]
+fn test_untraced_helpers_match_traced() {
+    let (base, exp, modulus) = (5, 12297829313753557747, 18446743979220271189);
+    let expected = mod_pow(base, exp, modulus);
+    assert_eq!(mod_pow_montgomery(base, exp, modulus), expected);
+    assert_eq!(mod_pow_fixed_window(base, exp, modulus, 5), expected);
+    assert_eq!(mod_pow_sliding_window(base, exp, modulus, 5), expected);
+}
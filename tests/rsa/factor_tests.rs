@@ -0,0 +1,72 @@
+use encryption_demo::rsa::attacks::factor::{
+    factor, fermat, pollard_p_minus_1, pollard_rho, quadratic_sieve, recover_private_key, trial_division, FactorMethod,
+};
+use encryption_demo::rsa::key_generation::generate_keypair;
+use encryption_demo::rsa::types::RsaPublicKey;
+
+const N: u64 = 1000000016000000063; // 1000000007 × 1000000009
+
+#[test]
+fn test_all_methods_factor_small_modulus() {
+    for method in FactorMethod::ALL {
+        let result = factor(3233, method, 100_000);
+        assert_eq!(result.factors, Some((53, 61)), "{} failed", method.name());
+    }
+}
+
+#[test]
+fn test_trial_division_respects_budget() {
+    let result = trial_division(N, 1000);
+    assert!(result.factors.is_none());
+    assert_eq!(result.iterations, 1000);
+}
+
+#[test]
+fn test_fermat_close_primes() {
+    let result = fermat(N, 10);
+    assert_eq!(result.factors, Some((1000000007, 1000000009)));
+    assert_eq!(result.iterations, 1);
+}
+
+#[test]
+fn test_pollard_rho_large_modulus() {
+    let result = pollard_rho(N, 1_000_000);
+    assert_eq!(result.factors, Some((1000000007, 1000000009)));
+}
+
+#[test]
+fn test_pollard_p_minus_1_smooth_factor() {
+    // 1000000007 - 1 = 2 × 500000003, but 1000000009 - 1 = 2³ × 3² × 7 × 109² × 167
+    let result = pollard_p_minus_1(N, 1000);
+    assert_eq!(result.factors, Some((1000000007, 1000000009)));
+}
+
+#[test]
+fn test_quadratic_sieve_large_modulus() {
+    let result = quadratic_sieve(N, 2_000_000);
+    assert_eq!(result.factors, Some((1000000007, 1000000009)));
+}
+
+#[test]
+fn test_prime_modulus_is_not_factored() {
+    for method in FactorMethod::ALL {
+        assert!(factor(65521, method, 10_000).factors.is_none());
+    }
+}
+
+#[test]
+fn test_recover_private_key_matches_generated() {
+    let keypair = generate_keypair(61, 53);
+    let recovered = recover_private_key(&keypair.public_key, 53, 61).unwrap();
+    assert_eq!(recovered.d, keypair.private_key.d);
+}
+
+#[test]
+fn test_recover_private_key_rejects_non_rsa_moduli() {
+    // n = 4: p = q gives φ = 1 and a useless d = 0
+    assert!(recover_private_key(&RsaPublicKey { n: 4, e: 3 }, 2, 2).is_err());
+    // 12 = 2 × 6 has a composite factor
+    assert!(recover_private_key(&RsaPublicKey { n: 12, e: 5 }, 2, 6).is_err());
+    // e = 3 shares a factor with φ(3233) = 3120
+    assert!(recover_private_key(&RsaPublicKey { n: 3233, e: 3 }, 53, 61).is_err());
+}
@@ -0,0 +1,52 @@
+use encryption_demo::rsa::jwk::{
+    jwk_thumbprint, jwk_to_public_key, public_key_from_jwk_json, public_key_to_jwk, public_key_to_jwk_json,
+};
+use encryption_demo::rsa::types::RsaPublicKey;
+
+fn demo_key() -> RsaPublicKey {
+    RsaPublicKey { n: 18446743979220271189, e: 3 }
+}
+
+#[test]
+fn test_jwk_members_are_base64url() {
+    let jwk = public_key_to_jwk(&demo_key());
+    assert_eq!(jwk.kty, "RSA");
+    assert_eq!(jwk.n, "____6gAAAFU");
+    assert_eq!(jwk.e, "Aw");
+}
+
+#[test]
+fn test_jwk_e_65537_is_aqab() {
+    let jwk = public_key_to_jwk(&RsaPublicKey { n: 3233, e: 65537 });
+    assert_eq!(jwk.e, "AQAB");
+}
+
+#[test]
+fn test_jwk_thumbprint_rfc7638() {
+    // SHA-256 of {"e":"Aw","kty":"RSA","n":"____6gAAAFU"}, checked with Python's hashlib
+    assert_eq!(jwk_thumbprint(&demo_key()), "zsypTqcku9zsbotRwgONf7AT7cVu2ziIi4nopED218U");
+}
+
+#[test]
+fn test_jwk_json_round_trip() {
+    let key = public_key_from_jwk_json(&public_key_to_jwk_json(&demo_key())).unwrap();
+    assert_eq!((key.n, key.e), (18446743979220271189, 3));
+}
+
+#[test]
+fn test_jwk_ignores_extra_members() {
+    let json = r#"{"kty":"RSA","use":"sig","alg":"RS256","n":"DKE","e":"AQAB"}"#;
+    let key = public_key_from_jwk_json(json).unwrap();
+    assert_eq!((key.n, key.e), (3233, 65537));
+}
+
+#[test]
+fn test_jwk_rejects_wrong_kty_and_large_modulus() {
+    let mut jwk = public_key_to_jwk(&demo_key());
+    jwk.kty = "EC".to_string();
+    assert!(jwk_to_public_key(&jwk).is_err());
+
+    // 9 significant bytes do not fit the demo's u64
+    let json = r#"{"kty":"RSA","n":"AQIDBAUGBwgJ","e":"AQAB"}"#;
+    assert!(public_key_from_jwk_json(json).is_err());
+}
@@ -0,0 +1,131 @@
+use encryption_demo::rsa::key_check::{batch_gcd, validate_keypair, CheckStatus, KeyCheckInput};
+use encryption_demo::rsa::key_generation::generate_key_components;
+
+fn status(input: &KeyCheckInput, name: &str) -> CheckStatus {
+    validate_keypair(input, &[]).find(name).unwrap_or_else(|| panic!("no check named {}", name)).status
+}
+
+#[test]
+fn test_generated_key_is_consistent() {
+    let components = generate_key_components(65521, 65519);
+    let report = validate_keypair(&KeyCheckInput::from(&components), &[]);
+
+    assert!(report.is_valid());
+    assert_eq!(report.count(CheckStatus::Skipped), 1); // no batch to compare against
+    for name in ["n = p × q", "e·d ≡ 1 (mod λ(n))", "dp = d mod (p-1)", "dq = d mod (q-1)", "qinv = q⁻¹ mod p"] {
+        assert_eq!(report.find(name).unwrap().status, CheckStatus::Pass, "{}", name);
+    }
+}
+
+#[test]
+fn test_derives_missing_prime() {
+    let input = KeyCheckInput { n: Some(3233), q: Some(53), e: Some(17), d: Some(2753), ..Default::default() };
+    let report = validate_keypair(&input, &[]);
+
+    assert!(report.find("n = p × q").unwrap().detail.contains("= 61"));
+    assert_eq!(report.find("e·d ≡ 1 (mod λ(n))").unwrap().status, CheckStatus::Pass);
+}
+
+#[test]
+fn test_detects_inconsistencies() {
+    let wrong_n = KeyCheckInput { n: Some(3234), p: Some(61), q: Some(53), ..Default::default() };
+    assert_eq!(status(&wrong_n, "n = p × q"), CheckStatus::Fail);
+
+    let composite = KeyCheckInput { p: Some(61), q: Some(51), ..Default::default() };
+    assert_eq!(status(&composite, "q is prime"), CheckStatus::Fail);
+
+    let wrong_d = KeyCheckInput { p: Some(61), q: Some(53), e: Some(17), d: Some(2754), ..Default::default() };
+    let report = validate_keypair(&wrong_d, &[]);
+    assert!(!report.is_valid());
+    assert!(report.find("e·d ≡ 1 (mod λ(n))").unwrap().detail.contains("expected d ≡ 413"));
+
+    let even_e = KeyCheckInput { p: Some(61), q: Some(53), e: Some(16), ..Default::default() };
+    assert_eq!(status(&even_e, "e"), CheckStatus::Fail);
+
+    let mut components = generate_key_components(61, 53);
+    components.qinv += 1;
+    assert_eq!(status(&KeyCheckInput::from(&components), "qinv = q⁻¹ mod p"), CheckStatus::Fail);
+}
+
+#[test]
+fn test_round_trip_without_primes() {
+    let good = KeyCheckInput { n: Some(3233), e: Some(17), d: Some(2753), ..Default::default() };
+    assert_eq!(status(&good, "e·d ≡ 1 (mod λ(n))"), CheckStatus::Pass);
+
+    let bad = KeyCheckInput { d: Some(2754), ..good };
+    assert_eq!(status(&bad, "e·d ≡ 1 (mod λ(n))"), CheckStatus::Fail);
+}
+
+#[test]
+fn test_weakness_warnings() {
+    // 1000000007 - 1 = 2 × 500000003, 1000000009 - 1 = 2³ × 3² × 7 × 109² × 167
+    let input = KeyCheckInput { p: Some(1000000007), q: Some(1000000009), ..Default::default() };
+    assert_eq!(status(&input, "|p - q|"), CheckStatus::Warn);
+    assert_eq!(status(&input, "p - 1 smoothness"), CheckStatus::Pass);
+    assert_eq!(status(&input, "q - 1 smoothness"), CheckStatus::Warn);
+
+    // 257 and 1000003 are far apart, so Fermat would need millions of steps
+    let far = KeyCheckInput { p: Some(257), q: Some(1000003), ..Default::default() };
+    assert_eq!(status(&far, "|p - q|"), CheckStatus::Pass);
+
+    let small_d = KeyCheckInput { n: Some(4292870399), e: Some(2223949307), d: Some(5), ..Default::default() };
+    let report = validate_keypair(&small_d, &[]);
+    assert!(report.checks.iter().any(|check| check.status == CheckStatus::Warn && check.detail.contains("Wiener")));
+}
+
+#[test]
+fn test_batch_gcd() {
+    // 61 × 53, 61 × 47, 67 × 71, 53 × 47
+    assert_eq!(batch_gcd(&[3233, 2867, 4757, 2491]), Ok(vec![3233, 2867, 1, 2491]));
+    assert_eq!(batch_gcd(&[3233, 4757]), Ok(vec![1, 1]));
+    assert_eq!(batch_gcd(&[3233, 3233]), Ok(vec![3233, 3233]));
+    assert_eq!(batch_gcd(&[3233, 2867]), Ok(vec![61, 61]));
+}
+
+#[test]
+fn test_batch_gcd_rejects_degenerate_moduli() {
+    assert!(batch_gcd(&[3233, 0, 3599]).unwrap_err().contains("position 2"));
+    assert!(batch_gcd(&[1, 3599]).is_err());
+}
+
+#[test]
+fn test_shared_factor_check() {
+    let input = KeyCheckInput { n: Some(3233), ..Default::default() };
+
+    let report = validate_keypair(&input, &[4757, 2867]);
+    let check = report.find("Shared factors (batch GCD)").unwrap();
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert!(check.detail.contains("shares 61 with 2867"));
+
+    let clean = validate_keypair(&input, &[4757]);
+    assert_eq!(clean.find("Shared factors (batch GCD)").unwrap().status, CheckStatus::Pass);
+}
+
+#[test]
+fn test_shared_factor_check_ignores_degenerate_moduli() {
+    let input = KeyCheckInput { n: Some(3233), ..Default::default() };
+    let report = validate_keypair(&input, &[0, 3599]);
+    let checks: Vec<_> = report.checks.iter().filter(|check| check.name == "Shared factors (batch GCD)").collect();
+    assert_eq!(checks[0].status, CheckStatus::Fail);
+    assert!(checks[0].detail.contains("shares 61 with 3599"));
+    assert_eq!(checks[1].status, CheckStatus::Warn);
+    assert!(checks[1].detail.contains("ignored moduli below 2: 0"));
+
+    let only_invalid = validate_keypair(&input, &[1]);
+    assert_eq!(only_invalid.find("Shared factors (batch GCD)").unwrap().status, CheckStatus::Skipped);
+}
+
+#[test]
+fn test_shared_factor_check_names_each_prime_source() {
+    // 3233 = 53 × 61, 3599 = 59 × 61, 3127 = 53 × 59: each prime of n comes from a different modulus
+    let input = KeyCheckInput { n: Some(3233), ..Default::default() };
+    let report = validate_keypair(&input, &[3599, 3127]);
+    let check = report.find("Shared factors (batch GCD)").unwrap();
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert!(check.detail.contains("61 with 3599"), "{}", check.detail);
+    assert!(check.detail.contains("53 with 3127"), "{}", check.detail);
+    assert!(!check.detail.contains("repeated"));
+
+    let repeated = validate_keypair(&input, &[3233]);
+    assert!(repeated.find("Shared factors (batch GCD)").unwrap().detail.contains("all of n with 3233 (repeated modulus)"));
+}
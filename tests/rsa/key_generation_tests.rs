@@ -1,4 +1,11 @@
-use encryption_demo::rsa::key_generation::generate_keypair;
+use encryption_demo::common::random::DemoRng;
+use encryption_demo::rsa::key_generation::{
+    generate_key_components_with_exponent, generate_key_components_with_totient, generate_keypair,
+    generate_keypair_with_exponent, generate_keypair_with_totient, generate_multi_prime_key, select_public_exponent,
+    totient,
+};
+use encryption_demo::rsa::math_utils::gcd;
+use encryption_demo::rsa::types::{ExponentPolicy, Totient};
 
 #[test]
 fn test_generate_keypair_basic() {
@@ -32,3 +39,109 @@ fn test_keypair_mathematical_consistency() {
     let product = (keypair.public_key.e as u128 * keypair.private_key.d as u128) % phi_n as u128;
     assert_eq!(product, 1);
 }
+
+#[test]
+fn test_totient() {
+    assert_eq!(totient(&[61, 53], Totient::Euler), 3120);
+    assert_eq!(totient(&[61, 53], Totient::Carmichael), 780);
+    assert_eq!(totient(&[61, 53, 47], Totient::Carmichael), 17940);
+}
+
+#[test]
+fn test_carmichael_d_is_reduced_euler_d() {
+    let euler = generate_keypair_with_totient(61, 53, Totient::Euler);
+    let carmichael = generate_keypair_with_totient(61, 53, Totient::Carmichael);
+
+    assert_eq!(euler.public_key.e, carmichael.public_key.e);
+    assert_eq!(carmichael.private_key.d, 223);
+    assert_eq!(euler.private_key.d % 780, carmichael.private_key.d);
+    assert_eq!(euler.private_key.d, generate_keypair(61, 53).private_key.d);
+}
+
+#[test]
+fn test_carmichael_components_match_openssl_style_crt() {
+    let components = generate_key_components_with_totient(65521, 65519, Totient::Carmichael);
+    let euler = generate_key_components_with_totient(65521, 65519, Totient::Euler);
+
+    assert_eq!(euler.d % totient(&[65521, 65519], Totient::Carmichael), components.d);
+    assert_eq!((components.dp, components.dq, components.qinv), (euler.dp, euler.dq, euler.qinv));
+}
+
+#[test]
+fn test_multi_prime_key() {
+    let key = generate_multi_prime_key(&[61, 53, 47], Totient::Carmichael);
+
+    assert_eq!(key.n, 151951);
+    assert_eq!((key.e, key.d), (7, 2563));
+    let primes: Vec<u64> = key.factors.iter().map(|f| f.prime).collect();
+    assert_eq!(primes, [61, 53, 47]);
+    for factor in &key.factors {
+        assert_eq!(factor.exponent, key.d % (factor.prime - 1));
+    }
+    // t₂ = 61⁻¹ mod 53, t₃ = (61·53)⁻¹ mod 47
+    assert_eq!(key.factors[1].coefficient * 61 % 53, 1);
+    assert_eq!(key.factors[2].coefficient * 3233 % 47, 1);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in 64 bits")]
+fn test_multi_prime_key_rejects_overflow() {
+    generate_multi_prime_key(&[4294967291, 4294967279, 3], Totient::Euler);
+}
+
+#[test]
+fn test_select_public_exponent_policies() {
+    let mut rng = DemoRng::new(7);
+
+    // φ(n) = 3120 = 2⁴ × 3 × 5 × 13
+    assert_eq!(select_public_exponent(3120, ExponentPolicy::Common, &mut rng), Ok(7));
+    assert_eq!(select_public_exponent(3120, ExponentPolicy::Smallest, &mut rng), Ok(7));
+    assert_eq!(select_public_exponent(3120, ExponentPolicy::Explicit(17), &mut rng), Ok(17));
+    assert!(select_public_exponent(3120, ExponentPolicy::F4, &mut rng).unwrap_err().contains("smaller than φ(n)"));
+    assert_eq!(select_public_exponent(4292739600, ExponentPolicy::F4, &mut rng), Ok(65537));
+    // φ(n) = 76 = 2² × 19
+    assert_eq!(select_public_exponent(76, ExponentPolicy::Smallest, &mut rng), Ok(3));
+}
+
+#[test]
+fn test_select_random_public_exponent() {
+    let mut rng = DemoRng::new(42);
+    for _ in 0..100 {
+        let e = select_public_exponent(3120, ExponentPolicy::Random, &mut rng).unwrap();
+        assert!(e > 1 && e < 3120 && e % 2 == 1 && gcd(e, 3120) == 1);
+    }
+}
+
+#[test]
+fn test_select_public_exponent_none_exists() {
+    // p=2, q=3: φ(n) = 2 leaves no room for 1 < e < φ(n)
+    let error = select_public_exponent(2, ExponentPolicy::Random, &mut DemoRng::new(1)).unwrap_err();
+    assert!(error.contains("Could not find suitable public exponent"));
+}
+
+#[test]
+fn test_keypair_with_explicit_exponent() {
+    let keypair = generate_keypair_with_exponent(61, 53, Totient::Carmichael, ExponentPolicy::Explicit(17)).unwrap();
+    assert_eq!((keypair.public_key.e, keypair.private_key.d), (17, 413));
+
+    let components = generate_key_components_with_exponent(61, 53, Totient::Euler, ExponentPolicy::Explicit(17)).unwrap();
+    assert_eq!(components.d, 2753);
+    assert_eq!((components.dp, components.dq), (2753 % 60, 2753 % 52));
+}
+
+#[test]
+fn test_keypair_rejects_invalid_exponent() {
+    for e in [1, 4, 15, 3120, 65537] {
+        assert!(generate_keypair_with_exponent(61, 53, Totient::Euler, ExponentPolicy::Explicit(e)).is_err(), "e={}", e);
+    }
+}
+
+#[test]
+fn test_exponent_policy_from_str() {
+    assert_eq!("".parse(), Ok(ExponentPolicy::Common));
+    assert_eq!("Smallest".parse(), Ok(ExponentPolicy::Smallest));
+    assert_eq!("f4".parse(), Ok(ExponentPolicy::F4));
+    assert_eq!("random".parse(), Ok(ExponentPolicy::Random));
+    assert_eq!("65537".parse(), Ok(ExponentPolicy::Explicit(65537)));
+    assert!("big".parse::<ExponentPolicy>().is_err());
+}
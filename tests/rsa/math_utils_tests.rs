@@ -1,4 +1,4 @@
-use encryption_demo::rsa::math_utils::{extended_gcd, is_prime, mod_inverse, mod_pow};
+use encryption_demo::rsa::math_utils::{crt, extended_gcd, integer_root, is_prime, lcm, mod_inverse, mod_pow};
 
 // Extended GCD tests
 #[test]
@@ -73,3 +73,35 @@ fn test_is_prime_larger_numbers() {
     assert!(!is_prime(100));
     assert!(!is_prime(256));
 }
+
+// Integer root tests
+#[test]
+fn test_integer_root_exact() {
+    assert_eq!(integer_root(27, 3), 3);
+    assert_eq!(integer_root(1 << 64, 2), 1 << 32);
+}
+
+#[test]
+fn test_integer_root_floor() {
+    assert_eq!(integer_root(26, 3), 2);
+    assert_eq!(integer_root(u128::MAX, 3), 6981463658331);
+}
+
+// Chinese Remainder Theorem tests
+#[test]
+fn test_crt_basic() {
+    // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7) → x = 23
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+}
+
+#[test]
+fn test_crt_non_coprime_moduli() {
+    assert!(crt(&[(1, 4), (3, 6)]).is_none());
+}
+
+#[test]
+fn test_lcm() {
+    assert_eq!(lcm(60, 52), 780);
+    assert_eq!(lcm(7, 13), 91);
+    assert_eq!(lcm(12, 12), 12);
+}
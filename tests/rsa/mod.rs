@@ -1,6 +1,22 @@
+mod common_modulus_tests;
+mod constant_time_tests;
 mod encryption_tests;
+mod envelope_tests;
+mod exponentiation_tests;
+mod factor_tests;
 mod integration_tests;
+mod jwk_tests;
+mod key_check_tests;
 mod key_generation_tests;
+mod malleability_tests;
 mod math_utils_tests;
+mod montgomery_tests;
+mod oaep_tests;
+mod openssh_tests;
+mod related_message_tests;
+mod serialization_tests;
+mod small_exponent_tests;
 mod text_encoding_tests;
+mod timing_tests;
 mod validation_tests;
+mod wiener_tests;
@@ -0,0 +1,36 @@
+use encryption_demo::rsa::math_utils::mod_mul;
+use encryption_demo::rsa::montgomery::MontgomeryContext;
+
+#[test]
+fn test_rejects_even_modulus() {
+    assert!(MontgomeryContext::new(3234).is_none());
+    assert!(MontgomeryContext::new(1).is_none());
+    assert!(MontgomeryContext::new(3233).is_some());
+}
+
+#[test]
+fn test_n_prime_satisfies_definition() {
+    let context = MontgomeryContext::new(18446743979220271189).unwrap();
+    assert_eq!(context.n.wrapping_mul(context.n_prime), u64::MAX);
+}
+
+#[test]
+fn test_round_trip_conversion() {
+    let context = MontgomeryContext::new(3233).unwrap();
+    for a in [0, 1, 42, 3232] {
+        assert_eq!(context.from_montgomery(context.to_montgomery(a)), a);
+    }
+    assert_eq!(context.from_montgomery(context.one()), 1);
+}
+
+#[test]
+fn test_mul_matches_mod_mul_near_u64_max() {
+    // Largest odd moduli exercise the carry out of t + m·n in REDC
+    for n in [u64::MAX, u64::MAX - 2, 18446743979220271189, 3233] {
+        let context = MontgomeryContext::new(n).unwrap();
+        for (a, b) in [(n - 1, n - 1), (n - 1, 2), (123456789, 987654321 % n)] {
+            let product = context.mul(context.to_montgomery(a), context.to_montgomery(b));
+            assert_eq!(context.from_montgomery(product), mod_mul(a, b, n), "n={} a={} b={}", n, a, b);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use encryption_demo::common::random::DemoRng;
+use encryption_demo::rsa::oaep::{block_bytes, max_message, mgf1, oaep_decode, oaep_encode, oaep_encode_with_seed};
+use encryption_demo::sha256::hash::sha256_bytes;
+
+const N: u64 = 18446743979220271189; // 4294967291 × 4294967279
+
+#[test]
+fn test_block_size() {
+    assert_eq!(block_bytes(N), 7);
+    assert_eq!(block_bytes(1 << 40), 5);
+    assert_eq!(max_message(N), Some(0xff_ffff));
+    assert_eq!(max_message(1 << 40), Some(0xff));
+    assert_eq!(max_message(3233), None);
+}
+
+#[test]
+fn test_mgf1_matches_sha256_prefix() {
+    // The first block is SHA-256(seed ‖ 00000000)
+    let mask = mgf1(b"seed", 40);
+    assert_eq!(mask.len(), 40);
+    assert_eq!(mask[..32], sha256_bytes(b"seed\0\0\0\0"));
+}
+
+#[test]
+fn test_round_trip() {
+    let mut rng = DemoRng::new(3);
+    for message in [0, 1, 100, 0xff_ffff] {
+        let block = oaep_encode(message, N, &mut rng).unwrap();
+        assert!(block < N);
+        assert_eq!(oaep_decode(block, N), Ok(message));
+    }
+}
+
+#[test]
+fn test_encoding_is_randomised() {
+    assert_ne!(oaep_encode_with_seed(100, N, [0, 1]).unwrap(), oaep_encode_with_seed(100, N, [0, 2]).unwrap());
+}
+
+#[test]
+fn test_rejects_bad_input() {
+    let mut rng = DemoRng::new(3);
+    assert!(oaep_encode(0x100_0000, N, &mut rng).unwrap_err().contains("does not fit"));
+    assert!(oaep_encode(1, 3233, &mut rng).unwrap_err().contains("too small"));
+
+    // The seeded entry point checks sizes too rather than underflowing
+    assert!(oaep_encode_with_seed(1, 3233, [0, 0]).unwrap_err().contains("too small"));
+    assert!(oaep_encode_with_seed(0x100_0000, N, [0, 0]).unwrap_err().contains("does not fit"));
+
+    let block = oaep_encode_with_seed(100, N, [0x12, 0x34]).unwrap();
+    assert!(oaep_decode(block ^ 1, N).unwrap_err().contains("Padding check failed"));
+}
@@ -0,0 +1,54 @@
+use encryption_demo::rsa::openssh::{
+    decode_ssh_blob, encode_ssh_blob, openssh_fingerprint, public_key_from_openssh, public_key_to_openssh,
+};
+use encryption_demo::rsa::types::RsaPublicKey;
+
+fn demo_key() -> RsaPublicKey {
+    RsaPublicKey { n: 18446743979220271189, e: 3 }
+}
+
+#[test]
+fn test_openssh_line_matches_ssh_keygen() {
+    // `ssh-keygen -i -m PKCS8` on the SubjectPublicKeyInfo of the same key
+    assert_eq!(public_key_to_openssh(&demo_key(), None), "ssh-rsa AAAAB3NzaC1yc2EAAAABAwAAAAkA////6gAAAFU=");
+}
+
+#[test]
+fn test_mpint_adds_sign_byte() {
+    let blob = encode_ssh_blob(&demo_key());
+    // string "ssh-rsa", mpint e = 03, mpint n = 00 ff ff ff ea 00 00 00 55
+    assert_eq!(&blob[..11], b"\0\0\0\x07ssh-rsa");
+    assert_eq!(&blob[11..16], &[0, 0, 0, 1, 3]);
+    assert_eq!(&blob[16..20], &[0, 0, 0, 9]);
+    assert_eq!(blob[20], 0x00);
+    assert_eq!(decode_ssh_blob(&blob).unwrap().n, demo_key().n);
+}
+
+#[test]
+fn test_openssh_round_trip_with_comment() {
+    let line = public_key_to_openssh(&demo_key(), Some("alice@laptop"));
+    let (key, comment) = public_key_from_openssh(&line).unwrap();
+    assert_eq!((key.n, key.e), (demo_key().n, demo_key().e));
+    assert_eq!(comment.as_deref(), Some("alice@laptop"));
+}
+
+#[test]
+fn test_openssh_skips_authorized_keys_options() {
+    let line = "no-pty,from=\"10.0.0.1\" ssh-rsa AAAAB3NzaC1yc2EAAAABAwAAAAkA////6gAAAFU=";
+    let (key, comment) = public_key_from_openssh(line).unwrap();
+    assert_eq!(key.e, 3);
+    assert!(comment.is_none());
+}
+
+#[test]
+fn test_openssh_fingerprint() {
+    // SHA-256 of the blob, unpadded base64, checked with Python's hashlib
+    assert_eq!(openssh_fingerprint(&demo_key()), "SHA256:iUqMfqCpZSF6gTyIH2EMDUrdxA7N8QsXDBhFvTaWgVw");
+}
+
+#[test]
+fn test_openssh_rejects_other_key_types() {
+    assert!(public_key_from_openssh("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIA==").is_err());
+    // Blob claiming ssh-rsa on the line but holding another type inside
+    assert!(public_key_from_openssh("ssh-rsa AAAAC3NzaC1lZDI1NTE5AAAAIA==").is_err());
+}
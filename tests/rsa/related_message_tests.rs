@@ -0,0 +1,31 @@
+use encryption_demo::rsa::attacks::related_message::{
+    format_polynomial, franklin_reiter_attack, poly_gcd, related_message_scenario,
+};
+
+#[test]
+fn test_poly_gcd_common_root() {
+    // (x - 2)(x - 3) and (x - 2)(x - 5) over Z_101 share the factor x - 2
+    let a = vec![6, 96, 1];
+    let b = vec![10, 94, 1];
+    let (gcd, _) = poly_gcd(&a, &b, 101).unwrap();
+    assert_eq!(gcd, vec![99, 1]);
+}
+
+#[test]
+fn test_format_polynomial() {
+    assert_eq!(format_polynomial(&vec![7, 5, 0, 1]), "x³ + 5x + 7");
+    assert_eq!(format_polynomial(&vec![0]), "0");
+}
+
+#[test]
+fn test_scenario_requires_e3() {
+    assert!(related_message_scenario(42, 61, 53, 1, 1).is_none());
+}
+
+#[test]
+fn test_franklin_reiter_recovers_message() {
+    let message = 310939249775; // "Hello"
+    let s = related_message_scenario(message, 4294967291, 4294967279, 2, 7).unwrap();
+    let recovery = franklin_reiter_attack(s.public_key.n, s.alpha, s.beta, s.c1, s.c2).unwrap();
+    assert_eq!(recovery.message, message);
+}
@@ -0,0 +1,47 @@
+use encryption_demo::rsa::attacks::small_exponent::{broadcast_scenario, cube_root_attack, hastad_attack};
+use encryption_demo::rsa::encryption::encrypt;
+use encryption_demo::rsa::key_generation::generate_keypair;
+
+#[test]
+fn test_cube_root_attack_small_message() {
+    let keypair = generate_keypair(4294967291, 4294967279);
+    assert_eq!(keypair.public_key.e, 3);
+
+    let ciphertext = encrypt(18537, &keypair.public_key);
+    assert_eq!(cube_root_attack(ciphertext, &keypair.public_key), Some(18537));
+}
+
+#[test]
+fn test_cube_root_attack_fails_when_message_wraps() {
+    let keypair = generate_keypair(65537, 65543);
+    let ciphertext = encrypt(18537, &keypair.public_key);
+    assert!(cube_root_attack(ciphertext, &keypair.public_key).is_none());
+}
+
+#[test]
+fn test_broadcast_scenario_uses_e3() {
+    let recipients = broadcast_scenario(42, 3, 1000);
+    assert_eq!(recipients.len(), 3);
+    assert!(recipients.iter().all(|r| r.public_key.e == 3));
+}
+
+#[test]
+fn test_hastad_recovers_message() {
+    let message = 310939249775; // "Hello"
+    let recipients = broadcast_scenario(message, 3, 1_000_000);
+    let congruences: Vec<_> = recipients.iter().map(|r| (r.ciphertext, r.public_key.n)).collect();
+
+    let recovery = hastad_attack(&congruences, 3).unwrap();
+    assert_eq!(recovery.message, message);
+    assert_eq!(recovery.combined, (message as u128).pow(3));
+}
+
+#[test]
+fn test_hastad_needs_enough_ciphertexts() {
+    let message = 310939249775;
+    let recipients = broadcast_scenario(message, 2, 1_000_000);
+    let congruences: Vec<_> = recipients.iter().map(|r| (r.ciphertext, r.public_key.n)).collect();
+
+    // Two moduli are not enough: m³ > n₁n₂ so the CRT value is not a cube
+    assert!(hastad_attack(&congruences, 3).is_none());
+}
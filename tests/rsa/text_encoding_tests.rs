@@ -1,4 +1,4 @@
-use encryption_demo::rsa::text_encoding::{number_to_text, text_to_number};
+use encryption_demo::rsa::text_encoding::{number_to_text, parse_message, text_to_number};
 
 #[test]
 fn test_text_to_number_basic() {
@@ -44,3 +44,10 @@ fn test_text_encoding_case_sensitive() {
     let num2 = text_to_number("hi").unwrap();
     assert_ne!(num1, num2);
 }
+
+#[test]
+fn test_parse_message_number_or_text() {
+    assert_eq!(parse_message("42"), Some(42));
+    assert_eq!(parse_message("Hi"), text_to_number("Hi"));
+    assert!(parse_message("").is_none());
+}
@@ -0,0 +1,94 @@
+use encryption_demo::common::random::DemoRng;
+use encryption_demo::rsa::attacks::timing::{
+    attack_context, constant_time_mod_pow, leaky_mod_pow, recover_exponent, target_decrypt, TimingSample, TimingTarget,
+};
+use encryption_demo::rsa::encryption::encrypt;
+use encryption_demo::rsa::key_generation::generate_keypair;
+use encryption_demo::rsa::math_utils::mod_pow;
+use encryption_demo::rsa::montgomery::MontgomeryContext;
+
+/// Model of the leaky implementation's cost: a base time plus a penalty per extra subtraction
+fn modelled_nanos(context: &MontgomeryContext, ciphertext: u64, exp: u64) -> u64 {
+    let base = context.to_montgomery(ciphertext).0;
+    let mut result = base;
+    let mut extra = 0;
+    for i in (0..63 - exp.leading_zeros()).rev() {
+        let (square, flag) = context.redc_with_flag(result as u128 * result as u128);
+        result = square;
+        extra += u64::from(flag);
+        if exp >> i & 1 == 1 {
+            let (product, flag) = context.redc_with_flag(result as u128 * base as u128);
+            result = product;
+            extra += u64::from(flag);
+        }
+    }
+    500 + 40 * extra
+}
+
+#[test]
+fn test_attack_context_uses_smallest_r() {
+    let context = attack_context(3233).unwrap();
+    assert_eq!(context.r_bits, 12);
+    assert!(attack_context(3234).is_none());
+    assert!(MontgomeryContext::with_r_bits(3233, 11).is_none());
+}
+
+#[test]
+fn test_reduced_r_matches_mod_pow() {
+    let context = attack_context(4292870399).unwrap();
+    for (base, exp) in [(2, 1560996131), (4292870398, 65537), (12345, 1)] {
+        assert_eq!(leaky_mod_pow(&context, base, exp), mod_pow(base, exp, 4292870399));
+        assert_eq!(constant_time_mod_pow(&context, base, exp), mod_pow(base, exp, 4292870399));
+    }
+    assert_eq!(leaky_mod_pow(&context, 5, 0), 1);
+}
+
+#[test]
+fn test_every_target_decrypts_correctly() {
+    let key_pair = generate_keypair(65521, 65519);
+    let context = attack_context(key_pair.public_key.n).unwrap();
+    let mut rng = DemoRng::new(3);
+    let ciphertext = encrypt(424242, &key_pair.public_key);
+    for target in TimingTarget::ALL {
+        assert_eq!(target_decrypt(target, &context, &key_pair, ciphertext, &mut rng), 424242, "{}", target.name());
+    }
+}
+
+#[test]
+fn test_recovers_d_from_modelled_timings() {
+    let key_pair = generate_keypair(65521, 65519);
+    let n = key_pair.public_key.n;
+    let d = key_pair.private_key.d;
+    let context = attack_context(n).unwrap();
+    let mut rng = DemoRng::new(2024);
+
+    let samples: Vec<TimingSample> = (0..5_000)
+        .map(|_| {
+            let ciphertext = rng.below(n);
+            TimingSample { ciphertext, nanos: modelled_nanos(&context, ciphertext, d) }
+        })
+        .collect();
+
+    let result = recover_exponent(&samples, n, 64 - d.leading_zeros()).unwrap();
+    assert_eq!(result.recovered_d, d);
+    assert_eq!(result.guesses.len() as u32, 63 - d.leading_zeros());
+}
+
+#[test]
+fn test_constant_timings_reveal_nothing() {
+    let key_pair = generate_keypair(65521, 65519);
+    let d = key_pair.private_key.d;
+    let mut rng = DemoRng::new(5);
+    let samples: Vec<TimingSample> =
+        (0..1_000).map(|_| TimingSample { ciphertext: rng.below(key_pair.public_key.n), nanos: 900 }).collect();
+
+    let result = recover_exponent(&samples, key_pair.public_key.n, 64 - d.leading_zeros()).unwrap();
+    assert!(result.guesses.iter().all(|g| g.difference_if_one == 0.0 && g.difference_if_zero == 0.0));
+    assert_ne!(result.recovered_d, d);
+}
+
+#[test]
+fn test_recover_rejects_bad_input() {
+    assert!(recover_exponent(&[], 3234, 10).is_err());
+    assert!(recover_exponent(&[], 3233, 1).is_err());
+}
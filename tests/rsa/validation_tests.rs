@@ -1,5 +1,7 @@
-// Note: validation functions are pub(super) so we test them indirectly through the demo module
-use encryption_demo::rsa::demo::run_rsa_demo;
+// Note: prime validation functions are pub(super) so we test them indirectly through the demo module
+use encryption_demo::rsa::demo::{run_multi_prime_demo, run_rsa_demo};
+use encryption_demo::rsa::types::{RsaKeyPair, RsaPrivateKey, RsaPublicKey, Totient};
+use encryption_demo::rsa::validation::{exponent_warnings, validate_public_exponent};
 
 #[test]
 #[should_panic(expected = "must be prime")]
@@ -27,3 +29,55 @@ fn test_validation_valid_inputs() {
     // This should not panic
     run_rsa_demo(4, 3, 11);
 }
+
+#[test]
+#[should_panic(expected = "must be a prime number")]
+fn test_multi_prime_non_prime() {
+    run_multi_prime_demo("42", &[61, 53, 49], Totient::Euler);
+}
+
+#[test]
+#[should_panic(expected = "Primes must be different")]
+fn test_multi_prime_repeated_prime() {
+    run_multi_prime_demo("42", &[61, 53, 61], Totient::Euler);
+}
+
+#[test]
+#[should_panic(expected = "at least 2 primes")]
+fn test_multi_prime_single_prime() {
+    run_multi_prime_demo("42", &[61], Totient::Euler);
+}
+
+#[test]
+fn test_validate_public_exponent() {
+    assert_eq!(validate_public_exponent(7, 3120), Ok(()));
+    assert!(validate_public_exponent(1, 3120).unwrap_err().contains("greater than 1"));
+    assert!(validate_public_exponent(3121, 3120).unwrap_err().contains("smaller than φ(n)"));
+    assert!(validate_public_exponent(8, 3120).unwrap_err().contains("must be odd"));
+    assert!(validate_public_exponent(39, 3120).unwrap_err().contains("shares the factor 39"));
+}
+
+fn key_pair(n: u64, e: u64, d: u64) -> RsaKeyPair {
+    RsaKeyPair {
+        public_key: RsaPublicKey { n, e },
+        private_key: RsaPrivateKey { n, d },
+    }
+}
+
+#[test]
+fn test_exponent_warnings() {
+    // 4294967291 × 4294967279 with e = 65537: nothing to warn about
+    let n = 18446743979220271189;
+    assert!(exponent_warnings(&key_pair(n, 65537, 9331878932546167513)).is_empty());
+
+    let small = exponent_warnings(&key_pair(n, 3, 12297829313753557747));
+    assert_eq!(small.len(), 1);
+    assert!(small[0].contains("small"));
+
+    // 61 × 53: 1561² = 781 × 3120 + 1, so e = 1561 is its own inverse mod φ(n)
+    let same = exponent_warnings(&key_pair(3233, 1561, 1561));
+    assert!(same.iter().any(|w| w.contains("d = e")));
+
+    let wiener = exponent_warnings(&key_pair(n, 65537, 5));
+    assert!(wiener.iter().any(|w| w.contains("Wiener")));
+}
@@ -0,0 +1,55 @@
+use encryption_demo::rsa::attacks::wiener::{continued_fraction, convergents, generate_vulnerable_keypair, wiener_attack};
+use encryption_demo::rsa::math_utils::wiener_bound;
+use encryption_demo::rsa::types::RsaPublicKey;
+
+#[test]
+fn test_continued_fraction_basic() {
+    // 415/93 = [4; 2, 6, 7]
+    assert_eq!(continued_fraction(415, 93), vec![4, 2, 6, 7]);
+}
+
+#[test]
+fn test_convergents_basic() {
+    let result = convergents(&[4, 2, 6, 7]);
+    assert_eq!(result, vec![(4, 1), (9, 2), (58, 13), (415, 93)]);
+}
+
+#[test]
+fn test_vulnerable_keypair_has_small_d() {
+    let keypair = generate_vulnerable_keypair(65521, 65519).unwrap();
+    assert!(keypair.private_key.d <= wiener_bound(keypair.public_key.n));
+}
+
+#[test]
+fn test_vulnerable_keypair_primes_too_small() {
+    assert!(generate_vulnerable_keypair(61, 53).is_none());
+}
+
+#[test]
+fn test_wiener_recovers_private_key() {
+    let keypair = generate_vulnerable_keypair(4294967291, 4294967279).unwrap();
+    let result = wiener_attack(&keypair.public_key);
+    let recovery = result.recovery.unwrap();
+
+    assert_eq!(recovery.d, keypair.private_key.d);
+    assert_eq!(recovery.p as u128 * recovery.q as u128, keypair.public_key.n as u128);
+}
+
+#[test]
+fn test_wiener_fails_on_standard_key() {
+    // e = 17, d = 2753: far above the Wiener bound
+    let result = wiener_attack(&RsaPublicKey { n: 3233, e: 17 });
+    assert!(result.recovery.is_none());
+}
+
+#[test]
+fn test_wiener_handles_zero_exponent() {
+    // e·d - 1 would underflow on the first convergent
+    let result = wiener_attack(&RsaPublicKey { n: 3233, e: 0 });
+    assert!(result.recovery.is_none());
+}
+
+#[test]
+fn test_vulnerable_keypair_modulus_overflow() {
+    assert!(generate_vulnerable_keypair(4294967311, 4294967357).is_none());
+}
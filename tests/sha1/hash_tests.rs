@@ -0,0 +1,31 @@
+use encryption_demo::sha1::compression::round_function;
+use encryption_demo::sha1::hash::{sha1, sha1_bytes};
+
+#[test]
+fn test_sha1_fips_examples() {
+    assert_eq!(hex::encode(sha1_bytes(b"")), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+    assert_eq!(hex::encode(sha1_bytes(b"abc")), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(
+        hex::encode(sha1_bytes(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+        "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+    );
+}
+
+#[test]
+fn test_sha1_million_a() {
+    assert_eq!(hex::encode(sha1_bytes(&vec![b'a'; 1_000_000])), "34aa973cd4c4daa4f61eeb2bdbad27316534016f");
+}
+
+#[test]
+fn test_sha1_words() {
+    assert_eq!(sha1(b"abc"), [0xa9993e36, 0x4706816a, 0xba3e2571, 0x7850c26c, 0x9cd0d89d]);
+}
+
+#[test]
+fn test_round_function_stages() {
+    let (b, c, d) = (0xf0f0f0f0, 0xcccccccc, 0xaaaaaaaa);
+    assert_eq!(round_function(0, b, c, d), (b & c) | (!b & d));
+    assert_eq!(round_function(20, b, c, d), b ^ c ^ d);
+    assert_eq!(round_function(40, b, c, d), (b & c) | (b & d) | (c & d));
+    assert_eq!(round_function(79, b, c, d), b ^ c ^ d);
+}
@@ -0,0 +1 @@
+mod hash_tests;
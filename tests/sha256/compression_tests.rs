@@ -0,0 +1,33 @@
+use encryption_demo::sha256::compression::{compress_block, message_schedule, round_states};
+use encryption_demo::sha256::constants::INITIAL_HASH;
+use encryption_demo::sha256::preprocessing::preprocess_message;
+
+#[test]
+fn test_round_states_end_in_compression_output() {
+    let block = preprocess_message(b"abc").blocks[0];
+    let states = round_states(INITIAL_HASH, &block);
+    assert_eq!(states.len(), 64);
+
+    let last = states[63];
+    let fed_forward: Vec<u32> = INITIAL_HASH.iter().zip(last).map(|(h, v)| h.wrapping_add(v)).collect();
+    assert_eq!(fed_forward, compress_block(INITIAL_HASH, &block, false));
+}
+
+#[test]
+fn test_round_states_shift_registers() {
+    let block = preprocess_message(b"abc").blocks[0];
+    let states = round_states(INITIAL_HASH, &block);
+    for t in 1..64 {
+        assert_eq!(states[t][1..4], states[t - 1][0..3], "b, c, d after round {}", t + 1);
+        assert_eq!(states[t][5..8], states[t - 1][4..7], "f, g, h after round {}", t + 1);
+    }
+}
+
+#[test]
+fn test_message_schedule_starts_with_block() {
+    let block = preprocess_message(b"abc").blocks[0];
+    let w = message_schedule(&block);
+    assert_eq!(w[..16], block);
+    // FIPS 180-2 appendix B.1 example: W[16] for "abc"
+    assert_eq!(w[16], 0x61626380);
+}
@@ -0,0 +1,23 @@
+use encryption_demo::sha256::hash::sha256;
+use encryption_demo::sha256::length_extension::{extend, glue_padding};
+
+#[test]
+fn test_forged_digest_matches_real_hash() {
+    let secret_and_message = b"secret||user=alice";
+    for extension in [b"".as_slice(), b"&admin=true", &[b'x'; 100]] {
+        let (glue, forged) = extend(sha256(secret_and_message), secret_and_message.len(), extension);
+        let full = [secret_and_message.as_slice(), &glue, extension].concat();
+        assert_eq!(forged, sha256(&full), "extension of {} bytes", extension.len());
+    }
+}
+
+#[test]
+fn test_glue_padding() {
+    let glue = glue_padding(3);
+    assert_eq!(glue.len(), 61);
+    assert_eq!(glue[0], 0x80);
+    assert_eq!(glue[53..], 24u64.to_be_bytes());
+
+    // A 56-byte message pushes the length into a second block
+    assert_eq!(glue_padding(56).len(), 72);
+}
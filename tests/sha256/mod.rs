@@ -0,0 +1,5 @@
+mod compression_tests;
+mod hmac_tests;
+mod length_extension_tests;
+mod preprocessing_tests;
+mod reduced_tests;
@@ -0,0 +1,30 @@
+use encryption_demo::sha256::preprocessing::{pad_message, preprocess_message, LengthEncoding};
+
+#[test]
+fn test_pad_message_length_encoding() {
+    let big = pad_message(b"abc", LengthEncoding::BigEndian);
+    let little = pad_message(b"abc", LengthEncoding::LittleEndian);
+
+    assert_eq!(big.len(), 64);
+    assert_eq!(&big[..4], b"abc\x80");
+    assert_eq!(big[..56], little[..56]);
+    assert_eq!(big[56..], 24u64.to_be_bytes());
+    assert_eq!(little[56..], 24u64.to_le_bytes());
+}
+
+#[test]
+fn test_pad_message_block_boundaries() {
+    // 55 bytes fit with the 0x80 and length; 56 bytes spill into a second block
+    assert_eq!(pad_message(&[0; 55], LengthEncoding::BigEndian).len(), 64);
+    assert_eq!(pad_message(&[0; 56], LengthEncoding::BigEndian).len(), 128);
+    assert_eq!(pad_message(&[0; 64], LengthEncoding::LittleEndian).len(), 128);
+}
+
+#[test]
+fn test_preprocess_message_uses_big_endian_words() {
+    let processed = preprocess_message(b"abc");
+    assert_eq!(processed.blocks.len(), 1);
+    assert_eq!(processed.blocks[0][0], 0x61626380);
+    assert_eq!(processed.blocks[0][15], 24);
+    assert_eq!(processed.original_length, 3);
+}
@@ -0,0 +1,71 @@
+use encryption_demo::sha256::compression::round_states;
+use encryption_demo::sha256::constants::{INITIAL_HASH, ROUND_CONSTANTS};
+use encryption_demo::sha256::hash::{sha256, sha256_bytes};
+use encryption_demo::sha256::preprocessing::preprocess_message;
+use encryption_demo::sha256::reduced::{parse_initial_hash, parse_word, NonStandardSha256, FULL_ROUNDS};
+
+#[test]
+fn test_full_rounds_with_defaults_is_sha256() {
+    let variant = NonStandardSha256::new(FULL_ROUNDS).unwrap();
+    assert!(variant.is_standard());
+    assert_eq!(variant.describe(), "SHA-256 (standard parameters)");
+    for message in [&b"abc"[..], b"", &[0x5a; 200]] {
+        assert_eq!(variant.hash(message), sha256(message));
+        assert_eq!(variant.hash_bytes(message), sha256_bytes(message));
+    }
+}
+
+#[test]
+fn test_round_count_bounds() {
+    assert!(NonStandardSha256::new(0).is_err());
+    assert!(NonStandardSha256::new(FULL_ROUNDS + 1).is_err());
+    assert!(NonStandardSha256::new(1).is_ok());
+}
+
+#[test]
+fn test_round_output_matches_round_states() {
+    let block = preprocess_message(b"abc").blocks[0];
+    let states = round_states(INITIAL_HASH, &block);
+    for rounds in [1, 4, 17, 63] {
+        let variant = NonStandardSha256::new(rounds).unwrap();
+        assert_eq!(variant.round_output(INITIAL_HASH, &block), states[rounds - 1]);
+    }
+}
+
+#[test]
+fn test_reduced_rounds_are_flagged_and_differ() {
+    let variant = NonStandardSha256::new(8).unwrap();
+    assert!(!variant.is_standard());
+    assert_eq!(variant.describe(), "NON-STANDARD SHA-256 variant: 8 of 64 rounds");
+    assert_ne!(variant.hash(b"abc"), sha256(b"abc"));
+}
+
+#[test]
+fn test_substitutions_are_flagged() {
+    let variant = NonStandardSha256::new(FULL_ROUNDS)
+        .unwrap()
+        .with_initial_hash([0; 8])
+        .with_round_constants(&[0, 1])
+        .unwrap();
+    assert!(!variant.is_standard());
+    assert_eq!(variant.describe(), "NON-STANDARD SHA-256 variant: custom IV, 2 custom round constant(s)");
+    assert_eq!(variant.round_constants[2..], ROUND_CONSTANTS[2..]);
+    assert_ne!(variant.hash(b"abc"), sha256(b"abc"));
+
+    // Writing the standard values back is still SHA-256
+    let same = NonStandardSha256::new(FULL_ROUNDS).unwrap().with_round_constants(&ROUND_CONSTANTS[..3]).unwrap();
+    assert!(same.is_standard());
+    assert!(NonStandardSha256::new(4).unwrap().with_round_constants(&[0; 65]).is_err());
+}
+
+#[test]
+fn test_parse_words_and_iv() {
+    assert_eq!(parse_word("0x428a2f98"), Ok(0x428a2f98));
+    assert_eq!(parse_word("ff"), Ok(0xff));
+    assert!(parse_word("123456789").is_err());
+    assert!(parse_word("xyz").is_err());
+
+    let iv = parse_initial_hash("6a09e667bb67ae853c6ef372a54ff53a510e527f9b05688c1f83d9ab5be0cd19").unwrap();
+    assert_eq!(iv, INITIAL_HASH);
+    assert!(parse_initial_hash("6a09e667").is_err());
+}
@@ -0,0 +1,71 @@
+use encryption_demo::sha3::hash::{sha3_224, sha3_256, sha3_384, sha3_512, shake128, shake256};
+use encryption_demo::sha3::types::Sha3Variant;
+
+#[test]
+fn test_sha3_fips202_abc() {
+    assert_eq!(hex::encode(sha3_224(b"abc")), "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf");
+    assert_eq!(
+        hex::encode(sha3_256(b"abc")),
+        "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+    );
+    assert_eq!(
+        hex::encode(sha3_384(b"abc")),
+        "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+    );
+    assert_eq!(
+        hex::encode(sha3_512(b"abc")),
+        "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+         10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+    );
+}
+
+#[test]
+fn test_sha3_empty_message() {
+    assert_eq!(
+        hex::encode(sha3_256(b"")),
+        "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+    );
+    assert_eq!(
+        hex::encode(shake128(b"", 32)),
+        "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+    );
+    assert_eq!(
+        hex::encode(shake256(b"", 64)),
+        "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+         d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+    );
+}
+
+#[test]
+fn test_rate_boundary() {
+    // 135 bytes leave one padding byte (0x86); 136 bytes need a whole extra block
+    assert_eq!(
+        hex::encode(sha3_256(&[b'a'; 135])),
+        "8094bb53c44cfb1e67b7c30447f9a1c33696d2463ecc1d9c92538913392843c9"
+    );
+    assert_eq!(
+        hex::encode(sha3_256(&[b'a'; 136])),
+        "3fc5559f14db8e453a0a3091edbd2bc25e11528d81c66fa570a4efdcc2695ee1"
+    );
+}
+
+#[test]
+fn test_shake_output_is_a_prefix_stream() {
+    // 400 bytes need three squeezes of the 168-byte SHAKE128 rate
+    let long = shake128(b"abc", 400);
+    assert_eq!(long.len(), 400);
+    assert_eq!(hex::encode(&long[380..]), "47b4a0586ee173e30bd4d08f2bc59c6114bdd745");
+    assert_eq!(shake128(b"abc", 20), long[..20]);
+}
+
+#[test]
+fn test_variant_parameters() {
+    assert_eq!(Sha3Variant::Sha3_256.rate_bytes(), 136);
+    assert_eq!(Sha3Variant::Sha3_512.rate_bytes(), 72);
+    assert_eq!(Sha3Variant::Shake128.rate_bytes(), 168);
+    assert_eq!(Sha3Variant::Sha3_224.digest_bytes(), Some(28));
+    assert_eq!(Sha3Variant::Shake256.digest_bytes(), None);
+    assert_eq!("SHA3-384".parse::<Sha3Variant>(), Ok(Sha3Variant::Sha3_384));
+    assert_eq!("shake_256".parse::<Sha3Variant>(), Ok(Sha3Variant::Shake256));
+    assert!("sha3-1024".parse::<Sha3Variant>().is_err());
+}
@@ -0,0 +1,51 @@
+use encryption_demo::sha3::keccak::{chi, iota, keccak_f, pi, rho, theta};
+use encryption_demo::sha3::types::State;
+
+#[test]
+fn test_keccak_f_zero_state() {
+    // Keccak team's KeccakF-1600 intermediate values: permutation of the all-zero state
+    let mut state: State = [0; 25];
+    keccak_f(&mut state, false);
+    assert_eq!(state[0], 0xf1258f7940e1dde7);
+    assert_eq!(state[1], 0x84d5ccf933c0478a);
+    assert_eq!(state[24], 0xeaf1ff7b5ceca249);
+}
+
+#[test]
+fn test_theta_spreads_one_bit_to_two_columns() {
+    let mut state: State = [0; 25];
+    state[0] = 1;
+    theta(&mut state);
+    // Column x = 0 keeps the bit; columns 1 and 4 receive it in every row
+    assert_eq!(state[0], 1);
+    for y in 0..5 {
+        assert_eq!(state[4 + 5 * y], 2, "x=4, y={}", y);
+        assert_eq!(state[1 + 5 * y], 1, "x=1, y={}", y);
+        assert_eq!(state[2 + 5 * y], 0);
+    }
+}
+
+#[test]
+fn test_rho_and_pi_move_lanes() {
+    let mut state: State = [0; 25];
+    state[1] = 1; // lane (1, 0)
+    rho(&mut state);
+    assert_eq!(state[1], 2);
+    pi(&mut state);
+    // (x, y) = (1, 0) moves to (0, 2)
+    assert_eq!(state[10], 2);
+    assert_eq!(state.iter().filter(|&&lane| lane != 0).count(), 1);
+}
+
+#[test]
+fn test_chi_and_iota() {
+    let mut state: State = [0; 25];
+    state[2] = u64::MAX;
+    chi(&mut state);
+    // Row y = 0: a[x] ^= !a[x+1] & a[x+2], so lanes 0 and 2 are set
+    assert_eq!(state[..5], [u64::MAX, 0, u64::MAX, 0, 0]);
+
+    let mut state: State = [0; 25];
+    iota(&mut state, 1);
+    assert_eq!(state[0], 0x8082);
+}
@@ -0,0 +1,3 @@
+mod hash_tests;
+mod keccak_tests;
+mod sponge_tests;
@@ -0,0 +1,24 @@
+use encryption_demo::sha3::sponge::{absorb_block, pad, squeeze_block};
+use encryption_demo::sha3::types::State;
+
+#[test]
+fn test_pad_domain_suffix_and_final_bit() {
+    let padded = pad(b"abc", 136, 0x06);
+    assert_eq!(padded.len(), 136);
+    assert_eq!(padded[3], 0x06);
+    assert_eq!(padded[135], 0x80);
+
+    // One byte left: suffix and final bit share it
+    let padded = pad(&[0; 135], 136, 0x1f);
+    assert_eq!(padded.len(), 136);
+    assert_eq!(padded[135], 0x9f);
+}
+
+#[test]
+fn test_absorb_and_squeeze_are_little_endian() {
+    let mut state: State = [0; 25];
+    absorb_block(&mut state, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(state[0], 0x0807060504030201);
+    assert_eq!(state[1], 9);
+    assert_eq!(squeeze_block(&state, 16), [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0, 0, 0, 0]);
+}
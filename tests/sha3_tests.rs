@@ -0,0 +1 @@
+mod sha3;
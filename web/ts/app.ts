@@ -1,4 +1,4 @@
-import { initWasm, runRsaDemo, runSha256Demo } from './demos.ts';
+import { initWasm, runRsaDemo, runRsaAttackDemo, runSha256Demo, runMerkleDemo, runAvalancheDemo } from './demos.ts';
 import { getElement, getElements, onEnterKey, onEnterKeyUnlessShift } from './utils.ts';
 
 const switchTab = (event: Event): void => {
@@ -23,12 +23,22 @@ const setupEventListeners = (): void => {
     getElements('.tab-button').forEach(btn => btn.addEventListener('click', switchTab));
 
     registerClickHandler('rsa-run-btn', () => void runRsaDemo());
+    registerClickHandler('attack-run-btn', () => void runRsaAttackDemo());
     registerClickHandler('sha256-run-btn', () => void runSha256Demo());
+    registerClickHandler('merkle-run-btn', () => void runMerkleDemo());
+    registerClickHandler('avalanche-run-btn', () => void runAvalancheDemo());
 
     ['rsa-message', 'rsa-p', 'rsa-q'].forEach(id =>
         registerEnterKeyHandler(id, () => void runRsaDemo())
     );
+    ['attack-message', 'attack-p', 'attack-q', 'attack-alpha', 'attack-beta'].forEach(id =>
+        registerEnterKeyHandler(id, () => void runRsaAttackDemo())
+    );
     registerEnterKeyHandler('sha256-message', () => void runSha256Demo(), true);
+    registerEnterKeyHandler('merkle-prove', () => void runMerkleDemo());
+    ['avalanche-message', 'avalanche-samples'].forEach(id =>
+        registerEnterKeyHandler(id, () => void runAvalancheDemo())
+    );
 };
 
 const init = async (): Promise<void> => {
@@ -1,6 +1,6 @@
-import { initWasm as initWasmModule, isWasmReady, rsaDemoText, rsaDemoNumber, sha256DemoText } from './wasm.ts';
-import { renderRsaResults, renderSha256Results, renderError, renderLoading } from './ui.ts';
-import type { RsaResult, Sha256Result } from './ui.ts';
+import { initWasm as initWasmModule, isWasmReady, rsaDemoText, rsaDemoNumber, rsaCommonModulusDemo, rsaFranklinReiterDemo, sha256DemoText, merkleDemo, avalancheDemo } from './wasm.ts';
+import { renderRsaResults, renderRsaAttackResults, renderSha256Results, renderMerkleResults, renderAvalancheResults, renderError, renderLoading } from './ui.ts';
+import type { RsaResult, RsaAttackResult, Sha256Result, MerkleResult, AvalancheResult } from './ui.ts';
 import { getElement, getTrimmedValue, getValue, parseIntSafe, isNumericString, validateNonEmpty, validateNumbers, parseResult } from './utils.ts';
 import type { ValidationResult, ParsedResult } from './utils.ts';
 
@@ -10,6 +10,17 @@ interface RsaInputs {
     readonly message: string;
     readonly p: number | null;
     readonly q: number | null;
+    readonly e: string;
+    readonly resultsDiv: HTMLElement | null;
+}
+
+interface RsaAttackInputs {
+    readonly attack: string;
+    readonly message: string;
+    readonly p: number | null;
+    readonly q: number | null;
+    readonly alpha: number | null;
+    readonly beta: number | null;
     readonly resultsDiv: HTMLElement | null;
 }
 
@@ -18,6 +29,19 @@ interface Sha256Inputs {
     readonly resultsDiv: HTMLElement | null;
 }
 
+interface MerkleInputs {
+    readonly leaves: string;
+    readonly prove: number | null;
+    readonly scheme: string;
+    readonly resultsDiv: HTMLElement | null;
+}
+
+interface AvalancheInputs {
+    readonly message: string;
+    readonly samples: number | null;
+    readonly resultsDiv: HTMLElement | null;
+}
+
 interface DemoConfig<TInputs, TResult> {
     readonly getInputs: () => TInputs;
     readonly validate: (inputs: TInputs) => ValidationResult;
@@ -30,35 +54,88 @@ const getRsaInputs = (): RsaInputs => ({
     message: getTrimmedValue('rsa-message'),
     p: parseIntSafe(getValue('rsa-p')),
     q: parseIntSafe(getValue('rsa-q')),
+    e: getTrimmedValue('rsa-e'),
     resultsDiv: getElement('rsa-results')
 });
 
+const getRsaAttackInputs = (): RsaAttackInputs => ({
+    attack: getValue('attack-type'),
+    message: getTrimmedValue('attack-message'),
+    p: parseIntSafe(getValue('attack-p')),
+    q: parseIntSafe(getValue('attack-q')),
+    alpha: parseIntSafe(getValue('attack-alpha')),
+    beta: parseIntSafe(getValue('attack-beta')),
+    resultsDiv: getElement('attack-results')
+});
+
 const getSha256Inputs = (): Sha256Inputs => ({
     message: getValue('sha256-message'),
     resultsDiv: getElement('sha256-results')
 });
 
+const getMerkleInputs = (): MerkleInputs => ({
+    leaves: getTrimmedValue('merkle-leaves'),
+    prove: parseIntSafe(getValue('merkle-prove')),
+    scheme: getValue('merkle-scheme'),
+    resultsDiv: getElement('merkle-results')
+});
+
+const getAvalancheInputs = (): AvalancheInputs => ({
+    message: getValue('avalanche-message'),
+    samples: parseIntSafe(getValue('avalanche-samples')),
+    resultsDiv: getElement('avalanche-results')
+});
+
 const validateRsaInputs = ({ message, p, q }: RsaInputs): ValidationResult => {
     const messageCheck = validateNonEmpty(message, 'a message');
     if (!messageCheck.valid) return messageCheck;
     return validateNumbers(p, q);
 };
 
+const validateRsaAttackInputs = ({ message, p, q }: RsaAttackInputs): ValidationResult => {
+    const messageCheck = validateNonEmpty(message, 'a message');
+    if (!messageCheck.valid) return messageCheck;
+    return validateNumbers(p, q);
+};
+
 const validateSha256Inputs = ({ message }: Sha256Inputs): ValidationResult =>
     validateNonEmpty(message, 'a message');
 
-const computeRsa = (message: string, p: number, q: number): ParsedResult<RsaResult> => {
+const validateMerkleInputs = ({ leaves }: MerkleInputs): ValidationResult =>
+    validateNonEmpty(leaves, 'at least one leaf');
+
+const validateAvalancheInputs = ({ message }: AvalancheInputs): ValidationResult =>
+    validateNonEmpty(message, 'a message');
+
+const computeRsa = (message: string, p: number, q: number, e: string): ParsedResult<RsaResult> => {
     const resultJson = isNumericString(message)
-        ? rsaDemoNumber(BigInt(message), BigInt(p), BigInt(q))
-        : rsaDemoText(message, BigInt(p), BigInt(q));
+        ? rsaDemoNumber(BigInt(message), BigInt(p), BigInt(q), e)
+        : rsaDemoText(message, BigInt(p), BigInt(q), e);
     return parseResult<RsaResult>(resultJson);
 };
 
+const computeRsaAttack = ({ attack, message, p, q, alpha, beta }: RsaAttackInputs): ParsedResult<RsaAttackResult> => {
+    const resultJson = attack === 'franklin-reiter'
+        ? rsaFranklinReiterDemo(message, BigInt(p!), BigInt(q!), BigInt(alpha ?? 1), BigInt(beta ?? 1))
+        : rsaCommonModulusDemo(message, BigInt(p!), BigInt(q!));
+    return parseResult<RsaAttackResult>(resultJson);
+};
+
 const computeSha256 = (message: string): ParsedResult<Sha256Result> => {
     const resultJson = sha256DemoText(message);
     return parseResult<Sha256Result>(resultJson);
 };
 
+const computeMerkle = ({ leaves, prove, scheme }: MerkleInputs): ParsedResult<MerkleResult> => {
+    const resultJson = merkleDemo(leaves, prove === null || prove < 0 ? undefined : prove, scheme);
+    return parseResult<MerkleResult>(resultJson);
+};
+
+const computeAvalanche = ({ message, samples }: AvalancheInputs): ParsedResult<AvalancheResult> => {
+    const resultJson = avalancheDemo(message, samples ?? 16);
+    return parseResult<AvalancheResult>(resultJson);
+};
+
 const runDemo = async <TInputs extends { resultsDiv: HTMLElement | null }, TResult>({
     getInputs,
     validate,
@@ -103,11 +180,19 @@ const runDemo = async <TInputs extends { resultsDiv: HTMLElement | null }, TResu
 export const runRsaDemo = (): Promise<void> => runDemo<RsaInputs, RsaResult>({
     getInputs: getRsaInputs,
     validate: validateRsaInputs,
-    compute: ({ message, p, q }) => computeRsa(message, p!, q!),
+    compute: ({ message, p, q, e }) => computeRsa(message, p!, q!, e),
     renderSuccess: renderRsaResults,
     loadingMessage: 'Processing...'
 });
 
+export const runRsaAttackDemo = (): Promise<void> => runDemo<RsaAttackInputs, RsaAttackResult>({
+    getInputs: getRsaAttackInputs,
+    validate: validateRsaAttackInputs,
+    compute: computeRsaAttack,
+    renderSuccess: renderRsaAttackResults,
+    loadingMessage: 'Attacking...'
+});
+
 export const runSha256Demo = (): Promise<void> => runDemo<Sha256Inputs, Sha256Result>({
     getInputs: getSha256Inputs,
     validate: validateSha256Inputs,
@@ -116,3 +201,18 @@ export const runSha256Demo = (): Promise<void> => runDemo<Sha256Inputs, Sha256Re
     loadingMessage: 'Computing hash...'
 });
 
+export const runMerkleDemo = (): Promise<void> => runDemo<MerkleInputs, MerkleResult>({
+    getInputs: getMerkleInputs,
+    validate: validateMerkleInputs,
+    compute: computeMerkle,
+    renderSuccess: renderMerkleResults,
+    loadingMessage: 'Building tree...'
+});
+
+export const runAvalancheDemo = (): Promise<void> => runDemo<AvalancheInputs, AvalancheResult>({
+    getInputs: getAvalancheInputs,
+    validate: validateAvalancheInputs,
+    compute: computeAvalanche,
+    renderSuccess: renderAvalancheResults,
+    loadingMessage: 'Flipping bits...'
+});
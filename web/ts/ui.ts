@@ -21,6 +21,16 @@ export interface RsaResult {
     readonly success: boolean;
     readonly original_message: string;
     readonly decrypted_text?: string;
+    readonly warnings: readonly string[];
+}
+
+export interface RsaAttackResult {
+    readonly attack: string;
+    readonly steps: readonly RsaStep[];
+    readonly success: boolean;
+    readonly original_message: string;
+    readonly recovered_message?: number;
+    readonly recovered_text?: string;
 }
 
 export interface Sha256Step {
@@ -35,6 +45,34 @@ export interface Sha256Result {
     readonly steps: readonly Sha256Step[];
 }
 
+export interface MerkleTreeNode {
+    readonly depth: number;
+    readonly label: string;
+    readonly hash: string;
+    readonly role: '' | 'proven' | 'path' | 'sibling';
+}
+
+export interface MerkleResult {
+    readonly scheme: string;
+    readonly leaf_count: number;
+    readonly root: string;
+    readonly nodes: readonly MerkleTreeNode[];
+    readonly proof: readonly string[];
+    readonly verified?: boolean;
+    readonly steps: readonly Sha256Step[];
+}
+
+export interface AvalancheResult {
+    readonly input_bits: number;
+    readonly trials: number;
+    readonly mean_distance: number;
+    readonly heatmap: readonly (readonly number[])[];
+    readonly heatmap_row_bits: number;
+    readonly heatmap_column_bits: number;
+    readonly round_mean: readonly number[];
+    readonly steps: readonly Sha256Step[];
+}
+
 const renderStepCard = (stepNumber: number, title: string, description: string, content: string): string => `
     <div class="step-card">
         <div class="step-title">
@@ -74,19 +112,64 @@ const renderSha256Step = ({ step_number, title, description, data = [] }: Sha256
     return renderStepCard(step_number, title, description, dataHtml);
 };
 
+const merkleRoleMarkers: Readonly<Record<MerkleTreeNode['role'], string>> = {
+    '': '',
+    proven: ' ◀ proven leaf',
+    path: ' ● path',
+    sibling: ' ◆ proof'
+};
+
+const renderMerkleNode = ({ depth, label, hash, role }: MerkleTreeNode): string => `
+    <div class="merkle-node ${role ? `merkle-${role}` : ''}" style="padding-left: ${depth * 1.5}rem;">
+        ${label} = ${hash.slice(0, 16)}…${merkleRoleMarkers[role]}
+    </div>
+`;
+
+// Blue when a bit flips too rarely, red when too often, dark at the ideal ½
+const renderHeatCell = (probability: number): string => {
+    const bias = Math.min(Math.abs(probability - 0.5) * 2, 1);
+    const hue = probability < 0.5 ? 210 : 0;
+    return `<div class="heatmap-cell" style="background: hsl(${hue}, 80%, ${10 + bias * 50}%);" title="p = ${probability.toFixed(3)}"></div>`;
+};
+
+const renderHeatmap = (heatmap: AvalancheResult['heatmap'], rowBits: number, columnBits: number): string => `
+    <div class="heatmap-caption">Rows: ${rowBits} input bit(s) each · Columns: ${columnBits} output bits each · Bright = far from ½</div>
+    <div class="heatmap">
+        ${heatmap.map(row => `<div class="heatmap-row">${row.map(renderHeatCell).join('')}</div>`).join('')}
+    </div>
+`;
+
+const renderRoundBars = (roundMean: readonly number[]): string => `
+    <div class="heatmap-caption">State bits differing after each of the ${roundMean.length} rounds (128 = fully mixed)</div>
+    <div class="round-bars">
+        ${roundMean.map((bits, round) => `<div class="round-bar" style="height: ${(bits / 256) * 100}%;" title="Round ${round + 1}: ${bits.toFixed(1)} bits"></div>`).join('')}
+    </div>
+`;
+
 const renderMessage = (className: string, icon: string, message: string): string =>
     `<div class="${className}">${icon} ${message}</div>`;
 
 
-export const buildRsaResultsHtml = ({ key_pair, steps, success, original_message, decrypted_text }: RsaResult): string => {
+export const buildRsaResultsHtml = ({ key_pair, steps, success, original_message, decrypted_text, warnings }: RsaResult): string => {
     const keyPairHtml = key_pair ? renderKeyPairDisplay(key_pair) : '';
+    const warningsHtml = warnings.map(warning => renderMessage('warning-message', '⚠️', warning)).join('');
     const stepsHtml = steps.map(renderRsaStep).join('');
     const successHtml = success
         ? renderMessage('success-message', '✅',
             `Success! Original message "${original_message}" was encrypted and decrypted correctly.` +
             (decrypted_text ? `<br>Decrypted text: "${decrypted_text}"` : ''))
         : '';
-    return keyPairHtml + stepsHtml + successHtml;
+    return keyPairHtml + warningsHtml + stepsHtml + successHtml;
+};
+
+export const buildRsaAttackResultsHtml = ({ attack, steps, success, original_message, recovered_text }: RsaAttackResult): string => {
+    const stepsHtml = steps.map(renderRsaStep).join('');
+    const outcomeHtml = success
+        ? renderMessage('success-message', '🕵️',
+            `${attack} attack recovered "${original_message}" without the private key.` +
+            (recovered_text ? `<br>Recovered text: "${recovered_text}"` : ''))
+        : renderMessage('error-message', '❌', `${attack} attack did not recover the message.`);
+    return stepsHtml + outcomeHtml;
 };
 
 export const buildSha256ResultsHtml = ({ hash, steps }: Sha256Result): string => {
@@ -96,6 +179,29 @@ export const buildSha256ResultsHtml = ({ hash, steps }: Sha256Result): string =>
     return hashHtml + stepsHtml + successHtml;
 };
 
+export const buildMerkleResultsHtml = ({ scheme, leaf_count, root, nodes, verified, steps }: MerkleResult): string => {
+    const rootHtml = `<div class="hash-result"><strong>Merkle Root (${scheme}, ${leaf_count} leaves):</strong><br>${root}</div>`;
+    const treeHtml = nodes.length > 0 ? `<div class="merkle-tree">${nodes.map(renderMerkleNode).join('')}</div>` : '';
+    const stepsHtml = steps.map(renderSha256Step).join('');
+    const outcomeHtml = verified === undefined
+        ? ''
+        : verified
+            ? renderMessage('success-message', '✅', 'Inclusion proof verified against the root!')
+            : renderMessage('error-message', '❌', 'Inclusion proof did not match the root.');
+    return rootHtml + treeHtml + stepsHtml + outcomeHtml;
+};
+
+export const buildAvalancheResultsHtml = ({ input_bits, trials, mean_distance, heatmap, heatmap_row_bits, heatmap_column_bits, round_mean, steps }: AvalancheResult): string => {
+    const summaryHtml = `<div class="hash-result"><strong>Mean Hamming distance:</strong> ${mean_distance.toFixed(2)} of 256 bits over ${trials} flips of ${input_bits} input bits</div>`;
+    const heatmapHtml = renderHeatmap(heatmap, heatmap_row_bits, heatmap_column_bits);
+    const roundsHtml = renderRoundBars(round_mean);
+    const stepsHtml = steps.map(renderSha256Step).join('');
+    const outcomeHtml = Math.abs(mean_distance - 128) < 4
+        ? renderMessage('success-message', '✅', 'About half the output bits flip for every input bit: a strong avalanche effect.')
+        : renderMessage('warning-message', '⚠️', 'The mean distance is far from 128 bits; try more samples.');
+    return summaryHtml + heatmapHtml + roundsHtml + stepsHtml + outcomeHtml;
+};
+
 export const buildErrorHtml = (message: string): string =>
     renderMessage('error-message', '❌', message);
 
@@ -111,9 +217,18 @@ export const renderToContainer = (container: HTMLElement, html: string): void =>
 export const renderRsaResults = (container: HTMLElement, result: RsaResult): void =>
     renderToContainer(container, buildRsaResultsHtml(result));
 
+export const renderRsaAttackResults = (container: HTMLElement, result: RsaAttackResult): void =>
+    renderToContainer(container, buildRsaAttackResultsHtml(result));
+
 export const renderSha256Results = (container: HTMLElement, result: Sha256Result): void =>
     renderToContainer(container, buildSha256ResultsHtml(result));
 
+export const renderMerkleResults = (container: HTMLElement, result: MerkleResult): void =>
+    renderToContainer(container, buildMerkleResultsHtml(result));
+
+export const renderAvalancheResults = (container: HTMLElement, result: AvalancheResult): void =>
+    renderToContainer(container, buildAvalancheResultsHtml(result));
+
 export const renderError = (container: HTMLElement, message: string): void =>
     renderToContainer(container, buildErrorHtml(message));
 
@@ -4,9 +4,13 @@ import type { InitInput } from '../pkg';
 
 interface WasmModule {
     readonly default: (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>) => Promise<unknown>;
-    readonly rsa_demo_text: (text: string, p: bigint, q: bigint) => string;
-    readonly rsa_demo_number: (message: bigint, p: bigint, q: bigint) => string;
+    readonly rsa_demo_text: (text: string, p: bigint, q: bigint, e: string) => string;
+    readonly rsa_demo_number: (message: bigint, p: bigint, q: bigint, e: string) => string;
+    readonly rsa_common_modulus_demo: (message: string, p: bigint, q: bigint) => string;
+    readonly rsa_franklin_reiter_demo: (message: string, p: bigint, q: bigint, alpha: bigint, beta: bigint) => string;
     readonly sha256_demo_text: (text: string) => string;
+    readonly merkle_demo: (leavesText: string, prove: number | undefined, scheme: string) => string;
+    readonly avalanche_demo: (text: string, samples: number) => string;
 }
 
 interface WasmState {
@@ -34,12 +38,24 @@ const wasmState = createWasmState();
 
 export const isWasmReady = (): boolean => wasmState.isReady();
 
-export const rsaDemoText = (text: string, p: bigint, q: bigint): string => wasmState.get().rsa_demo_text(text, p, q);
+export const rsaDemoText = (text: string, p: bigint, q: bigint, e: string): string => wasmState.get().rsa_demo_text(text, p, q, e);
 
-export const rsaDemoNumber = (message: bigint, p: bigint, q: bigint): string => wasmState.get().rsa_demo_number(message, p, q);
+export const rsaDemoNumber = (message: bigint, p: bigint, q: bigint, e: string): string =>
+    wasmState.get().rsa_demo_number(message, p, q, e);
+
+export const rsaCommonModulusDemo = (message: string, p: bigint, q: bigint): string =>
+    wasmState.get().rsa_common_modulus_demo(message, p, q);
+
+export const rsaFranklinReiterDemo = (message: string, p: bigint, q: bigint, alpha: bigint, beta: bigint): string =>
+    wasmState.get().rsa_franklin_reiter_demo(message, p, q, alpha, beta);
 
 export const sha256DemoText = (text: string): string => wasmState.get().sha256_demo_text(text);
 
+export const merkleDemo = (leavesText: string, prove: number | undefined, scheme: string): string =>
+    wasmState.get().merkle_demo(leavesText, prove, scheme);
+
+export const avalancheDemo = (text: string, samples: number): string => wasmState.get().avalanche_demo(text, samples);
+
 export const initWasm = async (): Promise<boolean> => {
     try {
         const wasm = await import('../pkg/encryption_demo.js') as WasmModule;